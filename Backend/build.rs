@@ -0,0 +1,29 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+
+const PATCHES_DIR: &str = "../Database/patches";
+
+// Derives EXPECTED_SCHEMA_VERSION from the latest patch bundled with this code
+fn main() {
+  println!("cargo:rerun-if-changed={}", PATCHES_DIR);
+  let version = latest_patch_version(Path::new(PATCHES_DIR)).expect("No patch found in Database/patches");
+  let out_dir = env::var("OUT_DIR").unwrap();
+  fs::write(Path::new(&out_dir).join("schema_version.rs"), format!("pub const EXPECTED_SCHEMA_VERSION: u32 = {};", version)).unwrap();
+}
+
+// Same naming as Patch::from_path, i.e. 00042_some_change.zip
+fn latest_patch_version(dir: &Path) -> Option<u32> {
+  fs::read_dir(dir).unwrap().filter_map(|entry| {
+    let path = entry.unwrap().path();
+    if path.is_dir() {
+      println!("cargo:rerun-if-changed={}", path.display());
+      return latest_patch_version(&path);
+    }
+    let file_name = path.file_name()?.to_str()?;
+    if !file_name.ends_with(".zip") || file_name.len() < 6 || file_name.as_bytes()[5] != b'_' {
+      return None;
+    }
+    file_name.get(..5)?.parse::<u32>().ok()
+  }).max()
+}
\ No newline at end of file
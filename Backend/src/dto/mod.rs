@@ -1,11 +1,15 @@
 pub use self::cachable::Cachable;
 pub use self::plausability::CheckPlausability;
+pub use self::range_filter::RangeFilter;
+pub use self::search_cursor::SearchCursor;
 pub use self::search_result::SearchResult;
 pub use self::select_option::SelectOption;
 pub use self::table_filter::TableFilter;
 
 mod cachable;
 mod table_filter;
+mod range_filter;
 mod select_option;
 mod plausability;
-mod search_result;
\ No newline at end of file
+mod search_result;
+mod search_cursor;
\ No newline at end of file
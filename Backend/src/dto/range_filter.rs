@@ -0,0 +1,13 @@
+// Inclusive bounds, a missing bound is unbounded
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RangeFilter<T> {
+  pub min: Option<T>,
+  pub max: Option<T>
+}
+
+impl<T: PartialOrd> RangeFilter<T> {
+  pub fn contains(&self, value: &T) -> bool {
+    self.min.as_ref().map(|min| value >= min).unwrap_or(true)
+      && self.max.as_ref().map(|max| value <= max).unwrap_or(true)
+  }
+}
\ No newline at end of file
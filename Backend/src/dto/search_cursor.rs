@@ -0,0 +1,24 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+// Opaque keyset cursor, i.e. the sort key of the last item of a page, hex encoded
+pub trait SearchCursor: Sized {
+  fn to_cursor(&self) -> String;
+  fn from_cursor(cursor: &str) -> Option<Self>;
+}
+
+impl<T: Serialize + DeserializeOwned> SearchCursor for T {
+  fn to_cursor(&self) -> String {
+    serde_json::to_vec(self).unwrap().iter().map(|byte| format!("{:02x}", byte)).collect()
+  }
+
+  fn from_cursor(cursor: &str) -> Option<Self> {
+    if cursor.len() % 2 != 0 || !cursor.is_ascii() {
+      return None;
+    }
+    let bytes: Option<Vec<u8>> = (0..cursor.len()).step_by(2)
+      .map(|i| u8::from_str_radix(&cursor[i..i + 2], 16).ok())
+      .collect();
+    bytes.and_then(|bytes| serde_json::from_slice(&bytes).ok())
+  }
+}
@@ -1,5 +1,6 @@
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct SearchResult<T> {
   pub result: Vec<T>,
-  pub num_items: usize
+  pub num_items: usize,
+  pub next_cursor: Option<String>
 }
\ No newline at end of file
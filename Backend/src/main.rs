@@ -14,8 +14,6 @@ extern crate serde_json;
 extern crate str_util;
 extern crate time_util;
 extern crate validator;
-#[macro_use] extern crate lazy_static;
-extern crate regex;
 extern crate dotenv;
 
 use rocket_okapi::swagger_ui::make_swagger_ui;
@@ -27,6 +25,8 @@ use dotenv::dotenv;
 use crate::modules::account;
 use crate::modules::armory;
 use crate::modules::data;
+use crate::modules::migration;
+use crate::modules::migration::tools::{ApplyMigrations, CheckSchemaVersion};
 use crate::modules::tooltip;
 
 pub mod dto;
@@ -35,12 +35,39 @@ pub mod modules;
 fn main() {
   dotenv().ok();
 
-  let account = account::Account::default().init();
-  let data = data::Data::default().init(None);
-  let armory = armory::Armory::default().init();
+  let migration = migration::Migration::default().init().unwrap_or_else(|failure| exit_with(failure));
+  let args: Vec<String> = std::env::args().collect();
+  if args.get(1).map(String::as_str) == Some("migrate") {
+    if let Err(failure) = migration::tools::run_command(&migration, &args[2..]) {
+      exit_with(failure);
+    }
+    return;
+  }
+  if std::env::var("MIGRATE_ON_STARTUP").map(|value| value == "true").unwrap_or(false) {
+    migration.apply_pending().unwrap_or_else(|failure| exit_with(failure));
+  }
+  // Refuse to start on a schema that this code has not been written for
+  migration.check_schema_version().unwrap_or_else(|failure| exit_with(failure));
+  if args.get(1).map(String::as_str) == Some("localization") {
+    if let Err(failure) = data::tools::run_translation_command(&data::Data::default().init(None).unwrap_or_else(|failure| exit_with(failure)), &args[2..]) {
+      exit_with(failure);
+    }
+    return;
+  }
+
+  let account = account::Account::default().init().unwrap_or_else(|failure| exit_with(failure));
+  if args.get(1).map(String::as_str) == Some("admin") {
+    if let Err(failure) = account::tools::run_admin_command(&account, &args[2..]) {
+      exit_with(failure);
+    }
+    return;
+  }
+  let data = data::DataStore::new(data::Data::default().init(None).unwrap_or_else(|failure| exit_with(failure)));
+  let armory = armory::Armory::default().init().unwrap_or_else(|failure| exit_with(failure));
   let tooltip = tooltip::Tooltip::default().init();
 
   let prometheus = PrometheusMetrics::new();
+  armory.register_metrics(prometheus.registry());
   let mut igniter = rocket::ignite();
   igniter = igniter.manage(account);
   igniter = igniter.manage(data);
@@ -78,16 +105,18 @@ fn main() {
     account::transfer::create::create, account::transfer::create::confirm, account::transfer::create::resend_confirm,
     account::transfer::get::get_account_information,
     account::transfer::forgot::receive_confirmation, account::transfer::forgot::send_confirmation,
-    account::transfer::update::request_mail, account::transfer::update::confirm_mail, account::transfer::update::password, account::transfer::update::nickname]);
+    account::transfer::update::request_mail, account::transfer::update::confirm_mail, account::transfer::update::password, account::transfer::update::nickname,
+    account::transfer::admin::grant_access_right, account::transfer::admin::revoke_access_right, account::transfer::admin::lock_account, account::transfer::admin::unlock_account]);
 
   igniter = igniter.mount("/API/data/", routes_with_openapi![
     data::transfer::expansion::get_expansion, data::transfer::expansion::get_all_expansions,
     data::transfer::language::get_language, data::transfer::language::get_all_languages, data::transfer::language::get_language_by_short_code,
-    data::transfer::localization::get_localization,
+    data::transfer::localization::get_localization, data::transfer::localization::get_localization_coverage,
     data::transfer::race::get_race, data::transfer::race::get_all_races,
     data::transfer::race::get_race_localized, data::transfer::race::get_all_races_localized,
     data::transfer::profession::get_profession, data::transfer::profession::get_all_professions,
     data::transfer::server::get_server, data::transfer::server::get_all_servers,
+    data::transfer::server_ownership::get_owned_servers, data::transfer::server_ownership::claim_server, data::transfer::server_ownership::transfer_server,
     data::transfer::hero_class::get_hero_class, data::transfer::hero_class::get_all_hero_classes,
     data::transfer::hero_class::get_hero_class_localized, data::transfer::hero_class::get_all_hero_classes_localized,
     data::transfer::spell::get_spell,
@@ -97,7 +126,7 @@ fn main() {
     data::transfer::spell_effect::get_spell_effects,
     data::transfer::npc::get_npc,
     data::transfer::icon::get_icon,
-    data::transfer::item::get_item,
+    data::transfer::item::get_item, data::transfer::item_search::search_items,
     data::transfer::gem::get_gem,
     data::transfer::enchant::get_enchant,
     data::transfer::item_bonding::get_item_bonding, data::transfer::item_bonding::get_all_item_bondings,
@@ -115,17 +144,19 @@ fn main() {
     data::transfer::itemset_effect::get_itemset_effects,
     data::transfer::title::get_title, data::transfer::title::get_all_titles,
     data::transfer::item_random_property_points::get_item_random_property_points,
+    data::transfer::reload::reload, data::transfer::reload::get_reload_status,
   ]);
 
   igniter = igniter.mount("/API/armory/", routes_with_openapi![
-    armory::transfer::character::set_character, armory::transfer::character::get_character, armory::transfer::character::get_character_by_uid,
+    armory::transfer::character::set_character, armory::transfer::character::set_characters, armory::transfer::character::get_character, armory::transfer::character::get_character_by_uid,
     armory::transfer::character::delete_character, armory::transfer::character::delete_character_by_uid, armory::transfer::character::get_characters_by_name,
     armory::transfer::guild::get_guild, armory::transfer::guild::get_guilds_by_name, armory::transfer::guild::delete_guild,
     armory::transfer::guild::delete_guild_by_uid, armory::transfer::guild::create_guild, armory::transfer::guild::update_guild_name,
     armory::transfer::character_history::set_character_history, armory::transfer::character_history::get_character_history, armory::transfer::character_history::delete_character_history,
     armory::transfer::character_search::get_character_search_result,
-    armory::transfer::character_viewer::get_character_viewer, armory::transfer::character_viewer::get_character_viewer_by_history,
-    armory::transfer::guild_viewer::get_guild_view,
+    armory::transfer::character_viewer::get_character_viewer, armory::transfer::character_viewer::get_character_viewer_by_history, armory::transfer::character_viewer::get_character_diff,
+    armory::transfer::guild_viewer::get_guild_view, armory::transfer::guild_viewer::get_guild_timeline,
+    armory::transfer::moderation::moderate_delete_character, armory::transfer::moderation::moderate_delete_guild, armory::transfer::moderation::moderate_guild_name,
   ]);
 
   igniter = igniter.mount("/API/tooltip/", routes_with_openapi![
@@ -136,4 +167,9 @@ fn main() {
   ]);
 
   igniter.launch();
+}
+
+fn exit_with(failure: impl std::fmt::Display) -> ! {
+  eprintln!("{}", failure);
+  std::process::exit(1);
 }
\ No newline at end of file
@@ -0,0 +1,20 @@
+use schemars::JsonSchema;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub enum AccessRight {
+  Admin = 1,
+  Moderator = 2,
+  ServerManager = 4,
+  DataEditor = 8
+}
+
+impl AccessRight {
+  pub fn bit(self) -> u32 {
+    self as u32
+  }
+
+  // Admins implicitly hold every other right
+  pub fn is_granted(self, access_rights: u32) -> bool {
+    access_rights & (AccessRight::Admin.bit() | self.bit()) != 0
+  }
+}
\ No newline at end of file
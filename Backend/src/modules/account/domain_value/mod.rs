@@ -1,3 +1,5 @@
+pub use self::access_right::AccessRight;
 pub use self::account_information::AccountInformation;
 
+mod access_right;
 mod account_information;
\ No newline at end of file
@@ -25,6 +25,9 @@ pub enum Failure {
   TooManyDays,
   DateInThePast,
   TokenPurposeLength,
+  AccountLocked,
+  InvalidMember,
+  SelfAdministration,
   Unknown,
 }
 
@@ -49,6 +52,9 @@ impl Responder<'static> for Failure {
       Failure::TooManyDays => Status::new(531, "TooManyDays"),
       Failure::DateInThePast => Status::new(532, "DateInThePast"),
       Failure::TokenPurposeLength => Status::new(533, "TokenPurposeLength"),
+      Failure::AccountLocked => Status::new(539, "AccountLocked"),
+      Failure::InvalidMember => Status::new(540, "InvalidMember"),
+      Failure::SelfAdministration => Status::new(541, "SelfAdministration"),
       Failure::Unknown => Status::new(599, "Unknown"),
     };
     Response::build()
@@ -76,6 +82,9 @@ impl OpenApiResponder<'static> for Failure {
     add_schema_response(&mut responses, 531, "text/plain", schema.clone())?;
     add_schema_response(&mut responses, 532, "text/plain", schema.clone())?;
     add_schema_response(&mut responses, 533, "text/plain", schema.clone())?;
+    add_schema_response(&mut responses, 539, "text/plain", schema.clone())?;
+    add_schema_response(&mut responses, 540, "text/plain", schema.clone())?;
+    add_schema_response(&mut responses, 541, "text/plain", schema.clone())?;
     add_schema_response(&mut responses, 599, "text/plain", schema.clone())?;
     Ok(responses)
   }
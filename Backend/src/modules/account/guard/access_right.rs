@@ -0,0 +1,90 @@
+use okapi::openapi3::{Parameter, ParameterValue, Responses};
+use rocket::http::Status;
+use rocket::outcome::Outcome::*;
+use rocket::request::{self, FromRequest, Request, State};
+use rocket::Response;
+use rocket::response::Responder;
+use rocket_okapi::gen::OpenApiGenerator;
+use rocket_okapi::request::OpenApiFromRequest;
+use rocket_okapi::response::OpenApiResponder;
+use rocket_okapi::util::add_schema_response;
+
+use crate::modules::account::Account;
+use crate::modules::account::domain_value::AccessRight;
+use crate::modules::account::guard::Authenticate;
+use crate::modules::account::tools::Administrate;
+
+fn authorize(req: &Request, access_right: AccessRight) -> request::Outcome<u32, ()> {
+  Authenticate::from_request(req)
+    .and_then(|authenticate| {
+      let account_req = req.guard::<State<'_, Account>>();
+      if account_req.is_failure() {
+        return Failure((Status::Unauthorized, ()));
+      }
+
+      if !account_req.unwrap().has_access_right(authenticate.0, access_right) {
+        return Failure((Status::Forbidden, ()));
+      }
+      Success(authenticate.0)
+    })
+}
+
+// Each guard wraps the id of an authenticated member holding the respective access right
+macro_rules! access_right_guard {
+  ($guard:ident, $access_right:expr) => {
+    pub struct $guard(pub u32);
+
+    impl<'a, 'r> FromRequest<'a, 'r> for $guard {
+      type Error = ();
+
+      fn from_request(req: &'a Request<'r>) -> request::Outcome<Self, ()> {
+        authorize(req, $access_right).map($guard)
+      }
+    }
+
+    impl<'a, 'r> OpenApiFromRequest<'a, 'r> for $guard {
+      fn request_parameter(_: &mut OpenApiGenerator, _: String) -> rocket_okapi::Result<Parameter> {
+        Ok(Parameter {
+          name: "X-Authorization".to_owned(),
+          location: "header".to_owned(),
+          description: None,
+          required: true,
+          deprecated: false,
+          allow_empty_value: false,
+          value: ParameterValue::Schema {
+            style: None,
+            explode: None,
+            allow_reserved: false,
+            schema: Default::default(),
+            example: None,
+            examples: None,
+          },
+          extensions: Default::default(),
+        })
+      }
+    }
+
+    // This implementation is required from OpenAPI, it does nothing here
+    // and is not supposed to be used!
+    impl Responder<'static> for $guard {
+      fn respond_to(self, _: &Request) -> Result<Response<'static>, Status> {
+        Response::build().status(Status::Forbidden).ok()
+      }
+    }
+
+    impl OpenApiResponder<'static> for $guard {
+      fn responses(gen: &mut OpenApiGenerator) -> rocket_okapi::Result<Responses> {
+        let mut responses = Responses::default();
+        let schema = gen.json_schema::<String>();
+        add_schema_response(&mut responses, 401, "text/plain", schema.clone())?;
+        add_schema_response(&mut responses, 403, "text/plain", schema)?;
+        Ok(responses)
+      }
+    }
+  };
+}
+
+access_right_guard!(Admin, AccessRight::Admin);
+access_right_guard!(Moderator, AccessRight::Moderator);
+access_right_guard!(ServerManager, AccessRight::ServerManager);
+access_right_guard!(DataEditor, AccessRight::DataEditor);
\ No newline at end of file
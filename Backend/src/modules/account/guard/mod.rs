@@ -1,5 +1,7 @@
+pub use self::access_right::{Admin, DataEditor, Moderator, ServerManager};
 pub use self::authenticate::Authenticate;
 pub use self::server_owner::ServerOwner;
 
+mod access_right;
 mod authenticate;
 mod server_owner;
\ No newline at end of file
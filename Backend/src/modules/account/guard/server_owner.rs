@@ -10,7 +10,8 @@ use rocket_okapi::response::OpenApiResponder;
 use rocket_okapi::util::add_schema_response;
 
 use crate::modules::account::guard::Authenticate;
-use crate::modules::data::Data;
+use crate::modules::data::DataStore;
+use crate::modules::data::tools::ServerOwnership;
 
 pub struct ServerOwner(pub u32);
 
@@ -20,19 +21,33 @@ impl<'a, 'r> FromRequest<'a, 'r> for ServerOwner {
   fn from_request(req: &'a Request<'r>) -> request::Outcome<Self, ()> {
     Authenticate::from_request(req)
       .and_then(|authenticate| {
-        let data_req = req.guard::<State<'_, Data>>();
+        let data_req = req.guard::<State<'_, DataStore>>();
         if data_req.is_failure() {
           return Failure((Status::Unauthorized, ()));
         }
 
-        let data = data_req.unwrap();
-        let server_res = data.servers.iter().find(|(_, server)| server.owner.contains(&authenticate.0));
-        if server_res.is_none() {
-          return Failure((Status::Unauthorized, ()));
-        }
+        let data = data_req.unwrap().snapshot();
+        let owned_servers = data.get_owned_servers(authenticate.0);
+        match req.headers().get_one("X-Server") {
+          Some(server_header) => {
+            let server_id_res = server_header.parse::<u32>();
+            if server_id_res.is_err() {
+              return Failure((Status::BadRequest, ()));
+            }
 
-        let (id, _) = server_res.unwrap();
-        Success(ServerOwner(*id))
+            let server_id = server_id_res.unwrap();
+            if !owned_servers.iter().any(|server| server.id == server_id) {
+              return Failure((Status::Unauthorized, ()));
+            }
+            Success(ServerOwner(server_id))
+          },
+          // Without a selection, the target is only unambiguous if the member owns a single server
+          None => match owned_servers.len() {
+            0 => Failure((Status::Unauthorized, ())),
+            1 => Success(ServerOwner(owned_servers[0].id)),
+            _ => Failure((Status::BadRequest, ()))
+          }
+        }
       })
   }
 }
@@ -42,7 +57,7 @@ impl<'a, 'r> OpenApiFromRequest<'a, 'r> for ServerOwner {
     Ok(Parameter {
       name: "X-Authorization".to_owned(),
       location: "header".to_owned(),
-      description: None,
+      description: Some("Members owning multiple servers select the target server by its id in the X-Server header.".to_owned()),
       required: true,
       deprecated: false,
       allow_empty_value: false,
@@ -71,6 +86,7 @@ impl OpenApiResponder<'static> for ServerOwner {
   fn responses(gen: &mut OpenApiGenerator) -> rocket_okapi::Result<Responses> {
     let mut responses = Responses::default();
     let schema = gen.json_schema::<String>();
+    add_schema_response(&mut responses, 400, "text/plain", schema.clone())?;
     add_schema_response(&mut responses, 401, "text/plain", schema)?;
     Ok(responses)
   }
@@ -3,15 +3,15 @@ use std::sync::RwLock;
 
 use language::material::Dictionary;
 use mysql_connection::material::MySQLConnection;
-use mysql_connection::tools::{Execute, Select};
 use str_util::sha3;
 
 use crate::modules::account::language::init::Init;
 use crate::modules::account::material::{APIToken, Member};
+use crate::modules::account::repository::{AccountBackend, MemberRepository, MySQLAccountBackend, TokenRepository};
 
 #[derive(Debug)]
 pub struct Account {
-  pub db_main: MySQLConnection,
+  pub backend: Box<dyn AccountBackend>,
   pub dictionary: Dictionary,
   pub member: RwLock<HashMap<u32, Member>>,
   pub api_token_to_member_id: RwLock<HashMap<String, u32>>,
@@ -23,11 +23,18 @@ pub struct Account {
 // Also: Write locks may not be acquired within a query
 impl Default for Account {
   fn default() -> Self
+  {
+    Account::with_backend(Box::new(MySQLAccountBackend::new(MySQLConnection::new("main"))))
+  }
+}
+
+impl Account {
+  pub fn with_backend(backend: Box<dyn AccountBackend>) -> Self
   {
     let dictionary = Dictionary::default();
     Dictionary::init(&dictionary);
     Account {
-      db_main: MySQLConnection::new("main"),
+      backend,
       dictionary,
       member: RwLock::new(HashMap::new()),
       api_tokens: RwLock::new(HashMap::new()),
@@ -35,10 +42,8 @@ impl Default for Account {
       requires_mail_confirmation: RwLock::new(HashMap::new()),
     }
   }
-}
 
-impl Account {
-  pub fn init(self) -> Self
+  pub fn init(self) -> Result<Self, String>
   {
     {
       let mut requires_mail_confirmation = self.requires_mail_confirmation.write().unwrap();
@@ -47,23 +52,10 @@ impl Account {
       let mut member = self.member.write().unwrap();
 
       // Cleaning first
-      self.clean_tokens();
+      self.backend.delete_expired_tokens();
 
       // We are a little wasteful here because we do not insert it directly but rather create a vector first and then copy it over
-      for entry in self.db_main.select("SELECT id, nickname, mail, password, salt, mail_confirmed, forgot_password, delete_account, new_mail, access_rights FROM account_member", &|mut row| {
-        Member {
-          id: row.take(0).unwrap(),
-          nickname: row.take(1).unwrap(),
-          mail: row.take(2).unwrap(),
-          password: row.take(3).unwrap(),
-          salt: row.take(4).unwrap(),
-          mail_confirmed: row.take(5).unwrap(),
-          forgot_password: row.take(6).unwrap(),
-          delete_account: row.take(7).unwrap(),
-          new_mail: row.take(8).unwrap(),
-          access_rights: row.take(9).unwrap()
-        }
-      }) {
+      for entry in self.backend.load_members()? {
         // Prepping api_token map
         api_token.insert(entry.id, vec![]);
 
@@ -87,24 +79,12 @@ impl Account {
         member.insert(entry.id, entry);
       }
 
-      for entry in self.db_main.select("SELECT id, member_id, token, purpose, exp_date FROM account_api_token", &|mut row| {
-        APIToken {
-          id: row.take(0).unwrap(),
-          member_id: row.take(1).unwrap(),
-          token: Some(row.take(2).unwrap()),
-          purpose: row.take(3).unwrap(),
-          exp_date: row.take(4).unwrap(),
-        }
-      }) {
+      for entry in self.backend.load_tokens()? {
         api_token_to_member_id.insert(entry.token.as_ref().unwrap().clone(), entry.member_id);
         api_token.get_mut(&entry.member_id).unwrap().push(entry);
       }
     }
 
-    self
-  }
-
-  fn clean_tokens(&self) {
-    self.db_main.execute("DELETE FROM account_api_token WHERE exp_date < UNIX_TIMESTAMP()");
+    Ok(self)
   }
 }
\ No newline at end of file
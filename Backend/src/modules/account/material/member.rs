@@ -1,6 +1,6 @@
 use schemars::JsonSchema;
 
-#[derive(Deserialize, Serialize, Debug, JsonSchema)]
+#[derive(Deserialize, Serialize, Debug, Clone, JsonSchema)]
 pub struct Member {
   pub id: u32,
   pub nickname: String,
@@ -11,5 +11,6 @@ pub struct Member {
   pub forgot_password: bool,
   pub delete_account: bool,
   pub new_mail: String, // Non-Empty means that a change was requested
-  pub access_rights: u32
+  pub access_rights: u32,
+  pub locked: bool
 }
\ No newline at end of file
@@ -5,10 +5,11 @@ mod tests;
 
 mod domain_value;
 mod material;
-mod tools;
 mod language;
 
 pub mod guard;
+pub mod repository;
+pub mod tools;
 pub mod dto;
 pub mod transfer;
 
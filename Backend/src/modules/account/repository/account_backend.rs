@@ -0,0 +1,7 @@
+use std::fmt::Debug;
+
+use crate::modules::account::repository::{MemberRepository, TokenRepository};
+
+pub trait AccountBackend: MemberRepository + TokenRepository + Debug + Send + Sync {}
+
+impl<T: MemberRepository + TokenRepository + Debug + Send + Sync> AccountBackend for T {}
\ No newline at end of file
@@ -0,0 +1,127 @@
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+use crate::modules::account::material::{APIToken, Member};
+use crate::modules::account::repository::{MemberRepository, TokenRepository};
+
+// Keeps everything in process memory, e.g. to run the account module without a database
+#[derive(Debug, Default)]
+pub struct InMemoryAccountBackend {
+  state: Mutex<InMemoryState>,
+}
+
+#[derive(Debug, Default)]
+struct InMemoryState {
+  members: BTreeMap<u32, Member>,
+  api_tokens: BTreeMap<u32, APIToken>,
+  last_member_id: u32,
+  last_token_id: u32,
+}
+
+impl MemberRepository for InMemoryAccountBackend {
+  fn load_members(&self) -> Result<Vec<Member>, String> {
+    Ok(self.state.lock().unwrap().members.values().cloned().collect())
+  }
+
+  fn insert_member(&self, mail: &str, nickname: &str, password: &str, salt: &str) -> Option<u32> {
+    let mut state = self.state.lock().unwrap();
+    if state.members.values().any(|member| member.mail == mail) {
+      return None;
+    }
+
+    state.last_member_id += 1;
+    let member_id = state.last_member_id;
+    state.members.insert(member_id, Member {
+      id: member_id,
+      nickname: nickname.to_owned(),
+      mail: mail.to_owned(),
+      password: password.to_owned(),
+      salt: salt.to_owned(),
+      mail_confirmed: false,
+      forgot_password: false,
+      delete_account: false,
+      new_mail: String::new(),
+      access_rights: 0,
+      locked: false,
+    });
+    Some(member_id)
+  }
+
+  fn update_member(&self, member: &Member) -> bool {
+    let mut state = self.state.lock().unwrap();
+    match state.members.get_mut(&member.id) {
+      Some(entry) => {
+        *entry = member.clone();
+        true
+      },
+      None => false,
+    }
+  }
+
+  fn update_member_and_clear_tokens(&self, member: &Member) -> bool {
+    let mut state = self.state.lock().unwrap();
+    match state.members.get_mut(&member.id) {
+      Some(entry) => *entry = member.clone(),
+      None => return false,
+    }
+    state.api_tokens.retain(|_, api_token| api_token.member_id != member.id);
+    true
+  }
+
+  fn delete_member(&self, member_id: u32) -> bool {
+    let mut state = self.state.lock().unwrap();
+    state.api_tokens.retain(|_, api_token| api_token.member_id != member_id);
+    state.members.remove(&member_id);
+    true
+  }
+}
+
+impl TokenRepository for InMemoryAccountBackend {
+  fn load_tokens(&self) -> Result<Vec<APIToken>, String> {
+    Ok(self.state.lock().unwrap().api_tokens.values().cloned().collect())
+  }
+
+  fn insert_token(&self, member_id: u32, token: &str, purpose: &str, exp_date: u64) -> Option<APIToken> {
+    let mut state = self.state.lock().unwrap();
+    if !state.members.contains_key(&member_id) {
+      return None;
+    }
+
+    state.last_token_id += 1;
+    let api_token = APIToken {
+      id: state.last_token_id,
+      member_id,
+      token: Some(token.to_owned()),
+      purpose: purpose.to_owned(),
+      exp_date,
+    };
+    state.api_tokens.insert(api_token.id, api_token.clone());
+    Some(api_token)
+  }
+
+  fn update_token_exp_date(&self, token_id: u32, member_id: u32, exp_date: u64) -> bool {
+    let mut state = self.state.lock().unwrap();
+    if let Some(api_token) = state.api_tokens.get_mut(&token_id).filter(|api_token| api_token.member_id == member_id) {
+      api_token.exp_date = exp_date;
+    }
+    true
+  }
+
+  fn delete_token(&self, token_id: u32, member_id: u32) -> bool {
+    let mut state = self.state.lock().unwrap();
+    if state.api_tokens.get(&token_id).map(|api_token| api_token.member_id == member_id).unwrap_or(false) {
+      state.api_tokens.remove(&token_id);
+    }
+    true
+  }
+
+  fn delete_member_tokens(&self, member_id: u32) -> bool {
+    self.state.lock().unwrap().api_tokens.retain(|_, api_token| api_token.member_id != member_id);
+    true
+  }
+
+  fn delete_expired_tokens(&self) {
+    let now = time_util::now();
+    self.state.lock().unwrap().api_tokens.retain(|_, api_token| api_token.exp_date >= now);
+  }
+}
\ No newline at end of file
@@ -0,0 +1,11 @@
+use crate::modules::account::material::Member;
+
+pub trait MemberRepository {
+  fn load_members(&self) -> Result<Vec<Member>, String>;
+  fn insert_member(&self, mail: &str, nickname: &str, password: &str, salt: &str) -> Option<u32>;
+  fn update_member(&self, member: &Member) -> bool;
+  // Either both the member is updated and its api tokens are deleted or nothing happens
+  fn update_member_and_clear_tokens(&self, member: &Member) -> bool;
+  // Deleting a member also deletes its api tokens
+  fn delete_member(&self, member_id: u32) -> bool;
+}
\ No newline at end of file
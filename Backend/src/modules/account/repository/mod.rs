@@ -0,0 +1,11 @@
+pub use self::account_backend::AccountBackend;
+pub use self::in_memory_backend::InMemoryAccountBackend;
+pub use self::member_repository::MemberRepository;
+pub use self::mysql_backend::MySQLAccountBackend;
+pub use self::token_repository::TokenRepository;
+
+mod account_backend;
+mod in_memory_backend;
+mod member_repository;
+mod mysql_backend;
+mod token_repository;
\ No newline at end of file
@@ -0,0 +1,161 @@
+use mysql_connection::material::MySQLConnection;
+use mysql_connection::tools::{Execute, Select, StartTransaction, TakeColumn};
+
+use crate::modules::account::material::{APIToken, Member};
+use crate::modules::account::repository::{MemberRepository, TokenRepository};
+
+#[derive(Debug)]
+pub struct MySQLAccountBackend {
+  pub db_main: MySQLConnection,
+}
+
+impl MySQLAccountBackend {
+  pub fn new(db_main: MySQLConnection) -> Self {
+    MySQLAccountBackend { db_main }
+  }
+}
+
+const UPDATE_MEMBER: &str = "UPDATE account_member SET nickname=:nickname, mail=:mail, password=:password, mail_confirmed=:mail_confirmed, forgot_password=:forgot_password, \
+    delete_account=:delete_account, new_mail=:new_mail, access_rights=:access_rights, locked=:locked WHERE id=:id";
+
+fn member_params(member: &Member) -> Vec<(String, mysql_connection::Value)> {
+  params!(
+    "nickname" => member.nickname.clone(),
+    "mail" => member.mail.clone(),
+    "password" => member.password.clone(),
+    "mail_confirmed" => member.mail_confirmed,
+    "forgot_password" => member.forgot_password,
+    "delete_account" => member.delete_account,
+    "new_mail" => member.new_mail.clone(),
+    "access_rights" => member.access_rights,
+    "locked" => member.locked,
+    "id" => member.id
+  )
+}
+
+impl MemberRepository for MySQLAccountBackend {
+  fn load_members(&self) -> Result<Vec<Member>, String> {
+    self.db_main.try_select("SELECT id, nickname, mail, password, salt, mail_confirmed, forgot_password, delete_account, new_mail, access_rights, locked FROM account_member", &|mut row| {
+      Ok(Member {
+        id: row.take_column(0)?,
+        nickname: row.take_column(1)?,
+        mail: row.take_column(2)?,
+        password: row.take_column(3)?,
+        salt: row.take_column(4)?,
+        mail_confirmed: row.take_column(5)?,
+        forgot_password: row.take_column(6)?,
+        delete_account: row.take_column(7)?,
+        new_mail: row.take_column(8)?,
+        access_rights: row.take_column(9)?,
+        locked: row.take_column(10)?
+      })
+    }).map_err(|err| format!("Failed to load the account members: {}", err))
+  }
+
+  fn insert_member(&self, mail: &str, nickname: &str, password: &str, salt: &str) -> Option<u32> {
+    let mut transaction = self.db_main.start_transaction().ok()?;
+    transaction.execute_wparams("INSERT IGNORE INTO account_member (`mail`, `password`, `nickname`, `salt`, `joined`) VALUES (:mail, :pass, :nickname, :salt, UNIX_TIMESTAMP())", params!(
+      "nickname" => nickname,
+      "mail" => mail,
+      "pass" => password,
+      "salt" => salt
+    )).ok()?;
+
+    // Nothing is inserted if the mail is already in use
+    if transaction.affected_rows() == 0 {
+      return None;
+    }
+    let member_id = transaction.last_insert_id()? as u32;
+    transaction.commit().ok()?;
+    Some(member_id)
+  }
+
+  fn update_member(&self, member: &Member) -> bool {
+    self.db_main.execute_wparams(UPDATE_MEMBER, member_params(member))
+  }
+
+  fn update_member_and_clear_tokens(&self, member: &Member) -> bool {
+    let transaction_res = self.db_main.start_transaction();
+    if transaction_res.is_err() {
+      return false;
+    }
+    let mut transaction = transaction_res.unwrap();
+    transaction.execute_wparams(UPDATE_MEMBER, member_params(member)).is_ok()
+      && transaction.execute_wparams("DELETE FROM account_api_token WHERE member_id=:member_id", params!(
+        "member_id" => member.id
+      )).is_ok()
+      && transaction.commit().is_ok()
+  }
+
+  fn delete_member(&self, member_id: u32) -> bool {
+    // Due to foreign key constraints, other tables depending on the member_id will also be deleted
+    self.db_main.execute_wparams("DELETE FROM account_member WHERE id = :id", params!(
+      "id" => member_id
+    ))
+  }
+}
+
+impl TokenRepository for MySQLAccountBackend {
+  fn load_tokens(&self) -> Result<Vec<APIToken>, String> {
+    self.db_main.try_select("SELECT id, member_id, token, purpose, exp_date FROM account_api_token", &|mut row| {
+      Ok(APIToken {
+        id: row.take_column(0)?,
+        member_id: row.take_column(1)?,
+        token: Some(row.take_column(2)?),
+        purpose: row.take_column(3)?,
+        exp_date: row.take_column(4)?,
+      })
+    }).map_err(|err| format!("Failed to load the api tokens: {}", err))
+  }
+
+  fn insert_token(&self, member_id: u32, token: &str, purpose: &str, exp_date: u64) -> Option<APIToken> {
+    let mut transaction = self.db_main.start_transaction().ok()?;
+    transaction.execute_wparams(
+      "INSERT INTO account_api_token (member_id, token, purpose, exp_date) VALUES (:member_id, :token, :purpose, :exp_date)",
+      params!(
+        "member_id" => member_id,
+        "token" => token,
+        "purpose" => purpose,
+        "exp_date" => exp_date
+      ),
+    ).ok()?;
+
+    let api_token = APIToken {
+      id: transaction.last_insert_id()? as u32,
+      member_id,
+      token: Some(token.to_owned()),
+      purpose: purpose.to_owned(),
+      exp_date,
+    };
+    transaction.commit().ok()?;
+    Some(api_token)
+  }
+
+  fn update_token_exp_date(&self, token_id: u32, member_id: u32, exp_date: u64) -> bool {
+    self.db_main.execute_wparams("UPDATE account_api_token SET exp_date=:exp_date WHERE id=:id AND member_id=:member_id", params!(
+      "exp_date" => exp_date,
+      "id" => token_id,
+      "member_id" => member_id
+    ))
+  }
+
+  fn delete_token(&self, token_id: u32, member_id: u32) -> bool {
+    self.db_main.execute_wparams(
+      "DELETE FROM account_api_token WHERE id=:id AND member_id=:member_id",
+      params!(
+        "id" => token_id,
+        "member_id" => member_id
+      ),
+    )
+  }
+
+  fn delete_member_tokens(&self, member_id: u32) -> bool {
+    self.db_main.execute_wparams("DELETE FROM account_api_token WHERE member_id=:member_id", params!(
+      "member_id" => member_id
+    ))
+  }
+
+  fn delete_expired_tokens(&self) {
+    self.db_main.execute("DELETE FROM account_api_token WHERE exp_date < UNIX_TIMESTAMP()");
+  }
+}
\ No newline at end of file
@@ -0,0 +1,10 @@
+use crate::modules::account::material::APIToken;
+
+pub trait TokenRepository {
+  fn load_tokens(&self) -> Result<Vec<APIToken>, String>;
+  fn insert_token(&self, member_id: u32, token: &str, purpose: &str, exp_date: u64) -> Option<APIToken>;
+  fn update_token_exp_date(&self, token_id: u32, member_id: u32, exp_date: u64) -> bool;
+  fn delete_token(&self, token_id: u32, member_id: u32) -> bool;
+  fn delete_member_tokens(&self, member_id: u32) -> bool;
+  fn delete_expired_tokens(&self);
+}
\ No newline at end of file
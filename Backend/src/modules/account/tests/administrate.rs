@@ -0,0 +1,69 @@
+use crate::modules::account::domain_value::AccessRight;
+use crate::modules::account::tests::in_memory_account;
+use crate::modules::account::dto::Failure;
+use crate::modules::account::tools::{Administrate, Create, Login, run_admin_command, Token};
+
+#[test]
+fn access_right_is_granted() {
+  assert!(AccessRight::Moderator.is_granted(AccessRight::Moderator.bit()));
+  assert!(AccessRight::DataEditor.is_granted(AccessRight::Admin.bit()));
+  assert!(!AccessRight::Admin.is_granted(AccessRight::Moderator.bit() | AccessRight::ServerManager.bit()));
+  assert!(!AccessRight::ServerManager.is_granted(0));
+}
+
+#[test]
+fn grant_and_revoke_access_right() {
+  let account = in_memory_account();
+  let login = account.create("gjhdfgsdfsdfgsdfsd@jaylappTest.dev", "gjhdfgsdfsdfgsdfsd", "Password123456Password123456Password123456").unwrap();
+  assert!(!account.has_access_right(login.member_id, AccessRight::Moderator));
+
+  let acc_info = account.grant_access_right(login.member_id, AccessRight::Moderator);
+  assert!(acc_info.is_ok());
+  assert_eq!(acc_info.unwrap().access_rights, AccessRight::Moderator.bit());
+  assert!(account.has_access_right(login.member_id, AccessRight::Moderator));
+  assert!(!account.has_access_right(login.member_id, AccessRight::Admin));
+
+  assert!(match account.revoke_access_right(login.member_id, login.member_id, AccessRight::Moderator) { Err(Failure::SelfAdministration) => true, _ => false });
+  assert!(account.has_access_right(login.member_id, AccessRight::Moderator));
+
+  let acc_info = account.revoke_access_right(0, login.member_id, AccessRight::Moderator);
+  assert!(acc_info.is_ok());
+  assert_eq!(acc_info.unwrap().access_rights, 0);
+  assert!(!account.has_access_right(login.member_id, AccessRight::Moderator));
+
+  assert!(account.grant_access_right(0, AccessRight::Admin).is_err());
+}
+
+#[test]
+fn lock_and_unlock_account() {
+  let account = in_memory_account();
+  let login = account.create("kjhgdfsdghfdgsdfg@jaylappTest.dev", "kjhgdfsdghfdgsdfg", "Password123456Password123456Password123456").unwrap();
+  assert!(account.grant_access_right(login.member_id, AccessRight::Admin).is_ok());
+
+  assert!(match account.lock_account(login.member_id, login.member_id) { Err(Failure::SelfAdministration) => true, _ => false });
+  assert!(account.has_access_right(login.member_id, AccessRight::Admin));
+
+  assert!(account.lock_account(0, login.member_id).is_ok());
+  assert!(account.validate_token(login.token.as_ref().unwrap()).is_none());
+  assert!(account.login("kjhgdfsdghfdgsdfg@jaylappTest.dev", "Password123456Password123456Password123456").is_err());
+  assert!(!account.has_access_right(login.member_id, AccessRight::Admin));
+
+  assert!(account.unlock_account(login.member_id).is_ok());
+  assert!(account.login("kjhgdfsdghfdgsdfg@jaylappTest.dev", "Password123456Password123456Password123456").is_ok());
+  assert!(account.has_access_right(login.member_id, AccessRight::Admin));
+
+  assert!(account.lock_account(login.member_id, 0).is_err());
+}
+
+#[test]
+fn grant_first_admin() {
+  let account = in_memory_account();
+  let login = account.create("hgfdsjkhgsdfjhgsd@jaylappTest.dev", "hgfdsjkhgsdfjhgsd", "Password123456Password123456Password123456").unwrap();
+  assert!(!account.has_access_right(login.member_id, AccessRight::Admin));
+
+  assert!(run_admin_command(&account, &["grant".to_owned(), "HGFDSJKHGSDFJHGSD@jaylappTest.dev".to_owned()]).is_ok());
+  assert!(account.has_access_right(login.member_id, AccessRight::Admin));
+
+  assert!(run_admin_command(&account, &["grant".to_owned(), "nobody@jaylappTest.dev".to_owned()]).is_err());
+  assert!(run_admin_command(&account, &["grant".to_owned()]).is_err());
+}
\ No newline at end of file
@@ -1,13 +1,12 @@
-use mysql_connection::tools::Execute;
 use str_util::sha3;
 
 use crate::modules::account::dto::{CreateMember, Credentials};
-use crate::modules::account::material::Account;
+use crate::modules::account::tests::in_memory_account;
 use crate::modules::account::tools::{Create, GetAccountInformation};
 
 #[test]
 fn create_account() {
-  let account = Account::default();
+  let account = in_memory_account();
   let acc_mail = "mail@jaylappTest.dev";
   let post_obj = CreateMember {
     nickname: "NickName".to_string(),
@@ -19,13 +18,11 @@ fn create_account() {
 
   let login = account.create(&post_obj.credentials.mail, &post_obj.nickname, &post_obj.credentials.password);
   assert!(login.is_ok());
-
-  account.db_main.execute("DELETE FROM account_member WHERE mail='mail@jaylappTest.dev'");
 }
 
 #[test]
 fn mail_twice() {
-  let account = Account::default();
+  let account = in_memory_account();
   let post_obj = CreateMember {
     nickname: "BlaNameqqweq".to_string(),
     credentials: Credentials {
@@ -36,13 +33,11 @@ fn mail_twice() {
 
   let _ = account.create(&post_obj.credentials.mail, &post_obj.nickname, &post_obj.credentials.password).unwrap();
   assert!(account.create(&post_obj.credentials.mail, &post_obj.nickname, &post_obj.credentials.password).is_err());
-
-  account.db_main.execute("DELETE FROM account_member WHERE mail='bla@jaylappTest.dev'");
 }
 
 #[test]
 fn nickname_twice() {
-  let account = Account::default();
+  let account = in_memory_account();
   let post_obj = CreateMember {
     nickname: "BlaName".to_string(),
     credentials: Credentials {
@@ -61,14 +56,11 @@ fn nickname_twice() {
 
   let _ = account.create(&post_obj.credentials.mail, &post_obj.nickname, &post_obj.credentials.password).unwrap();
   assert!(account.create(&post_obj_two.credentials.mail, &post_obj_two.nickname, &post_obj_two.credentials.password).is_err());
-
-  account.db_main.execute("DELETE FROM account_member WHERE mail='bla2@jaylappTest.dev'");
-  account.db_main.execute("DELETE FROM account_member WHERE mail='bla3@jaylappTest.dev'");
 }
 
 #[test]
 fn mail_empty() {
-  let account = Account::default();
+  let account = in_memory_account();
   let post_obj = CreateMember {
     nickname: "NickName".to_string(),
     credentials: Credentials {
@@ -82,7 +74,7 @@ fn mail_empty() {
 
 #[test]
 fn password_empty() {
-  let account = Account::default();
+  let account = in_memory_account();
   let post_obj = CreateMember {
     nickname: "NickName".to_string(),
     credentials: Credentials {
@@ -96,7 +88,7 @@ fn password_empty() {
 
 #[test]
 fn nickname_empty() {
-  let account = Account::default();
+  let account = in_memory_account();
   let post_obj = CreateMember {
     nickname: "".to_string(),
     credentials: Credentials {
@@ -110,7 +102,7 @@ fn nickname_empty() {
 
 #[test]
 fn invalid_mail() {
-  let account = Account::default();
+  let account = in_memory_account();
   let post_obj = CreateMember {
     nickname: "asdasd".to_string(),
     credentials: Credentials {
@@ -124,7 +116,7 @@ fn invalid_mail() {
 
 #[test]
 fn invalid_nickname() {
-  let account = Account::default();
+  let account = in_memory_account();
   let post_obj = CreateMember {
     nickname: "asdasd asdfsdfs".to_string(),
     credentials: Credentials {
@@ -138,7 +130,7 @@ fn invalid_nickname() {
 
 #[test]
 fn confirm_mail() {
-  let account = Account::default();
+  let account = in_memory_account();
   let post_obj = CreateMember {
     nickname: "SomeNameWuuh".to_string(),
     credentials: Credentials {
@@ -157,6 +149,4 @@ fn confirm_mail() {
   account.confirm(&mail_id);
   let confirmed_information = account.get(login.member_id).unwrap();
   assert!(confirmed_information.mail_confirmed);
-
-  account.db_main.execute("DELETE FROM account_member WHERE mail='someNameWuuuuh@jaylappTest.dev'");
 }
\ No newline at end of file
@@ -1,12 +1,10 @@
-use mysql_connection::tools::Execute;
-
 use crate::modules::account::dto::{CreateMember, Credentials};
-use crate::modules::account::material::Account;
+use crate::modules::account::tests::in_memory_account;
 use crate::modules::account::tools::{Create, Delete};
 
 #[test]
 fn issue_delete() {
-  let account = Account::default();
+  let account = in_memory_account();
   let post_obj = CreateMember {
     nickname: "Nsdsdfsdfsdf".to_string(),
     credentials: Credentials {
@@ -18,13 +16,11 @@ fn issue_delete() {
   let val_pair = account.create(&post_obj.credentials.mail, &post_obj.nickname, &post_obj.credentials.password).unwrap();
   let issue_delete = account.issue_delete(val_pair.member_id);
   assert!(issue_delete.is_ok());
-
-  account.db_main.execute("DELETE FROM account_member WHERE mail='hdfgfdgdfd@jaylappTest.dev'");
 }
 
 #[test]
 fn confirm_mail() {
-  let account = Account::default();
+  let account = in_memory_account();
   let post_obj = CreateMember {
     nickname: "hfghsdssdgdfg".to_string(),
     credentials: Credentials {
@@ -36,6 +32,4 @@ fn confirm_mail() {
   let val_pair = account.create(&post_obj.credentials.mail, &post_obj.nickname, &post_obj.credentials.password).unwrap();
   let issue_delete = account.issue_delete(val_pair.member_id);
   assert!(issue_delete.is_ok());
-
-  account.db_main.execute("DELETE FROM account_member WHERE mail='hfghsdssdgdfg@jaylappTest.dev'");
 }
\ No newline at end of file
@@ -1,25 +1,24 @@
-use mysql_connection::tools::Execute;
 use str_util::sha3;
 
 use crate::modules::account::dto::{CreateMember, Credentials};
-use crate::modules::account::material::Account;
+use crate::modules::account::tests::in_memory_account;
 use crate::modules::account::tools::{Create, Forgot};
 
 #[test]
 fn send_forget_password_user_does_not_exist() {
-  let account = Account::default();
+  let account = in_memory_account();
   assert!(account.send_forgot_password("test@mail.de").is_ok());
 }
 
 #[test]
 fn send_forget_password_invalid_mail() {
-  let account = Account::default();
+  let account = in_memory_account();
   assert!(account.send_forgot_password("test").is_err());
 }
 
 #[test]
 fn send_forgot_password_user_exists_and_receive() {
-  let account = Account::default();
+  let account = in_memory_account();
   let post_obj = CreateMember {
     nickname: "fscngsuzfdcsv".to_string(),
     credentials: Credentials {
@@ -39,12 +38,10 @@ fn send_forgot_password_user_exists_and_receive() {
   let forgot_id = sha3::hash(&[&val_pair.member_id.to_string(), "forgot", &salt]);
   let receive_forgot = account.recv_forgot_password(&forgot_id);
   assert!(receive_forgot.is_ok());
-
-  account.db_main.execute("DELETE FROM account_member WHERE mail='fscngsuzfdcsv@jaylappTest.dev'");
 }
 
 #[test]
 fn recv_forgot_password_invalid_id() {
-  let account = Account::default();
+  let account = in_memory_account();
   assert!(account.recv_forgot_password("bla").is_err());
 }
\ No newline at end of file
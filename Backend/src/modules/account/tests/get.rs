@@ -1,19 +1,17 @@
-use mysql_connection::tools::Execute;
-
 use crate::modules::account::dto::{CreateMember, Credentials};
-use crate::modules::account::material::Account;
+use crate::modules::account::tests::in_memory_account;
 use crate::modules::account::tools::{Create, GetAccountInformation};
 
 #[test]
 fn get_does_not_exist() {
-  let account = Account::default();
+  let account = in_memory_account();
   let acc_info = account.get(42);
   assert!(acc_info.is_err());
 }
 
 #[test]
 fn get_exists() {
-  let account = Account::default();
+  let account = in_memory_account();
   let post_obj = CreateMember {
     nickname: "ijfeuhifsduhisdfuhiuhisdf".to_string(),
     credentials: Credentials {
@@ -25,6 +23,4 @@ fn get_exists() {
   let login = account.create(&post_obj.credentials.mail, &post_obj.nickname, &post_obj.credentials.password).unwrap();
   let acc_info = account.get(login.member_id);
   assert!(acc_info.is_ok());
-
-  account.db_main.execute("DELETE FROM account_member WHERE mail='ijfeuhifsduhisdfuhiuhisdf@jaylappTest.dev'");
 }
\ No newline at end of file
@@ -1,10 +1,51 @@
-use crate::modules::account::material::Account;
-use crate::modules::account::tools::Login;
+use str_util::{password, sha3};
+
+use crate::modules::account::repository::MemberRepository;
+use crate::modules::account::tests::in_memory_account;
+use crate::modules::account::tools::{Create, Login, Update};
 
 // User exists login is tested when creating an account
 #[test]
 fn login_user_does_not_exist() {
-  let account = Account::default();
+  let account = in_memory_account();
   let login = account.login("NothingLol", "NotSecret");
   assert!(login.is_err());
+}
+
+#[test]
+fn login_rehashes_legacy_password() {
+  let account = in_memory_account();
+  let login = account.create("hjkgsdfgsdfgasdfqw@jaylappTest.dev", "hjkgsdfgsdfgasdfqw", "Password123456Password123456Password123456").unwrap();
+  assert!(!password::needs_rehash(&account.member.read().unwrap().get(&login.member_id).unwrap().password));
+
+  // Simulate a member that was created before the password scheme was changed
+  {
+    let mut member = account.member.write().unwrap();
+    let entry = member.get_mut(&login.member_id).unwrap();
+    entry.password = sha3::hash(&["Password123456Password123456Password123456", &entry.salt]);
+    assert!(account.backend.update_member(entry));
+  }
+
+  assert!(account.login("hjkgsdfgsdfgasdfqw@jaylappTest.dev", "WrongPassword").is_err());
+  assert!(password::needs_rehash(&account.member.read().unwrap().get(&login.member_id).unwrap().password));
+
+  assert!(account.login("hjkgsdfgsdfgasdfqw@jaylappTest.dev", "Password123456Password123456Password123456").is_ok());
+  assert!(!password::needs_rehash(&account.member.read().unwrap().get(&login.member_id).unwrap().password));
+
+  // The rehashed password has to be persisted as well
+  let persisted_member = account.backend.load_members().unwrap().into_iter().find(|member| member.id == login.member_id).unwrap();
+  assert!(!password::needs_rehash(&persisted_member.password));
+
+  assert!(account.update_password("Password654321Password654321Password654321", login.member_id).is_ok());
+  assert!(!password::needs_rehash(&account.member.read().unwrap().get(&login.member_id).unwrap().password));
+  assert!(account.login("hjkgsdfgsdfgasdfqw@jaylappTest.dev", "Password654321Password654321Password654321").is_ok());
+}
+
+#[test]
+fn login_considers_whole_long_password() {
+  let account = in_memory_account();
+  let long_password = "Password123456".repeat(6);
+  account.create("lkjhgfdsapoiuztre@jaylappTest.dev", "lkjhgfdsapoiuztre", &long_password).unwrap();
+  assert!(account.login("lkjhgfdsapoiuztre@jaylappTest.dev", &long_password).is_ok());
+  assert!(account.login("lkjhgfdsapoiuztre@jaylappTest.dev", &format!("{}X", &long_password[..long_password.len() - 1])).is_err());
 }
\ No newline at end of file
@@ -1,7 +1,15 @@
+use crate::modules::account::material::Account;
+use crate::modules::account::repository::InMemoryAccountBackend;
+
+mod administrate;
 mod create;
 mod delete;
 mod get;
 mod login;
 mod update;
 mod token;
-mod forgot;
\ No newline at end of file
+mod forgot;
+
+fn in_memory_account() -> Account {
+  Account::with_backend(Box::new(InMemoryAccountBackend::default()))
+}
\ No newline at end of file
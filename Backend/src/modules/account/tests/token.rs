@@ -1,13 +1,11 @@
-use mysql_connection::tools::Execute;
-
 use crate::modules::account::dto::{CreateMember, Credentials};
-use crate::modules::account::material::Account;
+use crate::modules::account::tests::in_memory_account;
 use crate::modules::account::tools::{Create, Login, Token, Update};
 use str_util::sha3;
 
 #[test]
 fn validate_valid() {
-  let account = Account::default();
+  let account = in_memory_account();
   let post_obj = CreateMember {
     nickname: "cvcbmnbjfie".to_string(),
     credentials: Credentials {
@@ -18,19 +16,17 @@ fn validate_valid() {
 
   let api_token = account.create(&post_obj.credentials.mail, &post_obj.nickname, &post_obj.credentials.password).unwrap();
   assert!(account.validate_token(api_token.token.as_ref().unwrap()).is_some());
-
-  account.db_main.execute("DELETE FROM account_member WHERE mail='cvcbmnbjfie@jaylappTest.dev'");
 }
 
 #[test]
 fn validate_invalid() {
-  let account = Account::default();
+  let account = in_memory_account();
   assert!(account.validate_token("someHash").is_none());
 }
 
 #[test]
 fn validation_invalid_after_update() {
-  let account = Account::default();
+  let account = in_memory_account();
   let post_obj = CreateMember {
     nickname: "klsdkfsowerf".to_string(),
     credentials: Credentials {
@@ -48,13 +44,11 @@ fn validation_invalid_after_update() {
   let api_token_three = account.change_password("SuperDuperSecretPasswordDefNotSecretTho", api_token.member_id).unwrap();
   assert!(account.validate_token(api_token_two.token.as_ref().unwrap()).is_none());
   assert!(account.validate_token(api_token_three.token.as_ref().unwrap()).is_some());
-
-  account.db_main.execute("DELETE FROM account_member WHERE mail='klsdkfsowerf@jaylappTest.dev'");
 }
 
 #[test]
 fn get_all_tokens() {
-  let account = Account::default();
+  let account = in_memory_account();
   let post_obj = CreateMember {
     nickname: "fhfgjhfgjfghfjg".to_string(),
     credentials: Credentials {
@@ -66,13 +60,11 @@ fn get_all_tokens() {
   let tokens = account.get_all_token(api_token.member_id);
   assert_eq!(tokens.len(), 1);
   assert_eq!(tokens[0].token, None);
-
-  account.db_main.execute("DELETE FROM account_member WHERE mail='fhfgjhfgjfghfjg@jaylappTest.dev'");
 }
 
 #[test]
 fn delete_token() {
-  let account = Account::default();
+  let account = in_memory_account();
   let post_obj = CreateMember {
     nickname: "sadgsdfgsddfgsdg".to_string(),
     credentials: Credentials {
@@ -90,13 +82,11 @@ fn delete_token() {
 
   assert!(account.delete_token(new_token.id, api_token.member_id).is_ok());
   assert!(account.validate_token(new_token.token.as_ref().unwrap()).is_none());
-
-  account.db_main.execute("DELETE FROM account_member WHERE mail='sadgsdfgsddfgsdg@jaylappTest.dev'");
 }
 
 #[test]
 fn prolong_token() {
-  let account = Account::default();
+  let account = in_memory_account();
   let post_obj = CreateMember {
     nickname: "sdfjikoijhsdfuhiihud".to_string(),
     credentials: Credentials {
@@ -115,13 +105,11 @@ fn prolong_token() {
   assert!(new_token.is_ok());
   let in_thirty_days = time_util::get_ts_from_now_in_secs(30);
   assert!(in_thirty_days - new_token.unwrap().exp_date <= 5);
-
-  account.db_main.execute("DELETE FROM account_member WHERE mail='sdfjikoijhsdfuhiihud@jaylappTest.dev'");
 }
 
 #[test]
 fn prolong_token_by_str() {
-  let account = Account::default();
+  let account = in_memory_account();
   let post_obj = CreateMember {
     nickname: "sdfjiksdfsdfsdfsdfoijhsdfuhiihud".to_string(),
     credentials: Credentials {
@@ -140,6 +128,4 @@ fn prolong_token_by_str() {
   assert!(new_token.is_ok());
   let in_thirty_days = time_util::get_ts_from_now_in_secs(30);
   assert!(in_thirty_days - new_token.unwrap().exp_date <= 5);
-
-  account.db_main.execute("DELETE FROM account_member WHERE mail='sdfjiksdfsdfsdfsdfoijhsdfuhiihud@jaylappTest.dev'");
 }
\ No newline at end of file
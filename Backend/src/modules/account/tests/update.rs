@@ -1,13 +1,14 @@
-use mysql_connection::tools::Execute;
 use str_util::sha3;
 
 use crate::modules::account::dto::{CreateMember, Credentials};
-use crate::modules::account::material::Account;
-use crate::modules::account::tools::{Create, Update};
+use crate::modules::account::material::Member;
+use crate::modules::account::repository::{InMemoryAccountBackend, MemberRepository};
+use crate::modules::account::tests::in_memory_account;
+use crate::modules::account::tools::{Create, Token, Update};
 
 #[test]
 fn change_name() {
-  let account = Account::default();
+  let account = in_memory_account();
   let post_obj = CreateMember {
     nickname: "ijofsdiojsdfgiuhig".to_string(),
     credentials: Credentials {
@@ -20,13 +21,11 @@ fn change_name() {
   let changed_name = account.change_name("SomeUsername", api_token.member_id);
   assert!(changed_name.is_ok());
   assert_eq!(changed_name.unwrap().nickname, "SomeUsername".to_string());
-
-  account.db_main.execute("DELETE FROM account_member WHERE mail='ijofsdiojsdfgiuhig@jaylappTest.dev'");
 }
 
 #[test]
 fn change_name_empty_content() {
-  let account = Account::default();
+  let account = in_memory_account();
   let post_obj = CreateMember {
     nickname: "siodjfijsiojiospq".to_string(),
     credentials: Credentials {
@@ -38,13 +37,11 @@ fn change_name_empty_content() {
   let api_token = account.create(&post_obj.credentials.mail, &post_obj.nickname, &post_obj.credentials.password).unwrap();
   let changed_name = account.change_name("", api_token.member_id);
   assert!(changed_name.is_err());
-
-  account.db_main.execute("DELETE FROM account_member WHERE mail='siodjfijsiojiospq@jaylappTest.dev'");
 }
 
 #[test]
 fn change_name_invalid_content() {
-  let account = Account::default();
+  let account = in_memory_account();
   let post_obj = CreateMember {
     nickname: "ihsdfoiosdf".to_string(),
     credentials: Credentials {
@@ -56,13 +53,11 @@ fn change_name_invalid_content() {
   let api_token = account.create(&post_obj.credentials.mail, &post_obj.nickname, &post_obj.credentials.password).unwrap();
   let changed_name = account.change_name("ihsdfoiosdf ihsdfoiosdf", api_token.member_id);
   assert!(changed_name.is_err());
-
-  account.db_main.execute("DELETE FROM account_member WHERE mail='ihsdfoiosdf@jaylappTest.dev'");
 }
 
 #[test]
 fn change_name_name_taken() {
-  let account = Account::default();
+  let account = in_memory_account();
   let post_obj = CreateMember {
     nickname: "oasijidhaais".to_string(),
     credentials: Credentials {
@@ -83,14 +78,11 @@ fn change_name_name_taken() {
   let _ = account.create(&post_obj_two.credentials.mail, &post_obj_two.nickname, &post_obj_two.credentials.password).unwrap();
   let changed_name = account.change_name(&post_obj_two.nickname, api_token.member_id);
   assert!(changed_name.is_err());
-
-  account.db_main.execute("DELETE FROM account_member WHERE mail='oasijidhaais@jaylappTest.dev'");
-  account.db_main.execute("DELETE FROM account_member WHERE mail='guhzasooas@jaylappTest.dev'");
 }
 
 #[test]
 fn change_password_empty_content() {
-  let account = Account::default();
+  let account = in_memory_account();
   let post_obj = CreateMember {
     nickname: "mvfhhbvidsd".to_string(),
     credentials: Credentials {
@@ -102,13 +94,11 @@ fn change_password_empty_content() {
   let api_token = account.create(&post_obj.credentials.mail, &post_obj.nickname, &post_obj.credentials.password).unwrap();
   let changed_password = account.change_password("", api_token.member_id);
   assert!(changed_password.is_err());
-
-  account.db_main.execute("DELETE FROM account_member WHERE mail='mvfhhbvidsd@jaylappTest.dev'");
 }
 
 #[test]
 fn change_password() {
-  let account = Account::default();
+  let account = in_memory_account();
   let post_obj = CreateMember {
     nickname: "xdsdfgsdgs".to_string(),
     credentials: Credentials {
@@ -124,13 +114,13 @@ fn change_password() {
   assert_ne!(new_api_token.token, api_token.token);
   assert_ne!(new_api_token.id, api_token.id);
   assert_eq!(new_api_token.member_id, api_token.member_id);
-
-  account.db_main.execute("DELETE FROM account_member WHERE mail='xdsdfgsdgs@jaylappTest.dev'");
+  assert!(account.validate_token(api_token.token.as_ref().unwrap()).is_none());
+  assert!(account.validate_token(new_api_token.token.as_ref().unwrap()).is_some());
 }
 
 #[test]
 fn change_mail_empty_content() {
-  let account = Account::default();
+  let account = in_memory_account();
   let post_obj = CreateMember {
     nickname: "nsigsvbsdsd".to_string(),
     credentials: Credentials {
@@ -142,13 +132,11 @@ fn change_mail_empty_content() {
   let api_token = account.create(&post_obj.credentials.mail, &post_obj.nickname, &post_obj.credentials.password).unwrap();
   let changed_mail = account.request_change_mail("", api_token.member_id);
   assert!(changed_mail.is_err());
-
-  account.db_main.execute("DELETE FROM account_member WHERE mail='nsigsvbsdsd@jaylappTest.dev'");
 }
 
 #[test]
 fn change_mail_invalid_content() {
-  let account = Account::default();
+  let account = in_memory_account();
   let post_obj = CreateMember {
     nickname: "asiudfuhisduifs".to_string(),
     credentials: Credentials {
@@ -160,13 +148,11 @@ fn change_mail_invalid_content() {
   let api_token = account.create(&post_obj.credentials.mail, &post_obj.nickname, &post_obj.credentials.password).unwrap();
   let changed_mail = account.request_change_mail("asiudfuhisduifs", api_token.member_id);
   assert!(changed_mail.is_err());
-
-  account.db_main.execute("DELETE FROM account_member WHERE mail='asiudfuhisduifs@jaylappTest.dev'");
 }
 
 #[test]
 fn change_mail_mail_taken() {
-  let account = Account::default();
+  let account = in_memory_account();
   let post_obj = CreateMember {
     nickname: "csdazgtsdczas".to_string(),
     credentials: Credentials {
@@ -187,14 +173,11 @@ fn change_mail_mail_taken() {
   let _ = account.create(&post_obj_two.credentials.mail, &post_obj_two.nickname, &post_obj_two.credentials.password).unwrap();
   let changed_mail = account.request_change_mail(&post_obj_two.credentials.mail, api_token.member_id);
   assert!(changed_mail.is_err());
-
-  account.db_main.execute("DELETE FROM account_member WHERE mail='csdazgtsdczas@jaylappTest.dev'");
-  account.db_main.execute("DELETE FROM account_member WHERE mail='bdvshudvbsdv@jaylappTest.dev'");
 }
 
 #[test]
 fn change_mail() {
-  let account = Account::default();
+  let account = in_memory_account();
   let post_obj = CreateMember {
     nickname: "xdssdfsdfg".to_string(),
     credentials: Credentials {
@@ -223,6 +206,24 @@ fn change_mail() {
   assert_ne!(new_api_token.token, api_token.token);
   assert_ne!(new_api_token.id, api_token.id);
   assert_eq!(new_api_token.member_id, api_token.member_id);
+}
 
-  account.db_main.execute("DELETE FROM account_member WHERE mail='xdssdfsdfg2@bla.de'");
+#[test]
+fn update_missing_member() {
+  let backend = InMemoryAccountBackend::default();
+  let member = Member {
+    id: 42,
+    nickname: "sdfgjkhsdfgkjhsd".to_string(),
+    mail: "sdfgjkhsdfgkjhsd@jaylappTest.dev".to_string(),
+    password: String::new(),
+    salt: String::new(),
+    mail_confirmed: false,
+    forgot_password: false,
+    delete_account: false,
+    new_mail: String::new(),
+    access_rights: 0,
+    locked: false,
+  };
+  assert!(!backend.update_member(&member));
+  assert!(!backend.update_member_and_clear_tokens(&member));
 }
\ No newline at end of file
@@ -0,0 +1,20 @@
+use crate::modules::account::domain_value::AccessRight;
+use crate::modules::account::material::Account;
+use crate::modules::account::tools::Administrate;
+
+// backend admin grant <mail>   Grants the admin right to the member, e.g. to set up the first admin
+pub fn run_admin_command(account: &Account, args: &[String]) -> Result<(), String> {
+  match (args.get(0).map(|arg| arg.as_str()), args.get(1)) {
+    (Some("grant"), Some(mail)) => {
+      let member_id = account.member.read().unwrap().values()
+        .find(|member| member.mail.eq_ignore_ascii_case(mail))
+        .map(|member| member.id)
+        .ok_or_else(|| format!("No member with the mail {}", mail))?;
+      account.grant_access_right(member_id, AccessRight::Admin)
+        .map_err(|failure| format!("Granting the admin right failed: {:?}", failure))?;
+      println!("Granted the admin right to member {}", member_id);
+      Ok(())
+    },
+    _ => Err("Usage: backend admin grant <mail>".to_owned())
+  }
+}
\ No newline at end of file
@@ -0,0 +1,72 @@
+use crate::modules::account::dto::Failure;
+use crate::modules::account::domain_value::{AccessRight, AccountInformation};
+use crate::modules::account::material::{Account, Member};
+use crate::modules::account::repository::MemberRepository;
+use crate::modules::account::tools::{GetAccountInformation, Token};
+
+pub trait Administrate {
+  fn has_access_right(&self, member_id: u32, access_right: AccessRight) -> bool;
+  fn grant_access_right(&self, member_id: u32, access_right: AccessRight) -> Result<AccountInformation, Failure>;
+  fn revoke_access_right(&self, admin_id: u32, member_id: u32, access_right: AccessRight) -> Result<AccountInformation, Failure>;
+  fn lock_account(&self, admin_id: u32, member_id: u32) -> Result<(), Failure>;
+  fn unlock_account(&self, member_id: u32) -> Result<(), Failure>;
+}
+
+impl Administrate for Account {
+  fn has_access_right(&self, member_id: u32, access_right: AccessRight) -> bool {
+    let member = self.member.read().unwrap();
+    member.get(&member_id)
+      .map(|entry| !entry.locked && access_right.is_granted(entry.access_rights))
+      .unwrap_or(false)
+  }
+
+  fn grant_access_right(&self, member_id: u32, access_right: AccessRight) -> Result<AccountInformation, Failure> {
+    update_member_entry(self, member_id, |entry| entry.access_rights |= access_right.bit())
+      .and_then(|()| self.get(member_id))
+  }
+
+  // Admins can not lock themselves out, another admin has to do that
+  fn revoke_access_right(&self, admin_id: u32, member_id: u32, access_right: AccessRight) -> Result<AccountInformation, Failure> {
+    if admin_id == member_id {
+      return Err(Failure::SelfAdministration);
+    }
+    update_member_entry(self, member_id, |entry| entry.access_rights &= !access_right.bit())
+      .and_then(|()| self.get(member_id))
+  }
+
+  fn lock_account(&self, admin_id: u32, member_id: u32) -> Result<(), Failure> {
+    if admin_id == member_id {
+      return Err(Failure::SelfAdministration);
+    }
+    let mut member = self.member.write().unwrap();
+    let entry = member.get_mut(&member_id).ok_or(Failure::InvalidMember)?;
+    let mut updated_entry = entry.clone();
+    updated_entry.locked = true;
+
+    // Existing sessions must not outlive the lock
+    if !self.backend.update_member_and_clear_tokens(&updated_entry) {
+      return Err(Failure::Unknown);
+    }
+    *entry = updated_entry;
+    self.clear_cached_tokens(member_id);
+    Ok(())
+  }
+
+  fn unlock_account(&self, member_id: u32) -> Result<(), Failure> {
+    update_member_entry(self, member_id, |entry| entry.locked = false)
+  }
+}
+
+// Updates the stored member first, the cached one only if that succeeded
+fn update_member_entry(account: &Account, member_id: u32, update: impl FnOnce(&mut Member)) -> Result<(), Failure> {
+  let mut member = account.member.write().unwrap();
+  let entry = member.get_mut(&member_id).ok_or(Failure::InvalidMember)?;
+  let mut updated_entry = entry.clone();
+  update(&mut updated_entry);
+
+  if !account.backend.update_member(&updated_entry) {
+    return Err(Failure::Unknown);
+  }
+  *entry = updated_entry;
+  Ok(())
+}
\ No newline at end of file
@@ -1,13 +1,13 @@
 use language::domain_value::Language;
 use language::tools::Get;
 use mail;
-use mysql_connection::tools::{Execute, Select};
-use str_util::{random, sha3, strformat};
+use str_util::{password, random, sha3, strformat};
 use validator::domain_value::PasswordFailure;
 use validator::tools::{valid_mail, valid_nickname, valid_password};
 
 use crate::modules::account::dto::Failure;
 use crate::modules::account::material::{Account, APIToken, Member};
+use crate::modules::account::repository::MemberRepository;
 use crate::modules::account::tools::Token;
 
 pub trait Create {
@@ -33,6 +33,10 @@ impl Create for Account {
       Ok(_) => ()
     };
 
+    // Hashing is slow on purpose, hence it happens before the members are locked
+    let salt: String = random::alphanumeric(16);
+    let pass: String = password::hash(password, &salt);
+
     // The following part needs to be transactional
     let member_id: u32;
     {
@@ -47,36 +51,20 @@ impl Create for Account {
         }
       }
 
-      let salt: String = random::alphanumeric(16);
-      let pass: String = sha3::hash(&[password, &salt]);
-
-      if self.db_main.execute_wparams("INSERT IGNORE INTO account_member (`mail`, `password`, `nickname`, `salt`, `joined`) VALUES (:mail, :pass, :nickname, :salt, UNIX_TIMESTAMP())", params!(
-      "nickname" => nickname.clone(),
-      "mail" => lower_mail.clone(),
-      "pass" => pass.clone(),
-      "salt" => salt.clone()
-      ),
-      ) {
-        member_id = self.db_main.select_wparams_value("SELECT id FROM account_member WHERE mail = :mail", &|mut row| {
-          row.take(0).unwrap()
-        }, params!(
-        "mail" => lower_mail.clone()
-      )).unwrap();
-        member.insert(member_id, Member {
-          id: member_id,
-          nickname: nickname.to_owned(),
-          mail: lower_mail.clone(),
-          password: pass,
-          salt,
-          mail_confirmed: false,
-          forgot_password: false,
-          delete_account: false,
-          new_mail: String::new(),
-          access_rights: 0,
-        });
-      } else {
-        return Err(Failure::Unknown);
-      }
+      member_id = self.backend.insert_member(&lower_mail, nickname, &pass, &salt).ok_or(Failure::Unknown)?;
+      member.insert(member_id, Member {
+        id: member_id,
+        nickname: nickname.to_owned(),
+        mail: lower_mail.clone(),
+        password: pass,
+        salt,
+        mail_confirmed: false,
+        forgot_password: false,
+        delete_account: false,
+        new_mail: String::new(),
+        access_rights: 0,
+        locked: false,
+      });
     }
 
     self.send_confirmation(member_id);
@@ -116,11 +104,11 @@ impl Create for Account {
     }
 
     let member_id = *confirm_id_res.unwrap();
-    if self.db_main.execute_wparams("UPDATE account_member SET mail_confirmed=1 WHERE id=:id", params!(
-      "id" => member_id
-    )) {
-      let entry = member.get_mut(&member_id).unwrap();
-      entry.mail_confirmed = true;
+    let entry = member.get_mut(&member_id).unwrap();
+    let mut updated_entry = entry.clone();
+    updated_entry.mail_confirmed = true;
+    if self.backend.update_member(&updated_entry) {
+      *entry = updated_entry;
       requires_mail_confirmation.remove(id);
       return true;
     }
@@ -1,11 +1,11 @@
 use language::domain_value::Language;
 use language::tools::Get;
 use mail;
-use mysql_connection::tools::Execute;
 use str_util::{sha3, strformat};
 
 use crate::modules::account::dto::Failure;
 use crate::modules::account::material::Account;
+use crate::modules::account::repository::MemberRepository;
 
 pub trait Delete {
   fn issue_delete(&self, member_id: u32) -> Result<(), Failure>;
@@ -17,9 +17,11 @@ impl Delete for Account {
   {
     let mut requires_mail_confirmation = self.requires_mail_confirmation.write().unwrap();
     let mut member = self.member.write().unwrap();
-    if self.db_main.execute_wparams("UPDATE account_member SET delete_account=1 WHERE id=:id", params!("id" => member_id)) {
-      let entry = member.get_mut(&member_id).unwrap();
-      entry.delete_account = true;
+    let entry = member.get_mut(&member_id).unwrap();
+    let mut updated_entry = entry.clone();
+    updated_entry.delete_account = true;
+    if self.backend.update_member(&updated_entry) {
+      *entry = updated_entry;
 
       let delete_id = sha3::hash(&[&member_id.to_string(), "delete", &entry.salt]);
       requires_mail_confirmation.insert(delete_id.clone(), member_id);
@@ -47,11 +49,8 @@ impl Delete for Account {
       return Err(Failure::DeleteNotIssued);
     }
 
-    // Due to foreign key constraints, other tables depending on the member_id will also be deleted
     let member_id = *delete_confirmation_res.unwrap();
-    if self.db_main.execute_wparams("DELETE FROM account_member WHERE id = :id", params!(
-      "id" => member_id
-    )) {
+    if self.backend.delete_member(member_id) {
       {// Remove all other fields that somehow point to this member_id
         let member_entry = member.get(&member_id).unwrap();
 
@@ -1,12 +1,12 @@
 use language::domain_value::Language;
 use language::tools::Get;
 use mail;
-use mysql_connection::tools::Execute;
 use str_util::{random, sha3, strformat};
 use validator::tools::valid_mail;
 
 use crate::modules::account::dto::Failure;
 use crate::modules::account::material::{Account, APIToken};
+use crate::modules::account::repository::MemberRepository;
 use crate::modules::account::tools::{Token, Update};
 
 pub trait Forgot {
@@ -40,11 +40,13 @@ impl Forgot for Account {
     }
 
     let unwrapped_member_id = member_id.unwrap();
-    if self.db_main.execute_wparams("UPDATE account_member SET forgot_password=1 WHERE id=:id", params!("id" => unwrapped_member_id)) {
-      let entry = member.get_mut(&unwrapped_member_id).unwrap();
+    let entry = member.get_mut(&unwrapped_member_id).unwrap();
+    let mut updated_entry = entry.clone();
+    updated_entry.forgot_password = true;
+    if self.backend.update_member(&updated_entry) {
+      *entry = updated_entry;
       let forgot_id = sha3::hash(&[&unwrapped_member_id.to_string(), "forgot", &entry.salt]);
 
-      entry.forgot_password = true;
       requires_mail_confirmation.insert(forgot_id.clone(), unwrapped_member_id);
 
       // Only send a mail if we really set up the internal structures properly
@@ -67,11 +69,11 @@ impl Forgot for Account {
         Some(member_id) => {
           user_id = *member_id;
           let mut member = self.member.write().unwrap();
-          if self.db_main.execute_wparams("UPDATE account_member SET forgot_password=0 WHERE id=:id", params!(
-            "id" => *member_id
-          )) {
-            let entry = member.get_mut(member_id).unwrap();
-            entry.forgot_password = false;
+          let entry = member.get_mut(member_id).unwrap();
+          let mut updated_entry = entry.clone();
+          updated_entry.forgot_password = false;
+          if self.backend.update_member(&updated_entry) {
+            *entry = updated_entry;
           } else {
             return Err(Failure::Unknown);
           }
@@ -1,9 +1,10 @@
 use language::domain_value::Language;
 use language::tools::Get;
-use str_util::sha3;
+use str_util::password;
 
 use crate::modules::account::dto::Failure;
 use crate::modules::account::material::{Account, APIToken};
+use crate::modules::account::repository::MemberRepository;
 use crate::modules::account::tools::Token;
 
 pub trait Login {
@@ -23,11 +24,37 @@ impl Login for Account {
 
   fn validate_credentials(&self, mail: &str, password: &str) -> Result<u32, Failure> {
     let lower_mail = mail.to_lowercase();
-    for entry in self.member.read().unwrap().values() {
-      if entry.mail != lower_mail { continue; }
-      if entry.password != sha3::hash(&[&password, &entry.salt]) { break; } // Password is wrong
-      return Ok(entry.id);
+    // Bcrypt is slow on purpose, hence it must not block the members while verifying
+    let (member_id, salt, stored_hash) = self.member.read().unwrap().values()
+      .find(|entry| entry.mail == lower_mail)
+      .map(|entry| (entry.id, entry.salt.clone(), entry.password.clone()))
+      .ok_or(Failure::InvalidCredentials)?;
+    if !password::verify(password, &salt, &stored_hash) {
+      return Err(Failure::InvalidCredentials);
     }
-    Err(Failure::InvalidCredentials)
+
+    if password::needs_rehash(&stored_hash) {
+      rehash_password(self, password, member_id, &salt, &stored_hash);
+    }
+    Ok(member_id)
+  }
+}
+
+// Upgrades a password stored with an outdated scheme, existing tokens stay valid
+fn rehash_password(account: &Account, password: &str, member_id: u32, salt: &str, stored_hash: &str) {
+  let password_hash = password::hash(password, salt);
+  let mut member = account.member.write().unwrap();
+  let entry = match member.get_mut(&member_id) {
+    Some(entry) => entry,
+    None => return
+  };
+  // The password may have been changed meanwhile
+  if entry.password != stored_hash {
+    return;
+  }
+  let mut updated_entry = entry.clone();
+  updated_entry.password = password_hash;
+  if account.backend.update_member(&updated_entry) {
+    *entry = updated_entry;
   }
 }
\ No newline at end of file
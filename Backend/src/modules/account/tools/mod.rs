@@ -1,3 +1,5 @@
+pub use self::admin_command::run_admin_command;
+pub use self::administrate::Administrate;
 pub use self::create::Create;
 pub use self::delete::Delete;
 pub use self::forgot::Forgot;
@@ -6,6 +8,8 @@ pub use self::login::Login;
 pub use self::token::Token;
 pub use self::update::Update;
 
+mod admin_command;
+mod administrate;
 mod create;
 mod delete;
 mod forgot;
@@ -1,14 +1,15 @@
-use mysql_connection::tools::{Execute, Select};
 use str_util::{random, sha3};
 use time_util;
 
 use crate::modules::account::dto::Failure;
 use crate::modules::account::material::{Account, APIToken};
+use crate::modules::account::repository::TokenRepository;
 
 pub trait Token {
   fn get_all_token(&self, member_id: u32) -> Vec<APIToken>;
   fn validate_token(&self, api_token: &str) -> Option<u32>;
   fn clear_tokens(&self, member_id: u32) -> Result<(), Failure>;
+  fn clear_cached_tokens(&self, member_id: u32);
   fn create_token(&self, purpose: &str, member_id: u32, exp_date: u64) -> Result<APIToken, Failure>;
   fn delete_token(&self, token_id: u32, member_id: u32) -> Result<(), Failure>;
   fn prolong_token(&self, token_id: u32, member_id: u32, days: u32) -> Result<APIToken, Failure>;
@@ -62,21 +63,23 @@ impl Token for Account {
   }
 
   fn clear_tokens(&self, member_id: u32) -> Result<(), Failure> {
-    let mut api_token_to_member_id = self.api_token_to_member_id.write().unwrap();
-    let mut api_token = self.api_tokens.write().unwrap();
-
-    if !self.db_main.execute_wparams("DELETE FROM account_api_token WHERE member_id=:member_id", params!(
-      "member_id" => member_id
-    )) {
+    if !self.backend.delete_member_tokens(member_id) {
       return Err(Failure::Unknown);
     }
+    self.clear_cached_tokens(member_id);
+    Ok(())
+  }
 
-    for api_token in api_token.get(&member_id).unwrap() {
-      api_token_to_member_id.remove(api_token.token.as_ref().unwrap());
-    }
-    api_token.get_mut(&member_id).unwrap().clear();
+  fn clear_cached_tokens(&self, member_id: u32) {
+    let mut api_token_to_member_id = self.api_token_to_member_id.write().unwrap();
+    let mut api_token = self.api_tokens.write().unwrap();
 
-    Ok(())
+    if let Some(token_vec) = api_token.get_mut(&member_id) {
+      for api_token in token_vec.iter() {
+        api_token_to_member_id.remove(api_token.token.as_ref().unwrap());
+      }
+      token_vec.clear();
+    }
   }
 
   fn create_token(&self, purpose: &str, member_id: u32, exp_date: u64) -> Result<APIToken, Failure> {
@@ -101,6 +104,9 @@ impl Token for Account {
     {
       let member = self.member.read().unwrap();
       let member_entry = member.get(&member_id).unwrap();
+      if member_entry.locked {
+        return Err(Failure::AccountLocked);
+      }
       let salt: String = random::alphanumeric(16);
       real_token = sha3::hash(&[&member_entry.mail, &member_entry.password, &salt]);
       db_token = sha3::hash(&[&real_token, &"token".to_owned()]);
@@ -110,34 +116,7 @@ impl Token for Account {
     let mut api_token_to_member_id = self.api_token_to_member_id.write().unwrap();
     let mut api_tokens = self.api_tokens.write().unwrap();
 
-    if !self.db_main.execute_wparams(
-      "INSERT INTO account_api_token (member_id, token, purpose, exp_date) VALUES (:member_id, :token, :purpose, :exp_date)",
-      params!(
-        "member_id" => member_id,
-        "token" => db_token.clone(),
-        "purpose" => purpose,
-        "exp_date" => exp_date
-      ),
-    ) {
-      return Err(Failure::Unknown);
-    }
-
-    match self.db_main.select_wparams_value(
-      "SELECT id, member_id, token, purpose, exp_date FROM account_api_token WHERE member_id=:member_id AND token=:token",
-      &|mut row| {
-        APIToken {
-          id: row.take(0).unwrap(),
-          member_id: row.take(1).unwrap(),
-          token: Some(row.take(2).unwrap()),
-          purpose: row.take(3).unwrap(),
-          exp_date: row.take(4).unwrap(),
-        }
-      },
-      params!(
-        "member_id" => member_id,
-        "token" => db_token.clone()
-      ),
-    ) {
+    match self.backend.insert_token(member_id, &db_token, purpose, exp_date) {
       Some(token) => {
         if api_tokens.get(&member_id).is_none() {
           api_tokens.insert(member_id, vec![token.clone()]);
@@ -162,13 +141,7 @@ impl Token for Account {
     let mut api_token_to_member_id = self.api_token_to_member_id.write().unwrap();
     let mut api_tokens = self.api_tokens.write().unwrap();
 
-    if !self.db_main.execute_wparams(
-      "DELETE FROM account_api_token WHERE id=:id AND member_id=:member_id",
-      params!(
-        "id" => token_id,
-        "member_id" => member_id
-      ),
-    ) {
+    if !self.backend.delete_token(token_id, member_id) {
       return Err(Failure::Unknown);
     }
 
@@ -197,11 +170,7 @@ impl Token for Account {
     // Continue to update the token
     let mut api_tokens = self.api_tokens.write().unwrap();
     let exp_date = time_util::get_ts_from_now_in_secs(days as u64);
-    if self.db_main.execute_wparams("UPDATE account_api_token SET exp_date=:exp_date WHERE id=:id AND member_id=:member_id", params!(
-      "exp_date" => exp_date,
-      "id" => token_id,
-      "member_id" => member_id
-    )) {
+    if self.backend.update_token_exp_date(token_id, member_id, exp_date) {
       let token_vec = api_tokens.get_mut(&member_id).unwrap();
       let token_pos = token_vec.iter().position(|api_token| api_token.id == token_id).unwrap();
       let api_token = token_vec.get_mut(token_pos).unwrap();
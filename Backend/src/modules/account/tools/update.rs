@@ -1,13 +1,13 @@
 use language::domain_value::Language;
 use language::tools::Get;
-use mysql_connection::tools::Execute;
-use str_util::{sha3, strformat};
+use str_util::{password, sha3, strformat};
 use validator::domain_value::PasswordFailure;
 use validator::tools::{valid_mail, valid_nickname, valid_password};
 
 use crate::modules::account::dto::Failure;
 use crate::modules::account::domain_value::AccountInformation;
 use crate::modules::account::material::{Account, APIToken};
+use crate::modules::account::repository::MemberRepository;
 use crate::modules::account::tools::{GetAccountInformation, Token};
 
 pub trait Update {
@@ -37,12 +37,11 @@ impl Update for Account {
         }
       }
 
-      if self.db_main.execute_wparams("UPDATE account_member SET nickname=:nickname WHERE id=:id", params!(
-      "nickname" => new_nickname.clone(),
-      "id" => member_id
-    )) {
-        let entry = member.get_mut(&member_id).unwrap();
-        entry.nickname = new_nickname.to_owned();
+      let entry = member.get_mut(&member_id).unwrap();
+      let mut updated_entry = entry.clone();
+      updated_entry.nickname = new_nickname.to_owned();
+      if self.backend.update_member(&updated_entry) {
+        *entry = updated_entry;
       } else {
         return Err(Failure::NicknameIsInUse);
       }
@@ -66,23 +65,18 @@ impl Update for Account {
   }
 
   fn update_password(&self, new_password: &str, member_id: u32) -> Result<(), Failure> {
+    // Hashing is slow on purpose, hence it happens before the members are locked
+    let salt = self.member.read().unwrap().get(&member_id).map(|entry| entry.salt.clone()).ok_or(Failure::InvalidMember)?;
+    let password_hash = password::hash(new_password, &salt);
     let mut member = self.member.write().unwrap();
 
-    let hash: String;
-    {
-      let entry = member.get(&member_id).unwrap();
-      hash = sha3::hash(&[new_password, &entry.salt]);
-    }
+    let mut updated_entry = member.get(&member_id).ok_or(Failure::InvalidMember)?.clone();
+    updated_entry.password = password_hash;
 
-    if self.db_main.execute_wparams("UPDATE account_member SET password=:password WHERE id=:id", params!(
-      "password" => hash.clone(),
-      "id" => member_id
-    )) {
-      return self.clear_tokens(member_id).and_then(|()| {
-        let entry = member.get_mut(&member_id).unwrap();
-        entry.password = hash;
-        Ok(())
-      });
+    if self.backend.update_member_and_clear_tokens(&updated_entry) {
+      member.insert(member_id, updated_entry);
+      self.clear_cached_tokens(member_id);
+      return Ok(());
     }
     Err(Failure::Unknown)
   }
@@ -132,13 +126,12 @@ impl Update for Account {
         {
           let mut member = self.member.write().unwrap();
           let member_entry = member.get_mut(member_id).unwrap();
-          let lower_mail = member_entry.new_mail.clone();
-          if self.clear_tokens(*member_id).is_ok() && self.db_main.execute_wparams("UPDATE account_member SET mail=:mail WHERE id=:id", params!(
-            "mail" => lower_mail.clone(),
-            "id" => member_id
-          )) {
-            member_entry.mail = lower_mail.to_owned();
-            member_entry.new_mail = String::new();
+          let mut updated_entry = member_entry.clone();
+          updated_entry.mail = member_entry.new_mail.clone();
+          updated_entry.new_mail = String::new();
+          if self.backend.update_member_and_clear_tokens(&updated_entry) {
+            *member_entry = updated_entry;
+            self.clear_cached_tokens(*member_id);
           } else {
             return Err(Failure::Unknown);
           }
@@ -0,0 +1,38 @@
+use rocket::State;
+use rocket_contrib::json::Json;
+
+use crate::modules::account::dto::Failure;
+use crate::modules::account::domain_value::{AccessRight, AccountInformation};
+use crate::modules::account::guard::Admin;
+use crate::modules::account::material::Account;
+use crate::modules::account::tools::Administrate;
+
+#[openapi]
+#[post("/admin/grant/<member_id>", format = "application/json", data = "<access_right>")]
+pub fn grant_access_right(me: State<Account>, _admin: Admin, member_id: u32, access_right: Json<AccessRight>) -> Result<Json<AccountInformation>, Failure>
+{
+  me.grant_access_right(member_id, access_right.into_inner())
+    .and_then(|acc_info| Ok(Json(acc_info)))
+}
+
+#[openapi]
+#[post("/admin/revoke/<member_id>", format = "application/json", data = "<access_right>")]
+pub fn revoke_access_right(me: State<Account>, admin: Admin, member_id: u32, access_right: Json<AccessRight>) -> Result<Json<AccountInformation>, Failure>
+{
+  me.revoke_access_right(admin.0, member_id, access_right.into_inner())
+    .and_then(|acc_info| Ok(Json(acc_info)))
+}
+
+#[openapi]
+#[post("/admin/lock/<member_id>")]
+pub fn lock_account(me: State<Account>, admin: Admin, member_id: u32) -> Result<(), Failure>
+{
+  me.lock_account(admin.0, member_id)
+}
+
+#[openapi]
+#[post("/admin/unlock/<member_id>")]
+pub fn unlock_account(me: State<Account>, _admin: Admin, member_id: u32) -> Result<(), Failure>
+{
+  me.unlock_account(member_id)
+}
\ No newline at end of file
@@ -21,6 +21,17 @@ impl PartialEq for CharacterFacial {
 }
 
 impl CharacterFacial {
+  pub fn from_dto(id: u32, character_facial: &CharacterFacialDto) -> Self {
+    CharacterFacial {
+      id,
+      skin_color: character_facial.skin_color,
+      face_style: character_facial.face_style,
+      hair_style: character_facial.hair_style,
+      hair_color: character_facial.hair_color,
+      facial_hair: character_facial.facial_hair,
+    }
+  }
+
   pub fn compare_by_value(&self, other: &CharacterFacialDto) -> bool {
     self.skin_color == other.skin_color
       && self.face_style == other.face_style
@@ -36,4 +47,13 @@ impl CharacterFacial {
       && self.hair_color == other.hair_color
       && self.facial_hair == other.facial_hair
   }
-}
\ No newline at end of file
+}
+
+impl_from_row!(CharacterFacial, |row, prefix| [id, skin_color, face_style, hair_style, hair_color, facial_hair] => CharacterFacial {
+  id,
+  skin_color,
+  face_style,
+  hair_style,
+  hair_color,
+  facial_hair,
+});
\ No newline at end of file
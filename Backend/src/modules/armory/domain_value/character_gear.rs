@@ -1,6 +1,12 @@
+use mysql_connection::tools::FromRow;
+
 use crate::modules::armory::domain_value::CharacterItem;
 use crate::modules::armory::dto::{CharacterGearDto, CharacterItemDto};
 
+// The item columns of armory_gear, in the order of the items of a gear
+pub const GEAR_SLOTS: [&str; 19] = ["head", "neck", "shoulder", "back", "chest", "shirt", "tabard", "wrist", "main_hand", "off_hand",
+  "ternary_hand", "glove", "belt", "leg", "boot", "ring1", "ring2", "trinket1", "trinket2"];
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CharacterGear {
   pub id: u32,
@@ -36,6 +42,58 @@ impl PartialEq for CharacterGear {
 }
 
 impl CharacterGear {
+  // Builds the gear from its items in the order of GEAR_SLOTS
+  pub fn from_items(id: u32, items: Vec<Option<CharacterItem>>) -> Self {
+    let mut items = items.into_iter();
+    let mut next_item = || items.next().flatten();
+    CharacterGear {
+      id,
+      head: next_item(),
+      neck: next_item(),
+      shoulder: next_item(),
+      back: next_item(),
+      chest: next_item(),
+      shirt: next_item(),
+      tabard: next_item(),
+      wrist: next_item(),
+      main_hand: next_item(),
+      off_hand: next_item(),
+      ternary_hand: next_item(),
+      glove: next_item(),
+      belt: next_item(),
+      leg: next_item(),
+      boot: next_item(),
+      ring1: next_item(),
+      ring2: next_item(),
+      trinket1: next_item(),
+      trinket2: next_item(),
+    }
+  }
+
+  pub fn items(&self) -> Vec<&Option<CharacterItem>> {
+    vec![
+      &self.head,
+      &self.neck,
+      &self.shoulder,
+      &self.back,
+      &self.chest,
+      &self.shirt,
+      &self.tabard,
+      &self.wrist,
+      &self.main_hand,
+      &self.off_hand,
+      &self.ternary_hand,
+      &self.glove,
+      &self.belt,
+      &self.leg,
+      &self.boot,
+      &self.ring1,
+      &self.ring2,
+      &self.trinket1,
+      &self.trinket2
+    ]
+  }
+
   pub fn deep_eq(&self, other: &Self) -> bool {
     self.id == other.id
       && self.head.is_eq(&other.head)
@@ -82,6 +140,30 @@ impl CharacterGear {
   }
 }
 
+// The items are joined per slot and decoded with the prefix "<slot>_"
+impl_from_row!(CharacterGear, |row, prefix| [id] => CharacterGear {
+  id,
+  head: CharacterItem::from_row_opt(row, &format!("{}head_", prefix), "id")?,
+  neck: CharacterItem::from_row_opt(row, &format!("{}neck_", prefix), "id")?,
+  shoulder: CharacterItem::from_row_opt(row, &format!("{}shoulder_", prefix), "id")?,
+  back: CharacterItem::from_row_opt(row, &format!("{}back_", prefix), "id")?,
+  chest: CharacterItem::from_row_opt(row, &format!("{}chest_", prefix), "id")?,
+  shirt: CharacterItem::from_row_opt(row, &format!("{}shirt_", prefix), "id")?,
+  tabard: CharacterItem::from_row_opt(row, &format!("{}tabard_", prefix), "id")?,
+  wrist: CharacterItem::from_row_opt(row, &format!("{}wrist_", prefix), "id")?,
+  main_hand: CharacterItem::from_row_opt(row, &format!("{}main_hand_", prefix), "id")?,
+  off_hand: CharacterItem::from_row_opt(row, &format!("{}off_hand_", prefix), "id")?,
+  ternary_hand: CharacterItem::from_row_opt(row, &format!("{}ternary_hand_", prefix), "id")?,
+  glove: CharacterItem::from_row_opt(row, &format!("{}glove_", prefix), "id")?,
+  belt: CharacterItem::from_row_opt(row, &format!("{}belt_", prefix), "id")?,
+  leg: CharacterItem::from_row_opt(row, &format!("{}leg_", prefix), "id")?,
+  boot: CharacterItem::from_row_opt(row, &format!("{}boot_", prefix), "id")?,
+  ring1: CharacterItem::from_row_opt(row, &format!("{}ring1_", prefix), "id")?,
+  ring2: CharacterItem::from_row_opt(row, &format!("{}ring2_", prefix), "id")?,
+  trinket1: CharacterItem::from_row_opt(row, &format!("{}trinket1_", prefix), "id")?,
+  trinket2: CharacterItem::from_row_opt(row, &format!("{}trinket2_", prefix), "id")?,
+});
+
 trait EqByValue {
   fn is_eq_by_value(&self, other: &Option<CharacterItemDto>) -> bool;
   fn is_eq(&self, other: &Option<CharacterItem>) -> bool;
@@ -1,3 +1,5 @@
+use mysql_connection::tools::FromRow;
+
 use crate::modules::armory::domain_value::CharacterGear;
 use crate::modules::armory::dto::CharacterInfoDto;
 
@@ -25,6 +27,21 @@ impl PartialEq for CharacterInfo {
 }
 
 impl CharacterInfo {
+  // The gear of the DTO is the one given
+  pub fn from_dto(id: u32, gear: CharacterGear, character_info: &CharacterInfoDto) -> Self {
+    CharacterInfo {
+      id,
+      gear,
+      hero_class_id: character_info.hero_class_id,
+      level: character_info.level,
+      gender: character_info.gender,
+      profession1: character_info.profession1,
+      profession2: character_info.profession2,
+      talent_specialization: character_info.talent_specialization.clone(),
+      race_id: character_info.race_id,
+    }
+  }
+
   pub fn compare_by_value(&self, other: &CharacterInfoDto) -> bool {
     self.gear.compare_by_value(&other.gear)
       && self.hero_class_id == other.hero_class_id
@@ -47,4 +64,17 @@ impl CharacterInfo {
       && self.talent_specialization == other.talent_specialization
       && self.race_id == other.race_id
   }
-}
\ No newline at end of file
+}
+
+// The gear is decoded with the prefix "gear_"
+impl_from_row!(CharacterInfo, |row, prefix| [id, hero_class_id, level, gender, profession1, profession2, talent_specialization, race_id] => CharacterInfo {
+  id,
+  gear: CharacterGear::from_row(row, &format!("{}gear_", prefix))?,
+  hero_class_id,
+  level,
+  gender,
+  profession1,
+  profession2,
+  talent_specialization,
+  race_id,
+});
\ No newline at end of file
@@ -20,6 +20,17 @@ impl PartialEq for CharacterItem {
 }
 
 impl CharacterItem {
+  // Always has four gem slots, like the table does
+  pub fn from_dto(id: u32, character_item: &CharacterItemDto) -> Self {
+    CharacterItem {
+      id,
+      item_id: character_item.item_id,
+      random_property_id: character_item.random_property_id,
+      enchant_id: character_item.enchant_id,
+      gem_ids: (0..4).map(|index| character_item.gem_ids.get(index).cloned().flatten()).collect(),
+    }
+  }
+
   pub fn deep_eq(&self, other: &Self) -> bool {
     self.id == other.id
       && self.item_id == other.item_id
@@ -37,4 +48,12 @@ impl CharacterItem {
             == other.gem_ids.iter().filter(|x| x.is_some() && x.unwrap() == gem_flag.unwrap()).count())
           || (gem_flag.is_none() && self.gem_ids.iter().filter(|x| x.is_some()).count() == other.gem_ids.iter().filter(|x| x.is_some()).count()));
   }
-}
\ No newline at end of file
+}
+
+impl_from_row!(CharacterItem, |row, prefix| [id, item_id, random_property_id, enchant_id, gem_id1, gem_id2, gem_id3, gem_id4] => CharacterItem {
+  id,
+  item_id,
+  random_property_id,
+  enchant_id,
+  gem_ids: vec![gem_id1, gem_id2, gem_id3, gem_id4],
+});
\ No newline at end of file
@@ -0,0 +1,12 @@
+// A history row of a character next to the guild and rank of its previous history row
+#[derive(Debug, Clone, PartialEq)]
+pub struct GuildTransition {
+  pub id: u32,
+  pub character_id: u32,
+  pub character_name: String,
+  pub guild_id: Option<u32>,
+  pub guild_rank: Option<String>,
+  pub previous_guild_id: Option<u32>,
+  pub previous_guild_rank: Option<String>,
+  pub timestamp: u64,
+}
\ No newline at end of file
@@ -1,8 +1,9 @@
-pub use self::character_gear::CharacterGear;
+pub use self::character_gear::{CharacterGear, GEAR_SLOTS};
 pub use self::character_info::CharacterInfo;
 pub use self::character_item::CharacterItem;
 pub use self::character_guild::CharacterGuild;
 pub use self::character_facial::CharacterFacial;
+pub use self::guild_transition::GuildTransition;
 pub use self::history_moment::HistoryMoment;
 
 mod character_item;
@@ -10,4 +11,5 @@ mod character_info;
 mod character_gear;
 mod character_guild;
 mod character_facial;
+mod guild_transition;
 mod history_moment;
\ No newline at end of file
@@ -6,6 +6,7 @@ use rocket_okapi::gen::OpenApiGenerator;
 use rocket_okapi::response::OpenApiResponder;
 use rocket_okapi::util::add_schema_response;
 use schemars::JsonSchema;
+use std::fmt;
 use std::io::Cursor;
 
 #[derive(Debug, JsonSchema)]
@@ -15,26 +16,36 @@ pub enum ArmoryFailure {
   ImplausibleInput
 }
 
+impl ArmoryFailure {
+  pub fn status_code(&self) -> u16 {
+    match self {
+      ArmoryFailure::InvalidInput => 534,
+      ArmoryFailure::Database(_) => 535,
+      ArmoryFailure::ImplausibleInput => 536
+    }
+  }
+}
+
+impl fmt::Display for ArmoryFailure {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      ArmoryFailure::InvalidInput => write!(f, "Invalid input!"),
+      ArmoryFailure::Database(hint) => write!(f, "{}", hint),
+      ArmoryFailure::ImplausibleInput => write!(f, "Implausible input!")
+    }
+  }
+}
+
 impl Responder<'static> for ArmoryFailure {
   fn respond_to(self, _: &Request) -> Result<Response<'static>, Status> {
-    let body;
     let status = match self {
-      ArmoryFailure::InvalidInput => {
-        body = "Invalid input!".to_owned();
-        Status::new(534, "InvalidInput")
-      },
-      ArmoryFailure::Database(hint) => {
-        body = hint.clone();
-        Status::new(535, "Database")
-      },
-      ArmoryFailure::ImplausibleInput => {
-        body = "Implausible input!".to_owned();
-        Status::new(536, "ImplausibleInput")
-      }
+      ArmoryFailure::InvalidInput => Status::new(self.status_code(), "InvalidInput"),
+      ArmoryFailure::Database(_) => Status::new(self.status_code(), "Database"),
+      ArmoryFailure::ImplausibleInput => Status::new(self.status_code(), "ImplausibleInput")
     };
     Response::build()
       .status(status)
-      .sized_body(Cursor::new(body))
+      .sized_body(Cursor::new(self.to_string()))
       .ok()
   }
 }
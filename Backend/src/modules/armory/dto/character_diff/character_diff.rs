@@ -0,0 +1,19 @@
+use crate::modules::armory::dto::{CharacterDiffProfessionDto, CharacterDiffSlotDto, CharacterDiffStatDto, CharacterViewerGuildDto, ValueChangeDto};
+
+// Fields that did not change are None or left out of the vectors
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CharacterDiffDto {
+  pub character_id: u32,
+  pub from_history_id: u32,
+  pub to_history_id: u32,
+  pub from_timestamp: u64,
+  pub to_timestamp: u64,
+  pub name: Option<ValueChangeDto<String>>,
+  pub level: Option<ValueChangeDto<u8>>,
+  pub title: Option<ValueChangeDto<Option<String>>>,
+  pub guild: Option<ValueChangeDto<Option<CharacterViewerGuildDto>>>,
+  pub talent_specialization: Option<ValueChangeDto<Option<String>>>,
+  pub professions: Vec<CharacterDiffProfessionDto>,
+  pub gear: Vec<CharacterDiffSlotDto>,
+  pub stats: Vec<CharacterDiffStatDto>
+}
@@ -0,0 +1,9 @@
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CharacterDiffItemDto {
+  pub item_id: u32,
+  pub quality: u8,
+  pub icon: String,
+  pub random_property_id: Option<i16>,
+  pub enchant_id: Option<u32>,
+  pub gem_ids: Vec<Option<u32>>
+}
@@ -0,0 +1,8 @@
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CharacterDiffProfessionDto {
+  pub profession_id: u16,
+  pub icon: String,
+  pub name: String,
+  pub from_points: Option<u16>,
+  pub to_points: Option<u16>
+}
@@ -0,0 +1,11 @@
+use crate::modules::armory::dto::CharacterDiffItemDto;
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CharacterDiffSlotDto {
+  pub slot: String,
+  pub from: Option<CharacterDiffItemDto>,
+  pub to: Option<CharacterDiffItemDto>,
+  pub item_changed: bool,
+  pub enchant_changed: bool,
+  pub gems_changed: bool
+}
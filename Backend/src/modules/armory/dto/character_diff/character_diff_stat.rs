@@ -0,0 +1,7 @@
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CharacterDiffStatDto {
+  pub stat_type: String,
+  pub from_value: u16,
+  pub to_value: u16,
+  pub delta: i32
+}
@@ -0,0 +1,13 @@
+pub use self::character_diff::CharacterDiffDto;
+pub use self::character_diff_item::CharacterDiffItemDto;
+pub use self::character_diff_profession::CharacterDiffProfessionDto;
+pub use self::character_diff_slot::CharacterDiffSlotDto;
+pub use self::character_diff_stat::CharacterDiffStatDto;
+pub use self::value_change::ValueChangeDto;
+
+mod character_diff;
+mod character_diff_item;
+mod character_diff_profession;
+mod character_diff_slot;
+mod character_diff_stat;
+mod value_change;
@@ -0,0 +1,5 @@
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ValueChangeDto<T> {
+  pub from: T,
+  pub to: T
+}
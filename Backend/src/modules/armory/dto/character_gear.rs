@@ -24,6 +24,33 @@ pub struct CharacterGearDto {
   pub trinket2: Option<CharacterItemDto>,
 }
 
+impl CharacterGearDto {
+  // In the order of GEAR_SLOTS
+  pub fn items(&self) -> Vec<&Option<CharacterItemDto>> {
+    vec![
+      &self.head,
+      &self.neck,
+      &self.shoulder,
+      &self.back,
+      &self.chest,
+      &self.shirt,
+      &self.tabard,
+      &self.wrist,
+      &self.main_hand,
+      &self.off_hand,
+      &self.ternary_hand,
+      &self.glove,
+      &self.belt,
+      &self.leg,
+      &self.boot,
+      &self.ring1,
+      &self.ring2,
+      &self.trinket1,
+      &self.trinket2
+    ]
+  }
+}
+
 impl CheckPlausability for CharacterGearDto {
   fn is_plausible(&self) -> bool {
     self.head.is_plausible()
@@ -3,6 +3,8 @@ use crate::dto::TableFilter;
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CharacterSearchFilter {
   pub page: u32,
+  pub page_size: Option<u32>,
+  pub cursor: Option<String>,
   pub hero_class: TableFilter<u8>,
   pub name: TableFilter<String>,
   pub guild: TableFilter<String>,
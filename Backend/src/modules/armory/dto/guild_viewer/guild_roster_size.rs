@@ -0,0 +1,6 @@
+// Number of members at the end of the day starting at timestamp
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GuildRosterSizeDto {
+    pub timestamp: u64,
+    pub size: u32
+}
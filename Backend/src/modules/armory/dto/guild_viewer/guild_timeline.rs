@@ -0,0 +1,10 @@
+use crate::dto::SearchResult;
+use crate::modules::armory::dto::{GuildRosterSizeDto, GuildTimelineEventDto};
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GuildTimelineDto {
+    pub guild_id: u32,
+    pub guild_name: String,
+    pub events: SearchResult<GuildTimelineEventDto>,
+    pub roster_size: Vec<GuildRosterSizeDto>
+}
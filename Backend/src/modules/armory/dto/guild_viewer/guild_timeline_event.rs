@@ -0,0 +1,11 @@
+use crate::modules::armory::dto::GuildTimelineEventType;
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GuildTimelineEventDto {
+    pub character_id: u32,
+    pub character_name: String,
+    pub event_type: GuildTimelineEventType,
+    pub rank: Option<String>,
+    pub previous_rank: Option<String>,
+    pub timestamp: u64
+}
@@ -0,0 +1,6 @@
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub enum GuildTimelineEventType {
+    Join,
+    Leave,
+    RankChange
+}
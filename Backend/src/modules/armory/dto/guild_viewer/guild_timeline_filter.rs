@@ -0,0 +1,6 @@
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GuildTimelineFilter {
+    pub page: u32,
+    pub page_size: Option<u32>,
+    pub cursor: Option<String>
+}
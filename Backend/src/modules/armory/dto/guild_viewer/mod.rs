@@ -1,5 +1,15 @@
 pub use self::guild_viewer::GuildViewerDto;
 pub use self::guild_viewer_member::GuildViewerMemberDto;
+pub use self::guild_timeline::GuildTimelineDto;
+pub use self::guild_timeline_event::GuildTimelineEventDto;
+pub use self::guild_timeline_event_type::GuildTimelineEventType;
+pub use self::guild_timeline_filter::GuildTimelineFilter;
+pub use self::guild_roster_size::GuildRosterSizeDto;
 
 mod guild_viewer;
-mod guild_viewer_member;
\ No newline at end of file
+mod guild_viewer_member;
+mod guild_timeline;
+mod guild_timeline_event;
+mod guild_timeline_event_type;
+mod guild_timeline_filter;
+mod guild_roster_size;
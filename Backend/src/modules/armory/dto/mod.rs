@@ -8,8 +8,11 @@ pub use self::character_info::CharacterInfoDto;
 pub use self::character_item::CharacterItemDto;
 pub use self::character_search::*;
 pub use self::character_viewer::*;
+pub use self::character_diff::*;
 pub use self::guild_viewer::*;
 pub use self::guild::GuildDto;
+pub use self::set_character_result::SetCharacterResult;
+pub use self::set_character_status::SetCharacterStatus;
 
 mod character;
 mod character_history;
@@ -19,9 +22,12 @@ mod character_gear;
 mod guild;
 mod character_guild;
 mod character_facial;
+mod set_character_result;
+mod set_character_status;
 
 mod character_search;
 mod character_viewer;
+mod character_diff;
 mod guild_viewer;
 
 mod armory_failure;
\ No newline at end of file
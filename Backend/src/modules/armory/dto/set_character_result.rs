@@ -0,0 +1,29 @@
+use crate::modules::armory::dto::{ArmoryFailure, SetCharacterStatus};
+
+// A rejection carries the status code and reason the single upload would have responded with
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct SetCharacterResult {
+  pub server_uid: u64,
+  pub status: SetCharacterStatus,
+  pub code: Option<u16>,
+  pub reason: Option<String>,
+}
+
+impl SetCharacterResult {
+  pub fn new(server_uid: u64, status: Result<SetCharacterStatus, ArmoryFailure>) -> Self {
+    match status {
+      Ok(status) => SetCharacterResult {
+        server_uid,
+        status,
+        code: None,
+        reason: None,
+      },
+      Err(failure) => SetCharacterResult {
+        server_uid,
+        status: SetCharacterStatus::Rejected,
+        code: Some(failure.status_code()),
+        reason: Some(failure.to_string()),
+      }
+    }
+  }
+}
\ No newline at end of file
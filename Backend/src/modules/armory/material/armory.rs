@@ -1,157 +1,94 @@
 use std::collections::HashMap;
-use std::sync::RwLock;
+use std::env;
+use std::sync::{Mutex, RwLock};
 
 use mysql_connection::material::MySQLConnection;
-use mysql_connection::tools::Select;
+use rocket_prometheus::prometheus::Registry;
 
-use crate::modules::armory::domain_value::{CharacterFacial, CharacterGear, CharacterGuild, CharacterInfo, CharacterItem, HistoryMoment};
-use crate::modules::armory::material::{Character, CharacterHistory, Guild};
+use crate::modules::armory::material::{Cache, Character, CharacterHistory, CharacterSearchIndex, Guild};
+use crate::modules::armory::repository::{ArmoryBackend, CharacterSummaryRepository, GuildRepository, MySQLArmoryBackend};
+
+const DEFAULT_CHARACTER_CACHE_SIZE: usize = 10000;
+const DEFAULT_CHARACTER_HISTORY_CACHE_SIZE: usize = 10000;
 
 #[derive(Debug)]
 pub struct Armory {
-  pub db_main: MySQLConnection,
-  pub characters: RwLock<HashMap<u32, Character>>,
+  pub backend: Box<dyn ArmoryBackend>,
+  pub characters: Cache<Character>,
+  pub character_histories: Cache<CharacterHistory>,
+  // The caches are only locked for the duration of a single call, hence writes to a character are serialized by this lock
+  pub character_writes: Mutex<()>,
   pub guilds: RwLock<HashMap<u32, Guild>>,
+  // Lock order: character_writes, guilds, character_search_index
+  pub character_search_index: RwLock<CharacterSearchIndex>,
 }
 
 impl Default for Armory {
   fn default() -> Self
+  {
+    Armory::with_backend(Box::new(MySQLArmoryBackend::new(MySQLConnection::new("main"))))
+  }
+}
+
+impl Armory {
+  pub fn with_backend(backend: Box<dyn ArmoryBackend>) -> Self
   {
     Armory {
-      db_main: MySQLConnection::new("main"),
-      characters: RwLock::new(HashMap::new()),
+      backend,
+      characters: Cache::new("character", cache_size("CHARACTER_CACHE_SIZE", DEFAULT_CHARACTER_CACHE_SIZE)),
+      character_histories: Cache::new("character_history", cache_size("CHARACTER_HISTORY_CACHE_SIZE", DEFAULT_CHARACTER_HISTORY_CACHE_SIZE)),
+      character_writes: Mutex::new(()),
       guilds: RwLock::new(HashMap::new()),
+      character_search_index: RwLock::new(CharacterSearchIndex::default()),
     }
   }
-}
 
-impl Armory {
-  pub fn init(self) -> Self
+  pub fn init(self) -> Result<Self, String>
   {
-    self.characters.write().unwrap().init(&self.db_main);
-    self.guilds.write().unwrap().init(&self.db_main);
-    self
+    self.guilds.write().unwrap().init(self.backend.as_ref())?;
+    {
+      let guilds = self.guilds.read().unwrap();
+      let mut character_search_index = self.character_search_index.write().unwrap();
+      guilds.values().for_each(|guild| character_search_index.index_guild(guild));
+      character_search_index.init(self.backend.as_ref())?;
+    }
+    Ok(self)
   }
-}
 
-trait Init {
-  fn init(&mut self, db: &MySQLConnection);
+  pub fn register_metrics(&self, registry: &Registry) {
+    self.characters.register_metrics(registry);
+    self.character_histories.register_metrics(registry);
+  }
 }
 
-impl Init for HashMap<u32, Character> {
-  fn init(&mut self, db: &MySQLConnection) {
-    // Loading the character itself
-    db.select("SELECT * FROM armory_character", &|mut row| {
-      Character {
-        id: row.take(0).unwrap(),
-        server_id: row.take(1).unwrap(),
-        server_uid: row.take(2).unwrap(),
-        last_update: None,
-        history_moments: Vec::new(),
-      }
-    }).iter().for_each(|result| { self.insert(result.id, result.to_owned()); });
+// The number of cached entries, configured in the environment
+fn cache_size(variable: &str, default: usize) -> usize {
+  env::var(variable).ok()
+    .and_then(|size| size.parse::<usize>().ok())
+    .unwrap_or(default)
+}
 
-    // Loading the history_ids
-    db.select("SELECT id, timestamp, character_id FROM armory_character_history ORDER BY id", &|mut row| {
-      let id: u32 = row.take(0).unwrap();
-      let timestamp: u64 = row.take(1).unwrap();
-      let character_id: u32 = row.take(2).unwrap();
-      (id, timestamp, character_id)
-    }).iter().for_each(|result| self.get_mut(&result.2).unwrap().history_moments.push(HistoryMoment {
-      id: result.0,
-      timestamp: result.1,
-    }));
+trait Init {
+  fn init(&mut self, repository: &dyn ArmoryBackend) -> Result<(), String>;
+}
 
-    // Load the actual newest character history data
-    db.select("SELECT ach.*, acf.*, aci.*, ag.id, ai1.*, ai2.*, ai3.*, ai4.*, ai5.*, ai6.*, ai7.*, ai8.*, ai9.*, ai10.*, ai11.*, ai12.*, ai13.*, ai14.*, ai15.*, ai16.*, ai17.*, ai18.*, ai19.* FROM armory_character_history ach JOIN (SELECT MAX(id) id FROM armory_character_history GROUP BY character_id) ach_max ON ach.id = ach_max.id LEFT JOIN armory_character_facial acf ON acf.id = ach.facial JOIN armory_character_info aci ON ach.character_info_id = aci.id JOIN armory_gear ag ON aci.gear_id = ag.id LEFT JOIN armory_item ai1 ON ag.head = ai1.id LEFT JOIN armory_item ai2 ON ag.neck = ai2.id LEFT JOIN armory_item ai3 ON ag.shoulder = ai3.id LEFT JOIN armory_item ai4 ON ag.back = ai4.id LEFT JOIN armory_item ai5 ON ag.chest = ai5.id LEFT JOIN armory_item ai6 ON ag.shirt = ai6.id LEFT JOIN armory_item ai7 ON ag.tabard = ai7.id LEFT JOIN armory_item ai8 ON ag.wrist = ai8.id LEFT JOIN armory_item ai9 ON ag.main_hand = ai9.id LEFT JOIN armory_item ai10 ON ag.off_hand = ai10.id LEFT JOIN armory_item ai11 ON ag.ternary_hand = ai11.id LEFT JOIN armory_item ai12 ON ag.glove = ai12.id LEFT JOIN armory_item ai13 ON ag.belt = ai13.id LEFT JOIN armory_item ai14 ON ag.leg = ai14.id LEFT JOIN armory_item ai15 ON ag.boot = ai15.id LEFT JOIN armory_item ai16 ON ag.ring1 = ai16.id LEFT JOIN armory_item ai17 ON ag.ring2 = ai17.id LEFT JOIN armory_item ai18 ON ag.trinket1 = ai18.id LEFT JOIN armory_item ai19 ON ag.trinket2 = ai19.id", &|mut row| {
-      let mut gear_slots: Vec<Option<CharacterItem>> = Vec::new();
-      for i in (27..179).step_by(8) {
-        let id = row.take_opt(i).unwrap().ok();
-        if id.is_none() {
-          gear_slots.push(None);
-          continue;
+impl Init for CharacterSearchIndex {
+  fn init(&mut self, repository: &dyn ArmoryBackend) -> Result<(), String> {
+    repository.load_character_summaries()?
+      .into_iter().for_each(|(character_id, server_id, server_uid, summary)| {
+        self.add_character(character_id, server_id, server_uid);
+        if let Some(summary) = summary {
+          self.index_summary(summary);
         }
-
-        gear_slots.push(Some(CharacterItem {
-          id: id.unwrap(),
-          item_id: row.take(i + 1).unwrap(),
-          random_property_id: row.take_opt(i + 2).unwrap().ok(),
-          enchant_id: row.take_opt(i + 3).unwrap().ok(),
-          gem_ids: vec![
-            row.take_opt(i + 4).unwrap().ok(),
-            row.take_opt(i + 5).unwrap().ok(),
-            row.take_opt(i + 6).unwrap().ok(),
-            row.take_opt(i + 7).unwrap().ok()
-          ],
-        }));
-      }
-
-      CharacterHistory {
-        id: row.take(0).unwrap(),
-        character_id: row.take(1).unwrap(),
-        character_name: row.take(3).unwrap(),
-        character_guild: row.take_opt(4).unwrap().ok().and_then(|guild_id| Some(CharacterGuild {
-          guild_id,
-          rank: row.take(5).unwrap(),
-        })),
-        character_title: row.take_opt(6).unwrap().ok(),
-        profession_skill_points1: row.take_opt(7).unwrap().ok(),
-        profession_skill_points2: row.take_opt(8).unwrap().ok(),
-        timestamp: row.take(10).unwrap(),
-        facial: row.take_opt(11).unwrap().ok().and_then(|facial_id: u32| Some(CharacterFacial {
-          id: facial_id.to_owned(),
-          skin_color: row.take(12).unwrap(),
-          face_style: row.take(13).unwrap(),
-          hair_style: row.take(14).unwrap(),
-          hair_color: row.take(15).unwrap(),
-          facial_hair: row.take(16).unwrap(),
-        })),
-        character_info: CharacterInfo {
-          id: row.take(17).unwrap(),
-          hero_class_id: row.take(19).unwrap(),
-          level: row.take(20).unwrap(),
-          gender: row.take(21).unwrap(),
-          profession1: row.take_opt(22).unwrap().ok(),
-          profession2: row.take_opt(23).unwrap().ok(),
-          talent_specialization: row.take_opt(24).unwrap().ok(),
-          race_id: row.take(25).unwrap(),
-          gear: CharacterGear {
-            id: row.take(26).unwrap(),
-            trinket2: gear_slots.pop().unwrap(),
-            trinket1: gear_slots.pop().unwrap(),
-            ring2: gear_slots.pop().unwrap(),
-            ring1: gear_slots.pop().unwrap(),
-            boot: gear_slots.pop().unwrap(),
-            leg: gear_slots.pop().unwrap(),
-            belt: gear_slots.pop().unwrap(),
-            glove: gear_slots.pop().unwrap(),
-            ternary_hand: gear_slots.pop().unwrap(),
-            off_hand: gear_slots.pop().unwrap(),
-            main_hand: gear_slots.pop().unwrap(),
-            wrist: gear_slots.pop().unwrap(),
-            tabard: gear_slots.pop().unwrap(),
-            shirt: gear_slots.pop().unwrap(),
-            chest: gear_slots.pop().unwrap(),
-            back: gear_slots.pop().unwrap(),
-            shoulder: gear_slots.pop().unwrap(),
-            neck: gear_slots.pop().unwrap(),
-            head: gear_slots.pop().unwrap(),
-          },
-        },
-      }
-    }).iter().for_each(|result| { self.get_mut(&result.character_id).unwrap().last_update = Some(result.to_owned()) });
+      });
+    Ok(())
   }
 }
 
 impl Init for HashMap<u32, Guild> {
-  fn init(&mut self, db: &MySQLConnection) {
-    db.select("SELECT * FROM armory_guild", &|mut row| {
-      Guild {
-        id: row.take(0).unwrap(),
-        server_uid: row.take(1).unwrap(),
-        server_id: row.take(2).unwrap(),
-        name: row.take(3).unwrap(),
-      }
-    }).iter().for_each(|result| { self.insert(result.id, result.to_owned()); });
+  fn init(&mut self, repository: &dyn ArmoryBackend) -> Result<(), String> {
+    repository.load_guilds()?
+      .into_iter().for_each(|guild| { self.insert(guild.id, guild); });
+    Ok(())
   }
 }
\ No newline at end of file
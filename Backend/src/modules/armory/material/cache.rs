@@ -0,0 +1,120 @@
+use std::convert::Infallible;
+use std::fmt::Debug;
+use std::sync::Mutex;
+
+use rocket_prometheus::prometheus::{IntCounter, IntGauge, Registry};
+
+use crate::modules::armory::material::LruCache;
+
+pub trait CacheBackend<K, V>: Debug + Send + Sync {
+  fn get(&mut self, key: &K) -> Option<V>;
+  fn get_mut(&mut self, key: &K) -> Option<&mut V>;
+  // Returns the number of evicted entries
+  fn insert(&mut self, key: K, value: V) -> usize;
+  fn remove(&mut self, key: &K);
+  fn len(&self) -> usize;
+}
+
+#[derive(Debug)]
+struct CacheState<V> {
+  backend: Box<dyn CacheBackend<u32, V>>,
+  // Incremented by every write, loads that raced with a write are not cached
+  generation: u64,
+}
+
+#[derive(Debug)]
+pub struct CacheMetrics {
+  pub hits: IntCounter,
+  pub misses: IntCounter,
+  pub evictions: IntCounter,
+  pub entries: IntGauge,
+}
+
+// Read-through cache, values are loaded from the database on a miss.
+// The lock is only held for the duration of a call, never while loading.
+#[derive(Debug)]
+pub struct Cache<V> {
+  state: Mutex<CacheState<V>>,
+  pub metrics: CacheMetrics,
+}
+
+impl<V: Clone + Debug + Send + Sync + 'static> Cache<V> {
+  pub fn new(name: &str, capacity: usize) -> Self {
+    Cache::with_backend(name, Box::new(LruCache::new(capacity)))
+  }
+
+  pub fn with_backend(name: &str, backend: Box<dyn CacheBackend<u32, V>>) -> Self {
+    Cache {
+      state: Mutex::new(CacheState { backend, generation: 0 }),
+      metrics: CacheMetrics {
+        hits: IntCounter::new(format!("armory_{}_cache_hits", name), format!("Lookups answered by the {} cache", name)).unwrap(),
+        misses: IntCounter::new(format!("armory_{}_cache_misses", name), format!("Lookups loaded from the database into the {} cache", name)).unwrap(),
+        evictions: IntCounter::new(format!("armory_{}_cache_evictions", name), format!("Entries evicted from the {} cache", name)).unwrap(),
+        entries: IntGauge::new(format!("armory_{}_cache_entries", name), format!("Entries currently in the {} cache", name)).unwrap(),
+      },
+    }
+  }
+
+  pub fn register_metrics(&self, registry: &Registry) {
+    registry.register(Box::new(self.metrics.hits.clone())).unwrap();
+    registry.register(Box::new(self.metrics.misses.clone())).unwrap();
+    registry.register(Box::new(self.metrics.evictions.clone())).unwrap();
+    registry.register(Box::new(self.metrics.entries.clone())).unwrap();
+  }
+
+  pub fn get_or_load(&self, key: u32, load: impl FnOnce() -> Option<V>) -> Option<V> {
+    self.try_get_or_load(key, || Ok::<_, Infallible>(load())).unwrap_or(None)
+  }
+
+  // Failed loads are handed to the caller and not cached
+  pub fn try_get_or_load<E>(&self, key: u32, load: impl FnOnce() -> Result<Option<V>, E>) -> Result<Option<V>, E> {
+    let generation = {
+      let mut state = self.state.lock().unwrap();
+      if let Some(value) = state.backend.get(&key) {
+        self.metrics.hits.inc();
+        return Ok(Some(value));
+      }
+      state.generation
+    };
+
+    self.metrics.misses.inc();
+    let value = match load()? {
+      Some(value) => value,
+      None => return Ok(None)
+    };
+    let mut state = self.state.lock().unwrap();
+    if state.generation == generation {
+      self.insert_locked(&mut state, key, value.clone());
+    }
+    Ok(Some(value))
+  }
+
+  pub fn insert(&self, key: u32, value: V) {
+    let mut state = self.state.lock().unwrap();
+    state.generation += 1;
+    self.insert_locked(&mut state, key, value);
+  }
+
+  // Uncached values are left alone, they are loaded up to date on the next access
+  pub fn update(&self, key: u32, update: impl FnOnce(&mut V)) {
+    let mut state = self.state.lock().unwrap();
+    state.generation += 1;
+    if let Some(value) = state.backend.get_mut(&key) {
+      update(value);
+    }
+  }
+
+  pub fn invalidate(&self, key: u32) {
+    let mut state = self.state.lock().unwrap();
+    state.generation += 1;
+    state.backend.remove(&key);
+    self.metrics.entries.set(state.backend.len() as i64);
+  }
+
+  fn insert_locked(&self, state: &mut CacheState<V>, key: u32, value: V) {
+    for _ in 0..state.backend.insert(key, value) {
+      self.metrics.evictions.inc();
+    }
+    self.metrics.entries.set(state.backend.len() as i64);
+  }
+}
\ No newline at end of file
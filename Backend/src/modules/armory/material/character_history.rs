@@ -1,3 +1,5 @@
+use mysql_connection::tools::FromRow;
+
 use crate::modules::armory::domain_value::{CharacterInfo, CharacterGuild, CharacterFacial};
 use crate::modules::armory::dto::CharacterHistoryDto;
 
@@ -52,4 +54,22 @@ impl CharacterHistory {
     // Technically we should also compare character_id => character_uid and guild_id => guild_dto
     // But this would require to make a get call
   }
-}
\ No newline at end of file
+}
+
+// The character info is decoded with the prefix "info_" and the facial with the prefix "facial_"
+impl_from_row!(CharacterHistory, |row, prefix| [id, character_id, character_name, guild_id, guild_rank, title, prof_skill_points1, prof_skill_points2, timestamp] => CharacterHistory {
+  id,
+  character_id,
+  character_info: CharacterInfo::from_row(row, &format!("{}info_", prefix))?,
+  character_name,
+  // Called as functions, as the column types are only inferred from the fields
+  character_guild: Option::map(guild_id, |guild_id| CharacterGuild {
+    guild_id,
+    rank: Option::unwrap_or_default(guild_rank),
+  }),
+  character_title: title,
+  profession_skill_points1: prof_skill_points1,
+  profession_skill_points2: prof_skill_points2,
+  facial: CharacterFacial::from_row_opt(row, &format!("{}facial_", prefix), "id")?,
+  timestamp,
+});
\ No newline at end of file
@@ -0,0 +1,233 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::modules::armory::domain_value::CharacterGuild;
+use crate::modules::armory::material::{Character, CharacterHistory, Guild};
+
+// Names are indexed by all of their substrings up to this length.
+// Longer queries are answered by intersecting the postings of their n-grams.
+const MAX_GRAM_LENGTH: usize = 3;
+
+// Everything search and guild views need to know about a character,
+// kept for every character while the full characters are only cached on demand
+#[derive(Debug, Clone)]
+pub struct CharacterSummary {
+  pub character_id: u32,
+  pub server_id: u32,
+  pub name: String,
+  pub hero_class_id: u8,
+  pub race_id: u8,
+  pub guild: Option<CharacterGuild>,
+  pub timestamp: u64,
+}
+
+#[derive(Debug, Clone)]
+struct IndexedCharacter {
+  name: String,
+  summary: CharacterSummary,
+}
+
+#[derive(Debug, Default)]
+pub struct CharacterSearchIndex {
+  characters: HashMap<u32, IndexedCharacter>,
+  uids: HashMap<(u32, u64), u32>,
+  character_uids: HashMap<u32, (u32, u64)>,
+  guild_names: HashMap<u32, String>,
+  name_grams: HashMap<String, HashSet<u32>>,
+  guild_name_grams: HashMap<String, HashSet<u32>>,
+  guild_members: HashMap<u32, HashSet<u32>>,
+  servers: HashMap<u32, HashSet<u32>>,
+  hero_classes: HashMap<u8, HashSet<u32>>,
+}
+
+impl CharacterSearchIndex {
+  pub fn index_character(&mut self, character: &Character) {
+    self.add_character(character.id, character.server_id, character.server_uid);
+    match character.last_update.as_ref() {
+      Some(last_update) => self.index_history(last_update),
+      None => self.remove_summary(character.id)
+    }
+  }
+
+  // Characters without any history are only known by their uid
+  pub fn add_character(&mut self, character_id: u32, server_id: u32, server_uid: u64) {
+    self.uids.insert((server_id, server_uid), character_id);
+    self.character_uids.insert(character_id, (server_id, server_uid));
+  }
+
+  pub fn index_history(&mut self, character_history: &CharacterHistory) {
+    if let Some(&(server_id, _)) = self.character_uids.get(&character_history.character_id) {
+      self.index_summary(CharacterSummary {
+        character_id: character_history.character_id,
+        server_id,
+        name: character_history.character_name.clone(),
+        hero_class_id: character_history.character_info.hero_class_id,
+        race_id: character_history.character_info.race_id,
+        guild: character_history.character_guild.clone(),
+        timestamp: character_history.timestamp,
+      });
+    }
+  }
+
+  pub fn index_summary(&mut self, summary: CharacterSummary) {
+    let character_id = summary.character_id;
+    self.remove_summary(character_id);
+    let entry = IndexedCharacter {
+      name: summary.name.to_lowercase(),
+      summary,
+    };
+
+    for gram in grams(&entry.name) {
+      self.name_grams.entry(gram).or_insert_with(HashSet::new).insert(character_id);
+    }
+    self.servers.entry(entry.summary.server_id).or_insert_with(HashSet::new).insert(character_id);
+    self.hero_classes.entry(entry.summary.hero_class_id).or_insert_with(HashSet::new).insert(character_id);
+    if let Some(character_guild) = entry.summary.guild.as_ref() {
+      self.guild_members.entry(character_guild.guild_id).or_insert_with(HashSet::new).insert(character_id);
+    }
+    self.characters.insert(character_id, entry);
+  }
+
+  pub fn remove_character(&mut self, character_id: u32) {
+    self.remove_summary(character_id);
+    if let Some(uid) = self.character_uids.remove(&character_id) {
+      self.uids.remove(&uid);
+    }
+  }
+
+  pub fn remove_summary(&mut self, character_id: u32) {
+    if let Some(entry) = self.characters.remove(&character_id) {
+      for gram in grams(&entry.name) {
+        remove_posting(&mut self.name_grams, &gram, character_id);
+      }
+      remove_posting(&mut self.servers, &entry.summary.server_id, character_id);
+      remove_posting(&mut self.hero_classes, &entry.summary.hero_class_id, character_id);
+      if let Some(character_guild) = entry.summary.guild.as_ref() {
+        remove_posting(&mut self.guild_members, &character_guild.guild_id, character_id);
+      }
+    }
+  }
+
+  pub fn get_character_id_by_uid(&self, server_id: u32, server_uid: u64) -> Option<u32> {
+    self.uids.get(&(server_id, server_uid)).cloned()
+  }
+
+  pub fn contains_character(&self, character_id: u32) -> bool {
+    self.character_uids.contains_key(&character_id)
+  }
+
+  pub fn summary(&self, character_id: u32) -> Option<&CharacterSummary> {
+    self.characters.get(&character_id).map(|entry| &entry.summary)
+  }
+
+  pub fn guild_members(&self, guild_id: u32) -> Vec<&CharacterSummary> {
+    self.guild_members.get(&guild_id)
+      .map(|members| members.iter().filter_map(|character_id| self.summary(*character_id)).collect())
+      .unwrap_or_default()
+  }
+
+  pub fn index_guild(&mut self, guild: &Guild) {
+    self.remove_guild(guild.id);
+    let name = guild.name.to_lowercase();
+    for gram in grams(&name) {
+      self.guild_name_grams.entry(gram).or_insert_with(HashSet::new).insert(guild.id);
+    }
+    self.guild_names.insert(guild.id, name);
+  }
+
+  pub fn remove_guild(&mut self, guild_id: u32) {
+    if let Some(name) = self.guild_names.remove(&guild_id) {
+      for gram in grams(&name) {
+        remove_posting(&mut self.guild_name_grams, &gram, guild_id);
+      }
+    }
+  }
+
+  pub fn num_characters(&self) -> usize {
+    self.characters.len()
+  }
+
+  // Returns the ids of all indexed characters matching every given criteria.
+  // Name and guild name are matched case insensitive as substring.
+  pub fn find(&self, server_id: Option<u32>, hero_class_id: Option<u8>, name: Option<&str>, guild_name: Option<&str>) -> Vec<u32> {
+    let mut computed_postings: Vec<HashSet<u32>> = Vec::new();
+    if let Some(name) = name.map(|name| name.to_lowercase()).filter(|name| !name.is_empty()) {
+      computed_postings.push(find_by_substring(&self.name_grams, &name, |character_id| self.characters.get(&character_id).unwrap().name.contains(&name)));
+    }
+    if let Some(guild_name) = guild_name.map(|guild_name| guild_name.to_lowercase()) {
+      let guild_ids = if guild_name.is_empty() {
+        self.guild_names.keys().cloned().collect()
+      } else {
+        find_by_substring(&self.guild_name_grams, &guild_name, |guild_id| self.guild_names.get(&guild_id).unwrap().contains(&guild_name))
+      };
+      computed_postings.push(guild_ids.iter()
+        .filter_map(|guild_id| self.guild_members.get(guild_id))
+        .flat_map(|members| members.iter().cloned())
+        .collect());
+    }
+
+    let empty_posting = HashSet::new();
+    let mut postings: Vec<&HashSet<u32>> = computed_postings.iter().collect();
+    if let Some(server_id) = server_id {
+      postings.push(self.servers.get(&server_id).unwrap_or(&empty_posting));
+    }
+    if let Some(hero_class_id) = hero_class_id {
+      postings.push(self.hero_classes.get(&hero_class_id).unwrap_or(&empty_posting));
+    }
+
+    if postings.is_empty() {
+      return self.characters.keys().cloned().collect();
+    }
+
+    // Start with the most selective posting list
+    postings.sort_by_key(|posting| posting.len());
+    let (smallest, rest) = postings.split_first().unwrap();
+    smallest.iter()
+      .filter(|character_id| rest.iter().all(|posting| posting.contains(character_id)))
+      .cloned()
+      .collect()
+  }
+}
+
+fn grams(input: &str) -> HashSet<String> {
+  let chars: Vec<char> = input.chars().collect();
+  let mut result = HashSet::new();
+  for length in 1..=MAX_GRAM_LENGTH {
+    for window in chars.windows(length) {
+      result.insert(window.iter().collect());
+    }
+  }
+  result
+}
+
+fn find_by_substring<F: Fn(u32) -> bool>(index: &HashMap<String, HashSet<u32>>, needle: &str, verify: F) -> HashSet<u32> {
+  if needle.chars().count() <= MAX_GRAM_LENGTH {
+    return index.get(needle).cloned().unwrap_or_default();
+  }
+
+  let chars: Vec<char> = needle.chars().collect();
+  let mut postings: Vec<&HashSet<u32>> = Vec::new();
+  for window in chars.windows(MAX_GRAM_LENGTH) {
+    let gram: String = window.iter().collect();
+    match index.get(&gram) {
+      Some(posting) => postings.push(posting),
+      None => return HashSet::new()
+    }
+  }
+  postings.sort_by_key(|posting| posting.len());
+  let (smallest, rest) = postings.split_first().unwrap();
+  // The n-gram intersection may yield false positives, e.g. "abcab" for "abcabc"
+  smallest.iter()
+    .filter(|id| rest.iter().all(|posting| posting.contains(id)))
+    .filter(|id| verify(**id))
+    .cloned()
+    .collect()
+}
+
+fn remove_posting<K: std::hash::Hash + Eq + Clone>(index: &mut HashMap<K, HashSet<u32>>, key: &K, id: u32) {
+  if let Some(posting) = index.get_mut(key) {
+    posting.remove(&id);
+    if posting.is_empty() {
+      index.remove(key);
+    }
+  }
+}
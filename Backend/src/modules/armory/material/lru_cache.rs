@@ -0,0 +1,72 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::modules::armory::material::CacheBackend;
+
+// Evicts the least recently used entries once more than capacity entries are cached
+#[derive(Debug)]
+pub struct LruCache<K, V> {
+  capacity: usize,
+  tick: u64,
+  entries: HashMap<K, (V, u64)>,
+  recency: BTreeMap<u64, K>,
+}
+
+impl<K: Eq + Hash + Clone, V> LruCache<K, V> {
+  pub fn new(capacity: usize) -> Self {
+    LruCache {
+      capacity: capacity.max(1),
+      tick: 0,
+      entries: HashMap::new(),
+      recency: BTreeMap::new(),
+    }
+  }
+
+  fn touch(&mut self, key: &K) {
+    self.tick += 1;
+    if let Some((_, last_used)) = self.entries.get_mut(key) {
+      self.recency.remove(last_used);
+      *last_used = self.tick;
+      self.recency.insert(self.tick, key.clone());
+    }
+  }
+}
+
+impl<K: Eq + Hash + Clone + Debug + Send + Sync, V: Clone + Debug + Send + Sync> CacheBackend<K, V> for LruCache<K, V> {
+  fn get(&mut self, key: &K) -> Option<V> {
+    self.touch(key);
+    self.entries.get(key).map(|(value, _)| value.clone())
+  }
+
+  fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+    self.touch(key);
+    self.entries.get_mut(key).map(|(value, _)| value)
+  }
+
+  fn insert(&mut self, key: K, value: V) -> usize {
+    self.remove(&key);
+    self.tick += 1;
+    self.recency.insert(self.tick, key.clone());
+    self.entries.insert(key, (value, self.tick));
+
+    let mut evicted = 0;
+    while self.entries.len() > self.capacity {
+      let (&last_used, _) = self.recency.iter().next().unwrap();
+      let key = self.recency.remove(&last_used).unwrap();
+      self.entries.remove(&key);
+      evicted += 1;
+    }
+    evicted
+  }
+
+  fn remove(&mut self, key: &K) {
+    if let Some((_, last_used)) = self.entries.remove(key) {
+      self.recency.remove(&last_used);
+    }
+  }
+
+  fn len(&self) -> usize {
+    self.entries.len()
+  }
+}
\ No newline at end of file
@@ -1,9 +1,15 @@
 pub use self::armory::Armory;
+pub use self::cache::{Cache, CacheBackend, CacheMetrics};
 pub use self::character::Character;
 pub use self::character_history::CharacterHistory;
+pub use self::character_search_index::{CharacterSearchIndex, CharacterSummary};
 pub use self::guild::Guild;
+pub use self::lru_cache::LruCache;
 
 mod guild;
 mod armory;
+mod cache;
 mod character;
-mod character_history;
\ No newline at end of file
+mod character_history;
+mod character_search_index;
+mod lru_cache;
\ No newline at end of file
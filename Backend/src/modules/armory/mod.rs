@@ -10,4 +10,5 @@ pub mod dto;
 pub mod tools;
 pub mod material;
 pub mod domain_value;
+pub mod repository;
 pub mod transfer;
\ No newline at end of file
@@ -0,0 +1,8 @@
+use std::fmt::Debug;
+
+use crate::modules::armory::repository::{CharacterSummaryRepository, GuildRepository, StartArmoryTransaction};
+
+// Characters and everything they reference are read and written through transactions
+pub trait ArmoryBackend: GuildRepository + CharacterSummaryRepository + StartArmoryTransaction + Debug + Send + Sync {}
+
+impl<T: GuildRepository + CharacterSummaryRepository + StartArmoryTransaction + Debug + Send + Sync> ArmoryBackend for T {}
\ No newline at end of file
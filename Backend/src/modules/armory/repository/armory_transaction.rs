@@ -0,0 +1,15 @@
+use crate::modules::armory::repository::{CharacterFacialRepository, CharacterGearRepository, CharacterHistoryRepository, CharacterInfoRepository, CharacterItemRepository, CharacterRepository};
+
+// Writes only become visible to others once the transaction is committed, dropping it rolls them back
+pub trait ArmoryTransaction: CharacterRepository + CharacterHistoryRepository + CharacterInfoRepository + CharacterGearRepository + CharacterItemRepository + CharacterFacialRepository {
+  // Guilds that are uploaded along with characters are created in the same transaction
+  fn insert_guild(&mut self, server_id: u32, server_uid: u64, name: &str) -> Result<u32, String>;
+  fn savepoint(&mut self, name: &str) -> Result<(), String>;
+  fn release_savepoint(&mut self, name: &str) -> Result<(), String>;
+  fn rollback_to_savepoint(&mut self, name: &str) -> Result<(), String>;
+  fn commit(self: Box<Self>) -> Result<(), String>;
+}
+
+pub trait StartArmoryTransaction {
+  fn start_transaction(&self) -> Result<Box<dyn ArmoryTransaction + '_>, String>;
+}
\ No newline at end of file
@@ -0,0 +1,9 @@
+use crate::modules::armory::domain_value::CharacterFacial;
+use crate::modules::armory::dto::CharacterFacialDto;
+
+pub trait CharacterFacialRepository {
+  fn load_character_facial(&mut self, facial_id: u32) -> Result<Option<CharacterFacial>, String>;
+  fn find_character_facial(&mut self, character_facial: &CharacterFacialDto) -> Result<Option<u32>, String>;
+  // Hands out the id of the existing facial if the values are known already
+  fn insert_character_facial(&mut self, character_facial: &CharacterFacialDto) -> Result<u32, String>;
+}
\ No newline at end of file
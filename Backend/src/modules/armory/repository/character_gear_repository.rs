@@ -0,0 +1,9 @@
+use crate::modules::armory::domain_value::CharacterGear;
+
+// The gear references one character item id per slot, in the order of GEAR_SLOTS
+pub trait CharacterGearRepository {
+  fn load_character_gear(&mut self, gear_id: u32) -> Result<Option<CharacterGear>, String>;
+  fn find_character_gear(&mut self, character_item_ids: &[Option<u32>]) -> Result<Option<u32>, String>;
+  // Hands out the id of the existing gear if the items are known already
+  fn insert_character_gear(&mut self, character_item_ids: &[Option<u32>]) -> Result<u32, String>;
+}
\ No newline at end of file
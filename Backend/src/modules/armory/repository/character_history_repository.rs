@@ -0,0 +1,15 @@
+use crate::modules::armory::domain_value::CharacterGuild;
+use crate::modules::armory::dto::CharacterHistoryDto;
+use crate::modules::armory::material::CharacterHistory;
+
+pub trait CharacterHistoryRepository {
+  fn load_character_history(&mut self, character_history_id: u32) -> Result<Option<CharacterHistory>, String>;
+  // Id and timestamp of a history of the character with these values, that has been updated since the given timestamp
+  fn find_character_history(&mut self, character_id: u32, character_info_id: u32, facial_id: Option<u32>, character_guild: Option<&CharacterGuild>,
+                            character_history: &CharacterHistoryDto, since: u64) -> Result<Option<(u32, u64)>, String>;
+  fn insert_character_history(&mut self, character_id: u32, character_info_id: u32, facial_id: Option<u32>, character_guild: Option<&CharacterGuild>,
+                              character_history: &CharacterHistoryDto, timestamp: u64) -> Result<u32, String>;
+  fn touch_character_histories(&mut self, character_history_ids: &[u32], timestamp: u64) -> Result<(), String>;
+  // Info, gear, items and facials are shared by value between histories, hence only those that are no longer referenced are deleted with them
+  fn delete_character_histories(&mut self, character_history_ids: &[u32]) -> Result<(), String>;
+}
\ No newline at end of file
@@ -0,0 +1,10 @@
+use crate::modules::armory::domain_value::CharacterInfo;
+use crate::modules::armory::dto::CharacterInfoDto;
+
+// The gear of the info is referenced by its id, the gear of the DTO is ignored
+pub trait CharacterInfoRepository {
+  fn load_character_info(&mut self, character_info_id: u32) -> Result<Option<CharacterInfo>, String>;
+  fn find_character_info(&mut self, gear_id: u32, character_info: &CharacterInfoDto) -> Result<Option<u32>, String>;
+  // Hands out the id of the existing info if the values are known already
+  fn insert_character_info(&mut self, gear_id: u32, character_info: &CharacterInfoDto) -> Result<u32, String>;
+}
\ No newline at end of file
@@ -0,0 +1,9 @@
+use crate::modules::armory::domain_value::CharacterItem;
+use crate::modules::armory::dto::CharacterItemDto;
+
+pub trait CharacterItemRepository {
+  fn load_character_item(&mut self, character_item_id: u32) -> Result<Option<CharacterItem>, String>;
+  fn find_character_item(&mut self, character_item: &CharacterItemDto) -> Result<Option<u32>, String>;
+  // Hands out the id of the existing item if the values are known already
+  fn insert_character_item(&mut self, character_item: &CharacterItemDto) -> Result<u32, String>;
+}
\ No newline at end of file
@@ -0,0 +1,9 @@
+use crate::modules::armory::material::Character;
+
+pub trait CharacterRepository {
+  // With its history moments, the last update is resolved through the character histories
+  fn load_character(&mut self, character_id: u32) -> Result<Option<Character>, String>;
+  fn insert_character(&mut self, server_id: u32, server_uid: u64) -> Result<u32, String>;
+  // Also deletes its character histories
+  fn delete_character(&mut self, character_id: u32) -> Result<(), String>;
+}
\ No newline at end of file
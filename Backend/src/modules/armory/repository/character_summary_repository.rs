@@ -0,0 +1,6 @@
+use crate::modules::armory::material::CharacterSummary;
+
+pub trait CharacterSummaryRepository {
+  // Id, server id and server uid of every character, with the summary of its newest character history if it has one
+  fn load_character_summaries(&self) -> Result<Vec<(u32, u32, u64, Option<CharacterSummary>)>, String>;
+}
\ No newline at end of file
@@ -0,0 +1,17 @@
+use crate::modules::armory::domain_value::GuildTransition;
+use crate::modules::armory::material::Guild;
+
+pub trait GuildRepository {
+  fn load_guilds(&self) -> Result<Vec<Guild>, String>;
+  fn insert_guild(&self, server_id: u32, server_uid: u64, name: &str) -> Option<u32>;
+  fn update_guild_name(&self, server_id: u32, server_uid: u64, name: &str) -> bool;
+  // Also removes the guild from the character histories, returning the ids of these histories and their characters
+  fn delete_guild(&self, guild_id: u32) -> Option<Vec<(u32, u32)>>;
+
+  // Events are the transitions of members that join, leave or change their rank within the guild
+  fn count_guild_events(&self, guild_id: u32) -> Result<u64, String>;
+  // Newest first, starting after the given timestamp and id
+  fn load_guild_events(&self, guild_id: u32, before: (u64, u32), offset: u32, limit: u32) -> Result<Vec<GuildTransition>, String>;
+  // Joins minus leaves per interval, by the start of the interval in ascending order
+  fn load_guild_roster_changes(&self, guild_id: u32, interval: u64) -> Result<Vec<(u64, i64)>, String>;
+}
\ No newline at end of file
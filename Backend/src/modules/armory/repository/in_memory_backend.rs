@@ -0,0 +1,543 @@
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+use crate::modules::armory::domain_value::{CharacterFacial, CharacterGear, CharacterGuild, CharacterInfo, CharacterItem, GEAR_SLOTS, GuildTransition, HistoryMoment};
+use crate::modules::armory::dto::{CharacterFacialDto, CharacterHistoryDto, CharacterInfoDto, CharacterItemDto};
+use crate::modules::armory::material::{Character, CharacterHistory, CharacterSummary, Guild};
+use crate::modules::armory::repository::{ArmoryTransaction, CharacterFacialRepository, CharacterGearRepository, CharacterHistoryRepository, CharacterInfoRepository,
+  CharacterItemRepository, CharacterRepository, CharacterSummaryRepository, GuildRepository, StartArmoryTransaction};
+
+// Keeps the armory in process memory, e.g. to test it without a database
+#[derive(Debug, Default)]
+pub struct InMemoryArmoryBackend {
+  state: Mutex<InMemoryState>,
+}
+
+// The rows reference each other by id, like the tables do
+#[derive(Debug, Default, Clone)]
+struct InMemoryState {
+  guilds: BTreeMap<u32, Guild>,
+  last_guild_id: u32,
+  // Server id and server uid by character id
+  characters: BTreeMap<u32, (u32, u64)>,
+  last_character_id: u32,
+  character_histories: BTreeMap<u32, CharacterHistoryRow>,
+  last_character_history_id: u32,
+  character_infos: BTreeMap<u32, CharacterInfoRow>,
+  last_character_info_id: u32,
+  // Character item ids in the order of GEAR_SLOTS
+  character_gears: BTreeMap<u32, Vec<Option<u32>>>,
+  last_character_gear_id: u32,
+  character_items: BTreeMap<u32, CharacterItem>,
+  last_character_item_id: u32,
+  character_facials: BTreeMap<u32, CharacterFacial>,
+  last_character_facial_id: u32,
+  // Counts the commits, a transaction is only committed if nothing else has been committed since it started
+  version: u64,
+}
+
+#[derive(Debug, Clone)]
+struct CharacterHistoryRow {
+  character_id: u32,
+  character_info_id: u32,
+  character_name: String,
+  character_guild: Option<CharacterGuild>,
+  character_title: Option<u16>,
+  profession_skill_points1: Option<u16>,
+  profession_skill_points2: Option<u16>,
+  facial_id: Option<u32>,
+  timestamp: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct CharacterInfoRow {
+  gear_id: u32,
+  hero_class_id: u8,
+  level: u8,
+  gender: bool,
+  profession1: Option<u16>,
+  profession2: Option<u16>,
+  talent_specialization: Option<String>,
+  race_id: u8,
+}
+
+impl CharacterInfoRow {
+  fn new(gear_id: u32, character_info: &CharacterInfoDto) -> Self {
+    CharacterInfoRow {
+      gear_id,
+      hero_class_id: character_info.hero_class_id,
+      level: character_info.level,
+      gender: character_info.gender,
+      profession1: character_info.profession1,
+      profession2: character_info.profession2,
+      talent_specialization: character_info.talent_specialization.clone(),
+      race_id: character_info.race_id,
+    }
+  }
+}
+
+impl InMemoryState {
+  fn character_history(&self, character_history_id: u32) -> Option<CharacterHistory> {
+    let row = self.character_histories.get(&character_history_id)?;
+    Some(CharacterHistory {
+      id: character_history_id,
+      character_id: row.character_id,
+      character_info: self.character_info(row.character_info_id)?,
+      character_name: row.character_name.clone(),
+      character_guild: row.character_guild.clone(),
+      character_title: row.character_title,
+      profession_skill_points1: row.profession_skill_points1,
+      profession_skill_points2: row.profession_skill_points2,
+      facial: row.facial_id.and_then(|facial_id| self.character_facials.get(&facial_id).cloned()),
+      timestamp: row.timestamp,
+    })
+  }
+
+  fn character_info(&self, character_info_id: u32) -> Option<CharacterInfo> {
+    let row = self.character_infos.get(&character_info_id)?;
+    Some(CharacterInfo {
+      id: character_info_id,
+      gear: self.character_gear(row.gear_id)?,
+      hero_class_id: row.hero_class_id,
+      level: row.level,
+      gender: row.gender,
+      profession1: row.profession1,
+      profession2: row.profession2,
+      talent_specialization: row.talent_specialization.clone(),
+      race_id: row.race_id,
+    })
+  }
+
+  fn character_gear(&self, gear_id: u32) -> Option<CharacterGear> {
+    let character_item_ids = self.character_gears.get(&gear_id)?;
+    Some(CharacterGear::from_items(gear_id, character_item_ids.iter()
+      .map(|character_item_id| character_item_id.and_then(|character_item_id| self.character_items.get(&character_item_id).cloned()))
+      .collect()))
+  }
+
+  fn summary(&self, character_history_id: u32) -> Option<CharacterSummary> {
+    let row = self.character_histories.get(&character_history_id)?;
+    let character_info = self.character_infos.get(&row.character_info_id);
+    Some(CharacterSummary {
+      character_id: row.character_id,
+      server_id: self.characters.get(&row.character_id)?.0,
+      name: row.character_name.clone(),
+      hero_class_id: character_info.map(|character_info| character_info.hero_class_id).unwrap_or_default(),
+      race_id: character_info.map(|character_info| character_info.race_id).unwrap_or_default(),
+      guild: row.character_guild.clone(),
+      timestamp: row.timestamp,
+    })
+  }
+
+  // Oldest first, like the window over the history rows of each character
+  fn guild_events(&self, guild_id: u32) -> Vec<GuildTransition> {
+    let guild_id = Some(guild_id);
+    let mut character_histories: BTreeMap<u32, Vec<(u32, &CharacterHistoryRow)>> = BTreeMap::new();
+    self.character_histories.iter().for_each(|(character_history_id, row)| character_histories.entry(row.character_id).or_default().push((*character_history_id, row)));
+
+    let mut events: Vec<GuildTransition> = character_histories.into_iter()
+      .filter(|(_, rows)| rows.iter().any(|(_, row)| row.character_guild.as_ref().map(|character_guild| character_guild.guild_id) == guild_id))
+      .flat_map(|(_, mut rows)| {
+        rows.sort_by_key(|(character_history_id, row)| (row.timestamp, *character_history_id));
+        let mut previous_guild: Option<CharacterGuild> = None;
+        rows.into_iter().map(|(character_history_id, row)| {
+          let transition = GuildTransition {
+            id: character_history_id,
+            character_id: row.character_id,
+            character_name: row.character_name.clone(),
+            guild_id: row.character_guild.as_ref().map(|character_guild| character_guild.guild_id),
+            guild_rank: row.character_guild.as_ref().map(|character_guild| character_guild.rank.clone()),
+            previous_guild_id: previous_guild.as_ref().map(|character_guild| character_guild.guild_id),
+            previous_guild_rank: previous_guild.as_ref().map(|character_guild| character_guild.rank.clone()),
+            timestamp: row.timestamp,
+          };
+          previous_guild = row.character_guild.clone();
+          transition
+        }).collect::<Vec<GuildTransition>>()
+      })
+      .filter(|transition| (transition.guild_id == guild_id || transition.previous_guild_id == guild_id)
+        && !(transition.guild_id == transition.previous_guild_id && transition.guild_rank == transition.previous_guild_rank))
+      .collect();
+    events.sort_by_key(|transition| (transition.timestamp, transition.id));
+    events
+  }
+
+  fn delete_character_histories(&mut self, character_history_ids: &[u32]) {
+    let deleted_rows: Vec<CharacterHistoryRow> = character_history_ids.iter().filter_map(|character_history_id| self.character_histories.remove(character_history_id)).collect();
+
+    // Info, gear, items and facials are shared by value between histories, hence only those that are no longer referenced are removed
+    for character_info_id in deleted_rows.iter().map(|row| row.character_info_id) {
+      if self.character_histories.values().any(|row| row.character_info_id == character_info_id) {
+        continue;
+      }
+      let gear_id = match self.character_infos.remove(&character_info_id) {
+        Some(character_info) => character_info.gear_id,
+        None => continue
+      };
+      if self.character_infos.values().any(|character_info| character_info.gear_id == gear_id) {
+        continue;
+      }
+      for character_item_id in self.character_gears.remove(&gear_id).unwrap_or_default().into_iter().flatten() {
+        if !self.character_gears.values().any(|character_item_ids| character_item_ids.contains(&Some(character_item_id))) {
+          self.character_items.remove(&character_item_id);
+        }
+      }
+    }
+    for facial_id in deleted_rows.iter().filter_map(|row| row.facial_id) {
+      if !self.character_histories.values().any(|row| row.facial_id == Some(facial_id)) {
+        self.character_facials.remove(&facial_id);
+      }
+    }
+  }
+}
+
+impl GuildRepository for InMemoryArmoryBackend {
+  fn load_guilds(&self) -> Result<Vec<Guild>, String> {
+    Ok(self.state.lock().unwrap().guilds.values().cloned().collect())
+  }
+
+  fn insert_guild(&self, server_id: u32, server_uid: u64, name: &str) -> Option<u32> {
+    let mut transaction = self.start_transaction().ok()?;
+    let guild_id = transaction.insert_guild(server_id, server_uid, name).ok()?;
+    transaction.commit().ok()?;
+    Some(guild_id)
+  }
+
+  fn update_guild_name(&self, server_id: u32, server_uid: u64, name: &str) -> bool {
+    let mut state = self.state.lock().unwrap();
+    match state.guilds.values_mut().find(|guild| guild.server_id == server_id && guild.server_uid == server_uid) {
+      Some(guild) => {
+        guild.name = name.to_owned();
+        state.version += 1;
+        true
+      },
+      None => false,
+    }
+  }
+
+  fn delete_guild(&self, guild_id: u32) -> Option<Vec<(u32, u32)>> {
+    let mut state = self.state.lock().unwrap();
+    state.guilds.remove(&guild_id)?;
+    state.version += 1;
+    // The rank alone would still reveal the membership
+    Some(state.character_histories.iter_mut()
+      .filter(|(_, row)| row.character_guild.as_ref().map(|character_guild| character_guild.guild_id) == Some(guild_id))
+      .map(|(character_history_id, row)| {
+        row.character_guild = None;
+        (*character_history_id, row.character_id)
+      }).collect())
+  }
+
+  fn count_guild_events(&self, guild_id: u32) -> Result<u64, String> {
+    Ok(self.state.lock().unwrap().guild_events(guild_id).len() as u64)
+  }
+
+  fn load_guild_events(&self, guild_id: u32, before: (u64, u32), offset: u32, limit: u32) -> Result<Vec<GuildTransition>, String> {
+    Ok(self.state.lock().unwrap().guild_events(guild_id).into_iter().rev()
+      .filter(|transition| (transition.timestamp, transition.id) < before)
+      .skip(offset as usize)
+      .take(limit as usize)
+      .collect())
+  }
+
+  fn load_guild_roster_changes(&self, guild_id: u32, interval: u64) -> Result<Vec<(u64, i64)>, String> {
+    let mut roster_changes: BTreeMap<u64, i64> = BTreeMap::new();
+    for transition in self.state.lock().unwrap().guild_events(guild_id) {
+      let change = roster_changes.entry(transition.timestamp - transition.timestamp % interval).or_default();
+      if transition.guild_id == Some(guild_id) && transition.previous_guild_id != Some(guild_id) {
+        *change += 1;
+      } else if transition.previous_guild_id == Some(guild_id) && transition.guild_id != Some(guild_id) {
+        *change -= 1;
+      }
+    }
+    Ok(roster_changes.into_iter().collect())
+  }
+}
+
+impl CharacterSummaryRepository for InMemoryArmoryBackend {
+  fn load_character_summaries(&self) -> Result<Vec<(u32, u32, u64, Option<CharacterSummary>)>, String> {
+    let state = self.state.lock().unwrap();
+    Ok(state.characters.iter().map(|(character_id, (server_id, server_uid))| {
+      let newest_summary = state.character_histories.iter().rev()
+        .find(|(_, row)| row.character_id == *character_id)
+        .and_then(|(character_history_id, _)| state.summary(*character_history_id));
+      (*character_id, *server_id, *server_uid, newest_summary)
+    }).collect())
+  }
+}
+
+impl StartArmoryTransaction for InMemoryArmoryBackend {
+  fn start_transaction(&self) -> Result<Box<dyn ArmoryTransaction + '_>, String> {
+    let state = self.state.lock().unwrap().clone();
+    Ok(Box::new(InMemoryArmoryTransaction {
+      backend: self,
+      version: state.version,
+      state,
+      savepoints: Vec::new(),
+    }))
+  }
+}
+
+// Works on a copy of the state, which replaces the state of the backend when it is committed
+pub struct InMemoryArmoryTransaction<'a> {
+  backend: &'a InMemoryArmoryBackend,
+  version: u64,
+  state: InMemoryState,
+  savepoints: Vec<(String, InMemoryState)>,
+}
+
+impl<'a> InMemoryArmoryTransaction<'a> {
+  fn savepoint_index(&self, name: &str) -> Result<usize, String> {
+    self.savepoints.iter().rposition(|(savepoint, _)| savepoint == name)
+      .ok_or_else(|| format!("Unknown savepoint {}", name))
+  }
+}
+
+impl<'a> ArmoryTransaction for InMemoryArmoryTransaction<'a> {
+  fn insert_guild(&mut self, server_id: u32, server_uid: u64, name: &str) -> Result<u32, String> {
+    if self.state.guilds.values().any(|guild| guild.server_id == server_id && (guild.server_uid == server_uid || guild.name == name)) {
+      return Err(format!("Duplicate guild {}", server_uid));
+    }
+
+    self.state.last_guild_id += 1;
+    let guild_id = self.state.last_guild_id;
+    self.state.guilds.insert(guild_id, Guild {
+      id: guild_id,
+      server_id,
+      server_uid,
+      name: name.to_owned(),
+    });
+    Ok(guild_id)
+  }
+
+  fn savepoint(&mut self, name: &str) -> Result<(), String> {
+    self.savepoints.push((name.to_owned(), self.state.clone()));
+    Ok(())
+  }
+
+  fn release_savepoint(&mut self, name: &str) -> Result<(), String> {
+    let index = self.savepoint_index(name)?;
+    self.savepoints.truncate(index);
+    Ok(())
+  }
+
+  fn rollback_to_savepoint(&mut self, name: &str) -> Result<(), String> {
+    let index = self.savepoint_index(name)?;
+    self.savepoints.truncate(index + 1);
+    self.state = self.savepoints[index].1.clone();
+    Ok(())
+  }
+
+  fn commit(mut self: Box<Self>) -> Result<(), String> {
+    let backend = self.backend;
+    let mut state = backend.state.lock().unwrap();
+    if state.version != self.version {
+      return Err("The armory has been written concurrently".to_owned());
+    }
+    self.state.version += 1;
+    *state = self.state;
+    Ok(())
+  }
+}
+
+impl<'a> CharacterRepository for InMemoryArmoryTransaction<'a> {
+  fn load_character(&mut self, character_id: u32) -> Result<Option<Character>, String> {
+    Ok(self.state.characters.get(&character_id).map(|(server_id, server_uid)| Character {
+      id: character_id,
+      server_id: *server_id,
+      server_uid: *server_uid,
+      last_update: None,
+      history_moments: self.state.character_histories.iter()
+        .filter(|(_, row)| row.character_id == character_id)
+        .map(|(character_history_id, row)| HistoryMoment {
+          id: *character_history_id,
+          timestamp: row.timestamp,
+        }).collect(),
+    }))
+  }
+
+  fn insert_character(&mut self, server_id: u32, server_uid: u64) -> Result<u32, String> {
+    if self.state.characters.values().any(|character| *character == (server_id, server_uid)) {
+      return Err(format!("Duplicate character {}", server_uid));
+    }
+
+    self.state.last_character_id += 1;
+    let character_id = self.state.last_character_id;
+    self.state.characters.insert(character_id, (server_id, server_uid));
+    Ok(character_id)
+  }
+
+  fn delete_character(&mut self, character_id: u32) -> Result<(), String> {
+    let character_history_ids: Vec<u32> = self.state.character_histories.iter()
+      .filter(|(_, row)| row.character_id == character_id)
+      .map(|(character_history_id, _)| *character_history_id)
+      .collect();
+    self.state.delete_character_histories(&character_history_ids);
+    self.state.characters.remove(&character_id);
+    Ok(())
+  }
+}
+
+impl<'a> CharacterHistoryRepository for InMemoryArmoryTransaction<'a> {
+  fn load_character_history(&mut self, character_history_id: u32) -> Result<Option<CharacterHistory>, String> {
+    Ok(self.state.character_history(character_history_id))
+  }
+
+  fn find_character_history(&mut self, character_id: u32, character_info_id: u32, facial_id: Option<u32>, character_guild: Option<&CharacterGuild>,
+                            character_history: &CharacterHistoryDto, since: u64) -> Result<Option<(u32, u64)>, String> {
+    Ok(self.state.character_histories.iter().rev()
+      .find(|(_, row)| row.character_id == character_id
+        && row.character_info_id == character_info_id
+        && row.character_name == character_history.character_name
+        && row.character_guild.as_ref() == character_guild
+        && row.character_title == character_history.character_title
+        && row.profession_skill_points1 == character_history.profession_skill_points1
+        && row.profession_skill_points2 == character_history.profession_skill_points2
+        && row.facial_id == facial_id
+        && row.timestamp >= since)
+      .map(|(character_history_id, row)| (*character_history_id, row.timestamp)))
+  }
+
+  fn insert_character_history(&mut self, character_id: u32, character_info_id: u32, facial_id: Option<u32>, character_guild: Option<&CharacterGuild>,
+                              character_history: &CharacterHistoryDto, timestamp: u64) -> Result<u32, String> {
+    if !self.state.characters.contains_key(&character_id) || !self.state.character_infos.contains_key(&character_info_id) {
+      return Err("The character history references a missing row".to_owned());
+    }
+
+    self.state.last_character_history_id += 1;
+    let character_history_id = self.state.last_character_history_id;
+    self.state.character_histories.insert(character_history_id, CharacterHistoryRow {
+      character_id,
+      character_info_id,
+      character_name: character_history.character_name.clone(),
+      character_guild: character_guild.cloned(),
+      character_title: character_history.character_title,
+      profession_skill_points1: character_history.profession_skill_points1,
+      profession_skill_points2: character_history.profession_skill_points2,
+      facial_id,
+      timestamp,
+    });
+    Ok(character_history_id)
+  }
+
+  fn touch_character_histories(&mut self, character_history_ids: &[u32], timestamp: u64) -> Result<(), String> {
+    character_history_ids.iter().for_each(|character_history_id| {
+      if let Some(row) = self.state.character_histories.get_mut(character_history_id) {
+        row.timestamp = timestamp;
+      }
+    });
+    Ok(())
+  }
+
+  fn delete_character_histories(&mut self, character_history_ids: &[u32]) -> Result<(), String> {
+    self.state.delete_character_histories(character_history_ids);
+    Ok(())
+  }
+}
+
+impl<'a> CharacterInfoRepository for InMemoryArmoryTransaction<'a> {
+  fn load_character_info(&mut self, character_info_id: u32) -> Result<Option<CharacterInfo>, String> {
+    Ok(self.state.character_info(character_info_id))
+  }
+
+  fn find_character_info(&mut self, gear_id: u32, character_info: &CharacterInfoDto) -> Result<Option<u32>, String> {
+    let row = CharacterInfoRow::new(gear_id, character_info);
+    Ok(self.state.character_infos.iter()
+      .find(|(_, character_info)| **character_info == row)
+      .map(|(character_info_id, _)| *character_info_id))
+  }
+
+  fn insert_character_info(&mut self, gear_id: u32, character_info: &CharacterInfoDto) -> Result<u32, String> {
+    if let Some(character_info_id) = self.find_character_info(gear_id, character_info)? {
+      return Ok(character_info_id);
+    }
+    if !self.state.character_gears.contains_key(&gear_id) {
+      return Err("The character info references a missing gear".to_owned());
+    }
+
+    self.state.last_character_info_id += 1;
+    let character_info_id = self.state.last_character_info_id;
+    self.state.character_infos.insert(character_info_id, CharacterInfoRow::new(gear_id, character_info));
+    Ok(character_info_id)
+  }
+}
+
+impl<'a> CharacterGearRepository for InMemoryArmoryTransaction<'a> {
+  fn load_character_gear(&mut self, gear_id: u32) -> Result<Option<CharacterGear>, String> {
+    Ok(self.state.character_gear(gear_id))
+  }
+
+  fn find_character_gear(&mut self, character_item_ids: &[Option<u32>]) -> Result<Option<u32>, String> {
+    Ok(self.state.character_gears.iter()
+      .find(|(_, gear_item_ids)| gear_item_ids.as_slice() == character_item_ids)
+      .map(|(gear_id, _)| *gear_id))
+  }
+
+  fn insert_character_gear(&mut self, character_item_ids: &[Option<u32>]) -> Result<u32, String> {
+    if character_item_ids.len() != GEAR_SLOTS.len() {
+      return Err(format!("Expected {} gear slots, got {}", GEAR_SLOTS.len(), character_item_ids.len()));
+    }
+    if let Some(gear_id) = self.find_character_gear(character_item_ids)? {
+      return Ok(gear_id);
+    }
+    if character_item_ids.iter().flatten().any(|character_item_id| !self.state.character_items.contains_key(character_item_id)) {
+      return Err("The gear references a missing character item".to_owned());
+    }
+
+    self.state.last_character_gear_id += 1;
+    let gear_id = self.state.last_character_gear_id;
+    self.state.character_gears.insert(gear_id, character_item_ids.to_vec());
+    Ok(gear_id)
+  }
+}
+
+impl<'a> CharacterItemRepository for InMemoryArmoryTransaction<'a> {
+  fn load_character_item(&mut self, character_item_id: u32) -> Result<Option<CharacterItem>, String> {
+    Ok(self.state.character_items.get(&character_item_id).cloned())
+  }
+
+  fn find_character_item(&mut self, character_item: &CharacterItemDto) -> Result<Option<u32>, String> {
+    // Unlike compare_by_value, the gems are compared by slot, like the unique key does
+    let character_item = CharacterItem::from_dto(0, character_item);
+    Ok(self.state.character_items.values()
+      .find(|existing_item| existing_item.item_id == character_item.item_id
+        && existing_item.random_property_id == character_item.random_property_id
+        && existing_item.enchant_id == character_item.enchant_id
+        && existing_item.gem_ids == character_item.gem_ids)
+      .map(|existing_item| existing_item.id))
+  }
+
+  fn insert_character_item(&mut self, character_item: &CharacterItemDto) -> Result<u32, String> {
+    if let Some(character_item_id) = self.find_character_item(character_item)? {
+      return Ok(character_item_id);
+    }
+
+    self.state.last_character_item_id += 1;
+    let character_item_id = self.state.last_character_item_id;
+    self.state.character_items.insert(character_item_id, CharacterItem::from_dto(character_item_id, character_item));
+    Ok(character_item_id)
+  }
+}
+
+impl<'a> CharacterFacialRepository for InMemoryArmoryTransaction<'a> {
+  fn load_character_facial(&mut self, facial_id: u32) -> Result<Option<CharacterFacial>, String> {
+    Ok(self.state.character_facials.get(&facial_id).cloned())
+  }
+
+  fn find_character_facial(&mut self, character_facial: &CharacterFacialDto) -> Result<Option<u32>, String> {
+    Ok(self.state.character_facials.values()
+      .find(|existing_facial| existing_facial.compare_by_value(character_facial))
+      .map(|existing_facial| existing_facial.id))
+  }
+
+  fn insert_character_facial(&mut self, character_facial: &CharacterFacialDto) -> Result<u32, String> {
+    if let Some(facial_id) = self.find_character_facial(character_facial)? {
+      return Ok(facial_id);
+    }
+
+    self.state.last_character_facial_id += 1;
+    let facial_id = self.state.last_character_facial_id;
+    self.state.character_facials.insert(facial_id, CharacterFacial::from_dto(facial_id, character_facial));
+    Ok(facial_id)
+  }
+}
\ No newline at end of file
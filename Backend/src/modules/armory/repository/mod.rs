@@ -0,0 +1,25 @@
+pub use self::armory_backend::ArmoryBackend;
+pub use self::armory_transaction::{ArmoryTransaction, StartArmoryTransaction};
+pub use self::character_facial_repository::CharacterFacialRepository;
+pub use self::character_gear_repository::CharacterGearRepository;
+pub use self::character_history_repository::CharacterHistoryRepository;
+pub use self::character_info_repository::CharacterInfoRepository;
+pub use self::character_item_repository::CharacterItemRepository;
+pub use self::character_repository::CharacterRepository;
+pub use self::character_summary_repository::CharacterSummaryRepository;
+pub use self::guild_repository::GuildRepository;
+pub use self::in_memory_backend::InMemoryArmoryBackend;
+pub use self::mysql_backend::{MySQLArmoryBackend, select_character_history};
+
+mod armory_backend;
+mod armory_transaction;
+mod character_facial_repository;
+mod character_gear_repository;
+mod character_history_repository;
+mod character_info_repository;
+mod character_item_repository;
+mod character_repository;
+mod character_summary_repository;
+mod guild_repository;
+mod in_memory_backend;
+mod mysql_backend;
\ No newline at end of file
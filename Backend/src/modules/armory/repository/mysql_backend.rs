@@ -0,0 +1,583 @@
+use mysql_connection::{Row, Value};
+use mysql_connection::material::{MySQLConnection, MySQLError, MySQLTransaction};
+use mysql_connection::tools::{Execute, FromRow, Select, StartTransaction, TakeColumn, select_columns};
+
+use crate::modules::armory::domain_value::{CharacterFacial, CharacterGear, CharacterGuild, CharacterInfo, CharacterItem, GEAR_SLOTS, GuildTransition, HistoryMoment};
+use crate::modules::armory::dto::{CharacterFacialDto, CharacterHistoryDto, CharacterInfoDto, CharacterItemDto};
+use crate::modules::armory::material::{Character, CharacterHistory, CharacterSummary, Guild};
+use crate::modules::armory::repository::{ArmoryTransaction, CharacterFacialRepository, CharacterGearRepository, CharacterHistoryRepository, CharacterInfoRepository,
+  CharacterItemRepository, CharacterRepository, CharacterSummaryRepository, GuildRepository, StartArmoryTransaction};
+
+// Every history row of everyone who was ever a member next to the previous row of that character,
+// as leaving is only visible in rows without this guild
+const GUILD_TRANSITIONS: &str = "SELECT id, character_id, character_name, guild_id, guild_rank, timestamp, \
+  LAG(guild_id) OVER (PARTITION BY character_id ORDER BY timestamp, id) AS previous_guild_id, \
+  LAG(guild_rank) OVER (PARTITION BY character_id ORDER BY timestamp, id) AS previous_guild_rank \
+  FROM armory_character_history WHERE character_id IN (SELECT character_id FROM armory_character_history WHERE guild_id=:guild_id)";
+// Transitions that join, leave or change the rank within the guild
+const GUILD_EVENT_CONDITION: &str = "(guild_id <=> :guild_id OR previous_guild_id <=> :guild_id) \
+  AND NOT (guild_id <=> previous_guild_id AND IFNULL(guild_rank, '') = IFNULL(previous_guild_rank, ''))";
+
+#[derive(Debug)]
+pub struct MySQLArmoryBackend {
+  pub db_main: MySQLConnection,
+}
+
+impl MySQLArmoryBackend {
+  pub fn new(db_main: MySQLConnection) -> Self {
+    MySQLArmoryBackend { db_main }
+  }
+}
+
+impl GuildRepository for MySQLArmoryBackend {
+  fn load_guilds(&self) -> Result<Vec<Guild>, String> {
+    self.db_main.try_select("SELECT id, server_uid, server_id, guild_name FROM armory_guild", &|mut row| {
+      Ok(Guild {
+        id: row.take_column(0)?,
+        server_uid: row.take_column(1)?,
+        server_id: row.take_column(2)?,
+        name: row.take_column(3)?,
+      })
+    }).map_err(|err| format!("Failed to load the guilds: {}", err))
+  }
+
+  fn insert_guild(&self, server_id: u32, server_uid: u64, name: &str) -> Option<u32> {
+    let mut transaction = self.db_main.start_transaction().ok()?;
+    transaction.execute_wparams("INSERT INTO armory_guild (`server_id`, `server_uid`, `guild_name`) VALUES (:server_id, :server_uid, :guild_name)", params!(
+      "server_id" => server_id,
+      "server_uid" => server_uid,
+      "guild_name" => name
+    )).ok()?;
+    let guild_id = transaction.last_insert_id()? as u32;
+    transaction.commit().ok()?;
+    Some(guild_id)
+  }
+
+  fn update_guild_name(&self, server_id: u32, server_uid: u64, name: &str) -> bool {
+    self.db_main.execute_wparams("UPDATE armory_guild SET guild_name=:guild_name WHERE server_id=:server_id AND server_uid=:server_uid", params!(
+      "server_id" => server_id,
+      "server_uid" => server_uid,
+      "guild_name" => name
+    ))
+  }
+
+  fn delete_guild(&self, guild_id: u32) -> Option<Vec<(u32, u32)>> {
+    let mut transaction = self.db_main.start_transaction().ok()?;
+    let memberships = transaction.try_select_wparams("SELECT id, character_id FROM armory_character_history WHERE guild_id=:id", &|mut row| {
+      Ok((row.take_column(0)?, row.take_column(1)?))
+    }, params!("id" => guild_id)).ok()?;
+    // The rank alone would still reveal the membership
+    transaction.execute_wparams("UPDATE armory_character_history SET guild_id=NULL, guild_rank=NULL WHERE guild_id=:id", params!(
+      "id" => guild_id
+    )).ok()?;
+    transaction.execute_wparams("DELETE FROM armory_guild WHERE id=:id", params!(
+      "id" => guild_id
+    )).ok()?;
+    if transaction.affected_rows() == 0 {
+      return None;
+    }
+    transaction.commit().ok()?;
+    Some(memberships)
+  }
+
+  fn count_guild_events(&self, guild_id: u32) -> Result<u64, String> {
+    self.db_main.try_select_wparams_value(&format!("SELECT COUNT(*) FROM ({}) transitions WHERE {}", GUILD_TRANSITIONS, GUILD_EVENT_CONDITION),
+      &|mut row| row.take_column::<u64>(0), params!("guild_id" => guild_id))
+      .map(|num_events| num_events.unwrap_or(0))
+      .map_err(|err| format!("Failed to count the guild events: {}", err))
+  }
+
+  fn load_guild_events(&self, guild_id: u32, before: (u64, u32), offset: u32, limit: u32) -> Result<Vec<GuildTransition>, String> {
+    self.db_main.try_select_wparams(&format!("SELECT id, character_id, character_name, guild_id, guild_rank, previous_guild_id, previous_guild_rank, timestamp FROM ({}) transitions \
+      WHERE {} AND (timestamp < :before_timestamp OR (timestamp = :before_timestamp AND id < :before_id)) ORDER BY timestamp DESC, id DESC LIMIT :offset, :limit", GUILD_TRANSITIONS, GUILD_EVENT_CONDITION),
+      &|mut row| {
+        Ok(GuildTransition {
+          id: row.take_column(0)?,
+          character_id: row.take_column(1)?,
+          character_name: row.take_column(2)?,
+          guild_id: row.take_column(3)?,
+          guild_rank: row.take_column(4)?,
+          previous_guild_id: row.take_column(5)?,
+          previous_guild_rank: row.take_column(6)?,
+          timestamp: row.take_column(7)?,
+        })
+      }, params!("guild_id" => guild_id, "before_timestamp" => before.0, "before_id" => before.1, "offset" => offset, "limit" => limit))
+      .map_err(|err| format!("Failed to load the guild events: {}", err))
+  }
+
+  fn load_guild_roster_changes(&self, guild_id: u32, interval: u64) -> Result<Vec<(u64, i64)>, String> {
+    self.db_main.try_select_wparams(&format!("SELECT timestamp - timestamp % {} AS day, \
+      CAST(SUM(guild_id <=> :guild_id AND NOT previous_guild_id <=> :guild_id) AS SIGNED) - CAST(SUM(previous_guild_id <=> :guild_id AND NOT guild_id <=> :guild_id) AS SIGNED) \
+      FROM ({}) transitions WHERE {} GROUP BY day ORDER BY day", interval, GUILD_TRANSITIONS, GUILD_EVENT_CONDITION),
+      &|mut row| Ok((row.take_column(0)?, row.take_column(1)?)), params!("guild_id" => guild_id))
+      .map_err(|err| format!("Failed to load the guild roster changes: {}", err))
+  }
+}
+
+impl CharacterSummaryRepository for MySQLArmoryBackend {
+  // Only the summary of the newest character history is loaded, the characters themselves are loaded on demand
+  fn load_character_summaries(&self) -> Result<Vec<(u32, u32, u64, Option<CharacterSummary>)>, String> {
+    self.db_main.try_select("SELECT ac.id, ac.server_id, ac.server_uid, ach.character_name, ach.guild_id, ach.guild_rank, ach.timestamp, aci.hero_class_id, aci.race_id \
+      FROM armory_character ac \
+      LEFT JOIN armory_character_history ach ON ach.id = (SELECT MAX(id) FROM armory_character_history WHERE character_id = ac.id) \
+      LEFT JOIN armory_character_info aci ON aci.id = ach.character_info_id", &|mut row| {
+      let character_id: u32 = row.take_named("id")?;
+      let server_id: u32 = row.take_named("server_id")?;
+      let server_uid: u64 = row.take_named("server_uid")?;
+      let character_name: Option<String> = row.take_named("character_name")?;
+      let guild_id: Option<u32> = row.take_named("guild_id")?;
+      let guild_rank: Option<String> = row.take_named("guild_rank")?;
+      let timestamp: Option<u64> = row.take_named("timestamp")?;
+      let hero_class_id: Option<u8> = row.take_named("hero_class_id")?;
+      let race_id: Option<u8> = row.take_named("race_id")?;
+      let summary = character_name.map(|name| CharacterSummary {
+        character_id,
+        server_id,
+        name,
+        hero_class_id: hero_class_id.unwrap_or_default(),
+        race_id: race_id.unwrap_or_default(),
+        guild: guild_id.map(|guild_id| CharacterGuild {
+          guild_id,
+          rank: guild_rank.unwrap_or_default(),
+        }),
+        timestamp: timestamp.unwrap_or_default(),
+      });
+      Ok((character_id, server_id, server_uid, summary))
+    }).map_err(|err| format!("Failed to load the character summaries: {}", err))
+  }
+}
+
+impl StartArmoryTransaction for MySQLArmoryBackend {
+  fn start_transaction(&self) -> Result<Box<dyn ArmoryTransaction + '_>, String> {
+    Ok(Box::new(MySQLArmoryTransaction {
+      db_main: &self.db_main,
+      transaction: None,
+    }))
+  }
+}
+
+// Reads go through the connection until the first write starts the database transaction
+pub struct MySQLArmoryTransaction<'a> {
+  db_main: &'a MySQLConnection,
+  transaction: Option<MySQLTransaction>,
+}
+
+impl<'a> MySQLArmoryTransaction<'a> {
+  fn transaction(&mut self) -> Result<&mut MySQLTransaction, MySQLError> {
+    if self.transaction.is_none() {
+      self.transaction = Some(self.db_main.start_transaction()?);
+    }
+    Ok(self.transaction.as_mut().unwrap())
+  }
+
+  fn select<T>(&mut self, query_str: &str, process_row: &dyn Fn(Row) -> Result<T, MySQLError>, params: Vec<(String, Value)>) -> Result<Vec<T>, MySQLError> {
+    match self.transaction.as_mut() {
+      Some(transaction) => transaction.try_select_wparams(query_str, process_row, params),
+      None => self.db_main.try_select_wparams(query_str, process_row, params)
+    }
+  }
+
+  fn select_value<T>(&mut self, query_str: &str, process_row: &dyn Fn(Row) -> Result<T, MySQLError>, params: Vec<(String, Value)>) -> Result<Option<T>, MySQLError> {
+    self.select(query_str, process_row, params).map(|mut values| values.pop())
+  }
+
+  fn execute(&mut self, query_str: &str, params: Vec<(String, Value)>) -> Result<(), MySQLError> {
+    self.transaction()?.execute_wparams(query_str, params)
+  }
+
+  // Id of the inserted row, or of the existing one that LAST_INSERT_ID(id) hands out on a duplicate key
+  fn insert(&mut self, query_str: &str, params: Vec<(String, Value)>) -> Result<u32, MySQLError> {
+    let transaction = self.transaction()?;
+    transaction.execute_wparams(query_str, params)?;
+    transaction.last_insert_id()
+      .map(|id| id as u32)
+      .ok_or_else(|| MySQLError::Query { query: query_str.to_owned(), reason: "No id has been generated".to_owned() })
+  }
+}
+
+impl<'a> ArmoryTransaction for MySQLArmoryTransaction<'a> {
+  fn insert_guild(&mut self, server_id: u32, server_uid: u64, name: &str) -> Result<u32, String> {
+    self.insert("INSERT INTO armory_guild (`server_id`, `server_uid`, `guild_name`) VALUES (:server_id, :server_uid, :guild_name)", params!(
+      "server_id" => server_id,
+      "server_uid" => server_uid,
+      "guild_name" => name
+    )).map_err(|err| err.to_string())
+  }
+
+  fn savepoint(&mut self, name: &str) -> Result<(), String> {
+    self.execute(&format!("SAVEPOINT {}", name), Vec::new()).map_err(|err| err.to_string())
+  }
+
+  fn release_savepoint(&mut self, name: &str) -> Result<(), String> {
+    self.execute(&format!("RELEASE SAVEPOINT {}", name), Vec::new()).map_err(|err| err.to_string())
+  }
+
+  // Some failures, e.g. deadlocks, roll back the whole transaction, including the savepoint
+  fn rollback_to_savepoint(&mut self, name: &str) -> Result<(), String> {
+    self.execute(&format!("ROLLBACK TO SAVEPOINT {}", name), Vec::new()).map_err(|err| err.to_string())
+  }
+
+  fn commit(self: Box<Self>) -> Result<(), String> {
+    match self.transaction {
+      Some(transaction) => transaction.commit().map_err(|err| err.to_string()),
+      None => Ok(())
+    }
+  }
+}
+
+impl<'a> CharacterRepository for MySQLArmoryTransaction<'a> {
+  fn load_character(&mut self, character_id: u32) -> Result<Option<Character>, String> {
+    let character = self.select_value("SELECT id, server_id, server_uid FROM armory_character WHERE id=:id", &|mut row| {
+      Ok(Character {
+        id: row.take_named("id")?,
+        server_id: row.take_named("server_id")?,
+        server_uid: row.take_named("server_uid")?,
+        last_update: None,
+        history_moments: Vec::new(),
+      })
+    }, params!("id" => character_id)).map_err(|err| err.to_string())?;
+
+    match character {
+      Some(mut character) => {
+        character.history_moments = self.select("SELECT id, timestamp FROM armory_character_history WHERE character_id=:character_id ORDER BY id", &|mut row| {
+          Ok(HistoryMoment {
+            id: row.take_named("id")?,
+            timestamp: row.take_named("timestamp")?,
+          })
+        }, params!("character_id" => character_id)).map_err(|err| err.to_string())?;
+        Ok(Some(character))
+      },
+      None => Ok(None)
+    }
+  }
+
+  fn insert_character(&mut self, server_id: u32, server_uid: u64) -> Result<u32, String> {
+    self.insert("INSERT INTO armory_character (`server_id`, `server_uid`) VALUES (:server_id, :server_uid)", params!(
+      "server_id" => server_id,
+      "server_uid" => server_uid
+    )).map_err(|err| err.to_string())
+  }
+
+  fn delete_character(&mut self, character_id: u32) -> Result<(), String> {
+    let character_history_ids: Vec<u32> = self.select("SELECT id FROM armory_character_history WHERE character_id=:id", &|mut row| row.take_column(0), params!(
+      "id" => character_id
+    )).map_err(|err| err.to_string())?;
+    let transaction = self.transaction().map_err(|err| err.to_string())?;
+    delete_character_histories_in_transaction(transaction, &character_history_ids)
+      .and_then(|()| transaction.execute_wparams("DELETE FROM armory_character WHERE id=:id", params!(
+        "id" => character_id
+      )))
+      .map_err(|err| err.to_string())
+  }
+}
+
+impl<'a> CharacterHistoryRepository for MySQLArmoryTransaction<'a> {
+  fn load_character_history(&mut self, character_history_id: u32) -> Result<Option<CharacterHistory>, String> {
+    self.select_value(&format!("{} WHERE ach.id=:id", select_character_history()), &|mut row| CharacterHistory::from_row(&mut row, ""), params!(
+      "id" => character_history_id
+    )).map_err(|err| err.to_string())
+  }
+
+  fn find_character_history(&mut self, character_id: u32, character_info_id: u32, facial_id: Option<u32>, character_guild: Option<&CharacterGuild>,
+                            character_history: &CharacterHistoryDto, since: u64) -> Result<Option<(u32, u64)>, String> {
+    let mut params = character_history_params(character_id, character_info_id, facial_id, character_guild, character_history);
+    params.push(("since".to_owned(), Value::from(since)));
+    self.select_value("SELECT id, timestamp FROM armory_character_history WHERE character_id=:character_id AND character_info_id=:character_info_id AND character_name=:character_name \
+      AND ((ISNULL(:guild_id) AND ISNULL(guild_id)) OR guild_id = :guild_id) \
+      AND ((ISNULL(:guild_rank) AND ISNULL(guild_rank)) OR guild_rank = :guild_rank) \
+      AND ((ISNULL(:title) AND ISNULL(title)) OR title = :title) \
+      AND ((ISNULL(:prof_skill_points1) AND ISNULL(prof_skill_points1)) OR prof_skill_points1 = :prof_skill_points1) \
+      AND ((ISNULL(:prof_skill_points2) AND ISNULL(prof_skill_points2)) OR prof_skill_points2 = :prof_skill_points2) \
+      AND ((ISNULL(:facial) AND ISNULL(facial)) OR facial = :facial) \
+      AND timestamp >= :since", &|mut row| Ok((row.take_column(0)?, row.take_column(1)?)), params)
+      .map_err(|err| err.to_string())
+  }
+
+  fn insert_character_history(&mut self, character_id: u32, character_info_id: u32, facial_id: Option<u32>, character_guild: Option<&CharacterGuild>,
+                              character_history: &CharacterHistoryDto, timestamp: u64) -> Result<u32, String> {
+    let mut params = character_history_params(character_id, character_info_id, facial_id, character_guild, character_history);
+    params.push(("timestamp".to_owned(), Value::from(timestamp)));
+    self.insert("INSERT INTO armory_character_history (`character_id`, `character_info_id`, `character_name`, `title`, `guild_id`, `guild_rank`, `prof_skill_points1`, `prof_skill_points2`, `facial`, `timestamp`) \
+      VALUES (:character_id, :character_info_id, :character_name, :title, :guild_id, :guild_rank, :prof_skill_points1, :prof_skill_points2, :facial, :timestamp)", params)
+      .map_err(|err| err.to_string())
+  }
+
+  fn touch_character_histories(&mut self, character_history_ids: &[u32], timestamp: u64) -> Result<(), String> {
+    if character_history_ids.is_empty() {
+      return Ok(());
+    }
+    self.execute(&format!("UPDATE armory_character_history SET `timestamp` = :timestamp WHERE id IN ({})", id_list(character_history_ids)), params!(
+      "timestamp" => timestamp
+    )).map_err(|err| err.to_string())
+  }
+
+  fn delete_character_histories(&mut self, character_history_ids: &[u32]) -> Result<(), String> {
+    let transaction = self.transaction().map_err(|err| err.to_string())?;
+    delete_character_histories_in_transaction(transaction, character_history_ids).map_err(|err| err.to_string())
+  }
+}
+
+impl<'a> CharacterInfoRepository for MySQLArmoryTransaction<'a> {
+  fn load_character_info(&mut self, character_info_id: u32) -> Result<Option<CharacterInfo>, String> {
+    let mut columns = Vec::new();
+    let mut joins = Vec::new();
+    select_character_info(&mut columns, &mut joins, "");
+    self.select_value(&format!("SELECT {} FROM armory_character_info aci {} WHERE aci.id=:id", columns.join(", "), joins.join(" ")),
+      &|mut row| CharacterInfo::from_row(&mut row, ""), params!("id" => character_info_id))
+      .map_err(|err| err.to_string())
+  }
+
+  fn find_character_info(&mut self, gear_id: u32, character_info: &CharacterInfoDto) -> Result<Option<u32>, String> {
+    self.select_value("SELECT id FROM armory_character_info WHERE gear_id=:gear_id \
+      AND hero_class_id=:hero_class_id AND level=:level AND gender=:gender \
+      AND ((ISNULL(:profession1) AND ISNULL(profession1)) OR profession1 = :profession1) \
+      AND ((ISNULL(:profession2) AND ISNULL(profession2)) OR profession2 = :profession2) \
+      AND ((ISNULL(:talent_specialization) AND ISNULL(talent_specialization)) OR talent_specialization = :talent_specialization) \
+      AND race_id=:race_id", &|mut row| row.take_column(0), character_info_params(gear_id, character_info))
+      .map_err(|err| err.to_string())
+  }
+
+  fn insert_character_info(&mut self, gear_id: u32, character_info: &CharacterInfoDto) -> Result<u32, String> {
+    // It may happen that another thread is inserting the same info, then the unique key hands us the existing row
+    self.insert("INSERT INTO armory_character_info (`gear_id`, `hero_class_id`, `level`, `gender`, `profession1`, `profession2`, `talent_specialization`, `race_id`) \
+      VALUES (:gear_id, :hero_class_id, :level, :gender, :profession1, :profession2, :talent_specialization, :race_id) \
+      ON DUPLICATE KEY UPDATE id=LAST_INSERT_ID(id)", character_info_params(gear_id, character_info))
+      .map_err(|err| err.to_string())
+  }
+}
+
+impl<'a> CharacterGearRepository for MySQLArmoryTransaction<'a> {
+  fn load_character_gear(&mut self, gear_id: u32) -> Result<Option<CharacterGear>, String> {
+    let mut columns = Vec::new();
+    let mut joins = Vec::new();
+    select_character_gear(&mut columns, &mut joins, "");
+    self.select_value(&format!("SELECT {} FROM armory_gear ag {} WHERE ag.id=:id", columns.join(", "), joins.join(" ")),
+      &|mut row| CharacterGear::from_row(&mut row, ""), params!("id" => gear_id))
+      .map_err(|err| err.to_string())
+  }
+
+  fn find_character_gear(&mut self, character_item_ids: &[Option<u32>]) -> Result<Option<u32>, String> {
+    let params = character_gear_params(character_item_ids)?;
+    self.select_value(&format!("SELECT id FROM armory_gear WHERE {}", GEAR_SLOTS.iter()
+      .map(|slot| format!("((ISNULL(:{0}) AND ISNULL(`{0}`)) OR `{0}` = :{0})", slot))
+      .collect::<Vec<String>>()
+      .join(" AND ")), &|mut row| row.take_column(0), params)
+      .map_err(|err| err.to_string())
+  }
+
+  fn insert_character_gear(&mut self, character_item_ids: &[Option<u32>]) -> Result<u32, String> {
+    let params = character_gear_params(character_item_ids)?;
+    self.insert(&format!("INSERT INTO armory_gear ({}) VALUES ({}) ON DUPLICATE KEY UPDATE id=LAST_INSERT_ID(id)",
+      GEAR_SLOTS.iter().map(|slot| format!("`{}`", slot)).collect::<Vec<String>>().join(", "),
+      GEAR_SLOTS.iter().map(|slot| format!(":{}", slot)).collect::<Vec<String>>().join(", ")), params)
+      .map_err(|err| err.to_string())
+  }
+}
+
+impl<'a> CharacterItemRepository for MySQLArmoryTransaction<'a> {
+  fn load_character_item(&mut self, character_item_id: u32) -> Result<Option<CharacterItem>, String> {
+    self.select_value("SELECT * FROM armory_item WHERE id=:id", &|mut row| CharacterItem::from_row(&mut row, ""), params!(
+      "id" => character_item_id
+    )).map_err(|err| err.to_string())
+  }
+
+  fn find_character_item(&mut self, character_item: &CharacterItemDto) -> Result<Option<u32>, String> {
+    self.select_value("SELECT id FROM armory_item WHERE item_id=:item_id \
+      AND ((ISNULL(:random_property_id) AND ISNULL(random_property_id)) OR random_property_id = :random_property_id) \
+      AND ((ISNULL(:enchant_id) AND ISNULL(enchant_id)) OR enchant_id = :enchant_id) \
+      AND ((ISNULL(:gem_id1) AND ISNULL(gem_id1)) OR gem_id1 = :gem_id1) \
+      AND ((ISNULL(:gem_id2) AND ISNULL(gem_id2)) OR gem_id2 = :gem_id2) \
+      AND ((ISNULL(:gem_id3) AND ISNULL(gem_id3)) OR gem_id3 = :gem_id3) \
+      AND ((ISNULL(:gem_id4) AND ISNULL(gem_id4)) OR gem_id4 = :gem_id4)", &|mut row| row.take_column(0), character_item_params(character_item))
+      .map_err(|err| err.to_string())
+  }
+
+  fn insert_character_item(&mut self, character_item: &CharacterItemDto) -> Result<u32, String> {
+    self.insert("INSERT INTO armory_item (`item_id`, `random_property_id`, `enchant_id`, `gem_id1`, `gem_id2`, `gem_id3`, `gem_id4`) \
+      VALUES (:item_id, :random_property_id, :enchant_id, :gem_id1, :gem_id2, :gem_id3, :gem_id4) \
+      ON DUPLICATE KEY UPDATE id=LAST_INSERT_ID(id)", character_item_params(character_item))
+      .map_err(|err| err.to_string())
+  }
+}
+
+impl<'a> CharacterFacialRepository for MySQLArmoryTransaction<'a> {
+  fn load_character_facial(&mut self, facial_id: u32) -> Result<Option<CharacterFacial>, String> {
+    self.select_value("SELECT * FROM armory_character_facial WHERE id=:id", &|mut row| CharacterFacial::from_row(&mut row, ""), params!(
+      "id" => facial_id
+    )).map_err(|err| err.to_string())
+  }
+
+  fn find_character_facial(&mut self, character_facial: &CharacterFacialDto) -> Result<Option<u32>, String> {
+    self.select_value("SELECT id FROM armory_character_facial WHERE \
+      skin_color=:skin_color \
+      AND face_style=:face_style \
+      AND hair_style=:hair_style \
+      AND hair_color=:hair_color \
+      AND facial_hair=:facial_hair", &|mut row| row.take_column(0), character_facial_params(character_facial))
+      .map_err(|err| err.to_string())
+  }
+
+  fn insert_character_facial(&mut self, character_facial: &CharacterFacialDto) -> Result<u32, String> {
+    self.insert("INSERT INTO armory_character_facial (`skin_color`, `face_style`, `hair_style`, `hair_color`, `facial_hair`) \
+      VALUES (:skin_color, :face_style, :hair_style, :hair_color, :facial_hair) \
+      ON DUPLICATE KEY UPDATE id=LAST_INSERT_ID(id)", character_facial_params(character_facial))
+      .map_err(|err| err.to_string())
+  }
+}
+
+// Selects every column a CharacterHistory is decoded from, the caller appends its conditions
+pub fn select_character_history() -> String {
+  let mut columns = vec![
+    select_columns::<CharacterHistory>("ach", ""),
+    select_columns::<CharacterFacial>("acf", "facial_"),
+  ];
+  let mut joins = vec![
+    "LEFT JOIN armory_character_facial acf ON acf.id = ach.facial".to_owned(),
+    "JOIN armory_character_info aci ON ach.character_info_id = aci.id".to_owned(),
+  ];
+  select_character_info(&mut columns, &mut joins, "info_");
+  format!("SELECT {} FROM armory_character_history ach {}", columns.join(", "), joins.join(" "))
+}
+
+// The info is aliased as aci by the caller
+fn select_character_info(columns: &mut Vec<String>, joins: &mut Vec<String>, prefix: &str) {
+  columns.push(select_columns::<CharacterInfo>("aci", prefix));
+  joins.push("JOIN armory_gear ag ON aci.gear_id = ag.id".to_owned());
+  select_character_gear(columns, joins, &format!("{}gear_", prefix));
+}
+
+// The gear is aliased as ag by the caller
+fn select_character_gear(columns: &mut Vec<String>, joins: &mut Vec<String>, prefix: &str) {
+  columns.push(select_columns::<CharacterGear>("ag", prefix));
+  for slot in GEAR_SLOTS.iter() {
+    let alias = format!("ai_{}", slot);
+    columns.push(select_columns::<CharacterItem>(&alias, &format!("{}{}_", prefix, slot)));
+    joins.push(format!("LEFT JOIN armory_item {} ON ag.`{}` = {}.id", alias, slot, alias));
+  }
+}
+
+fn character_history_params(character_id: u32, character_info_id: u32, facial_id: Option<u32>, character_guild: Option<&CharacterGuild>, character_history: &CharacterHistoryDto) -> Vec<(String, Value)> {
+  params!(
+    "character_id" => character_id,
+    "character_info_id" => character_info_id,
+    "character_name" => character_history.character_name.clone(),
+    "title" => character_history.character_title,
+    "guild_id" => character_guild.map(|character_guild| character_guild.guild_id),
+    "guild_rank" => character_guild.map(|character_guild| character_guild.rank.clone()),
+    "prof_skill_points1" => character_history.profession_skill_points1,
+    "prof_skill_points2" => character_history.profession_skill_points2,
+    "facial" => facial_id
+  )
+}
+
+fn character_info_params(gear_id: u32, character_info: &CharacterInfoDto) -> Vec<(String, Value)> {
+  params!(
+    "gear_id" => gear_id,
+    "hero_class_id" => character_info.hero_class_id,
+    "level" => character_info.level,
+    "gender" => character_info.gender,
+    "profession1" => character_info.profession1,
+    "profession2" => character_info.profession2,
+    "talent_specialization" => character_info.talent_specialization.clone(),
+    "race_id" => character_info.race_id
+  )
+}
+
+fn character_gear_params(character_item_ids: &[Option<u32>]) -> Result<Vec<(String, Value)>, String> {
+  if character_item_ids.len() != GEAR_SLOTS.len() {
+    return Err(format!("Expected {} gear slots, got {}", GEAR_SLOTS.len(), character_item_ids.len()));
+  }
+  Ok(GEAR_SLOTS.iter().zip(character_item_ids.iter())
+    .map(|(slot, character_item_id)| (slot.to_string(), Value::from(*character_item_id)))
+    .collect())
+}
+
+fn character_item_params(character_item: &CharacterItemDto) -> Vec<(String, Value)> {
+  params!(
+    "item_id" => character_item.item_id,
+    "random_property_id" => character_item.random_property_id,
+    "enchant_id" => character_item.enchant_id,
+    "gem_id1" => character_item.gem_ids.get(0).cloned().flatten(),
+    "gem_id2" => character_item.gem_ids.get(1).cloned().flatten(),
+    "gem_id3" => character_item.gem_ids.get(2).cloned().flatten(),
+    "gem_id4" => character_item.gem_ids.get(3).cloned().flatten()
+  )
+}
+
+fn character_facial_params(character_facial: &CharacterFacialDto) -> Vec<(String, Value)> {
+  params!(
+    "skin_color" => character_facial.skin_color,
+    "face_style" => character_facial.face_style,
+    "hair_style" => character_facial.hair_style,
+    "hair_color" => character_facial.hair_color,
+    "facial_hair" => character_facial.facial_hair
+  )
+}
+
+// Info, gear, items and facials are shared by value between histories, hence only those that are no longer referenced are removed
+fn delete_character_histories_in_transaction(transaction: &mut MySQLTransaction, character_history_ids: &[u32]) -> Result<(), MySQLError> {
+  if character_history_ids.is_empty() {
+    return Ok(());
+  }
+  let character_info_ids = select_by_ids(transaction, "SELECT DISTINCT character_info_id FROM armory_character_history", character_history_ids, &|mut row| {
+    Ok(vec![row.take_column(0)?])
+  })?;
+  let facial_ids = select_by_ids(transaction, "SELECT DISTINCT facial FROM armory_character_history", character_history_ids, &|mut row| {
+    Ok(row.take_column::<Option<u32>>(0)?.into_iter().collect())
+  })?;
+  let gear_ids = select_by_ids(transaction, "SELECT DISTINCT gear_id FROM armory_character_info", &character_info_ids, &|mut row| {
+    Ok(vec![row.take_column(0)?])
+  })?;
+  let item_ids = select_by_ids(transaction, &format!("SELECT {} FROM armory_gear", GEAR_SLOTS.iter().map(|slot| format!("`{}`", slot)).collect::<Vec<String>>().join(", ")), &gear_ids, &|mut row| {
+    let mut item_ids = Vec::new();
+    for index in 0..GEAR_SLOTS.len() {
+      if let Some(item_id) = row.take_column(index)? {
+        item_ids.push(item_id);
+      }
+    }
+    Ok(item_ids)
+  })?;
+
+  transaction.execute(&format!("DELETE FROM armory_character_history WHERE id IN ({})", id_list(character_history_ids)))?;
+
+  // Referencing rows are removed first, as the foreign keys restrict the deletion
+  delete_unreferenced(transaction, "armory_character_info", &character_info_ids, "SELECT 1 FROM armory_character_history ach WHERE ach.character_info_id = armory_character_info.id")?;
+  delete_unreferenced(transaction, "armory_character_facial", &facial_ids, "SELECT 1 FROM armory_character_history ach WHERE ach.facial = armory_character_facial.id")?;
+  delete_unreferenced(transaction, "armory_gear", &gear_ids, "SELECT 1 FROM armory_character_info aci WHERE aci.gear_id = armory_gear.id")?;
+
+  // Each slot is looked up on its own, so that the lookups are restricted to the candidates by the slot's index
+  if item_ids.is_empty() {
+    return Ok(());
+  }
+  let referenced_item_ids: Vec<u32> = transaction.try_select_wparams(&GEAR_SLOTS.iter()
+    .map(|slot| format!("SELECT `{}` FROM armory_gear WHERE `{}` IN ({})", slot, slot, id_list(&item_ids)))
+    .collect::<Vec<String>>()
+    .join(" UNION "), &|mut row| row.take_column(0), Vec::new())?;
+  let unreferenced_item_ids: Vec<u32> = item_ids.into_iter().filter(|item_id| !referenced_item_ids.contains(item_id)).collect();
+  if unreferenced_item_ids.is_empty() {
+    return Ok(());
+  }
+  transaction.execute(&format!("DELETE FROM armory_item WHERE id IN ({})", id_list(&unreferenced_item_ids)))
+}
+
+fn id_list(ids: &[u32]) -> String {
+  ids.iter().map(|id| id.to_string()).collect::<Vec<String>>().join(",")
+}
+
+fn select_by_ids(transaction: &mut MySQLTransaction, query: &str, ids: &[u32], process_row: &dyn Fn(Row) -> Result<Vec<u32>, MySQLError>) -> Result<Vec<u32>, MySQLError> {
+  if ids.is_empty() {
+    return Ok(Vec::new());
+  }
+  let mut result = transaction.try_select_wparams(&format!("{} WHERE id IN ({})", query, id_list(ids)), process_row, Vec::new())?
+    .into_iter().flatten().collect::<Vec<u32>>();
+  result.sort();
+  result.dedup();
+  Ok(result)
+}
+
+fn delete_unreferenced(transaction: &mut MySQLTransaction, table: &str, ids: &[u32], references: &str) -> Result<(), MySQLError> {
+  if ids.is_empty() {
+    return Ok(());
+  }
+  transaction.execute(&format!("DELETE FROM {} WHERE id IN ({}) AND NOT EXISTS ({})", table, id_list(ids), references))
+}
\ No newline at end of file
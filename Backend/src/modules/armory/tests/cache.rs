@@ -0,0 +1,65 @@
+use std::cell::Cell;
+
+use crate::modules::armory::material::{Cache, CacheBackend, LruCache};
+
+#[test]
+fn lru_cache_evicts_least_recently_used() {
+  let mut cache: LruCache<u32, String> = LruCache::new(2);
+  assert_eq!(cache.insert(1, "Jaina".to_owned()), 0);
+  assert_eq!(cache.insert(2, "Thrall".to_owned()), 0);
+  assert_eq!(cache.get(&1), Some("Jaina".to_owned()));
+  assert_eq!(cache.insert(3, "Arthas".to_owned()), 1);
+  assert_eq!(cache.get(&2), None);
+  assert_eq!(cache.get(&1), Some("Jaina".to_owned()));
+  assert_eq!(cache.get(&3), Some("Arthas".to_owned()));
+  assert_eq!(cache.len(), 2);
+
+  cache.remove(&1);
+  assert_eq!(cache.insert(4, "Sylvanas".to_owned()), 0);
+  assert_eq!(cache.len(), 2);
+}
+
+#[test]
+fn cache_reads_through() {
+  let cache: Cache<String> = Cache::new("test", 10);
+  let loads = Cell::new(0);
+  let load = || {
+    loads.set(loads.get() + 1);
+    Some("Jaina".to_owned())
+  };
+  assert_eq!(cache.get_or_load(1, load), Some("Jaina".to_owned()));
+  assert_eq!(cache.get_or_load(1, load), Some("Jaina".to_owned()));
+  assert_eq!(loads.get(), 1);
+  assert_eq!(cache.metrics.misses.get(), 1);
+  assert_eq!(cache.metrics.hits.get(), 1);
+  assert_eq!(cache.metrics.entries.get(), 1);
+
+  assert_eq!(cache.get_or_load(2, || None), None);
+  assert_eq!(cache.metrics.entries.get(), 1);
+
+  cache.update(1, |name| name.push_str(" Proudmoore"));
+  assert_eq!(cache.get_or_load(1, load), Some("Jaina Proudmoore".to_owned()));
+  cache.invalidate(1);
+  assert_eq!(cache.get_or_load(1, load), Some("Jaina".to_owned()));
+  assert_eq!(loads.get(), 2);
+}
+
+#[test]
+fn cache_discards_loads_racing_with_writes() {
+  let cache: Cache<String> = Cache::new("test", 10);
+  assert_eq!(cache.get_or_load(1, || {
+    cache.invalidate(1);
+    Some("Stale".to_owned())
+  }), Some("Stale".to_owned()));
+  assert_eq!(cache.metrics.entries.get(), 0);
+  assert_eq!(cache.get_or_load(1, || Some("Fresh".to_owned())), Some("Fresh".to_owned()));
+}
+
+#[test]
+fn cache_returns_failed_loads() {
+  let cache: Cache<String> = Cache::new("test", 10);
+  assert_eq!(cache.try_get_or_load(1, || Err("Connection lost")), Err("Connection lost"));
+  assert_eq!(cache.metrics.entries.get(), 0);
+  assert_eq!(cache.try_get_or_load::<&str>(1, || Ok(Some("Jaina".to_owned()))), Ok(Some("Jaina".to_owned())));
+  assert_eq!(cache.try_get_or_load(1, || Err("Connection lost")), Ok(Some("Jaina".to_owned())));
+}
\ No newline at end of file
@@ -1,14 +1,13 @@
 use std::{thread, time};
 
-use mysql_connection::tools::Execute;
-
-use crate::modules::armory::Armory;
-use crate::modules::armory::dto::{CharacterDto, CharacterGearDto, CharacterHistoryDto, CharacterInfoDto, CharacterItemDto, GuildDto, CharacterGuildDto, CharacterFacialDto};
-use crate::modules::armory::tools::{SetCharacter, GetCharacter, DeleteCharacter};
+use crate::modules::armory::repository::{CharacterFacialRepository, CharacterGearRepository, CharacterHistoryRepository, CharacterInfoRepository, CharacterItemRepository, CharacterRepository, StartArmoryTransaction};
+use crate::modules::armory::tests::in_memory_armory;
+use crate::modules::armory::dto::{CharacterDto, CharacterGearDto, CharacterHistoryDto, CharacterInfoDto, CharacterItemDto, GuildDto, CharacterGuildDto, CharacterFacialDto, SetCharacterStatus};
+use crate::modules::armory::tools::{SetCharacter, GetCharacter, DeleteCharacter, GetGuild, DeleteGuild};
 
 #[test]
 fn set_character() {
-  let armory = Armory::default();
+  let armory = in_memory_armory();
   let character_info_dto = CharacterInfoDto {
     gear: CharacterGearDto {
       head: Some(CharacterItemDto {
@@ -176,8 +175,6 @@ fn set_character() {
   let character2 = character_res2.unwrap();
   assert!(character2.deep_eq(&set_character2));
 
-  let character_history = character.last_update.unwrap();
-
   // Deleting the character
   let delete_result = armory.delete_character(character.id);
   assert!(delete_result.is_ok());
@@ -185,28 +182,193 @@ fn set_character() {
   // Check if it was actually deleted
   let character_res3 = armory.get_character(character.id);
   assert!(character_res3.is_none());
+}
+
+fn cascade_character_dto(server_uid: u64, character_name: &str) -> CharacterDto {
+  CharacterDto {
+    server_uid,
+    character_history: Some(CharacterHistoryDto {
+      character_info: CharacterInfoDto {
+        gear: CharacterGearDto {
+          head: None,
+          neck: None,
+          shoulder: None,
+          back: None,
+          chest: None,
+          shirt: None,
+          tabard: None,
+          wrist: None,
+          main_hand: Some(CharacterItemDto {
+            item_id: 40395,
+            random_property_id: None,
+            enchant_id: None,
+            gem_ids: vec![None, None, None, None],
+          }),
+          off_hand: None,
+          ternary_hand: Some(CharacterItemDto {
+            item_id: 40396,
+            random_property_id: None,
+            enchant_id: None,
+            gem_ids: vec![None, None, None, None],
+          }),
+          glove: None,
+          belt: None,
+          leg: None,
+          boot: None,
+          ring1: None,
+          ring2: None,
+          trinket1: None,
+          trinket2: None,
+        },
+        hero_class_id: 8,
+        level: 80,
+        gender: true,
+        profession1: None,
+        profession2: None,
+        talent_specialization: None,
+        race_id: 7,
+      },
+      character_name: character_name.to_string(),
+      character_title: None,
+      profession_skill_points1: None,
+      profession_skill_points2: None,
+      facial: Some(CharacterFacialDto {
+        skin_color: 9,
+        face_style: 8,
+        hair_style: 7,
+        hair_color: 6,
+        facial_hair: 5
+      }),
+      character_guild: None,
+    }),
+  }
+}
+
+#[test]
+fn delete_character_cascades() {
+  let armory = in_memory_armory();
+  let character = armory.set_character(3, cascade_character_dto(1231246, "Cascadeone")).unwrap();
+  let other_character = armory.set_character(3, cascade_character_dto(1231247, "Cascadetwo")).unwrap();
+  let character_history = character.last_update.clone().unwrap();
+  let character_info = character_history.character_info.clone();
+  let main_hand_id = character_info.gear.main_hand.as_ref().unwrap().id;
+  let ternary_hand_id = character_info.gear.ternary_hand.as_ref().unwrap().id;
+  let facial_id = character_history.facial.as_ref().unwrap().id;
+  let exists = |table: &str, id: u32| {
+    let mut transaction = armory.backend.start_transaction().unwrap();
+    match table {
+      "armory_character" => transaction.load_character(id).unwrap().is_some(),
+      "armory_character_history" => transaction.load_character_history(id).unwrap().is_some(),
+      "armory_character_info" => transaction.load_character_info(id).unwrap().is_some(),
+      "armory_gear" => transaction.load_character_gear(id).unwrap().is_some(),
+      "armory_item" => transaction.load_character_item(id).unwrap().is_some(),
+      "armory_character_facial" => transaction.load_character_facial(id).unwrap().is_some(),
+      _ => unreachable!()
+    }
+  };
+
+  // Values shared with another character are kept
+  assert!(armory.delete_character(character.id).is_ok());
+  assert!(armory.get_character(character.id).is_none());
+  assert!(!exists("armory_character", character.id));
+  assert!(!exists("armory_character_history", character_history.id));
+  assert!(exists("armory_character_info", character_info.id));
+  assert!(exists("armory_gear", character_info.gear.id));
+  assert!(exists("armory_item", main_hand_id));
+  assert!(exists("armory_character_facial", facial_id));
+  assert!(armory.get_character(other_character.id).unwrap().deep_eq(&other_character));
+
+  // The last reference takes everything with it
+  assert!(armory.delete_character(other_character.id).is_ok());
+  assert!(!exists("armory_character", other_character.id));
+  assert!(!exists("armory_character_history", other_character.last_update.as_ref().unwrap().id));
+  assert!(!exists("armory_character_info", character_info.id));
+  assert!(!exists("armory_gear", character_info.gear.id));
+  assert!(!exists("armory_item", main_hand_id));
+  assert!(!exists("armory_item", ternary_hand_id));
+  assert!(!exists("armory_character_facial", facial_id));
+
+  assert!(armory.delete_character(character.id).is_err());
+}
+
+#[test]
+fn set_characters() {
+  let armory = in_memory_armory();
+  let mut implausible_character = cascade_character_dto(0, "Batchzero");
+  implausible_character.character_history = None;
+  let results = armory.set_characters(3, vec![
+    cascade_character_dto(1231248, "Batchoutdated"),
+    cascade_character_dto(1231249, "Batchtwo"),
+    implausible_character,
+    cascade_character_dto(1231248, "Batchone"),
+  ]).unwrap();
+  assert_eq!(results.len(), 4);
+  assert_eq!(results[0].status, SetCharacterStatus::Created);
+  assert_eq!(results[0].server_uid, 1231248);
+  assert_eq!(results[1].status, SetCharacterStatus::Created);
+  assert_eq!(results[2].status, SetCharacterStatus::Rejected);
+  assert_eq!(results[2].code, Some(536));
+  assert!(results[2].reason.is_some());
+  assert_eq!(results[3].status, SetCharacterStatus::Created);
+
+  let character = armory.get_character_by_uid(3, 1231248).unwrap();
+  let other_character = armory.get_character_by_uid(3, 1231249).unwrap();
+  let last_update = character.last_update.clone().unwrap();
+  assert_eq!(last_update.character_name, "Batchone");
+  assert_eq!(character.history_moments.len(), 1);
+
+  // Same values only refresh the timestamp of the last update
+  thread::sleep(time::Duration::from_secs(1));
+  let results = armory.set_characters(3, vec![
+    cascade_character_dto(1231248, "Batchone"),
+    cascade_character_dto(1231249, "Batchthree"),
+  ]).unwrap();
+  assert_eq!(results[0].status, SetCharacterStatus::Unchanged);
+  assert_eq!(results[1].status, SetCharacterStatus::Created);
+  let touched_update = armory.get_character(character.id).unwrap().last_update.unwrap();
+  assert_eq!(touched_update.id, last_update.id);
+  assert!(touched_update.timestamp > last_update.timestamp);
+  let renamed_character = armory.get_character(other_character.id).unwrap();
+  assert_eq!(renamed_character.last_update.unwrap().character_name, "Batchthree");
+  assert_eq!(renamed_character.history_moments.len(), 2);
+
+  assert!(armory.set_characters(3, (0..101).map(|index| cascade_character_dto(1231250 + index, "Batchlimit")).collect()).is_err());
+
+  assert!(armory.delete_character(character.id).is_ok());
+  assert!(armory.delete_character(other_character.id).is_ok());
+}
+
+#[test]
+fn set_characters_with_guild() {
+  let armory = in_memory_armory();
+  let with_guild = |server_uid: u64, guild_uid: u64| {
+    let mut character = cascade_character_dto(server_uid, "Batchguild");
+    character.character_history.as_mut().unwrap().character_guild = Some(CharacterGuildDto {
+      guild: GuildDto {
+        name: "BatchGuildSample".to_string(),
+        server_uid: guild_uid,
+      },
+      rank: "Member".to_string()
+    });
+    character
+  };
+  let results = armory.set_characters(3, vec![
+    with_guild(1231360, 1231370),
+    with_guild(1231361, 0),
+    with_guild(1231362, 1231370),
+  ]).unwrap();
+  assert_eq!(results[0].status, SetCharacterStatus::Created);
+  assert_eq!(results[1].status, SetCharacterStatus::Rejected);
+  assert_eq!(results[2].status, SetCharacterStatus::Created);
+  assert!(armory.get_character_by_uid(3, 1231361).is_none());
+
+  // The guild is created once and shared within the batch
+  let guild = armory.get_guild_by_uid(3, 1231370).unwrap();
+  let guild_id = |server_uid: u64| armory.get_character_by_uid(3, server_uid).unwrap().last_update.unwrap().character_guild.unwrap().guild_id;
+  assert_eq!(guild_id(1231360), guild.id);
+  assert_eq!(guild_id(1231362), guild.id);
 
-  armory.db_main.execute_wparams("DELETE FROM armory_item WHERE id=:id", params!("id" => character_history.character_info.gear.head.unwrap().id));
-  armory.db_main.execute_wparams("DELETE FROM armory_item WHERE id=:id", params!("id" => character_history.character_info.gear.neck.unwrap().id));
-  armory.db_main.execute_wparams("DELETE FROM armory_item WHERE id=:id", params!("id" => character_history.character_info.gear.shoulder.unwrap().id));
-  armory.db_main.execute_wparams("DELETE FROM armory_item WHERE id=:id", params!("id" => character_history.character_info.gear.back.unwrap().id));
-  armory.db_main.execute_wparams("DELETE FROM armory_item WHERE id=:id", params!("id" => character_history.character_info.gear.chest.unwrap().id));
-  armory.db_main.execute_wparams("DELETE FROM armory_item WHERE id=:id", params!("id" => character_history.character_info.gear.wrist.unwrap().id));
-  armory.db_main.execute_wparams("DELETE FROM armory_item WHERE id=:id", params!("id" => character_history.character_info.gear.main_hand.unwrap().id));
-  armory.db_main.execute_wparams("DELETE FROM armory_item WHERE id=:id", params!("id" => character_history.character_info.gear.off_hand.unwrap().id));
-  armory.db_main.execute_wparams("DELETE FROM armory_item WHERE id=:id", params!("id" => character_history.character_info.gear.ternary_hand.unwrap().id));
-  armory.db_main.execute_wparams("DELETE FROM armory_item WHERE id=:id", params!("id" => character_history.character_info.gear.glove.unwrap().id));
-  armory.db_main.execute_wparams("DELETE FROM armory_item WHERE id=:id", params!("id" => character_history.character_info.gear.belt.unwrap().id));
-  armory.db_main.execute_wparams("DELETE FROM armory_item WHERE id=:id", params!("id" => character_history.character_info.gear.leg.unwrap().id));
-  armory.db_main.execute_wparams("DELETE FROM armory_item WHERE id=:id", params!("id" => character_history.character_info.gear.boot.unwrap().id));
-  armory.db_main.execute_wparams("DELETE FROM armory_item WHERE id=:id", params!("id" => character_history.character_info.gear.ring1.unwrap().id));
-  armory.db_main.execute_wparams("DELETE FROM armory_item WHERE id=:id", params!("id" => character_history.character_info.gear.ring2.unwrap().id));
-  armory.db_main.execute_wparams("DELETE FROM armory_item WHERE id=:id", params!("id" => character_history.character_info.gear.trinket1.unwrap().id));
-  armory.db_main.execute_wparams("DELETE FROM armory_item WHERE id=:id", params!("id" => character_history.character_info.gear.trinket2.unwrap().id));
-  armory.db_main.execute_wparams("DELETE FROM armory_gear WHERE id=:id", params!("id" => character_history.character_info.gear.id));
-  armory.db_main.execute_wparams("DELETE FROM armory_character_facial WHERE id=:id", params!("id" => character_history.facial.unwrap().id));
-  armory.db_main.execute_wparams("DELETE FROM armory_character_info WHERE id=:id", params!("id" => character_history.character_info.id));
-  armory.db_main.execute_wparams("DELETE FROM armory_character_history WHERE id=:id", params!("id" => character_history.id));
-  armory.db_main.execute_wparams("DELETE FROM armory_character WHERE id=:id", params!("id" => character_history.character_id));
-  armory.db_main.execute_wparams("DELETE FROM armory_guild WHERE id=:id", params!("id" => character_history.character_guild.unwrap().guild_id));
+  assert!(armory.delete_character(armory.get_character_id_by_uid(3, 1231360).unwrap()).is_ok());
+  assert!(armory.delete_character(armory.get_character_id_by_uid(3, 1231362).unwrap()).is_ok());
+  assert!(armory.delete_guild(guild.id).is_ok());
 }
\ No newline at end of file
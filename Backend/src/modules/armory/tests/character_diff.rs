@@ -0,0 +1,109 @@
+use crate::modules::armory::tests::in_memory_armory;
+use crate::modules::armory::dto::{CharacterDto, CharacterGearDto, CharacterHistoryDto, CharacterInfoDto, CharacterItemDto};
+use crate::modules::armory::tools::{SetCharacter, SetCharacterHistory, CharacterDiff};
+use crate::modules::data::Data;
+
+#[test]
+fn get_character_diff() {
+  let armory = in_memory_armory();
+  let mut character_info_dto = CharacterInfoDto {
+    gear: CharacterGearDto {
+      head: None,
+      neck: None,
+      shoulder: None,
+      back: None,
+      chest: None,
+      shirt: Some(CharacterItemDto {
+        item_id: 51,
+        random_property_id: Some(-5),
+        enchant_id: Some(1999),
+        gem_ids: vec![None, None, None, None],
+      }),
+      tabard: None,
+      wrist: None,
+      main_hand: None,
+      off_hand: None,
+      ternary_hand: None,
+      glove: None,
+      belt: None,
+      leg: None,
+      boot: None,
+      ring1: None,
+      ring2: None,
+      trinket1: None,
+      trinket2: None,
+    },
+    hero_class_id: 7,
+    level: 79,
+    gender: false,
+    profession1: Some(186),
+    profession2: None,
+    talent_specialization: None,
+    race_id: 4,
+  };
+  let mut character_history_dto = CharacterHistoryDto {
+    character_info: character_info_dto.to_owned(),
+    character_name: "Diffsdipetesdfsr".to_string(),
+    character_title: None,
+    profession_skill_points1: Some(223),
+    profession_skill_points2: None,
+    facial: None,
+    character_guild: None,
+  };
+  let character_dto = CharacterDto {
+    server_uid: 1231223446,
+    character_history: Some(character_history_dto.to_owned()),
+  };
+
+  let set_character_res = armory.set_character(3, character_dto);
+  assert!(set_character_res.is_ok());
+  let set_character = set_character_res.unwrap();
+  let from_history = set_character.last_update.unwrap();
+
+  character_info_dto.level = 80;
+  character_info_dto.gear.shirt.as_mut().unwrap().enchant_id = None;
+  // Unknown to the data, hence this slot can not be part of the diff
+  character_info_dto.gear.tabard = Some(CharacterItemDto {
+    item_id: 4294967,
+    random_property_id: None,
+    enchant_id: None,
+    gem_ids: vec![None, None, None, None],
+  });
+  character_history_dto.character_info = character_info_dto;
+  character_history_dto.profession_skill_points1 = Some(250);
+  let set_character_history_res = armory.set_character_history(3, character_history_dto, set_character.server_uid);
+  assert!(set_character_history_res.is_ok());
+  let to_history = set_character_history_res.unwrap();
+
+  let data = Data::default().init(None).unwrap();
+  let character_diff_res = armory.get_character_diff(&data, 1, set_character.id, from_history.id, to_history.id);
+  assert!(character_diff_res.is_ok());
+  let character_diff = character_diff_res.unwrap();
+
+  assert_eq!(character_diff.from_history_id, from_history.id);
+  assert_eq!(character_diff.to_history_id, to_history.id);
+  assert!(character_diff.name.is_none());
+  assert!(character_diff.guild.is_none());
+  assert!(character_diff.title.is_none());
+  assert!(character_diff.level.is_some());
+  assert_eq!(character_diff.level.as_ref().unwrap().from, 79);
+  assert_eq!(character_diff.level.as_ref().unwrap().to, 80);
+  assert_eq!(character_diff.professions.len(), 1);
+  assert_eq!(character_diff.professions[0].from_points, Some(223));
+  assert_eq!(character_diff.professions[0].to_points, Some(250));
+  assert_eq!(character_diff.gear.len(), 1);
+  assert_eq!(character_diff.gear[0].slot, "shirt");
+  assert!(!character_diff.gear[0].item_changed);
+  assert!(character_diff.gear[0].enchant_changed);
+  assert!(!character_diff.gear[0].gems_changed);
+
+  let no_diff_res = armory.get_character_diff(&data, 1, set_character.id, to_history.id, to_history.id);
+  assert!(no_diff_res.is_ok());
+  let no_diff = no_diff_res.unwrap();
+  assert!(no_diff.level.is_none());
+  assert!(no_diff.gear.is_empty());
+  assert!(no_diff.stats.is_empty());
+  assert!(no_diff.professions.is_empty());
+
+  assert!(armory.get_character_diff(&data, 1, set_character.id, from_history.id, 0).is_err());
+}
\ No newline at end of file
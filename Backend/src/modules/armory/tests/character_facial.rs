@@ -1,11 +1,10 @@
-use crate::modules::armory::Armory;
+use crate::modules::armory::tests::in_memory_armory;
 use crate::modules::armory::dto::CharacterFacialDto;
 use crate::modules::armory::tools::{CreateCharacterFacial, GetCharacterFacial};
-use mysql_connection::tools::Execute;
 
 #[test]
 fn character_facial() {
-  let armory = Armory::default();
+  let armory = in_memory_armory();
   let character_facial_dto = CharacterFacialDto {
     skin_color: 1,
     face_style: 2,
@@ -25,6 +24,4 @@ fn character_facial() {
 
   let character_facial2 = character_facial2_res.unwrap();
   assert!(character_facial2.deep_eq(&character_facial));
-
-  armory.db_main.execute_wparams("DELETE FROM armory_character_facial WHERE id=:id", params!("id" => character_facial.id));
 }
\ No newline at end of file
@@ -1,11 +1,10 @@
 use crate::modules::armory::dto::{CharacterGearDto, CharacterItemDto};
-use crate::modules::armory::Armory;
+use crate::modules::armory::tests::in_memory_armory;
 use crate::modules::armory::tools::{CreateCharacterGear, GetCharacterGear};
-use mysql_connection::tools::Execute;
 
 #[test]
 fn character_gear() {
-  let armory = Armory::default();
+  let armory = in_memory_armory();
   let character_gear_dto = CharacterGearDto {
     head: Some(CharacterItemDto {
       item_id: 32235,
@@ -119,22 +118,4 @@ fn character_gear() {
 
   let character_gear2 = character_gear_res2.unwrap();
   assert!(character_gear2.deep_eq(&character_gear));
-
-  armory.db_main.execute_wparams("DELETE FROM armory_item WHERE id=:id", params!("id" => character_gear.head.unwrap().id));
-  armory.db_main.execute_wparams("DELETE FROM armory_item WHERE id=:id", params!("id" => character_gear.neck.unwrap().id));
-  armory.db_main.execute_wparams("DELETE FROM armory_item WHERE id=:id", params!("id" => character_gear.shoulder.unwrap().id));
-  armory.db_main.execute_wparams("DELETE FROM armory_item WHERE id=:id", params!("id" => character_gear.back.unwrap().id));
-  armory.db_main.execute_wparams("DELETE FROM armory_item WHERE id=:id", params!("id" => character_gear.chest.unwrap().id));
-  armory.db_main.execute_wparams("DELETE FROM armory_item WHERE id=:id", params!("id" => character_gear.wrist.unwrap().id));
-  armory.db_main.execute_wparams("DELETE FROM armory_item WHERE id=:id", params!("id" => character_gear.main_hand.unwrap().id));
-  armory.db_main.execute_wparams("DELETE FROM armory_item WHERE id=:id", params!("id" => character_gear.ternary_hand.unwrap().id));
-  armory.db_main.execute_wparams("DELETE FROM armory_item WHERE id=:id", params!("id" => character_gear.glove.unwrap().id));
-  armory.db_main.execute_wparams("DELETE FROM armory_item WHERE id=:id", params!("id" => character_gear.belt.unwrap().id));
-  armory.db_main.execute_wparams("DELETE FROM armory_item WHERE id=:id", params!("id" => character_gear.leg.unwrap().id));
-  armory.db_main.execute_wparams("DELETE FROM armory_item WHERE id=:id", params!("id" => character_gear.boot.unwrap().id));
-  armory.db_main.execute_wparams("DELETE FROM armory_item WHERE id=:id", params!("id" => character_gear.ring1.unwrap().id));
-  armory.db_main.execute_wparams("DELETE FROM armory_item WHERE id=:id", params!("id" => character_gear.ring2.unwrap().id));
-  armory.db_main.execute_wparams("DELETE FROM armory_item WHERE id=:id", params!("id" => character_gear.trinket1.unwrap().id));
-  armory.db_main.execute_wparams("DELETE FROM armory_item WHERE id=:id", params!("id" => character_gear.trinket2.unwrap().id));
-  armory.db_main.execute_wparams("DELETE FROM armory_gear WHERE id=:id", params!("id" => character_gear.id));
 }
\ No newline at end of file
@@ -1,11 +1,10 @@
 use crate::modules::armory::dto::CharacterItemDto;
-use crate::modules::armory::Armory;
+use crate::modules::armory::tests::in_memory_armory;
 use crate::modules::armory::tools::{CreateCharacterItem, GetCharacterItem};
-use mysql_connection::tools::Execute;
 
 #[test]
 fn character_item() {
-  let armory = Armory::default();
+  let armory = in_memory_armory();
   let character_item_dto = CharacterItemDto {
     item_id: 19019,
     random_property_id: Some(1023),
@@ -24,6 +23,4 @@ fn character_item() {
 
   let character_item2 = character_item2_res.unwrap();
   assert!(character_item2.deep_eq(&character_item));
-
-  armory.db_main.execute("DELETE FROM armory_item WHERE item_id = 19019");
 }
\ No newline at end of file
@@ -1,15 +1,53 @@
 use crate::modules::armory::Armory;
+use crate::modules::armory::tests::in_memory_armory;
+use crate::modules::armory::material::CharacterSummary;
 use crate::modules::data::Data;
 use crate::modules::armory::tools::PerformCharacterSearch;
 use crate::modules::armory::dto::CharacterSearchFilter;
 use crate::dto::TableFilter;
 
+const SEARCH_TEST_SERVER_ID: u32 = 987654;
+
+fn filter(page_size: Option<u32>, cursor: Option<String>, name_sorting: Option<bool>) -> CharacterSearchFilter {
+  CharacterSearchFilter {
+    page: 0,
+    page_size,
+    cursor,
+    hero_class: TableFilter { filter: None, sorting: None },
+    name: TableFilter { filter: None, sorting: name_sorting },
+    guild: TableFilter { filter: None, sorting: None },
+    server: TableFilter { filter: Some(SEARCH_TEST_SERVER_ID), sorting: None },
+    last_updated: TableFilter { filter: None, sorting: None }
+  }
+}
+
+fn armory_with_characters(num_characters: u32) -> Armory {
+  let armory = in_memory_armory();
+  {
+    let mut character_search_index = armory.character_search_index.write().unwrap();
+    for character_id in 0..num_characters {
+      character_search_index.index_summary(CharacterSummary {
+        character_id: 900000000 + character_id,
+        server_id: SEARCH_TEST_SERVER_ID,
+        name: format!("Searchtest{:03}", character_id),
+        hero_class_id: 1,
+        race_id: 1,
+        guild: None,
+        timestamp: 0,
+      });
+    }
+  }
+  armory
+}
+
 #[test]
 fn character_search() {
-  let armory = Armory::default();
-  let data = Data::default().init(None);
+  let armory = in_memory_armory();
+  let data = Data::default().init(None).unwrap();
   let filter1 = CharacterSearchFilter {
     page: 0,
+    page_size: None,
+    cursor: None,
     hero_class: TableFilter { filter: None, sorting: None },
     name: TableFilter { filter: None, sorting: None },
     guild: TableFilter { filter: None, sorting: None },
@@ -17,7 +55,41 @@ fn character_search() {
     last_updated: TableFilter { filter: None, sorting: None }
   };
 
-  let csr1 = armory.get_character_search_result(&data, filter1.clone());
+  let csr1 = armory.get_character_search_result(&data, filter1.clone()).unwrap();
   assert_eq!(csr1.num_items, 0);
   assert_eq!(csr1.result.len(), 0);
+  assert!(csr1.next_cursor.is_none());
+}
+
+#[test]
+fn character_search_page_size_bounds() {
+  let armory = armory_with_characters(120);
+  let data = Data::default().init(None).unwrap();
+
+  assert_eq!(armory.get_character_search_result(&data, filter(None, None, None)).unwrap().result.len(), 10);
+  assert_eq!(armory.get_character_search_result(&data, filter(Some(0), None, None)).unwrap().result.len(), 1);
+  assert_eq!(armory.get_character_search_result(&data, filter(Some(25), None, None)).unwrap().result.len(), 25);
+  let csr = armory.get_character_search_result(&data, filter(Some(1000), None, None)).unwrap();
+  assert_eq!(csr.result.len(), 100);
+  assert_eq!(csr.num_items, 120);
+}
+
+#[test]
+fn character_search_cursor() {
+  let armory = armory_with_characters(25);
+  let data = Data::default().init(None).unwrap();
+
+  let first_page = armory.get_character_search_result(&data, filter(Some(10), None, Some(false))).unwrap();
+  assert_eq!(first_page.result[0].character.name, "Searchtest024");
+  let second_page = armory.get_character_search_result(&data, filter(Some(10), first_page.next_cursor.clone(), Some(false))).unwrap();
+  assert_eq!(second_page.result[0].character.name, "Searchtest014");
+  let last_page = armory.get_character_search_result(&data, filter(Some(10), second_page.next_cursor.clone(), Some(false))).unwrap();
+  assert_eq!(last_page.result.len(), 5);
+  assert!(last_page.next_cursor.is_none());
+
+  // A cursor is only valid for the sorting it was created with
+  assert!(armory.get_character_search_result(&data, filter(Some(10), first_page.next_cursor.clone(), Some(true))).is_err());
+  assert!(armory.get_character_search_result(&data, filter(Some(10), first_page.next_cursor, None)).is_err());
+  assert!(armory.get_character_search_result(&data, filter(Some(10), Some("not a cursor".to_owned()), Some(false))).is_err());
+  assert!(armory.get_character_search_result(&data, filter(Some(10), Some("7b7d".to_owned()), Some(false))).is_err());
 }
\ No newline at end of file
@@ -0,0 +1,106 @@
+use crate::modules::armory::domain_value::{CharacterGear, CharacterGuild, CharacterInfo};
+use crate::modules::armory::material::{Character, CharacterHistory, CharacterSearchIndex, Guild};
+
+fn create_character(id: u32, server_id: u32, name: &str, hero_class_id: u8, guild_id: Option<u32>) -> Character {
+  Character {
+    id,
+    server_id,
+    server_uid: id as u64,
+    last_update: Some(CharacterHistory {
+      id,
+      character_id: id,
+      character_info: CharacterInfo {
+        id,
+        gear: CharacterGear {
+          id,
+          head: None,
+          neck: None,
+          shoulder: None,
+          back: None,
+          chest: None,
+          shirt: None,
+          tabard: None,
+          wrist: None,
+          main_hand: None,
+          off_hand: None,
+          ternary_hand: None,
+          glove: None,
+          belt: None,
+          leg: None,
+          boot: None,
+          ring1: None,
+          ring2: None,
+          trinket1: None,
+          trinket2: None,
+        },
+        hero_class_id,
+        level: 60,
+        gender: false,
+        profession1: None,
+        profession2: None,
+        talent_specialization: None,
+        race_id: 1,
+      },
+      character_name: name.to_owned(),
+      character_guild: guild_id.map(|guild_id| CharacterGuild { guild_id, rank: "Member".to_owned() }),
+      character_title: None,
+      profession_skill_points1: None,
+      profession_skill_points2: None,
+      facial: None,
+      timestamp: 0,
+    }),
+    history_moments: Vec::new(),
+  }
+}
+
+fn sorted(mut ids: Vec<u32>) -> Vec<u32> {
+  ids.sort();
+  ids
+}
+
+#[test]
+fn character_search_index() {
+  let mut index = CharacterSearchIndex::default();
+  index.index_guild(&Guild { id: 1, server_id: 1, server_uid: 1, name: "Nostalrius Elite".to_owned() });
+  index.index_character(&create_character(1, 1, "Jaina", 8, Some(1)));
+  index.index_character(&create_character(2, 1, "Janosch", 1, None));
+  index.index_character(&create_character(3, 2, "Thrall", 7, Some(1)));
+  index.index_character(&create_character(4, 2, "Abcabd", 7, None));
+  assert_eq!(index.num_characters(), 4);
+
+  assert_eq!(sorted(index.find(None, None, None, None)), vec![1, 2, 3, 4]);
+  assert_eq!(sorted(index.find(None, None, Some("ja"), None)), vec![1, 2]);
+  assert_eq!(sorted(index.find(None, None, Some("JANOS"), None)), vec![2]);
+  assert_eq!(sorted(index.find(None, None, Some("abcabc"), None)), Vec::<u32>::new());
+  assert_eq!(sorted(index.find(Some(2), None, None, None)), vec![3, 4]);
+  assert_eq!(sorted(index.find(Some(2), Some(7), Some("thr"), None)), vec![3]);
+  assert_eq!(sorted(index.find(None, None, None, Some("elite"))), vec![1, 3]);
+  assert_eq!(sorted(index.find(None, None, None, Some(""))), vec![1, 3]);
+
+  // Renaming the guild
+  index.index_guild(&Guild { id: 1, server_id: 1, server_uid: 1, name: "Kronos".to_owned() });
+  assert_eq!(sorted(index.find(None, None, None, Some("elite"))), Vec::<u32>::new());
+  assert_eq!(sorted(index.find(None, None, None, Some("kro"))), vec![1, 3]);
+
+  // A new history moment replaces the old entry
+  index.index_character(&create_character(1, 1, "Proudmoore", 8, None));
+  assert_eq!(sorted(index.find(None, None, Some("ja"), None)), vec![2]);
+  assert_eq!(sorted(index.find(None, None, None, Some("kro"))), vec![3]);
+
+  assert_eq!(index.get_character_id_by_uid(2, 3), Some(3));
+  assert_eq!(index.guild_members(1).iter().map(|summary| summary.name.as_str()).collect::<Vec<&str>>(), vec!["Thrall"]);
+
+  index.remove_character(3);
+  index.remove_guild(1);
+  assert_eq!(index.get_character_id_by_uid(2, 3), None);
+  assert!(index.guild_members(1).is_empty());
+  assert_eq!(sorted(index.find(None, Some(7), None, None)), vec![4]);
+  assert_eq!(sorted(index.find(None, None, None, Some(""))), Vec::<u32>::new());
+  assert_eq!(index.num_characters(), 3);
+
+  // Characters without history can be looked up by uid, but are not searchable
+  index.add_character(5, 1, 5);
+  assert_eq!(index.get_character_id_by_uid(1, 5), Some(5));
+  assert!(index.contains_character(5));
+  assert_eq!(index.num_characters(), 3);
+}
\ No newline at end of file
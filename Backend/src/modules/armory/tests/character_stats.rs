@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+
+use crate::modules::armory::domain_value::{CharacterGear, CharacterItem};
+use crate::modules::armory::tools::get_character_stats;
+use crate::modules::data::{Data, Stat};
+use crate::modules::data::tools::{RetrieveItemsetEffect, RetrieveItemsetName, RetrieveLocalization, RetrieveStatType, SpellDescription};
+
+fn gear(item_ids: &[u32]) -> CharacterGear {
+  let mut items = item_ids.iter().enumerate().map(|(index, item_id)| Some(CharacterItem {
+    id: index as u32,
+    item_id: *item_id,
+    random_property_id: None,
+    enchant_id: None,
+    gem_ids: vec![None, None, None, None],
+  }));
+  let mut next = || items.next().unwrap_or(None);
+  CharacterGear {
+    id: 0,
+    head: next(),
+    neck: next(),
+    shoulder: next(),
+    back: next(),
+    chest: next(),
+    shirt: next(),
+    tabard: next(),
+    wrist: next(),
+    main_hand: next(),
+    off_hand: next(),
+    ternary_hand: next(),
+    glove: next(),
+    belt: next(),
+    leg: next(),
+    boot: next(),
+    ring1: next(),
+    ring2: next(),
+    trinket1: next(),
+    trinket2: next(),
+  }
+}
+
+fn stats(data: &Data, expansion_id: u8, item_ids: &[u32]) -> HashMap<String, u16> {
+  get_character_stats(data, 1, expansion_id, &gear(item_ids)).into_iter()
+    .map(|stat| (stat.stat_type, stat.stat_value))
+    .collect()
+}
+
+fn add(data: &Data, acc: &mut HashMap<String, u16>, stat_type: u8, stat_value: u16) {
+  let stat_type = data.localize(1, data.get_stat_type(stat_type).unwrap().localization_id);
+  *acc.entry(stat_type).or_insert(0) += stat_value;
+}
+
+#[test]
+fn set_bonus_requires_threshold() {
+  let data = Data::default().init(None).unwrap();
+
+  // The first set whose first bonus requires at least two pieces and grants stats
+  let (expansion_id, item_ids, bonus) = (1..=3u8).flat_map(|expansion_id| {
+    let mut itemset_ids: Vec<u16> = data.itemset_effects.get(expansion_id as usize - 1)
+      .map(|itemset_effects| itemset_effects.keys().cloned().collect())
+      .unwrap_or_default();
+    itemset_ids.sort();
+    itemset_ids.into_iter().map(move |itemset_id| (expansion_id, itemset_id))
+  }).find_map(|(expansion_id, itemset_id)| {
+    let itemset_effects = data.get_itemset_effects(expansion_id, itemset_id)?;
+    let threshold = itemset_effects.iter().map(|itemset_effect| itemset_effect.threshold).min()? as usize;
+    let bonus: Vec<Stat> = itemset_effects.iter()
+      .filter(|itemset_effect| itemset_effect.threshold as usize == threshold)
+      .flat_map(|itemset_effect| data.parse_stats(expansion_id, itemset_effect.spell_id))
+      .collect();
+    let mut item_ids = data.get_itemset_item_ids(expansion_id, itemset_id)?;
+    if threshold < 2 || item_ids.len() < threshold || bonus.is_empty() {
+      return None;
+    }
+    item_ids.sort();
+    item_ids.truncate(threshold);
+    Some((expansion_id, item_ids, bonus))
+  }).unwrap();
+
+  let (below_threshold_ids, last_piece_id) = item_ids.split_at(item_ids.len() - 1);
+  let below_threshold = stats(&data, expansion_id, below_threshold_ids);
+  let last_piece = stats(&data, expansion_id, last_piece_id);
+
+  // With N-1 pieces only the pieces themselves count, the N-th piece adds the bonus
+  let mut expected = below_threshold.clone();
+  last_piece.iter().for_each(|(stat_type, stat_value)| *expected.entry(stat_type.clone()).or_insert(0) += stat_value);
+  bonus.iter().for_each(|stat| add(&data, &mut expected, stat.stat_type, stat.stat_value));
+  assert_eq!(stats(&data, expansion_id, &item_ids), expected);
+}
\ No newline at end of file
@@ -1,12 +1,11 @@
-use crate::modules::armory::Armory;
+use crate::modules::armory::tests::in_memory_armory;
 use crate::modules::armory::dto::{CharacterDto, CharacterGearDto, CharacterHistoryDto, CharacterInfoDto, CharacterItemDto};
 use crate::modules::armory::tools::{SetCharacter, CharacterViewer};
-use mysql_connection::tools::Execute;
 use crate::modules::data::Data;
 
 #[test]
 fn get_character_viewer() {
-  let armory = Armory::default();
+  let armory = in_memory_armory();
   let character_info_dto = CharacterInfoDto {
     gear: CharacterGearDto {
       head: None,
@@ -60,7 +59,7 @@ fn get_character_viewer() {
   assert!(set_character_res.is_ok());
   let set_character = set_character_res.unwrap();
 
-  let data = Data::default().init(None);
+  let data = Data::default().init(None).unwrap();
   let character_viewer_res = armory.get_character_viewer(&data, 1, set_character.id);
   assert!(character_viewer_res.is_ok());
   let character_viewer = character_viewer_res.unwrap();
@@ -81,10 +80,4 @@ fn get_character_viewer() {
   assert_eq!(character_viewer.gender, character_info_dto.gender);
   assert_eq!(character_viewer.history_id, set_character.last_update.as_ref().unwrap().id);
   assert_eq!(character_viewer.history.len(), 1);
-
-  let character_history = set_character.last_update.unwrap();
-  armory.db_main.execute_wparams("DELETE FROM armory_item WHERE id=:id", params!("id" => character_history.character_info.gear.shirt.unwrap().id));
-  armory.db_main.execute_wparams("DELETE FROM armory_character_info WHERE id=:id", params!("id" => character_history.character_info.id));
-  armory.db_main.execute_wparams("DELETE FROM armory_character_history WHERE id=:id", params!("id" => character_history.id));
-  armory.db_main.execute_wparams("DELETE FROM armory_character WHERE id=:id", params!("id" => character_history.character_id));
 }
\ No newline at end of file
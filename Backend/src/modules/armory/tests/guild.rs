@@ -1,11 +1,11 @@
-use crate::modules::armory::dto::GuildDto;
-use crate::modules::armory::Armory;
-use crate::modules::armory::tools::{CreateGuild, GetGuild, UpdateGuild};
-use mysql_connection::tools::Execute;
+use crate::modules::armory::dto::{CharacterDto, CharacterGearDto, CharacterGuildDto, CharacterHistoryDto, CharacterInfoDto, GuildDto};
+use crate::modules::armory::tests::in_memory_armory;
+use crate::modules::armory::tools::{CreateGuild, DeleteCharacter, DeleteGuild, GetCharacter, GetGuild, SetCharacter, UpdateGuild};
+use crate::modules::armory::repository::{CharacterHistoryRepository, StartArmoryTransaction};
 
 #[test]
 fn guild() {
-  let armory = Armory::default();
+  let armory = in_memory_armory();
   let guild_dto = GuildDto {
     server_uid: 23423214,
     name: "WeirdGuildName".to_owned()
@@ -35,6 +35,70 @@ fn guild() {
   assert_eq!(guild3.server_uid, guild_dto.server_uid);
   assert_ne!(guild3.name, guild_dto.name);
   assert_eq!(guild3.name, new_guild_name);
+}
 
-  armory.db_main.execute_wparams("DELETE FROM armory_guild WHERE id=:id", params!("id" => guild.id));
+#[test]
+fn delete_guild_removes_memberships() {
+  let armory = in_memory_armory();
+  let guild_dto = GuildDto {
+    server_uid: 23423216,
+    name: "DeletedGuild".to_owned()
+  };
+  let character_dto = CharacterDto {
+    server_uid: 1231223448,
+    character_history: Some(CharacterHistoryDto {
+      character_info: CharacterInfoDto {
+        gear: CharacterGearDto {
+          head: None,
+          neck: None,
+          shoulder: None,
+          back: None,
+          chest: None,
+          shirt: None,
+          tabard: None,
+          wrist: None,
+          main_hand: None,
+          off_hand: None,
+          ternary_hand: None,
+          glove: None,
+          belt: None,
+          leg: None,
+          boot: None,
+          ring1: None,
+          ring2: None,
+          trinket1: None,
+          trinket2: None,
+        },
+        hero_class_id: 1,
+        level: 60,
+        gender: false,
+        profession1: None,
+        profession2: None,
+        talent_specialization: None,
+        race_id: 1,
+      },
+      character_name: "Deletedmember".to_string(),
+      character_title: None,
+      profession_skill_points1: None,
+      profession_skill_points2: None,
+      facial: None,
+      character_guild: Some(CharacterGuildDto {
+        guild: guild_dto.clone(),
+        rank: "Secretkeeper".to_string()
+      }),
+    }),
+  };
+
+  let character = armory.set_character(1, character_dto).unwrap();
+  let character_history_id = character.last_update.as_ref().unwrap().id;
+  let guild_id = character.last_update.as_ref().unwrap().character_guild.as_ref().unwrap().guild_id;
+
+  assert!(armory.delete_guild_by_uid(1, guild_dto.server_uid).is_ok());
+  assert!(armory.get_guild(guild_id).is_none());
+  assert!(armory.backend.start_transaction().unwrap().load_character_history(character_history_id).unwrap().unwrap().character_guild.is_none());
+  assert!(armory.get_character(character.id).unwrap().last_update.unwrap().character_guild.is_none());
+  assert!(armory.character_search_index.read().unwrap().guild_members(guild_id).is_empty());
+  assert!(armory.delete_guild_by_uid(1, guild_dto.server_uid).is_err());
+
+  let _ = armory.delete_character(character.id);
 }
\ No newline at end of file
@@ -0,0 +1,100 @@
+use crate::modules::armory::tests::in_memory_armory;
+use crate::modules::armory::dto::{CharacterDto, CharacterGearDto, CharacterGuildDto, CharacterHistoryDto, CharacterInfoDto, GuildDto, GuildTimelineEventType, GuildTimelineFilter};
+use crate::modules::armory::tools::{SetCharacter, SetCharacterHistory, GuildViewer};
+
+#[test]
+fn get_guild_timeline() {
+  let armory = in_memory_armory();
+  let character_info_dto = CharacterInfoDto {
+    gear: CharacterGearDto {
+      head: None,
+      neck: None,
+      shoulder: None,
+      back: None,
+      chest: None,
+      shirt: None,
+      tabard: None,
+      wrist: None,
+      main_hand: None,
+      off_hand: None,
+      ternary_hand: None,
+      glove: None,
+      belt: None,
+      leg: None,
+      boot: None,
+      ring1: None,
+      ring2: None,
+      trinket1: None,
+      trinket2: None,
+    },
+    hero_class_id: 1,
+    level: 60,
+    gender: false,
+    profession1: None,
+    profession2: None,
+    talent_specialization: None,
+    race_id: 1,
+  };
+  let guild_dto = GuildDto {
+    server_uid: 23423215,
+    name: "TimelineGuild".to_owned()
+  };
+  let mut character_history_dto = CharacterHistoryDto {
+    character_info: character_info_dto,
+    character_name: "Timelinemember".to_string(),
+    character_title: None,
+    profession_skill_points1: None,
+    profession_skill_points2: None,
+    facial: None,
+    character_guild: Some(CharacterGuildDto {
+      guild: guild_dto,
+      rank: "Initiate".to_string()
+    }),
+  };
+  let character_dto = CharacterDto {
+    server_uid: 1231223447,
+    character_history: Some(character_history_dto.to_owned()),
+  };
+
+  let set_character_res = armory.set_character(1, character_dto);
+  assert!(set_character_res.is_ok());
+  let set_character = set_character_res.unwrap();
+  let joined_history = set_character.last_update.unwrap();
+  let guild_id = joined_history.character_guild.as_ref().unwrap().guild_id;
+
+  character_history_dto.character_guild.as_mut().unwrap().rank = "Raider".to_string();
+  assert!(armory.set_character_history(1, character_history_dto.clone(), set_character.server_uid).is_ok());
+  character_history_dto.character_guild = None;
+  assert!(armory.set_character_history(1, character_history_dto, set_character.server_uid).is_ok());
+
+  let timeline_res = armory.get_guild_timeline(guild_id, GuildTimelineFilter { page: 0, page_size: None, cursor: None });
+  assert!(timeline_res.is_ok());
+  let timeline = timeline_res.unwrap();
+  assert_eq!(timeline.guild_id, guild_id);
+  assert_eq!(timeline.events.num_items, 3);
+  assert_eq!(timeline.events.result[0].event_type, GuildTimelineEventType::Leave);
+  assert_eq!(timeline.events.result[1].event_type, GuildTimelineEventType::RankChange);
+  assert_eq!(timeline.events.result[1].previous_rank, Some("Initiate".to_string()));
+  assert_eq!(timeline.events.result[1].rank, Some("Raider".to_string()));
+  assert_eq!(timeline.events.result[2].event_type, GuildTimelineEventType::Join);
+  assert_eq!(timeline.roster_size.last().unwrap().size, 0);
+
+  assert!(timeline.events.next_cursor.is_none());
+
+  let paged_timeline = armory.get_guild_timeline(guild_id, GuildTimelineFilter { page: 1, page_size: Some(2), cursor: None }).unwrap();
+  assert_eq!(paged_timeline.events.num_items, 3);
+  assert_eq!(paged_timeline.events.result.len(), 1);
+  assert_eq!(paged_timeline.events.result[0].event_type, GuildTimelineEventType::Join);
+
+  let first_page = armory.get_guild_timeline(guild_id, GuildTimelineFilter { page: 0, page_size: Some(2), cursor: None }).unwrap();
+  assert_eq!(first_page.events.result.len(), 2);
+  assert_eq!(first_page.events.result[0].event_type, GuildTimelineEventType::Leave);
+  assert!(first_page.events.next_cursor.is_some());
+  let second_page = armory.get_guild_timeline(guild_id, GuildTimelineFilter { page: 0, page_size: Some(2), cursor: first_page.events.next_cursor.clone() }).unwrap();
+  assert_eq!(second_page.events.result.len(), 1);
+  assert_eq!(second_page.events.result[0].event_type, GuildTimelineEventType::Join);
+  assert!(second_page.events.next_cursor.is_none());
+  assert!(armory.get_guild_timeline(guild_id, GuildTimelineFilter { page: 0, page_size: None, cursor: Some("garbage".to_owned()) }).is_err());
+
+  assert!(armory.get_guild_timeline(0, GuildTimelineFilter { page: 0, page_size: None, cursor: None }).is_err());
+}
\ No newline at end of file
@@ -1,3 +1,7 @@
+use crate::modules::armory::Armory;
+use crate::modules::armory::repository::InMemoryArmoryBackend;
+
+mod cache;
 mod character_item;
 mod character_gear;
 mod character_info;
@@ -6,4 +10,13 @@ mod character;
 mod guild;
 mod character_facial;
 mod character_search;
-mod character_viewer;
\ No newline at end of file
+mod character_search_index;
+mod character_viewer;
+mod character_diff;
+mod character_stats;
+mod guild_viewer;
+mod repository;
+
+fn in_memory_armory() -> Armory {
+  Armory::with_backend(Box::new(InMemoryArmoryBackend::default()))
+}
\ No newline at end of file
@@ -0,0 +1,183 @@
+use crate::modules::armory::domain_value::{CharacterGuild, GEAR_SLOTS};
+use crate::modules::armory::dto::{CharacterGearDto, CharacterHistoryDto, CharacterInfoDto, CharacterItemDto};
+use crate::modules::armory::repository::{CharacterGearRepository, CharacterHistoryRepository, CharacterInfoRepository, CharacterItemRepository, CharacterRepository,
+  CharacterSummaryRepository, GuildRepository, InMemoryArmoryBackend, StartArmoryTransaction};
+
+fn character_history_dto() -> CharacterHistoryDto {
+  CharacterHistoryDto {
+    character_info: CharacterInfoDto {
+      gear: CharacterGearDto {
+        head: None,
+        neck: None,
+        shoulder: None,
+        back: None,
+        chest: None,
+        shirt: None,
+        tabard: None,
+        wrist: None,
+        main_hand: None,
+        off_hand: None,
+        ternary_hand: None,
+        glove: None,
+        belt: None,
+        leg: None,
+        boot: None,
+        ring1: None,
+        ring2: None,
+        trinket1: None,
+        trinket2: None,
+      },
+      hero_class_id: 1,
+      level: 60,
+      gender: true,
+      profession1: None,
+      profession2: None,
+      talent_specialization: None,
+      race_id: 1,
+    },
+    character_name: "InMemoryCharacter".to_owned(),
+    character_guild: None,
+    character_title: None,
+    profession_skill_points1: None,
+    profession_skill_points2: None,
+    facial: None,
+  }
+}
+
+// Returns the ids of the character and its history
+fn insert_character(backend: &InMemoryArmoryBackend, server_uid: u64, character_guild: Option<CharacterGuild>, timestamp: u64) -> (u32, u32) {
+  let character_history_dto = character_history_dto();
+  let mut transaction = backend.start_transaction().unwrap();
+  let character_id = transaction.insert_character(1, server_uid).unwrap();
+  let gear_id = transaction.insert_character_gear(&vec![None; GEAR_SLOTS.len()]).unwrap();
+  let character_info_id = transaction.insert_character_info(gear_id, &character_history_dto.character_info).unwrap();
+  let character_history_id = transaction.insert_character_history(character_id, character_info_id, None, character_guild.as_ref(), &character_history_dto, timestamp).unwrap();
+  transaction.commit().unwrap();
+  (character_id, character_history_id)
+}
+
+#[test]
+fn guilds() {
+  let backend = InMemoryArmoryBackend::default();
+  let guild_id = backend.insert_guild(1, 42, "InMemoryGuild").unwrap();
+  assert!(backend.insert_guild(1, 42, "InMemoryGuild").is_none());
+
+  assert!(backend.update_guild_name(1, 42, "RenamedGuild"));
+  assert!(!backend.update_guild_name(1, 43, "RenamedGuild"));
+  assert_eq!(backend.load_guilds().unwrap()[0].name, "RenamedGuild");
+
+  let (character_id, character_history_id) = insert_character(&backend, 100, Some(CharacterGuild { guild_id, rank: "Member".to_owned() }), 1);
+  insert_character(&backend, 101, None, 2);
+  assert_eq!(backend.delete_guild(guild_id), Some(vec![(character_history_id, character_id)]));
+  assert!(backend.delete_guild(guild_id).is_none());
+  assert!(backend.load_guilds().unwrap().is_empty());
+  assert!(backend.start_transaction().unwrap().load_character_history(character_history_id).unwrap().unwrap().character_guild.is_none());
+}
+
+#[test]
+fn character_summaries() {
+  let backend = InMemoryArmoryBackend::default();
+  let (character_id, character_history_id) = insert_character(&backend, 100, None, 1);
+  {
+    let mut transaction = backend.start_transaction().unwrap();
+    transaction.insert_character(1, 101).unwrap();
+    let character_history = transaction.load_character_history(character_history_id).unwrap().unwrap();
+    let mut character_history_dto = character_history_dto();
+    character_history_dto.character_name = "RenamedCharacter".to_owned();
+    transaction.insert_character_history(character_id, character_history.character_info.id, None, None, &character_history_dto, 3).unwrap();
+    transaction.commit().unwrap();
+  }
+
+  let summaries = backend.load_character_summaries().unwrap();
+  assert_eq!(summaries.len(), 2);
+  assert_eq!(summaries[0].3.as_ref().unwrap().timestamp, 3);
+  assert_eq!(summaries[0].3.as_ref().unwrap().name, "RenamedCharacter");
+  // Characters without any history have no summary
+  assert!(summaries[1].3.is_none());
+}
+
+#[test]
+fn character_values() {
+  let backend = InMemoryArmoryBackend::default();
+  let character_item_dto = CharacterItemDto {
+    item_id: 19019,
+    random_property_id: None,
+    enchant_id: None,
+    gem_ids: vec![],
+  };
+  let (character_id, character_history_id) = insert_character(&backend, 100, None, 1);
+
+  let mut transaction = backend.start_transaction().unwrap();
+  // Values are shared, hence inserting them again returns the existing row
+  let character_item_id = transaction.insert_character_item(&character_item_dto).unwrap();
+  assert_eq!(transaction.insert_character_item(&character_item_dto).unwrap(), character_item_id);
+  assert_eq!(transaction.load_character_item(character_item_id).unwrap().unwrap().gem_ids.len(), 4);
+
+  let mut character_item_ids = vec![None; GEAR_SLOTS.len()];
+  character_item_ids[0] = Some(character_item_id);
+  let gear_id = transaction.insert_character_gear(&character_item_ids).unwrap();
+  assert_eq!(transaction.find_character_gear(&character_item_ids).unwrap(), Some(gear_id));
+  assert_eq!(transaction.load_character_gear(gear_id).unwrap().unwrap().head.unwrap().id, character_item_id);
+  assert!(transaction.insert_character_gear(&[None]).is_err());
+  assert!(transaction.insert_character_gear(&vec![Some(character_item_id + 1); GEAR_SLOTS.len()]).is_err());
+  assert!(transaction.insert_character_info(gear_id + 1, &character_history_dto().character_info).is_err());
+
+  // Deleting the last history that references a value deletes the value
+  let character_info_id = transaction.insert_character_info(gear_id, &character_history_dto().character_info).unwrap();
+  let new_character_history_id = transaction.insert_character_history(character_id, character_info_id, None, None, &character_history_dto(), 2).unwrap();
+  transaction.delete_character_histories(&[new_character_history_id]).unwrap();
+  assert!(transaction.load_character_info(character_info_id).unwrap().is_none());
+  assert!(transaction.load_character_gear(gear_id).unwrap().is_none());
+  assert!(transaction.load_character_item(character_item_id).unwrap().is_none());
+  assert!(transaction.load_character_history(character_history_id).unwrap().is_some());
+
+  transaction.delete_character(character_id).unwrap();
+  assert!(transaction.load_character(character_id).unwrap().is_none());
+  assert!(transaction.load_character_history(character_history_id).unwrap().is_none());
+}
+
+#[test]
+fn savepoints() {
+  let backend = InMemoryArmoryBackend::default();
+  let mut transaction = backend.start_transaction().unwrap();
+  let character_id = transaction.insert_character(1, 100).unwrap();
+
+  transaction.savepoint("character").unwrap();
+  let other_character_id = transaction.insert_character(1, 101).unwrap();
+  transaction.rollback_to_savepoint("character").unwrap();
+  assert!(transaction.load_character(other_character_id).unwrap().is_none());
+
+  // The savepoint is kept after rolling back to it
+  transaction.insert_character(1, 102).unwrap();
+  transaction.rollback_to_savepoint("character").unwrap();
+  transaction.release_savepoint("character").unwrap();
+  assert!(transaction.rollback_to_savepoint("character").is_err());
+
+  transaction.commit().unwrap();
+  let mut transaction = backend.start_transaction().unwrap();
+  assert!(transaction.load_character(character_id).unwrap().is_some());
+  assert_eq!(backend.load_character_summaries().unwrap().len(), 1);
+}
+
+#[test]
+fn transactions() {
+  let backend = InMemoryArmoryBackend::default();
+  let mut transaction = backend.start_transaction().unwrap();
+  transaction.insert_character(1, 100).unwrap();
+  // Uncommitted rows are only visible within the transaction
+  assert!(backend.load_character_summaries().unwrap().is_empty());
+  assert!(transaction.insert_character(1, 100).is_err());
+
+  let mut concurrent_transaction = backend.start_transaction().unwrap();
+  concurrent_transaction.insert_character(1, 101).unwrap();
+  transaction.commit().unwrap();
+  assert!(concurrent_transaction.commit().is_err());
+
+  let summaries = backend.load_character_summaries().unwrap();
+  assert_eq!(summaries.len(), 1);
+  assert_eq!(summaries[0].2, 100);
+
+  // A transaction that is dropped without committing changes nothing
+  backend.start_transaction().unwrap().insert_character(1, 102).unwrap();
+  assert_eq!(backend.load_character_summaries().unwrap().len(), 1);
+}
\ No newline at end of file
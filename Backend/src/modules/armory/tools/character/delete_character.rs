@@ -1,7 +1,6 @@
-use mysql_connection::tools::Execute;
-
 use crate::modules::armory::Armory;
 use crate::modules::armory::dto::ArmoryFailure;
+use crate::modules::armory::repository::{CharacterRepository, StartArmoryTransaction};
 use crate::modules::armory::tools::GetCharacter;
 
 pub trait DeleteCharacter {
@@ -10,14 +9,19 @@ pub trait DeleteCharacter {
 }
 
 impl DeleteCharacter for Armory {
+  // Removes everything that was ever uploaded of this character
   fn delete_character(&self, id: u32) -> Result<(), ArmoryFailure> {
-    let mut characters = self.characters.write().unwrap();
-    if self.db_main.execute_wparams("DELETE FROM armory_character WHERE id=:id", params!(
-      "id" => id
-    )) {
-      return characters.remove(&id).ok_or(ArmoryFailure::InvalidInput).and_then(|_| Ok(()));
-    }
-    Err(ArmoryFailure::Database("delete_character".to_owned()))
+    let _character_writes = self.character_writes.lock().unwrap();
+    let character = self.get_character(id).ok_or(ArmoryFailure::InvalidInput)?;
+    let mut transaction = self.backend.start_transaction().map_err(|_| ArmoryFailure::Database("delete_character".to_owned()))?;
+    transaction.delete_character(id)
+      .and_then(|()| transaction.commit())
+      .map_err(|_| ArmoryFailure::Database("delete_character".to_owned()))?;
+
+    character.history_moments.iter().for_each(|history_moment| self.character_histories.invalidate(history_moment.id));
+    self.characters.invalidate(id);
+    self.character_search_index.write().unwrap().remove_character(id);
+    Ok(())
   }
 
   fn delete_character_by_uid(&self, server_id: u32, uid: u64) -> Result<(), ArmoryFailure> {
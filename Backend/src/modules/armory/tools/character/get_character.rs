@@ -1,5 +1,7 @@
 use crate::modules::armory::Armory;
 use crate::modules::armory::material::Character;
+use crate::modules::armory::repository::{CharacterRepository, StartArmoryTransaction};
+use crate::modules::armory::tools::GetCharacterHistory;
 
 pub trait GetCharacter {
     fn get_character_id_by_uid(&self, server_id: u32, uid: u64) -> Option<u32>;
@@ -11,9 +13,7 @@ pub trait GetCharacter {
 
 impl GetCharacter for Armory {
     fn get_character_id_by_uid(&self, server_id: u32, uid: u64) -> Option<u32> {
-        let characters = self.characters.read().unwrap();
-        characters.iter().find(|(_, character)| character.server_id == server_id && character.server_uid == uid)
-            .and_then(|(id, _)| Some(id.clone()))
+        self.character_search_index.read().unwrap().get_character_id_by_uid(server_id, uid)
     }
 
     fn get_character_by_uid(&self, server_id: u32, uid: u64) -> Option<Character> {
@@ -22,23 +22,35 @@ impl GetCharacter for Armory {
     }
 
     fn get_character(&self, character_id: u32) -> Option<Character> {
-        let characters = self.characters.read().unwrap();
-        characters.get(&character_id)
-            .and_then(|character| Some(character.clone()))
+        if !self.character_search_index.read().unwrap().contains_character(character_id) {
+            return None;
+        }
+        self.characters.get_or_load(character_id, || load_character(self, character_id))
     }
 
     fn get_characters_by_name(&self, character_name: String) -> Vec<Character> {
-        let characters = self.characters.read().unwrap();
-        let name = character_name.to_lowercase();
-        characters.iter().filter(|(_, character)| character.last_update.is_some()
-            && character.last_update.as_ref().unwrap().character_name.to_lowercase().contains(&name))
-            .map(|(_, character)| character.clone()).collect()
+        let character_ids = self.character_search_index.read().unwrap().find(None, None, Some(&character_name), None);
+        character_ids.into_iter().filter_map(|character_id| self.get_character(character_id)).collect()
     }
 
     fn get_character_by_name(&self, server_id: u32, character_name: String) -> Option<Character> {
-        self.get_characters_by_name(character_name.clone())
-            .iter().find(|character| character.server_id == server_id
-            && character.last_update.as_ref().unwrap().character_name.to_lowercase() == character_name.to_lowercase())
-            .map(|character| character.to_owned())
+        let name = character_name.to_lowercase();
+        let character_id = {
+            let character_search_index = self.character_search_index.read().unwrap();
+            character_search_index.find(Some(server_id), None, Some(&name), None).into_iter()
+                .find(|character_id| character_search_index.summary(*character_id).unwrap().name.to_lowercase() == name)
+        };
+        character_id.and_then(|character_id| self.get_character(character_id))
+    }
+}
+
+fn load_character(armory: &Armory, character_id: u32) -> Option<Character> {
+    let mut character = armory.backend.start_transaction()
+        .and_then(|mut transaction| transaction.load_character(character_id))
+        .ok().flatten()?;
+
+    if let Some(history_moment) = character.history_moments.last() {
+        character.last_update = Some(armory.get_character_history(history_moment.id).ok()?);
     }
+    Some(character)
 }
\ No newline at end of file
@@ -1,11 +1,17 @@
+use std::collections::HashMap;
+
 use crate::modules::armory::Armory;
-use crate::modules::armory::dto::{ArmoryFailure, CharacterDto};
-use crate::modules::armory::material::Character;
-use crate::modules::armory::tools::{CreateCharacter, GetCharacter, SetCharacterHistory};
+use crate::modules::armory::dto::{ArmoryFailure, CharacterDto, GuildDto, SetCharacterResult, SetCharacterStatus};
+use crate::modules::armory::material::{Character, CharacterHistory, Guild};
+use crate::modules::armory::repository::{ArmoryTransaction, CharacterHistoryRepository, CharacterRepository, StartArmoryTransaction};
+use crate::modules::armory::tools::{CreateCharacter, CreateCharacterHistory, GetCharacter, GetGuild, SetCharacterHistory, is_unchanged};
 use crate::dto::CheckPlausability;
 
+const MAX_CHARACTERS_PER_BATCH: usize = 100;
+
 pub trait SetCharacter {
   fn set_character(&self, server_id: u32, update_character: CharacterDto) -> Result<Character, ArmoryFailure>;
+  fn set_characters(&self, server_id: u32, update_characters: Vec<CharacterDto>) -> Result<Vec<SetCharacterResult>, ArmoryFailure>;
 }
 
 impl SetCharacter for Armory {
@@ -32,4 +38,184 @@ impl SetCharacter for Armory {
 
     self.get_character(character_id).ok_or(ArmoryFailure::Database("get_character".to_owned()))
   }
+
+  // All characters of a batch are written in one transaction
+  fn set_characters(&self, server_id: u32, update_characters: Vec<CharacterDto>) -> Result<Vec<SetCharacterResult>, ArmoryFailure> {
+    if update_characters.len() > MAX_CHARACTERS_PER_BATCH {
+      return Err(ArmoryFailure::InvalidInput);
+    }
+
+    // A character that is uploaded several times in a batch is set to its last occurrence,
+    // the earlier occurrences share its result
+    let server_uids: Vec<u64> = update_characters.iter().map(|update_character| update_character.server_uid).collect();
+    let last_occurrence: HashMap<u64, usize> = server_uids.iter().enumerate().map(|(index, server_uid)| (*server_uid, index)).collect();
+
+    // Everything that is not confirmed in the end has not been persisted
+    let mut statuses: Vec<Result<SetCharacterStatus, ArmoryFailure>> = server_uids.iter().map(|_| Err(ArmoryFailure::Database("set_characters".to_owned()))).collect();
+    let into_results = |statuses: Vec<Result<SetCharacterStatus, ArmoryFailure>>| -> Result<Vec<SetCharacterResult>, ArmoryFailure> {
+      let results: Vec<SetCharacterResult> = server_uids.iter().zip(statuses.into_iter())
+        .map(|(server_uid, status)| SetCharacterResult::new(*server_uid, status))
+        .collect();
+      Ok(server_uids.iter().map(|server_uid| results[last_occurrence[server_uid]].clone()).collect())
+    };
+
+    // Validation
+    let mut pending = Vec::new();
+    for (index, update_character) in update_characters.into_iter().enumerate() {
+      if last_occurrence[&update_character.server_uid] != index {
+        continue;
+      }
+      if !update_character.is_plausible() {
+        statuses[index] = Err(ArmoryFailure::ImplausibleInput);
+        continue;
+      }
+      pending.push((index, update_character));
+    }
+
+    if pending.is_empty() {
+      return into_results(statuses);
+    }
+
+    let now = time_util::now();
+    let mut transaction = match self.backend.start_transaction() {
+      Ok(transaction) => transaction,
+      Err(_) => return into_results(statuses)
+    };
+
+    // A failing character only rolls back its own writes, including the guild it created.
+    // Guilds created earlier in this batch are shared by the following characters.
+    let mut staged = Vec::new();
+    let mut staged_guilds: HashMap<u64, Guild> = HashMap::new();
+    for (index, update_character) in pending.iter() {
+      let savepoint = format!("set_character_{}", index);
+      if transaction.savepoint(&savepoint).is_err() {
+        return into_results(statuses);
+      }
+      match stage_character(self, transaction.as_mut(), server_id, update_character, &staged_guilds, now) {
+        Ok(writes) => {
+          if transaction.release_savepoint(&savepoint).is_err() {
+            return into_results(statuses);
+          }
+          for write in writes.iter() {
+            if let StagedWrite::NewGuild(guild) = write {
+              staged_guilds.insert(guild.server_uid, guild.clone());
+            }
+          }
+          staged.push((*index, writes));
+        },
+        Err(failure) => {
+          if transaction.rollback_to_savepoint(&savepoint).is_err() {
+            return into_results(statuses);
+          }
+          statuses[*index] = Err(failure);
+        }
+      }
+    }
+
+    // Unchanged characters only refresh the timestamp of their last update, which is done at once
+    let touched_history_ids: Vec<u32> = staged.iter().flat_map(|(_, writes)| writes.iter()).filter_map(|write| match write {
+      StagedWrite::TouchedHistory(character_history) => Some(character_history.id),
+      _ => None
+    }).collect();
+    if transaction.touch_character_histories(&touched_history_ids, now).is_err() {
+      return into_results(statuses);
+    }
+
+    // The cache is only touched once everything is persisted.
+    // Concurrent writes of the same character or guild are rejected by the unique keys of the database.
+    let _character_writes = self.character_writes.lock().unwrap();
+    if transaction.commit().is_err() {
+      return into_results(statuses);
+    }
+    for (index, writes) in staged {
+      let mut status = SetCharacterStatus::Unchanged;
+      for write in writes {
+        match write {
+          StagedWrite::NewGuild(guild) => {
+            let mut guilds = self.guilds.write().unwrap();
+            self.character_search_index.write().unwrap().index_guild(&guild);
+            guilds.insert(guild.id, guild);
+          },
+          StagedWrite::NewCharacter(character) => {
+            self.character_search_index.write().unwrap().add_character(character.id, character.server_id, character.server_uid);
+            self.characters.insert(character.id, character);
+            status = SetCharacterStatus::Created;
+          },
+          StagedWrite::CreatedHistory(character_history) => {
+            self.cache_character_history(&character_history);
+            status = SetCharacterStatus::Created;
+          },
+          StagedWrite::TouchedHistory(mut character_history) => self.touch_character_history(&mut character_history, now)
+        }
+      }
+      statuses[index] = Ok(status);
+    }
+    into_results(statuses)
+  }
+}
+
+enum StagedWrite {
+  NewGuild(Guild),
+  NewCharacter(Character),
+  CreatedHistory(CharacterHistory),
+  TouchedHistory(CharacterHistory),
+}
+
+fn stage_guild(armory: &Armory, transaction: &mut dyn ArmoryTransaction, server_id: u32, guild: &GuildDto, staged_guilds: &HashMap<u64, Guild>) -> Result<(Guild, bool), ArmoryFailure> {
+  if guild.server_uid == 0 {
+    return Err(ArmoryFailure::InvalidInput);
+  }
+  if let Some(existing_guild) = armory.get_guild_by_uid(server_id, guild.server_uid).or_else(|| staged_guilds.get(&guild.server_uid).cloned()) {
+    return Ok((existing_guild, false));
+  }
+
+  let guild_id = transaction.insert_guild(server_id, guild.server_uid, &guild.name)
+    .map_err(|_| ArmoryFailure::Database("set_characters".to_owned()))?;
+  Ok((Guild {
+    id: guild_id,
+    server_uid: guild.server_uid,
+    name: guild.name.to_owned(),
+    server_id,
+  }, true))
+}
+
+// The cache is only read here, it is updated after the commit
+fn stage_character(armory: &Armory, transaction: &mut dyn ArmoryTransaction, server_id: u32, update_character: &CharacterDto, staged_guilds: &HashMap<u64, Guild>, now: u64) -> Result<Vec<StagedWrite>, ArmoryFailure> {
+  let mut writes = Vec::new();
+  let mut guild_id = None;
+  if let Some(character_guild) = update_character.character_history.as_ref().and_then(|character_history| character_history.character_guild.as_ref()) {
+    let (guild, is_new) = stage_guild(armory, transaction, server_id, &character_guild.guild, staged_guilds)?;
+    guild_id = Some(guild.id);
+    if is_new {
+      writes.push(StagedWrite::NewGuild(guild));
+    }
+  }
+
+  let mut last_update = None;
+  let character_id = match armory.get_character_id_by_uid(server_id, update_character.server_uid) {
+    Some(character_id) => {
+      last_update = armory.get_character(character_id).ok_or_else(|| ArmoryFailure::Database("set_characters".to_owned()))?.last_update;
+      character_id
+    },
+    None => {
+      let character_id = transaction.insert_character(server_id, update_character.server_uid)
+        .map_err(|_| ArmoryFailure::Database("set_characters".to_owned()))?;
+      writes.push(StagedWrite::NewCharacter(Character {
+        id: character_id,
+        server_id,
+        server_uid: update_character.server_uid,
+        last_update: None,
+        history_moments: Vec::new()
+      }));
+      character_id
+    }
+  };
+
+  if let Some(update_character_history) = update_character.character_history.as_ref() {
+    match last_update {
+      Some(last_update) if is_unchanged(&last_update, update_character_history, guild_id) => writes.push(StagedWrite::TouchedHistory(last_update)),
+      _ => writes.push(StagedWrite::CreatedHistory(armory.create_character_history_in_transaction(transaction, character_id, guild_id, update_character_history.clone(), now)?))
+    }
+  }
+  Ok(writes)
 }
\ No newline at end of file
@@ -0,0 +1,194 @@
+use std::collections::BTreeMap;
+
+use crate::modules::armory::Armory;
+use crate::modules::armory::domain_value::{CharacterGear, CharacterItem};
+use crate::modules::armory::dto::{ArmoryFailure, CharacterDiffDto, CharacterDiffItemDto, CharacterDiffProfessionDto, CharacterDiffSlotDto, CharacterDiffStatDto, CharacterViewerGuildDto, ValueChangeDto};
+use crate::modules::armory::material::CharacterHistory;
+use crate::modules::armory::tools::{get_character_stats, GetCharacter, GetCharacterHistory, GetGuild};
+use crate::modules::data::Data;
+use crate::modules::data::tools::{RetrieveIcon, RetrieveItem, RetrieveLocalization, RetrieveProfession, RetrieveServer, RetrieveTitle};
+
+pub trait CharacterDiff {
+  fn get_character_diff(&self, data: &Data, language_id: u8, character_id: u32, from_history_id: u32, to_history_id: u32) -> Result<CharacterDiffDto, ArmoryFailure>;
+}
+
+impl CharacterDiff for Armory {
+  fn get_character_diff(&self, data: &Data, language_id: u8, character_id: u32, from_history_id: u32, to_history_id: u32) -> Result<CharacterDiffDto, ArmoryFailure> {
+    let character = self.get_character(character_id).ok_or(ArmoryFailure::InvalidInput)?;
+    if character.last_update.is_none()
+      || !character.history_moments.iter().any(|history_moment| history_moment.id == from_history_id)
+      || !character.history_moments.iter().any(|history_moment| history_moment.id == to_history_id) {
+      return Err(ArmoryFailure::InvalidInput);
+    }
+
+    let last_update = character.last_update.unwrap();
+    let get_history = |character_history_id: u32| {
+      if last_update.id == character_history_id {
+        return Ok(last_update.clone());
+      }
+      self.get_character_history(character_history_id)
+    };
+    let from = get_history(from_history_id)?;
+    let to = get_history(to_history_id)?;
+    let expansion_id = data.get_server(character.server_id).unwrap().expansion_id;
+
+    Ok(CharacterDiffDto {
+      character_id,
+      from_history_id,
+      to_history_id,
+      from_timestamp: from.timestamp,
+      to_timestamp: to.timestamp,
+      name: value_change(from.character_name.clone(), to.character_name.clone()),
+      level: value_change(from.character_info.level, to.character_info.level),
+      title: value_change(get_title(data, language_id, &from), get_title(data, language_id, &to)),
+      guild: get_guild_change(self, &from, &to),
+      talent_specialization: value_change(from.character_info.talent_specialization.clone(), to.character_info.talent_specialization.clone()),
+      professions: get_profession_changes(data, language_id, &from, &to),
+      gear: get_gear_changes(data, expansion_id, &from.character_info.gear, &to.character_info.gear),
+      stats: get_stat_changes(data, language_id, expansion_id, &from.character_info.gear, &to.character_info.gear),
+    })
+  }
+}
+
+fn get_guild_change(armory: &Armory, from: &CharacterHistory, to: &CharacterHistory) -> Option<ValueChangeDto<Option<CharacterViewerGuildDto>>> {
+  if from.character_guild == to.character_guild {
+    return None;
+  }
+  let to_guild_dto = |character_history: &CharacterHistory| character_history.character_guild.as_ref().map(|character_guild| CharacterViewerGuildDto {
+    guild_id: character_guild.guild_id,
+    name: armory.get_guild(character_guild.guild_id).map(|guild| guild.name).unwrap_or_default(),
+    rank: character_guild.rank.clone(),
+  });
+  Some(ValueChangeDto {
+    from: to_guild_dto(from),
+    to: to_guild_dto(to),
+  })
+}
+
+fn value_change<T: PartialEq>(from: T, to: T) -> Option<ValueChangeDto<T>> {
+  if from == to {
+    return None;
+  }
+  Some(ValueChangeDto { from, to })
+}
+
+fn get_title(data: &Data, language_id: u8, character_history: &CharacterHistory) -> Option<String> {
+  character_history.character_title
+    .and_then(|title_id| data.get_title(title_id))
+    .map(|title| data.localize(language_id, title.localization_id).replace("%s", &character_history.character_name))
+}
+
+fn get_profession_changes(data: &Data, language_id: u8, from: &CharacterHistory, to: &CharacterHistory) -> Vec<CharacterDiffProfessionDto> {
+  let professions = |character_history: &CharacterHistory| {
+    let mut result = BTreeMap::new();
+    if let Some(profession_id) = character_history.character_info.profession1 {
+      result.insert(profession_id, character_history.profession_skill_points1.unwrap_or(0));
+    }
+    if let Some(profession_id) = character_history.character_info.profession2 {
+      result.insert(profession_id, character_history.profession_skill_points2.unwrap_or(0));
+    }
+    result
+  };
+  let from_professions = professions(from);
+  let to_professions = professions(to);
+
+  let mut profession_ids: Vec<u16> = from_professions.keys().chain(to_professions.keys()).cloned().collect();
+  profession_ids.sort();
+  profession_ids.dedup();
+  profession_ids.into_iter()
+    .filter(|profession_id| from_professions.get(profession_id) != to_professions.get(profession_id))
+    .filter_map(|profession_id| data.get_profession(profession_id).map(|profession| CharacterDiffProfessionDto {
+      profession_id,
+      icon: data.get_icon(profession.icon).unwrap().name,
+      name: data.localize(language_id, profession.localization_id),
+      from_points: from_professions.get(&profession_id).cloned(),
+      to_points: to_professions.get(&profession_id).cloned(),
+    }))
+    .collect()
+}
+
+fn get_gear_changes(data: &Data, expansion_id: u8, from: &CharacterGear, to: &CharacterGear) -> Vec<CharacterDiffSlotDto> {
+  gear_slots(from).into_iter().zip(gear_slots(to).into_iter())
+    .filter_map(|((slot, from_item), (_, to_item))| {
+      let item_changed = from_item.as_ref().map(|item| item.item_id) != to_item.as_ref().map(|item| item.item_id)
+        || from_item.as_ref().map(|item| item.random_property_id) != to_item.as_ref().map(|item| item.random_property_id);
+      let enchant_changed = from_item.as_ref().and_then(|item| item.enchant_id) != to_item.as_ref().and_then(|item| item.enchant_id);
+      let gems_changed = from_item.as_ref().map(|item| item.gem_ids.clone()).unwrap_or_default() != to_item.as_ref().map(|item| item.gem_ids.clone()).unwrap_or_default();
+      if !item_changed && !enchant_changed && !gems_changed {
+        return None;
+      }
+      // Slots with items unknown to the data of the expansion can not be described and are skipped
+      let from = match from_item {
+        Some(item) => Some(character_item_to_character_diff_item_dto(data, expansion_id, item)?),
+        None => None
+      };
+      let to = match to_item {
+        Some(item) => Some(character_item_to_character_diff_item_dto(data, expansion_id, item)?),
+        None => None
+      };
+      Some(CharacterDiffSlotDto {
+        slot: slot.to_owned(),
+        from,
+        to,
+        item_changed,
+        enchant_changed,
+        gems_changed,
+      })
+    })
+    .collect()
+}
+
+fn get_stat_changes(data: &Data, language_id: u8, expansion_id: u8, from: &CharacterGear, to: &CharacterGear) -> Vec<CharacterDiffStatDto> {
+  let mut stats: BTreeMap<String, (u16, u16)> = BTreeMap::new();
+  for stat in get_character_stats(data, language_id, expansion_id, from) {
+    stats.entry(stat.stat_type).or_insert((0, 0)).0 = stat.stat_value;
+  }
+  for stat in get_character_stats(data, language_id, expansion_id, to) {
+    stats.entry(stat.stat_type).or_insert((0, 0)).1 = stat.stat_value;
+  }
+  stats.into_iter()
+    .filter(|(_, (from_value, to_value))| from_value != to_value)
+    .map(|(stat_type, (from_value, to_value))| CharacterDiffStatDto {
+      stat_type,
+      from_value,
+      to_value,
+      delta: to_value as i32 - from_value as i32,
+    })
+    .collect()
+}
+
+fn gear_slots(gear: &CharacterGear) -> Vec<(&'static str, &Option<CharacterItem>)> {
+  vec![
+    ("head", &gear.head),
+    ("neck", &gear.neck),
+    ("shoulder", &gear.shoulder),
+    ("back", &gear.back),
+    ("chest", &gear.chest),
+    ("shirt", &gear.shirt),
+    ("tabard", &gear.tabard),
+    ("wrist", &gear.wrist),
+    ("main_hand", &gear.main_hand),
+    ("off_hand", &gear.off_hand),
+    ("ternary_hand", &gear.ternary_hand),
+    ("glove", &gear.glove),
+    ("belt", &gear.belt),
+    ("leg", &gear.leg),
+    ("boot", &gear.boot),
+    ("ring1", &gear.ring1),
+    ("ring2", &gear.ring2),
+    ("trinket1", &gear.trinket1),
+    ("trinket2", &gear.trinket2),
+  ]
+}
+
+fn character_item_to_character_diff_item_dto(data: &Data, expansion_id: u8, character_item: &CharacterItem) -> Option<CharacterDiffItemDto> {
+  let item = data.get_item(expansion_id, character_item.item_id)?;
+  Some(CharacterDiffItemDto {
+    item_id: character_item.item_id,
+    quality: item.quality,
+    icon: data.get_icon(item.icon)?.name,
+    random_property_id: character_item.random_property_id,
+    enchant_id: character_item.enchant_id,
+    gem_ids: character_item.gem_ids.clone(),
+  })
+}
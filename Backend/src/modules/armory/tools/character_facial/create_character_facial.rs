@@ -1,36 +1,27 @@
 use crate::modules::armory::Armory;
 use crate::modules::armory::domain_value::CharacterFacial;
 use crate::modules::armory::dto::{CharacterFacialDto, ArmoryFailure};
-use crate::modules::armory::tools::GetCharacterFacial;
-use mysql_connection::tools::Execute;
+use crate::modules::armory::repository::{ArmoryTransaction, CharacterFacialRepository, StartArmoryTransaction};
 
 pub trait CreateCharacterFacial {
   fn create_character_facial(&self, character_facial_dto: CharacterFacialDto) -> Result<CharacterFacial, ArmoryFailure>;
+  fn create_character_facial_in_transaction(&self, transaction: &mut dyn ArmoryTransaction, character_facial_dto: CharacterFacialDto) -> Result<CharacterFacial, ArmoryFailure>;
 }
 
 impl CreateCharacterFacial for Armory {
   fn create_character_facial(&self, character_facial_dto: CharacterFacialDto) -> Result<CharacterFacial, ArmoryFailure> {
-    // If it already exists, return this one
-    let existing_facial = self.get_character_facial_by_value(character_facial_dto.clone());
-    if existing_facial.is_ok() {
-      return existing_facial;
-    }
-
-    let params = params!(
-      "skin_color" => character_facial_dto.skin_color,
-      "face_style" => character_facial_dto.face_style,
-      "hair_style" => character_facial_dto.hair_style,
-      "hair_color" => character_facial_dto.hair_color,
-      "facial_hair" => character_facial_dto.facial_hair,
-    );
-
-    // It may fail due to the unique constraint if a race condition occurs
-    self.db_main.execute_wparams("INSERT INTO armory_character_facial (`skin_color`, `face_style`, `hair_style`, `hair_color`, `facial_hair`) VALUES (:skin_color, :face_style, :hair_style, :hair_color, :facial_hair)", params.clone());
-    let char_facial = self.get_character_facial_by_value(character_facial_dto.clone());
-    if char_facial.is_ok() {
-      return Ok(char_facial.unwrap());
-    }
+    let mut transaction = self.backend.start_transaction().map_err(|_| ArmoryFailure::Database("create_character_facial".to_owned()))?;
+    let character_facial = self.create_character_facial_in_transaction(transaction.as_mut(), character_facial_dto)?;
+    transaction.commit().map_err(|_| ArmoryFailure::Database("create_character_facial".to_owned()))?;
+    Ok(character_facial)
+  }
 
-    Err(ArmoryFailure::Database("create_character_facial".to_owned()))
+  fn create_character_facial_in_transaction(&self, transaction: &mut dyn ArmoryTransaction, character_facial_dto: CharacterFacialDto) -> Result<CharacterFacial, ArmoryFailure> {
+    // If it already exists, return this one
+    // If a race condition occurs, the repository hands us the existing one
+    transaction.find_character_facial(&character_facial_dto).transpose()
+      .unwrap_or_else(|| transaction.insert_character_facial(&character_facial_dto))
+      .map(|facial_id| CharacterFacial::from_dto(facial_id, &character_facial_dto))
+      .map_err(|_| ArmoryFailure::Database("create_character_facial".to_owned()))
   }
 }
\ No newline at end of file
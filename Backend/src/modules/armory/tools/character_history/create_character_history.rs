@@ -1,13 +1,14 @@
-use mysql_connection::tools::Execute;
-
 use crate::modules::armory::Armory;
-use crate::modules::armory::domain_value::HistoryMoment;
+use crate::modules::armory::domain_value::{CharacterGuild, HistoryMoment};
 use crate::modules::armory::dto::{ArmoryFailure, CharacterHistoryDto};
 use crate::modules::armory::material::CharacterHistory;
-use crate::modules::armory::tools::{CreateCharacterFacial, CreateCharacterInfo, CreateGuild, GetCharacter, GetCharacterHistory};
+use crate::modules::armory::repository::{ArmoryTransaction, CharacterHistoryRepository, StartArmoryTransaction};
+use crate::modules::armory::tools::{CreateCharacterFacial, CreateCharacterInfo, CreateGuild, GetCharacter};
 
 pub trait CreateCharacterHistory {
   fn create_character_history(&self, server_id: u32, character_history_dto: CharacterHistoryDto, character_uid: u64) -> Result<CharacterHistory, ArmoryFailure>;
+  fn create_character_history_in_transaction(&self, transaction: &mut dyn ArmoryTransaction, character_id: u32, guild_id: Option<u32>, character_history_dto: CharacterHistoryDto, now: u64) -> Result<CharacterHistory, ArmoryFailure>;
+  fn cache_character_history(&self, character_history: &CharacterHistory);
 }
 
 impl CreateCharacterHistory for Armory {
@@ -15,6 +16,7 @@ impl CreateCharacterHistory for Armory {
   // Assumption: Character exists
   fn create_character_history(&self, server_id: u32, character_history_dto: CharacterHistoryDto, character_uid: u64) -> Result<CharacterHistory, ArmoryFailure> {
     let character_id = self.get_character_id_by_uid(server_id, character_uid).unwrap();
+    // The guild is an entity on its own, hence it is committed independently
     let mut guild_id = None;
     if character_history_dto.character_guild.is_some() {
       let guild = self.create_guild(server_id, character_history_dto.character_guild.as_ref().unwrap().guild.to_owned());
@@ -23,45 +25,58 @@ impl CreateCharacterHistory for Armory {
       }
       guild_id = Some(guild.unwrap().id);
     }
-    let character_info_res = self.create_character_info(character_history_dto.character_info.to_owned());
-    if character_info_res.is_err() {
-      return Err(character_info_res.err().unwrap());
-    }
-    let character_info = character_info_res.unwrap();
+
+    // Info, gear, items, facial and the history row itself are either written all together or not at all
+    let mut transaction = self.backend.start_transaction().map_err(|_| ArmoryFailure::Database("create_character_history".to_owned()))?;
+    let character_history = self.create_character_history_in_transaction(transaction.as_mut(), character_id, guild_id, character_history_dto, time_util::now())?;
+
+    // The cache is only touched once everything is persisted
+    let _character_writes = self.character_writes.lock().unwrap();
+    transaction.commit().map_err(|_| ArmoryFailure::Database("create_character_history".to_owned()))?;
+    self.cache_character_history(&character_history);
+    Ok(character_history)
+  }
+
+  fn create_character_history_in_transaction(&self, transaction: &mut dyn ArmoryTransaction, character_id: u32, guild_id: Option<u32>, character_history_dto: CharacterHistoryDto, now: u64) -> Result<CharacterHistory, ArmoryFailure> {
+    let character_info = self.create_character_info_in_transaction(transaction, character_history_dto.character_info.to_owned())?;
 
     let mut facial = None;
     if character_history_dto.facial.is_some() {
-      let facial_res = self.create_character_facial(character_history_dto.facial.as_ref().unwrap().clone());
-      if facial_res.is_err() {
-        return Err(facial_res.err().unwrap());
-      }
-      facial = facial_res.ok();
+      facial = Some(self.create_character_facial_in_transaction(transaction, character_history_dto.facial.as_ref().unwrap().clone())?);
     }
 
-    let params = params!(
-      "character_id" => character_id,
-      "character_info_id" => character_info.id,
-      "character_name" => character_history_dto.character_name.clone(),
-      "title" => character_history_dto.character_title.clone(),
-      "guild_id" => guild_id.clone(),
-      "guild_rank" => character_history_dto.character_guild.as_ref().and_then(|chr_guild_dto| Some(chr_guild_dto.rank.clone())),
-      "prof_skill_points1" => character_history_dto.profession_skill_points1.clone(),
-      "prof_skill_points2" => character_history_dto.profession_skill_points2.clone(),
-      "facial" => facial.as_ref().and_then(|chr_facial| Some(chr_facial.id.clone()))
-    );
-    self.db_main.execute_wparams("INSERT INTO armory_character_history (`character_id`, `character_info_id`, `character_name`, `title`, `guild_id`, `guild_rank`, `prof_skill_points1`, `prof_skill_points2`, `facial`, `timestamp`) VALUES (:character_id, :character_info_id, :character_name, :title, :guild_id, :guild_rank, :prof_skill_points1, :prof_skill_points2, :facial, UNIX_TIMESTAMP())", params.clone());
-    let character_history_res = self.get_character_history_by_value(character_id, character_history_dto);
-    if character_history_res.is_ok() {
-      let mut characters = self.characters.write().unwrap();
-      let mut character = characters.get_mut(&character_id).unwrap();
-      character.last_update = Some(character_history_res.as_ref().unwrap().clone());
+    let character_guild = guild_id.map(|guild_id| CharacterGuild {
+      guild_id,
+      rank: character_history_dto.character_guild.as_ref().map(|chr_guild_dto| chr_guild_dto.rank.clone()).unwrap_or_default(),
+    });
+    let character_history_id = transaction.insert_character_history(character_id, character_info.id, facial.as_ref().map(|chr_facial| chr_facial.id),
+      character_guild.as_ref(), &character_history_dto, now)
+      .map_err(|_| ArmoryFailure::Database("create_character_history".to_owned()))?;
+
+    Ok(CharacterHistory {
+      id: character_history_id,
+      character_id,
+      character_info,
+      character_name: character_history_dto.character_name.to_owned(),
+      character_guild,
+      character_title: character_history_dto.character_title,
+      profession_skill_points1: character_history_dto.profession_skill_points1,
+      profession_skill_points2: character_history_dto.profession_skill_points2,
+      facial,
+      timestamp: now,
+    })
+  }
+
+  // Assumption: The history has been committed and the character writes are locked
+  fn cache_character_history(&self, character_history: &CharacterHistory) {
+    self.characters.update(character_history.character_id, |character| {
+      character.last_update = Some(character_history.clone());
       character.history_moments.push(HistoryMoment {
-        id: character_history_res.as_ref().unwrap().id,
-        timestamp: character_history_res.as_ref().unwrap().timestamp,
+        id: character_history.id,
+        timestamp: character_history.timestamp,
       });
-      return Ok(character_history_res.unwrap());
-    }
-
-    Err(ArmoryFailure::Database("create_character_history".to_owned()))
+    });
+    self.character_histories.insert(character_history.id, character_history.clone());
+    self.character_search_index.write().unwrap().index_history(character_history);
   }
 }
\ No newline at end of file
@@ -1,8 +1,7 @@
-use mysql_connection::tools::Execute;
-
 use crate::modules::armory::Armory;
 use crate::modules::armory::dto::ArmoryFailure;
-use crate::modules::armory::tools::GetCharacterHistory;
+use crate::modules::armory::repository::{CharacterHistoryRepository, StartArmoryTransaction};
+use crate::modules::armory::tools::{GetCharacter, GetCharacterHistory};
 
 pub trait DeleteCharacterHistory {
   fn delete_character_history(&self, character_history_id: u32) -> Result<(), ArmoryFailure>;
@@ -16,21 +15,19 @@ impl DeleteCharacterHistory for Armory {
     }
     let character_history = character_history_res.unwrap();
 
-    let mut characters = self.characters.write().unwrap();
-    if self.db_main.execute_wparams("DELETE FROM armory_character_history WHERE id=:id", params!(
-      "id" => character_history_id
-    )) {
-      let mut character = characters.get_mut(&character_history.character_id).unwrap();
-      let hm = character.history_moments.iter().find(|history_moment| history_moment.id == character_history_id).unwrap().clone();
-      character.history_moments.remove_item(&hm);
-      if character.last_update.contains(&character_history) {
-        let last_id = character.history_moments.last();
-        if last_id.is_some() {
-          character.last_update = self.get_character_history(last_id.unwrap().id).ok();
-        }
-      }
-      return Ok(());
+    let _character_writes = self.character_writes.lock().unwrap();
+    // Info, gear, items and facials that are no longer referenced are deleted along with it
+    let mut transaction = self.backend.start_transaction().map_err(|_| ArmoryFailure::Database("delete_character_history".to_owned()))?;
+    transaction.delete_character_histories(&[character_history_id])
+      .and_then(|()| transaction.commit())
+      .map_err(|_| ArmoryFailure::Database("delete_character_history".to_owned()))?;
+
+    // Reloading the character yields its new last update
+    self.character_histories.invalidate(character_history_id);
+    self.characters.invalidate(character_history.character_id);
+    if let Some(character) = self.get_character(character_history.character_id) {
+      self.character_search_index.write().unwrap().index_character(&character);
     }
-    Err(ArmoryFailure::Database("delete_character_history".to_owned()))
+    Ok(())
   }
 }
\ No newline at end of file
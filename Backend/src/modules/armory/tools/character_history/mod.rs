@@ -1,5 +1,5 @@
 pub use self::create_character_history::CreateCharacterHistory;
-pub use self::set_character_history::SetCharacterHistory;
+pub use self::set_character_history::{SetCharacterHistory, is_unchanged};
 pub use self::get_character_history::GetCharacterHistory;
 pub use self::delete_character_history::DeleteCharacterHistory;
 
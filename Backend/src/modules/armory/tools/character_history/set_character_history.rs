@@ -1,13 +1,13 @@
-use mysql_connection::tools::Execute;
-
 use crate::modules::armory::Armory;
 use crate::modules::armory::dto::{ArmoryFailure, CharacterHistoryDto};
 use crate::modules::armory::material::CharacterHistory;
+use crate::modules::armory::repository::{CharacterHistoryRepository, StartArmoryTransaction};
 use crate::modules::armory::tools::{CreateCharacterHistory, CreateGuild, GetCharacter};
 use crate::dto::CheckPlausability;
 
 pub trait SetCharacterHistory {
   fn set_character_history(&self, server_id: u32, update_character_history: CharacterHistoryDto, uid: u64) -> Result<CharacterHistory, ArmoryFailure>;
+  fn touch_character_history(&self, last_update: &mut CharacterHistory, now: u64);
 }
 
 impl SetCharacterHistory for Armory {
@@ -27,22 +27,20 @@ impl SetCharacterHistory for Armory {
     let guild_id = update_character_history.character_guild.as_ref().and_then(|chr_guild_dto| self.create_guild(server_id, chr_guild_dto.guild.clone()).ok().and_then(|gld| Some(gld.id)));
 
     { // Check whether this is a new entry or just the same as previously
-      let mut characters = self.characters.write().unwrap();
-      let character = characters.get_mut(&character_id).unwrap();
+      let _character_writes = self.character_writes.lock().unwrap();
+      let character = self.get_character(character_id).ok_or_else(|| ArmoryFailure::Database("set_character_history".to_owned()))?;
 
       if character.last_update.is_some() {
-        let mut last_update = character.last_update.as_mut().unwrap();
-        if last_update.compare_by_value(&update_character_history) &&
-          ((last_update.character_guild.is_none() && guild_id.is_none())
-            || (last_update.character_guild.is_some() && guild_id.is_some() && last_update.character_guild.as_ref().unwrap().guild_id == *guild_id.as_ref().unwrap()))
-        {
+        let mut last_update = character.last_update.unwrap();
+        if is_unchanged(&last_update, &update_character_history, guild_id) {
           let now = time_util::now();
-          if self.db_main.execute_wparams("UPDATE armory_character_history SET `timestamp` = :timestamp WHERE id=:id", params!(
-            "timestamp" => now.clone(),
-            "id" => last_update.id
-          )) {
-            last_update.timestamp = now.to_owned();
-            return Ok(last_update.clone());
+          let touched = self.backend.start_transaction().and_then(|mut transaction| {
+            transaction.touch_character_histories(&[last_update.id], now)?;
+            transaction.commit()
+          });
+          if touched.is_ok() {
+            self.touch_character_history(&mut last_update, now);
+            return Ok(last_update);
           }
           return Err(ArmoryFailure::Database("set_character_history".to_owned()));
         }
@@ -50,4 +48,19 @@ impl SetCharacterHistory for Armory {
     } // Else create a new history point and assign it to this character
     self.create_character_history(server_id, update_character_history, character_uid)
   }
+
+  // Assumption: The new timestamp has been persisted and the character writes are locked
+  fn touch_character_history(&self, last_update: &mut CharacterHistory, now: u64) {
+    last_update.timestamp = now;
+    self.characters.update(last_update.character_id, |character| character.last_update = Some(last_update.clone()));
+    self.character_histories.update(last_update.id, |character_history| character_history.timestamp = now);
+    self.character_search_index.write().unwrap().index_history(last_update);
+  }
+}
+
+// An update is the same as the last one if the values and the guild did not change
+pub fn is_unchanged(last_update: &CharacterHistory, update_character_history: &CharacterHistoryDto, guild_id: Option<u32>) -> bool {
+  last_update.compare_by_value(update_character_history) &&
+    ((last_update.character_guild.is_none() && guild_id.is_none())
+      || (last_update.character_guild.is_some() && guild_id.is_some() && last_update.character_guild.as_ref().unwrap().guild_id == *guild_id.as_ref().unwrap()))
 }
\ No newline at end of file
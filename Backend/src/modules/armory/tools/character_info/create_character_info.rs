@@ -1,49 +1,36 @@
-use mysql_connection::tools::Execute;
-
 use crate::modules::armory::Armory;
 use crate::modules::armory::domain_value::CharacterInfo;
 use crate::modules::armory::dto::{ArmoryFailure, CharacterInfoDto};
-use crate::modules::armory::tools::{CreateCharacterGear, GetCharacterInfo, strip_talent_specialization};
+use crate::modules::armory::repository::{ArmoryTransaction, CharacterInfoRepository, StartArmoryTransaction};
+use crate::modules::armory::tools::{CreateCharacterGear, strip_talent_specialization};
 
 pub trait CreateCharacterInfo {
   fn create_character_info(&self, character_info: CharacterInfoDto) -> Result<CharacterInfo, ArmoryFailure>;
+  fn create_character_info_in_transaction(&self, transaction: &mut dyn ArmoryTransaction, character_info: CharacterInfoDto) -> Result<CharacterInfo, ArmoryFailure>;
 }
 
 impl CreateCharacterInfo for Armory {
   fn create_character_info(&self, character_info: CharacterInfoDto) -> Result<CharacterInfo, ArmoryFailure> {
-    // Return existing one first
-
-    let existing_character_info = self.get_character_info_by_value(character_info.clone());
-    if existing_character_info.is_ok() {
-      return existing_character_info;
-    }
+    let mut transaction = self.backend.start_transaction().map_err(|_| ArmoryFailure::Database("create_character_info".to_owned()))?;
+    let char_info = self.create_character_info_in_transaction(transaction.as_mut(), character_info)?;
+    transaction.commit().map_err(|_| ArmoryFailure::Database("create_character_info".to_owned()))?;
+    Ok(char_info)
+  }
 
+  fn create_character_info_in_transaction(&self, transaction: &mut dyn ArmoryTransaction, character_info: CharacterInfoDto) -> Result<CharacterInfo, ArmoryFailure> {
     // Create the gear needed
-    let gear_res = self.create_character_gear(character_info.gear.to_owned());
-    if gear_res.is_err() {
-      return Err(gear_res.err().unwrap());
-    }
-    let gear = gear_res.unwrap();
-
-    let talent_specialization = strip_talent_specialization(&character_info.talent_specialization);
+    let gear = self.create_character_gear_in_transaction(transaction, character_info.gear.to_owned())?;
 
-    let params = params!(
-      "gear_id" => gear.id,
-      "hero_class_id" => character_info.hero_class_id,
-      "level" => character_info.level,
-      "gender" => character_info.gender,
-      "profession1" => character_info.profession1.clone(),
-      "profession2" => character_info.profession2.clone(),
-      "talent_specialization" => talent_specialization,
-      "race_id" => character_info.race_id
-    );
+    let character_info = CharacterInfoDto {
+      talent_specialization: strip_talent_specialization(&character_info.talent_specialization),
+      ..character_info
+    };
 
-    // It may fail due to the unique constraint if a race condition occurs
-    self.db_main.execute_wparams("INSERT INTO armory_character_info (`gear_id`, `hero_class_id`, `level`, `gender`, `profession1`, `profession2`, `talent_specialization`, `race_id`) VALUES (:gear_id, :hero_class_id, :level, :gender, :profession1, :profession2, :talent_specialization, :race_id)", params.clone());
-    let char_info = self.get_character_info_by_value(character_info.to_owned());
-    if char_info.is_ok() {
-      return Ok(char_info.unwrap());
-    }
-    Err(ArmoryFailure::Database("create_character_info".to_owned()))
+    // Return existing one first
+    // If a race condition occurs, the repository hands us the existing one
+    transaction.find_character_info(gear.id, &character_info).transpose()
+      .unwrap_or_else(|| transaction.insert_character_info(gear.id, &character_info))
+      .map(|character_info_id| CharacterInfo::from_dto(character_info_id, gear, &character_info))
+      .map_err(|_| ArmoryFailure::Database("create_character_info".to_owned()))
   }
 }
\ No newline at end of file
@@ -1,127 +1,189 @@
 use std::cmp::Ordering;
+use std::collections::HashMap;
 
-use crate::dto::SearchResult;
+use crate::dto::{SearchCursor, SearchResult};
 use crate::modules::armory::Armory;
-use crate::modules::armory::dto::{CharacterSearchCharacterDto, CharacterSearchFilter, CharacterSearchGuildDto, CharacterSearchResult};
-use crate::modules::armory::tools::GetGuild;
+use crate::modules::armory::dto::{ArmoryFailure, CharacterSearchCharacterDto, CharacterSearchFilter, CharacterSearchGuildDto, CharacterSearchResult};
+use crate::modules::armory::material::{CharacterSummary, Guild};
 use crate::modules::data::Data;
 use crate::modules::data::tools::RetrieveRace;
 
+const DEFAULT_PAGE_SIZE: u32 = 10;
+const MAX_PAGE_SIZE: u32 = 100;
+
 pub trait PerformCharacterSearch {
-    fn get_character_search_result(&self, data: &Data, filter: CharacterSearchFilter) -> SearchResult<CharacterSearchResult>;
+    fn get_character_search_result(&self, data: &Data, filter: CharacterSearchFilter) -> Result<SearchResult<CharacterSearchResult>, ArmoryFailure>;
 }
 
-impl PerformCharacterSearch for Armory {
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+enum SortColumn {
+    HeroClass,
+    Name,
+    Guild,
+    Server,
+    LastUpdated,
+}
 
-    // This implementation is far away from being optimal.
-    fn get_character_search_result(&self, data: &Data, filter: CharacterSearchFilter) -> SearchResult<CharacterSearchResult> {
-        let mut filter = filter.to_owned();
-        let characters = self.characters.read().unwrap();
-        if filter.name.filter.is_some() { *filter.name.filter.as_mut().unwrap() = filter.name.filter.as_ref().unwrap().to_lowercase(); }
-        if filter.guild.filter.is_some() { *filter.guild.filter.as_mut().unwrap() = filter.guild.filter.as_ref().unwrap().to_lowercase(); }
-        let mut result: Vec<CharacterSearchResult> = characters.iter()
-            .filter(|(_, character)| character.last_update.is_some())
-            .filter(|(_, character)| filter.server.filter.is_none() || filter.server.filter.contains(&character.server_id))
-            .filter(|(_, character)| filter.name.filter.is_none() || character.last_update.as_ref().unwrap().character_name.to_lowercase().contains(filter.name.filter.as_ref().unwrap()))
-            .filter(|(_, character)| filter.hero_class.filter.is_none() || filter.hero_class.filter.contains(&character.last_update.as_ref().unwrap().character_info.hero_class_id))
-            .filter(|(_, character)| {
-                if filter.last_updated.filter.is_none() {
-                    return true;
-                }
-                let filter_timestamp = filter.last_updated.filter.as_ref().unwrap().clone();
-                let current_timestamp = character.last_update.as_ref().unwrap().timestamp;
-                return current_timestamp >= filter_timestamp && current_timestamp <= filter_timestamp + 24 * 60 * 60;
-            })
-            // This is very expensive
-            .filter(|(_, character)| {
-                if filter.guild.filter.is_none() {
-                    return true;
-                }
-                if let Some(character_guild) = character.last_update.as_ref().unwrap().character_guild.as_ref() {
-                    if let Some(guild) = self.get_guild(character_guild.guild_id) {
-                        return guild.name.to_lowercase().contains(filter.guild.filter.as_ref().unwrap());
-                    }
-                }
-                return false;
+#[derive(Debug, Clone, Copy)]
+struct SortKey<'a> {
+    hero_class_id: u8,
+    name: &'a str,
+    guild_name: Option<&'a str>,
+    server_id: u32,
+    timestamp: u64,
+    character_id: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CharacterSearchCursor {
+    hero_class_id: u8,
+    name: String,
+    guild_name: Option<String>,
+    server_id: u32,
+    timestamp: u64,
+    character_id: u32,
+    // A cursor only continues the order it was created for
+    sortings: Vec<(SortColumn, bool)>,
+}
+
+impl<'a> SortKey<'a> {
+    fn compare(&self, other: &SortKey, sortings: &[(SortColumn, bool)]) -> Ordering {
+        sortings.iter()
+            .map(|(column, ascending)| {
+                let ordering = match column {
+                    SortColumn::HeroClass => self.hero_class_id.cmp(&other.hero_class_id),
+                    SortColumn::Name => self.name.cmp(other.name),
+                    SortColumn::Guild => self.guild_name.cmp(&other.guild_name),
+                    SortColumn::Server => self.server_id.cmp(&other.server_id),
+                    SortColumn::LastUpdated => self.timestamp.cmp(&other.timestamp),
+                };
+                if *ascending { ordering } else { ordering.reverse() }
             })
-            .map(|(_, character)| {
-                let race_id = character.last_update.as_ref().unwrap().character_info.race_id;
+            .find(|ordering| *ordering != Ordering::Equal)
+            // Ties are broken by id, which keeps the order total and the cursor unambiguous
+            .unwrap_or_else(|| self.character_id.cmp(&other.character_id))
+    }
+
+    fn encode_cursor(&self, sortings: &[(SortColumn, bool)]) -> String {
+        CharacterSearchCursor {
+            hero_class_id: self.hero_class_id,
+            name: self.name.to_owned(),
+            guild_name: self.guild_name.map(|guild_name| guild_name.to_owned()),
+            server_id: self.server_id,
+            timestamp: self.timestamp,
+            character_id: self.character_id,
+            sortings: sortings.to_vec(),
+        }.to_cursor()
+    }
+}
+
+impl CharacterSearchCursor {
+    fn as_sort_key(&self) -> SortKey {
+        SortKey {
+            hero_class_id: self.hero_class_id,
+            name: &self.name,
+            guild_name: self.guild_name.as_ref().map(|guild_name| guild_name.as_str()),
+            server_id: self.server_id,
+            timestamp: self.timestamp,
+            character_id: self.character_id,
+        }
+    }
+}
+
+impl PerformCharacterSearch for Armory {
+    fn get_character_search_result(&self, data: &Data, filter: CharacterSearchFilter) -> Result<SearchResult<CharacterSearchResult>, ArmoryFailure> {
+        let guilds = self.guilds.read().unwrap();
+        let character_search_index = self.character_search_index.read().unwrap();
+
+        let mut character_ids: Vec<u32> = character_search_index.find(filter.server.filter, filter.hero_class.filter,
+                                                                        filter.name.filter.as_ref().map(|name| name.as_str()),
+                                                                        filter.guild.filter.as_ref().map(|guild_name| guild_name.as_str()));
+        if let Some(filter_timestamp) = filter.last_updated.filter {
+            character_ids.retain(|character_id| {
+                let current_timestamp = character_search_index.summary(*character_id).unwrap().timestamp;
+                current_timestamp >= filter_timestamp && current_timestamp <= filter_timestamp + 24 * 60 * 60
+            });
+        }
+        let num_characters = character_ids.len();
+
+        let sortings: Vec<(SortColumn, bool)> = vec![
+            (SortColumn::HeroClass, filter.hero_class.sorting),
+            (SortColumn::Name, filter.name.sorting),
+            (SortColumn::Guild, filter.guild.sorting),
+            (SortColumn::Server, filter.server.sorting),
+            (SortColumn::LastUpdated, filter.last_updated.sorting),
+        ].into_iter().filter_map(|(column, sorting)| sorting.map(|ascending| (column, ascending))).collect();
+        let summary = |character_id: &u32| character_search_index.summary(*character_id).unwrap();
+        let compare = |left: &u32, right: &u32| sort_key(summary(left), &guilds)
+            .compare(&sort_key(summary(right), &guilds), &sortings);
+
+        // Keyset pagination continues after the cursor, otherwise the page is used as offset
+        let page_size = filter.page_size.unwrap_or(DEFAULT_PAGE_SIZE).max(1).min(MAX_PAGE_SIZE) as usize;
+        let mut offset = (filter.page as usize) * page_size;
+        if let Some(cursor) = filter.cursor.as_ref() {
+            let cursor = CharacterSearchCursor::from_cursor(cursor)
+                .filter(|cursor| cursor.sortings == sortings)
+                .ok_or(ArmoryFailure::InvalidInput)?;
+            let cursor_key = cursor.as_sort_key();
+            character_ids.retain(|character_id| sort_key(summary(character_id), &guilds).compare(&cursor_key, &sortings) == Ordering::Greater);
+            offset = 0;
+        }
+
+        // Only the requested page has to be ordered
+        let num_remaining = character_ids.len();
+        let page_end = std::cmp::min(offset + page_size, num_remaining);
+        if offset >= num_remaining {
+            character_ids.clear();
+        } else {
+            if page_end < num_remaining {
+                character_ids.select_nth_unstable_by(page_end, compare);
+                character_ids.truncate(page_end);
+            }
+            character_ids.sort_unstable_by(compare);
+            character_ids.drain(..offset);
+        }
+
+        let next_cursor = if page_end < num_remaining {
+            character_ids.last().map(|character_id| sort_key(summary(character_id), &guilds).encode_cursor(&sortings))
+        } else {
+            None
+        };
+
+        Ok(SearchResult {
+            result: character_ids.iter().map(|character_id| {
+                let character = summary(character_id);
                 CharacterSearchResult {
-                    faction: data.get_race(race_id).unwrap().faction,
-                    guild: character.last_update.as_ref().unwrap().character_guild.as_ref()
-                        .and_then(|character_guild| self.get_guild(character_guild.guild_id)
-                            .and_then(|guild| Some(CharacterSearchGuildDto {
+                    faction: data.get_race(character.race_id).unwrap().faction,
+                    guild: character.guild.as_ref()
+                        .and_then(|character_guild| guilds.get(&character_guild.guild_id)
+                            .map(|guild| CharacterSearchGuildDto {
                                 guild_id: guild.id,
                                 name: guild.name.to_owned(),
-                            }))),
+                            })),
                     character: CharacterSearchCharacterDto {
-                        character_id: character.id,
-                        name: character.last_update.as_ref().unwrap().character_name.clone(),
-                        hero_class_id: character.last_update.as_ref().unwrap().character_info.hero_class_id,
+                        character_id: character.character_id,
+                        name: character.name.clone(),
+                        hero_class_id: character.hero_class_id,
                         server_id: character.server_id,
                     },
-                    timestamp: character.last_update.as_ref().unwrap().timestamp,
+                    timestamp: character.timestamp,
                 }
-            })
-            .collect();
-        let num_characters = result.len();
-
-        result.sort_by(|left: &CharacterSearchResult, right: &CharacterSearchResult| {
-            if let Some(sorting) = filter.hero_class.sorting {
-                let ordering = left.character.hero_class_id.cmp(&right.character.hero_class_id);
-                if ordering != Ordering::Equal {
-                    return negate_ordering(ordering, sorting);
-                }
-            }
-
-            if let Some(sorting) = filter.name.sorting {
-                let ordering = left.character.name.cmp(&right.character.name);
-                if ordering != Ordering::Equal {
-                    return negate_ordering(ordering, sorting);
-                }
-            }
-
-            if let Some(sorting) = filter.guild.sorting {
-                if left.guild.is_some() && right.guild.is_some() {
-                    let ordering = left.guild.as_ref().unwrap().name.cmp(&right.guild.as_ref().unwrap().name);
-                    if ordering != Ordering::Equal {
-                        return negate_ordering(ordering, sorting);
-                    }
-                }
-            }
-
-            if let Some(sorting) = filter.server.sorting {
-                let ordering = left.character.server_id.cmp(&right.character.server_id);
-                if ordering != Ordering::Equal {
-                    return negate_ordering(ordering, sorting);
-                }
-            }
-
-            if let Some(sorting) = filter.last_updated.sorting {
-                let ordering = left.timestamp.cmp(&right.timestamp);
-                if ordering != Ordering::Equal {
-                    return negate_ordering(ordering, sorting);
-                }
-            }
-
-            return Ordering::Equal;
-        });
-        SearchResult {
-            result: result.iter().skip((filter.page * 10) as usize).take(10).map(|cs| cs.to_owned()).collect::<Vec<CharacterSearchResult>>(),
+            }).collect(),
             num_items: num_characters,
-        }
+            next_cursor,
+        })
     }
 }
 
-fn negate_ordering(ordering: Ordering, sorting: bool) -> Ordering {
-    if ordering == Ordering::Less {
-        if sorting {
-            return Ordering::Less;
-        }
-        return Ordering::Greater;
-    }
-    if sorting {
-        return Ordering::Greater;
+fn sort_key<'a>(character: &'a CharacterSummary, guilds: &'a HashMap<u32, Guild>) -> SortKey<'a> {
+    SortKey {
+        hero_class_id: character.hero_class_id,
+        name: &character.name,
+        guild_name: character.guild.as_ref()
+            .and_then(|character_guild| guilds.get(&character_guild.guild_id))
+            .map(|guild| guild.name.as_str()),
+        server_id: character.server_id,
+        timestamp: character.timestamp,
+        character_id: character.character_id,
     }
-    return Ordering::Less;
 }
\ No newline at end of file
@@ -39,14 +39,14 @@ impl CharacterViewer for Armory {
     let profession1 = character_history.character_info.profession1
       .and_then(|profession_id| data.get_profession(profession_id).and_then(|profession| Some(CharacterViewerProfessionDto {
         icon: data.get_icon(profession.icon).unwrap().name,
-        name: data.get_localization(language_id, profession.localization_id).unwrap().content,
+        name: data.localize(language_id, profession.localization_id),
         points: character_history.profession_skill_points1.unwrap(),
         point_max: profession_points_max
       })));
     let profession2  = character_history.character_info.profession2
       .and_then(|profession_id| data.get_profession(profession_id).and_then(|profession| Some(CharacterViewerProfessionDto {
         icon: data.get_icon(profession.icon).unwrap().name,
-        name: data.get_localization(language_id, profession.localization_id).unwrap().content,
+        name: data.localize(language_id, profession.localization_id),
         points: character_history.profession_skill_points2.unwrap(),
         point_max: profession_points_max
       })));
@@ -59,7 +59,7 @@ impl CharacterViewer for Armory {
         let breakdown_index = breakdown.iter().position(|value| *value == *breakdown_max).unwrap();
         Some(CharacterViewerTalentsDto {
           icon: data.get_icon(hero_class.talents[breakdown_index].icon).unwrap().name,
-          name: data.get_localization(language_id, hero_class.talents[breakdown_index].localization_id).unwrap().content,
+          name: data.localize(language_id, hero_class.talents[breakdown_index].localization_id),
           description: description.to_owned()
         })
       });
@@ -73,8 +73,7 @@ impl CharacterViewer for Armory {
       faction: race.faction,
       level: character_history.character_info.level,
       name: character_history.character_title.and_then(|title_id| data.get_title(title_id).and_then(|title|
-        data.get_localization(language_id, title.localization_id)
-          .and_then(|localization| Some(localization.content.replace("%s", &character_history.character_name)))))
+        Some(data.localize(language_id, title.localization_id).replace("%s", &character_history.character_name))))
         .or_else(|| Some(character_history.character_name.clone())).unwrap(),
       server_name: server.name.clone(),
       guild: character_history.character_guild.and_then(|guild| Some(CharacterViewerGuildDto {
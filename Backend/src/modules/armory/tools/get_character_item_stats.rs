@@ -1,8 +1,10 @@
+use std::collections::{HashMap, HashSet};
+
 use crate::modules::armory::domain_value::{CharacterGear, CharacterItem};
 use crate::modules::armory::dto::CharacterStat;
 use crate::modules::data::Stat;
 use crate::modules::data::Data;
-use crate::modules::data::tools::{RetrieveItem, RetrieveItemsetName, RetrieveItemsetEffect, SpellDescription, RetrieveLocalization, RetrieveStatType, RetrieveItemStat, RetrieveItemEffect, RetrieveEnchant, RetrieveItemSocket, RetrieveGem, RetrieveItemRandomProperty, RetrieveItemRandomPropertyPoints};
+use crate::modules::data::tools::{RetrieveItem, RetrieveItemsetEffect, SpellDescription, RetrieveLocalization, RetrieveStatType, RetrieveItemStat, RetrieveItemEffect, RetrieveEnchant, RetrieveItemSocket, RetrieveGem, RetrieveItemRandomProperty, RetrieveItemRandomPropertyPoints};
 
 pub fn get_character_stats(data: &Data, language_id: u8, expansion_id: u8, gear: &CharacterGear) -> Vec<CharacterStat> {
     let mut acc = get_item_stats(data, expansion_id, &gear.head, 0);
@@ -25,27 +27,24 @@ pub fn get_character_stats(data: &Data, language_id: u8, expansion_id: u8, gear:
     merge_character_stat_vec(&mut acc, get_item_stats(data, expansion_id, &gear.trinket1, 1));
     merge_character_stat_vec(&mut acc, get_item_stats(data, expansion_id, &gear.trinket2, 1));
 
-    // Stats from set bonus
-    let gear_to_vec_item_ids = gear_to_item_id_vec(&gear);
-    let mut considered_set_item_ids: Vec<u32> = Vec::new();
-    for item_id in gear_to_vec_item_ids {
-        if considered_set_item_ids.iter().find(|&&id| id == item_id).is_some() {
-            continue;
+    // Stats from set bonus, only those whose piece threshold is reached
+    let mut equipped_set_item_ids: HashMap<u16, HashSet<u32>> = HashMap::new();
+    for item_id in gear_to_item_id_vec(&gear) {
+        if let Some(itemset_id) = data.get_item(expansion_id, item_id).and_then(|item| item.itemset) {
+            equipped_set_item_ids.entry(itemset_id).or_insert_with(HashSet::new).insert(item_id);
         }
-        if let Some(item) = data.get_item(expansion_id, item_id) {
-            if let Some(itemset_id) = item.itemset {
-                let mut itemset_item_ids = data.get_itemset_item_ids(expansion_id, itemset_id).unwrap();
-                considered_set_item_ids.append(&mut itemset_item_ids);
-                data.get_itemset_effects(expansion_id, itemset_id).unwrap().iter()
-                    .for_each(|item_effect|
-                        merge_character_stat_vec(&mut acc, data.parse_stats(expansion_id, item_effect.spell_id)))
-            }
+    }
+    for (itemset_id, item_ids) in equipped_set_item_ids {
+        if let Some(itemset_effects) = data.get_active_itemset_effects(expansion_id, itemset_id, item_ids.len() as u8) {
+            itemset_effects.iter()
+                .for_each(|itemset_effect|
+                    merge_character_stat_vec(&mut acc, data.parse_stats(expansion_id, itemset_effect.spell_id)));
         }
     }
 
     acc.sort_by(|left, right| left.stat_type.cmp(&right.stat_type));
     acc.iter().map(|stat| CharacterStat {
-        stat_type: data.get_localization(language_id, data.get_stat_type(stat.stat_type).unwrap().localization_id).unwrap().content.to_owned(),
+        stat_type: data.localize(language_id, data.get_stat_type(stat.stat_type).unwrap().localization_id),
         stat_value: stat.stat_value,
     }).collect()
 }
@@ -121,7 +120,7 @@ fn get_item_stats(data: &Data, expansion_id: u8, item: &Option<CharacterItem>, s
             for i in 0..random_stats.enchant_ids.len() {
                 let enchant_id = random_stats.enchant_ids[i];
                 let enchant = data.get_enchant(expansion_id, enchant_id).unwrap();
-                let localization = data.get_localization(1, enchant.localization_id).unwrap().content.to_lowercase();
+                let localization = data.localize(1, enchant.localization_id).to_lowercase();
 
                 // Note: Duplicate code here with item_tooltip
                 let coefficient_value = random_stats.scaling_coefficients[i];
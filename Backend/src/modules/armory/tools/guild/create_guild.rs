@@ -1,8 +1,7 @@
-use mysql_connection::tools::{Execute, Select};
-
 use crate::modules::armory::Armory;
 use crate::modules::armory::dto::{ArmoryFailure, GuildDto};
 use crate::modules::armory::material::Guild;
+use crate::modules::armory::repository::GuildRepository;
 use crate::modules::armory::tools::GetGuild;
 
 pub trait CreateGuild {
@@ -24,19 +23,9 @@ impl CreateGuild for Armory {
 
     // Else create one
     let mut guilds = self.guilds.write().unwrap();
-    if self.db_main.execute_wparams("INSERT INTO armory_guild (`server_id`, `server_uid`, `guild_name`) VALUES (:server_id, :server_uid, :guild_name)", params!(
-      "server_id" => server_id,
-      "server_uid" => guild.server_uid,
-      "guild_name" => guild.name.clone()
-    )) {
-      let guild_id = self.db_main.select_wparams_value("SELECT id FROM armory_guild WHERE server_id=:server_id AND server_uid=:server_uid", &|mut row| {
-        let id: u32 = row.take(0).unwrap();
-        id
-      }, params!(
-        "server_id" => server_id,
-        "server_uid" => guild.server_uid
-      )).unwrap();
+    let guild_id = self.backend.insert_guild(server_id, guild.server_uid, &guild.name);
 
+    if let Some(guild_id) = guild_id {
       let new_guild = Guild {
         id: guild_id,
         server_uid: guild.server_uid,
@@ -44,6 +33,7 @@ impl CreateGuild for Armory {
         server_id,
       };
       guilds.insert(new_guild.id, new_guild.clone());
+      self.character_search_index.write().unwrap().index_guild(&new_guild);
 
       return Ok(new_guild.to_owned());
     }
@@ -1,7 +1,6 @@
-use mysql_connection::tools::Execute;
-
 use crate::modules::armory::Armory;
 use crate::modules::armory::dto::ArmoryFailure;
+use crate::modules::armory::repository::GuildRepository;
 use crate::modules::armory::tools::GetGuild;
 
 pub trait DeleteGuild {
@@ -11,13 +10,27 @@ pub trait DeleteGuild {
 
 impl DeleteGuild for Armory {
   fn delete_guild(&self, id: u32) -> Result<(), ArmoryFailure> {
+    let _character_writes = self.character_writes.lock().unwrap();
     let mut guilds = self.guilds.write().unwrap();
-    if self.db_main.execute_wparams("DELETE FROM armory_guild WHERE id=:id", params!(
-      "id" => id.clone()
-    )) {
-      return guilds.remove(&id).ok_or(ArmoryFailure::Database("Invalid guild id o.O".to_owned())).and_then(|_| Ok(()));
+    let memberships = self.backend.delete_guild(id).ok_or_else(|| ArmoryFailure::Database("delete_guild".to_owned()))?;
+
+    memberships.iter().for_each(|(character_history_id, character_id)| {
+      self.character_histories.invalidate(*character_history_id);
+      self.characters.invalidate(*character_id);
+    });
+    {
+      let mut character_search_index = self.character_search_index.write().unwrap();
+      character_search_index.remove_guild(id);
+      memberships.iter().for_each(|(_, character_id)| {
+        if let Some(mut summary) = character_search_index.summary(*character_id).cloned() {
+          if summary.guild.as_ref().map(|character_guild| character_guild.guild_id) == Some(id) {
+            summary.guild = None;
+            character_search_index.index_summary(summary);
+          }
+        }
+      });
     }
-    Err(ArmoryFailure::Database("delete_guild".to_owned()))
+    guilds.remove(&id).ok_or(ArmoryFailure::Database("Invalid guild id o.O".to_owned())).and_then(|_| Ok(()))
   }
 
   fn delete_guild_by_uid(&self, server_id: u32, uid: u64) -> Result<(), ArmoryFailure> {
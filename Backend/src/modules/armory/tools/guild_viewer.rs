@@ -1,11 +1,46 @@
+use crate::dto::{SearchCursor, SearchResult};
 use crate::modules::armory::Armory;
-use crate::modules::armory::dto::{ArmoryFailure, GuildViewerDto, GuildViewerMemberDto};
+use crate::modules::armory::domain_value::GuildTransition;
+use crate::modules::armory::dto::{ArmoryFailure, GuildRosterSizeDto, GuildTimelineDto, GuildTimelineEventDto, GuildTimelineEventType, GuildTimelineFilter, GuildViewerDto, GuildViewerMemberDto};
 use crate::modules::data::Data;
+use crate::modules::armory::repository::GuildRepository;
 use crate::modules::armory::tools::GetGuild;
 use crate::modules::data::tools::RetrieveRace;
 
+const DEFAULT_PAGE_SIZE: u32 = 20;
+const MAX_PAGE_SIZE: u32 = 100;
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct GuildTimelineCursor {
+    guild_id: u32,
+    timestamp: u64,
+    id: u32,
+}
+
+impl GuildTransition {
+    fn into_event(self, guild_id: u32) -> GuildTimelineEventDto {
+        let rank = if self.guild_id == Some(guild_id) { Some(self.guild_rank.unwrap_or_default()) } else { None };
+        let previous_rank = if self.previous_guild_id == Some(guild_id) { Some(self.previous_guild_rank.unwrap_or_default()) } else { None };
+        let event_type = match (&previous_rank, &rank) {
+            (None, _) => GuildTimelineEventType::Join,
+            (_, None) => GuildTimelineEventType::Leave,
+            _ => GuildTimelineEventType::RankChange
+        };
+        GuildTimelineEventDto {
+            character_id: self.character_id,
+            character_name: self.character_name,
+            event_type,
+            rank,
+            previous_rank,
+            timestamp: self.timestamp,
+        }
+    }
+}
+
 pub trait GuildViewer {
     fn get_guild_view(&self, data: &Data, language_id: u8, guild_id: u32) -> Result<GuildViewerDto, ArmoryFailure>;
+    fn get_guild_timeline(&self, guild_id: u32, filter: GuildTimelineFilter) -> Result<GuildTimelineDto, ArmoryFailure>;
 }
 
 impl GuildViewer for Armory {
@@ -16,23 +51,19 @@ impl GuildViewer for Armory {
         }
         let guild = guild.unwrap();
 
-        let characters = self.characters.read().unwrap();
-        let member = characters.iter()
-            .filter(|(_, character)| character.last_update.is_some())
-            .filter(|(_, character)| character.last_update.as_ref().unwrap().character_guild.is_some())
-            .filter(|(_, character)| character.last_update.as_ref().unwrap().character_guild.as_ref().unwrap().guild_id == guild_id)
-            .map(|(character_id, character)| {
-                let last_update = character.last_update.as_ref().unwrap();
-                let race = data.get_race(last_update.character_info.race_id).unwrap();
+        let character_search_index = self.character_search_index.read().unwrap();
+        let member = character_search_index.guild_members(guild_id).into_iter()
+            .map(|character| {
+                let race = data.get_race(character.race_id).unwrap();
 
                 GuildViewerMemberDto {
-                    character_id: character_id.clone(),
-                    character_name: last_update.character_name.clone(),
+                    character_id: character.character_id,
+                    character_name: character.name.clone(),
                     faction: race.faction,
                     race_id: race.id,
-                    hero_class_id: last_update.character_info.hero_class_id,
-                    rank: last_update.character_guild.as_ref().unwrap().rank.clone(),
-                    last_seen: last_update.timestamp
+                    hero_class_id: character.hero_class_id,
+                    rank: character.guild.as_ref().unwrap().rank.clone(),
+                    last_seen: character.timestamp
                 }
             }).collect();
 
@@ -42,4 +73,55 @@ impl GuildViewer for Armory {
             member
         })
     }
+
+    fn get_guild_timeline(&self, guild_id: u32, filter: GuildTimelineFilter) -> Result<GuildTimelineDto, ArmoryFailure> {
+        let guild = self.get_guild(guild_id).ok_or(ArmoryFailure::InvalidInput)?;
+        let page_size = filter.page_size.unwrap_or(DEFAULT_PAGE_SIZE).max(1).min(MAX_PAGE_SIZE);
+
+        // Keyset pagination continues after the cursor, otherwise the page is used as offset
+        let (cursor_timestamp, cursor_id, offset) = match filter.cursor.as_ref() {
+            Some(cursor) => {
+                let cursor = GuildTimelineCursor::from_cursor(cursor)
+                    .filter(|cursor| cursor.guild_id == guild_id)
+                    .ok_or(ArmoryFailure::InvalidInput)?;
+                (cursor.timestamp, cursor.id, 0)
+            },
+            None => (u64::MAX, u32::MAX, filter.page * page_size)
+        };
+
+        let num_items = self.backend.count_guild_events(guild_id)
+            .map_err(|_| ArmoryFailure::Database("get_guild_timeline".to_owned()))?;
+
+        // Newest events first, one more than requested tells whether there is a next page
+        let mut transitions = self.backend.load_guild_events(guild_id, (cursor_timestamp, cursor_id), offset, page_size + 1)
+            .map_err(|_| ArmoryFailure::Database("get_guild_timeline".to_owned()))?;
+
+        let next_cursor = if transitions.len() > page_size as usize {
+            transitions.truncate(page_size as usize);
+            transitions.last().map(|transition| GuildTimelineCursor { guild_id, timestamp: transition.timestamp, id: transition.id }.to_cursor())
+        } else {
+            None
+        };
+        let result = transitions.into_iter().map(|transition| transition.into_event(guild_id)).collect();
+
+        // Joins and leaves per day, accumulated to the size of the roster at the end of each day
+        let roster_changes = self.backend.load_guild_roster_changes(guild_id, SECONDS_PER_DAY)
+            .map_err(|_| ArmoryFailure::Database("get_guild_timeline".to_owned()))?;
+        let mut size: i64 = 0;
+        let roster_size = roster_changes.into_iter().map(|(day, change)| {
+            size = (size + change).max(0);
+            GuildRosterSizeDto { timestamp: day, size: size as u32 }
+        }).collect();
+
+        Ok(GuildTimelineDto {
+            guild_id,
+            guild_name: guild.name,
+            events: SearchResult {
+                result,
+                num_items: num_items as usize,
+                next_cursor,
+            },
+            roster_size,
+        })
+    }
 }
\ No newline at end of file
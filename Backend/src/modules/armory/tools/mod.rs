@@ -7,6 +7,7 @@ pub use self::character_info::*;
 pub use self::character_item::*;
 pub use self::character_search::PerformCharacterSearch;
 pub use self::character_viewer::CharacterViewer;
+pub use self::character_diff::CharacterDiff;
 pub use self::talent_specialization::*;
 pub use self::get_character_item_stats::get_character_stats;
 pub use self::guild_viewer::GuildViewer;
@@ -20,6 +21,7 @@ mod character_info;
 mod character_item;
 mod character_search;
 mod character_viewer;
+mod character_diff;
 mod talent_specialization;
 mod get_character_item_stats;
 mod guild_viewer;
\ No newline at end of file
@@ -3,7 +3,7 @@ use rocket_contrib::json::Json;
 
 use crate::modules::account::guard::ServerOwner;
 use crate::modules::armory::Armory;
-use crate::modules::armory::dto::{ArmoryFailure, CharacterDto};
+use crate::modules::armory::dto::{ArmoryFailure, CharacterDto, SetCharacterResult};
 use crate::modules::armory::material::Character;
 use crate::modules::armory::tools::{DeleteCharacter, GetCharacter, SetCharacter};
 
@@ -14,6 +14,13 @@ pub fn set_character(me: State<Armory>, owner: ServerOwner, character: Json<Char
   me.set_character(owner.0, character.into_inner()).and_then(|_| Ok(()))
 }
 
+#[openapi]
+#[post("/character/batch", format = "application/json", data = "<characters>")]
+pub fn set_characters(me: State<Armory>, owner: ServerOwner, characters: Json<Vec<CharacterDto>>) -> Result<Json<Vec<SetCharacterResult>>, ArmoryFailure>
+{
+  me.set_characters(owner.0, characters.into_inner()).and_then(|results| Ok(Json(results)))
+}
+
 #[openapi]
 #[get("/character/<id>")]
 pub fn get_character(me: State<Armory>, id: u32) -> Result<Json<Character>, ArmoryFailure>
@@ -3,13 +3,14 @@ use rocket_contrib::json::Json;
 
 use crate::dto::SearchResult;
 use crate::modules::armory::Armory;
-use crate::modules::armory::dto::{CharacterSearchFilter, CharacterSearchResult};
+use crate::modules::armory::dto::{ArmoryFailure, CharacterSearchFilter, CharacterSearchResult};
 use crate::modules::armory::tools::PerformCharacterSearch;
-use crate::modules::data::Data;
+use crate::modules::data::DataStore;
 
 #[openapi]
 #[post("/character_search", format = "application/json", data = "<filter>")]
-pub fn get_character_search_result(me: State<Armory>, data: State<Data>, filter: Json<CharacterSearchFilter>) -> Json<SearchResult<CharacterSearchResult>>
+pub fn get_character_search_result(me: State<Armory>, data: State<DataStore>, filter: Json<CharacterSearchFilter>) -> Result<Json<SearchResult<CharacterSearchResult>>, ArmoryFailure>
 {
-  Json(me.get_character_search_result(&data, filter.into_inner()))
+  let data = data.snapshot();
+  me.get_character_search_result(&data, filter.into_inner()).map(Json)
 }
\ No newline at end of file
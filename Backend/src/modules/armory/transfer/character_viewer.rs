@@ -2,16 +2,17 @@ use rocket::State;
 use rocket_contrib::json::Json;
 
 use crate::modules::armory::Armory;
-use crate::modules::armory::dto::{CharacterViewerDto, ArmoryFailure};
-use crate::modules::armory::tools::{CharacterViewer, GetCharacter};
-use crate::modules::data::Data;
+use crate::modules::armory::dto::{CharacterViewerDto, ArmoryFailure, CharacterDiffDto};
+use crate::modules::armory::tools::{CharacterViewer, GetCharacter, CharacterDiff};
+use crate::modules::data::DataStore;
 use crate::modules::data::guard::Language;
 use crate::modules::data::tools::RetrieveServer;
 
 #[openapi]
 #[get("/character_viewer/<server_name>/<character_name>")]
-pub fn get_character_viewer(me: State<Armory>, data: State<Data>, language: Language, server_name: String, character_name: String) -> Result<Json<CharacterViewerDto>, ArmoryFailure>
+pub fn get_character_viewer(me: State<Armory>, data: State<DataStore>, language: Language, server_name: String, character_name: String) -> Result<Json<CharacterViewerDto>, ArmoryFailure>
 {
+    let data = data.snapshot();
     data.get_server_by_name(server_name).ok_or(ArmoryFailure::InvalidInput)
         .and_then(|server|
             me.get_character_by_name(server.id, character_name).ok_or(ArmoryFailure::InvalidInput)
@@ -22,12 +23,26 @@ pub fn get_character_viewer(me: State<Armory>, data: State<Data>, language: Lang
 
 #[openapi]
 #[get("/character_viewer/<server_name>/<character_name>/<character_history_id>")]
-pub fn get_character_viewer_by_history(me: State<Armory>, data: State<Data>, language: Language, server_name: String, character_name: String, character_history_id: u32) -> Result<Json<CharacterViewerDto>, ArmoryFailure>
+pub fn get_character_viewer_by_history(me: State<Armory>, data: State<DataStore>, language: Language, server_name: String, character_name: String, character_history_id: u32) -> Result<Json<CharacterViewerDto>, ArmoryFailure>
 {
+    let data = data.snapshot();
     data.get_server_by_name(server_name).ok_or(ArmoryFailure::InvalidInput)
         .and_then(|server|
             me.get_character_by_name(server.id, character_name).ok_or(ArmoryFailure::InvalidInput)
                 .and_then(|character|
                     me.get_character_viewer_by_history_id(&data, language.0, character_history_id, character.id)
                         .and_then(|result| Ok(Json(result)))))
+}
+
+#[openapi]
+#[get("/character_viewer/<server_name>/<character_name>/<from_character_history_id>/<to_character_history_id>")]
+pub fn get_character_diff(me: State<Armory>, data: State<DataStore>, language: Language, server_name: String, character_name: String, from_character_history_id: u32, to_character_history_id: u32) -> Result<Json<CharacterDiffDto>, ArmoryFailure>
+{
+    let data = data.snapshot();
+    data.get_server_by_name(server_name).ok_or(ArmoryFailure::InvalidInput)
+        .and_then(|server|
+            me.get_character_by_name(server.id, character_name).ok_or(ArmoryFailure::InvalidInput)
+                .and_then(|character|
+                    me.get_character_diff(&data, language.0, character.id, from_character_history_id, to_character_history_id)
+                        .and_then(|result| Ok(Json(result)))))
 }
\ No newline at end of file
@@ -2,20 +2,34 @@ use rocket::State;
 use rocket_contrib::json::Json;
 
 use crate::modules::armory::Armory;
-use crate::modules::armory::dto::{ArmoryFailure, GuildViewerDto};
+use crate::modules::armory::dto::{ArmoryFailure, GuildTimelineDto, GuildTimelineFilter, GuildViewerDto};
 use crate::modules::armory::tools::{GetGuild, GuildViewer};
-use crate::modules::data::Data;
+use crate::modules::data::DataStore;
 use crate::modules::data::guard::Language;
 use crate::modules::data::tools::RetrieveServer;
 
 #[openapi]
 #[get("/guild_viewer/<server_name>/<guild_name>")]
-pub fn get_guild_view(me: State<Armory>, data: State<Data>, language: Language, server_name: String, guild_name: String) -> Result<Json<GuildViewerDto>, ArmoryFailure>
+pub fn get_guild_view(me: State<Armory>, data: State<DataStore>, language: Language, server_name: String, guild_name: String) -> Result<Json<GuildViewerDto>, ArmoryFailure>
 {
+    let data = data.snapshot();
     data.get_server_by_name(server_name).ok_or(ArmoryFailure::InvalidInput)
         .and_then(|server|
             me.get_guild_by_name(server.id, guild_name).ok_or(ArmoryFailure::InvalidInput)
                 .and_then(|guild|
                     me.get_guild_view(&data, language.0, guild.id)
                         .and_then(|result| Ok(Json(result)))))
+}
+
+#[openapi]
+#[post("/guild_viewer/<server_name>/<guild_name>/timeline", format = "application/json", data = "<filter>")]
+pub fn get_guild_timeline(me: State<Armory>, data: State<DataStore>, server_name: String, guild_name: String, filter: Json<GuildTimelineFilter>) -> Result<Json<GuildTimelineDto>, ArmoryFailure>
+{
+    let data = data.snapshot();
+    data.get_server_by_name(server_name).ok_or(ArmoryFailure::InvalidInput)
+        .and_then(|server|
+            me.get_guild_by_name(server.id, guild_name).ok_or(ArmoryFailure::InvalidInput)
+                .and_then(|guild|
+                    me.get_guild_timeline(guild.id, filter.into_inner())
+                        .and_then(|result| Ok(Json(result)))))
 }
\ No newline at end of file
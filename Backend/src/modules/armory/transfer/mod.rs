@@ -3,4 +3,5 @@ pub mod guild;
 pub mod character_history;
 pub mod character_search;
 pub mod character_viewer;
-pub mod guild_viewer;
\ No newline at end of file
+pub mod guild_viewer;
+pub mod moderation;
\ No newline at end of file
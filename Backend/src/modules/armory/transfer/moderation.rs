@@ -0,0 +1,29 @@
+use rocket::State;
+use rocket_contrib::json::Json;
+
+use crate::modules::account::guard::Moderator;
+use crate::modules::armory::Armory;
+use crate::modules::armory::dto::ArmoryFailure;
+use crate::modules::armory::tools::{DeleteCharacter, DeleteGuild, GetGuild, UpdateGuild};
+
+#[openapi]
+#[delete("/moderation/character/<id>")]
+pub fn moderate_delete_character(me: State<Armory>, _moderator: Moderator, id: u32) -> Result<(), ArmoryFailure>
+{
+  me.delete_character(id)
+}
+
+#[openapi]
+#[delete("/moderation/guild/<id>")]
+pub fn moderate_delete_guild(me: State<Armory>, _moderator: Moderator, id: u32) -> Result<(), ArmoryFailure>
+{
+  me.delete_guild(id)
+}
+
+#[openapi]
+#[post("/moderation/guild/<id>", format = "application/json", data = "<guild_name>")]
+pub fn moderate_guild_name(me: State<Armory>, _moderator: Moderator, id: u32, guild_name: Json<String>) -> Result<(), ArmoryFailure>
+{
+  let guild = me.get_guild(id).ok_or(ArmoryFailure::InvalidInput)?;
+  me.update_guild_name(guild.server_id, guild.server_uid, guild_name.into_inner())
+}
\ No newline at end of file
@@ -13,5 +13,15 @@ pub struct Item {
   pub itemset: Option<u16>,
   pub max_durability: Option<u16>,
   pub item_level: Option<u16>,
-  pub delay: Option<u16>
+  pub delay: Option<u16>,
+  // Bit (hero_class_id - 1) for each hero class that may use the item, None if every hero class may
+  pub allowable_class: Option<u32>
+}
+
+impl Item {
+  pub fn is_usable_by(&self, hero_class_id: u8) -> bool {
+    self.allowable_class
+      .map(|allowable_class| hero_class_id > 0 && hero_class_id <= 32 && allowable_class & (1 << (hero_class_id - 1)) != 0)
+      .unwrap_or(true)
+  }
 }
\ No newline at end of file
@@ -26,6 +26,7 @@ pub use self::profession::Profession;
 pub use self::race::Race;
 pub use self::server::Server;
 pub use self::spell::Spell;
+pub use self::spell_caster::SpellCaster;
 pub use self::spell_effect::SpellEffect;
 pub use self::stat::Stat;
 pub use self::stat_type::StatType;
@@ -42,6 +43,7 @@ mod profession;
 mod server;
 mod hero_class;
 mod spell;
+mod spell_caster;
 mod dispel_type;
 mod power_type;
 mod stat_type;
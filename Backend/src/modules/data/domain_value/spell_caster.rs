@@ -0,0 +1,6 @@
+// The character a spell description is shown for, resolving gender and conditions of the template
+#[derive(Debug, Clone, Default)]
+pub struct SpellCaster {
+  pub is_female: bool,
+  pub known_spell_ids: Vec<u32>,
+}
\ No newline at end of file
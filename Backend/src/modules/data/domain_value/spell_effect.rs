@@ -6,5 +6,7 @@ pub struct SpellEffect {
   pub points_lower: i32,
   pub points_upper: i32,
   pub chain_targets: u16,
-  pub radius: u32
+  pub radius: u32,
+  pub aura: u16,
+  pub misc_value: i32
 }
\ No newline at end of file
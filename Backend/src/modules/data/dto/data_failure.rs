@@ -0,0 +1,63 @@
+use okapi::openapi3::Responses;
+use rocket::{Request, Response};
+use rocket::http::Status;
+use rocket::response::Responder;
+use rocket_okapi::gen::OpenApiGenerator;
+use rocket_okapi::response::OpenApiResponder;
+use rocket_okapi::util::add_schema_response;
+use schemars::JsonSchema;
+use std::io::Cursor;
+
+#[derive(Debug, PartialEq, JsonSchema)]
+pub enum DataFailure {
+  InvalidInput,
+  Database(String),
+  NotServerOwner,
+  ServerAlreadyOwned,
+  ReloadInProgress
+}
+
+impl Responder<'static> for DataFailure {
+  fn respond_to(self, _: &Request) -> Result<Response<'static>, Status> {
+    let body;
+    let status = match self {
+      DataFailure::InvalidInput => {
+        body = "Invalid input!".to_owned();
+        Status::new(534, "InvalidInput")
+      },
+      DataFailure::Database(hint) => {
+        body = hint.clone();
+        Status::new(535, "Database")
+      },
+      DataFailure::NotServerOwner => {
+        body = "Not the owner of this server!".to_owned();
+        Status::new(537, "NotServerOwner")
+      },
+      DataFailure::ServerAlreadyOwned => {
+        body = "This server is already owned!".to_owned();
+        Status::new(538, "ServerAlreadyOwned")
+      },
+      DataFailure::ReloadInProgress => {
+        body = "A reload is already in progress!".to_owned();
+        Status::new(539, "ReloadInProgress")
+      }
+    };
+    Response::build()
+      .status(status)
+      .sized_body(Cursor::new(body))
+      .ok()
+  }
+}
+
+impl OpenApiResponder<'static> for DataFailure {
+  fn responses(gen: &mut OpenApiGenerator) -> rocket_okapi::Result<Responses> {
+    let mut responses = Responses::default();
+    let schema = gen.json_schema::<String>();
+    add_schema_response(&mut responses, 534, "text/plain", schema.clone())?;
+    add_schema_response(&mut responses, 535, "text/plain", schema.clone())?;
+    add_schema_response(&mut responses, 537, "text/plain", schema.clone())?;
+    add_schema_response(&mut responses, 538, "text/plain", schema.clone())?;
+    add_schema_response(&mut responses, 539, "text/plain", schema.clone())?;
+    Ok(responses)
+  }
+}
\ No newline at end of file
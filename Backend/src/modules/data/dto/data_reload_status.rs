@@ -0,0 +1,6 @@
+#[derive(Debug, Clone, Default, Serialize, JsonSchema)]
+pub struct DataReloadStatus {
+  pub reloading: bool,
+  pub last_reload: Option<u64>,
+  pub last_failure: Option<String>,
+}
\ No newline at end of file
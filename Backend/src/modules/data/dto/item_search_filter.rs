@@ -0,0 +1,21 @@
+use crate::dto::{RangeFilter, TableFilter};
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ItemSearchFilter {
+  pub page: u32,
+  pub page_size: Option<u32>,
+  pub cursor: Option<String>,
+  pub name: TableFilter<String>,
+  pub expansion_id: TableFilter<u8>,
+  pub quality: TableFilter<u8>,
+  pub inventory_type: TableFilter<u8>,
+  pub class_id: TableFilter<u8>,
+  pub item_level: TableFilter<RangeFilter<u16>>,
+  pub required_level: TableFilter<RangeFilter<u8>>,
+  // Every listed stat type has to be present on the item
+  pub stat_types: Option<Vec<u8>>,
+  // Only items of an itemset if true, only items without itemset if false. Sorted by the itemset id.
+  pub itemset: TableFilter<bool>,
+  // Only items that this hero class may use
+  pub hero_class_id: Option<u8>,
+}
\ No newline at end of file
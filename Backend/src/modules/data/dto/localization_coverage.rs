@@ -0,0 +1,9 @@
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct LocalizationCoverage {
+  pub language_id: u8,
+  pub num_entries: usize,
+  // Requested entries that were served in english instead
+  pub fallbacks: Vec<u32>,
+  // Requested entries that exist in neither language and were served as placeholder
+  pub placeholders: Vec<u32>,
+}
\ No newline at end of file
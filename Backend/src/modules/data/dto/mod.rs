@@ -1,3 +1,19 @@
 pub use self::available_server::AvailableServer;
+pub use self::data_failure::DataFailure;
+pub use self::data_reload_status::DataReloadStatus;
+pub use self::item_search_filter::ItemSearchFilter;
+pub use self::server_transfer::ServerTransfer;
+pub use self::localization_coverage::LocalizationCoverage;
+pub use self::translation_coverage::TranslationCoverage;
+pub use self::translation_failure::TranslationFailure;
+pub use self::translation_import_result::{TranslationImportResult, TranslationRejection};
 
-mod available_server;
\ No newline at end of file
+mod available_server;
+mod data_failure;
+mod data_reload_status;
+mod item_search_filter;
+mod server_transfer;
+mod localization_coverage;
+mod translation_coverage;
+mod translation_failure;
+mod translation_import_result;
\ No newline at end of file
@@ -0,0 +1,4 @@
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ServerTransfer {
+  pub new_owner_id: u32
+}
\ No newline at end of file
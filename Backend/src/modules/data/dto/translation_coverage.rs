@@ -0,0 +1,10 @@
+#[derive(Debug, Clone, PartialEq)]
+pub struct TranslationCoverage {
+  pub language_id: u8,
+  pub table: String,
+  // Entries of the table that exist in english
+  pub num_source: usize,
+  pub num_translated: usize,
+  // Translations whose english source changed since they have been imported
+  pub num_outdated: usize,
+}
\ No newline at end of file
@@ -0,0 +1,22 @@
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TranslationFailure {
+  Database(String),
+  InvalidFile(usize, String),
+  InvalidLanguage(String),
+  Io(String),
+  InvalidCommand(String),
+}
+
+impl fmt::Display for TranslationFailure {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      TranslationFailure::Database(reason) => write!(f, "Database error: {}", reason),
+      TranslationFailure::InvalidFile(line_number, reason) => write!(f, "Invalid translation file in line {}: {}", line_number, reason),
+      TranslationFailure::InvalidLanguage(language) => write!(f, "Unknown language '{}'", language),
+      TranslationFailure::Io(reason) => write!(f, "{}", reason),
+      TranslationFailure::InvalidCommand(usage) => write!(f, "{}", usage),
+    }
+  }
+}
\ No newline at end of file
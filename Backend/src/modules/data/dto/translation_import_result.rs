@@ -0,0 +1,12 @@
+#[derive(Debug, Clone, PartialEq)]
+pub struct TranslationRejection {
+  pub localization_id: u32,
+  pub reason: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TranslationImportResult {
+  pub num_imported: usize,
+  pub num_skipped: usize,
+  pub rejections: Vec<TranslationRejection>,
+}
\ No newline at end of file
@@ -8,7 +8,7 @@ use rocket_okapi::gen::OpenApiGenerator;
 use rocket_okapi::request::OpenApiFromRequest;
 use rocket_okapi::response::OpenApiResponder;
 
-use crate::modules::data::Data;
+use crate::modules::data::DataStore;
 use crate::modules::data::tools::RetrieveExpansion;
 
 pub struct Expansion(pub u8);
@@ -28,12 +28,12 @@ impl<'a, 'r> FromRequest<'a, 'r> for Expansion {
     }
     let expansion = expansion_res.unwrap();
 
-    let data_res = req.guard::<State<'_, Data>>();
+    let data_res = req.guard::<State<'_, DataStore>>();
     if data_res.is_failure() {
       return Failure((Status::NotFound, ()));
     }
 
-    let data = data_res.unwrap();
+    let data = data_res.unwrap().snapshot();
     match data.get_expansion(expansion) {
       Some(_) => Success(Expansion(expansion)),
       None => Failure((Status::NotFound, ()))
@@ -8,7 +8,7 @@ use rocket_okapi::gen::OpenApiGenerator;
 use rocket_okapi::request::OpenApiFromRequest;
 use rocket_okapi::response::OpenApiResponder;
 
-use crate::modules::data::Data;
+use crate::modules::data::DataStore;
 use crate::modules::data::tools::RetrieveLanguage;
 
 pub struct Language(pub u8);
@@ -23,12 +23,12 @@ impl<'a, 'r> FromRequest<'a, 'r> for Language {
     }
 
     let lang_short_code = lang_header.unwrap().to_lowercase();
-    let data_res = req.guard::<State<'_, Data>>();
+    let data_res = req.guard::<State<'_, DataStore>>();
     if data_res.is_failure() {
       return Success(Language(1));
     }
 
-    let data = data_res.unwrap();
+    let data = data_res.unwrap().snapshot();
     let language = data.get_language_by_short_code(lang_short_code).and_then(|language| Some(language.id));
     if language.is_none() {
       return Success(Language(1));
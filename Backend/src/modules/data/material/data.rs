@@ -1,22 +1,26 @@
 use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
 
 use language::material::Dictionary;
 use mysql_connection::material::MySQLConnection;
-use mysql_connection::tools::Select;
 
-use crate::modules::data::domain_value::{DispelType, Enchant, Expansion, Gem, HeroClass, Icon, Item, ItemBonding, ItemClass, ItemDamage, ItemDamageType, ItemEffect, ItemInventoryType, ItemQuality, ItemRandomProperty, ItemsetEffect, ItemsetName, ItemSheath, ItemSocket, ItemStat, Language, Localization, NPC, PowerType, Profession, Race, Server, Spell, SpellEffect, Stat, StatType, Title, ItemRandomPropertyPoints, HeroClassTalent};
+use crate::modules::data::domain_value::{DispelType, Enchant, Expansion, Gem, HeroClass, Icon, Item, ItemBonding, ItemClass, ItemDamage, ItemDamageType, ItemEffect, ItemInventoryType, ItemQuality, ItemRandomProperty, ItemsetEffect, ItemsetName, ItemSheath, ItemSocket, ItemStat, Language, Localization, NPC, PowerType, Profession, Race, Server, Spell, SpellEffect, StatType, Title, ItemRandomPropertyPoints};
 use crate::modules::data::language::init::Init as DictionaryInit;
+use crate::modules::data::material::{ItemSearchIndex, LocalizationResolver};
+use crate::modules::data::repository::{DataBackend, DataTableRepository, MySQLDataBackend};
 
 #[derive(Debug)]
 pub struct Data {
-  pub db_main: MySQLConnection,
+  pub backend: Arc<dyn DataBackend>,
   pub dictionary: Dictionary,
   pub expansions: HashMap<u8, Expansion>,
   pub languages: HashMap<u8, Language>,
-  pub localization: Vec<HashMap<u32, Localization>>,
+  pub localization: HashMap<u8, HashMap<u32, Localization>>,
+  pub localization_resolver: LocalizationResolver,
   pub races: HashMap<u8, Race>,
   pub professions: HashMap<u16, Profession>,
-  pub servers: HashMap<u32, Server>,
+  // Servers carry runtime state like their owner, hence they are shared by all snapshots instead of being reloaded
+  pub servers: Arc<RwLock<HashMap<u32, Server>>>,
   pub hero_classes: HashMap<u8, HeroClass>,
   pub spells: Vec<HashMap<u32, Spell>>,
   pub dispel_types: HashMap<u8, DispelType>,
@@ -43,22 +47,31 @@ pub struct Data {
   pub itemset_effects: Vec<HashMap<u16, Vec<ItemsetEffect>>>,
   pub titles: HashMap<u16, Title>,
   pub item_random_property_points: HashMap<u8, Vec<ItemRandomPropertyPoints>>,
+  pub item_search_index: ItemSearchIndex,
 }
 
 impl Default for Data {
   fn default() -> Self
+  {
+    Data::with_backend(Arc::new(MySQLDataBackend::new(MySQLConnection::new("main"))))
+  }
+}
+
+impl Data {
+  pub fn with_backend(backend: Arc<dyn DataBackend>) -> Self
   {
     let dictionary = Dictionary::default();
     Dictionary::init(&dictionary);
     Data {
-      db_main: MySQLConnection::new("main"),
+      backend,
       dictionary,
       expansions: HashMap::new(),
       languages: HashMap::new(),
-      localization: Vec::new(),
+      localization: HashMap::new(),
+      localization_resolver: LocalizationResolver::default(),
       races: HashMap::new(),
       professions: HashMap::new(),
-      servers: HashMap::new(),
+      servers: Arc::new(RwLock::new(HashMap::new())),
       hero_classes: HashMap::new(),
       spells: Vec::new(),
       dispel_types: HashMap::new(),
@@ -85,48 +98,57 @@ impl Default for Data {
       itemset_effects: Vec::new(),
       titles: HashMap::new(),
       item_random_property_points: HashMap::new(),
+      item_search_index: ItemSearchIndex::default(),
     }
   }
-}
 
-impl Data {
-  pub fn init(mut self, debug_collection: Option<u8>) -> Self
+  pub fn init(mut self, debug_collection: Option<u8>) -> Result<Self, String>
   {
     let init_flag = debug_collection.unwrap_or(0);
 
-    if self::Data::should_init(init_flag, 1) { self.expansions.init(&self.db_main); }
-    if self::Data::should_init(init_flag, 2) { self.languages.init(&self.db_main); }
-    if self::Data::should_init(init_flag, 3) { self.localization.init(&self.db_main); }
-    if self::Data::should_init(init_flag, 4) { self.races.init(&self.db_main); }
-    if self::Data::should_init(init_flag, 5) { self.professions.init(&self.db_main); }
-    if self::Data::should_init(init_flag, 6) { self.servers.init(&self.db_main); }
-    if self::Data::should_init(init_flag, 7) { self.hero_classes.init(&self.db_main); }
-    if self::Data::should_init(init_flag, 8) { self.spells.init(&self.db_main); }
-    if self::Data::should_init(init_flag, 9) { self.dispel_types.init(&self.db_main); }
-    if self::Data::should_init(init_flag, 10) { self.power_types.init(&self.db_main); }
-    if self::Data::should_init(init_flag, 11) { self.stat_types.init(&self.db_main); }
-    if self::Data::should_init(init_flag, 12) { self.spell_effects.init(&self.db_main); }
-    if self::Data::should_init(init_flag, 13) { self.npcs.init(&self.db_main); }
-    if self::Data::should_init(init_flag, 14) { self.icons.init(&self.db_main); }
-    if self::Data::should_init(init_flag, 15) { self.items.init(&self.db_main); }
-    if self::Data::should_init(init_flag, 16) { self.gems.init(&self.db_main); }
-    if self::Data::should_init(init_flag, 17) { self.enchants.init(&self.db_main); }
-    if self::Data::should_init(init_flag, 18) { self.item_bondings.init(&self.db_main); }
-    if self::Data::should_init(init_flag, 19) { self.item_classes.init(&self.db_main); }
-    if self::Data::should_init(init_flag, 20) { self.item_damages.init(&self.db_main); }
-    if self::Data::should_init(init_flag, 21) { self.item_damage_types.init(&self.db_main); }
-    if self::Data::should_init(init_flag, 22) { self.item_effects.init(&self.db_main); }
-    if self::Data::should_init(init_flag, 23) { self.item_inventory_types.init(&self.db_main); }
-    if self::Data::should_init(init_flag, 24) { self.item_qualities.init(&self.db_main); }
-    if self::Data::should_init(init_flag, 25) { self.item_random_properties.init(&self.db_main); }
-    if self::Data::should_init(init_flag, 26) { self.item_sheaths.init(&self.db_main); }
-    if self::Data::should_init(init_flag, 27) { self.item_sockets.init(&self.db_main); }
-    if self::Data::should_init(init_flag, 28) { self.item_stats.init(&self.db_main); }
-    if self::Data::should_init(init_flag, 29) { self.itemset_names.init(&self.db_main); }
-    if self::Data::should_init(init_flag, 30) { self.itemset_effects.init(&self.db_main); }
-    if self::Data::should_init(init_flag, 31) { self.titles.init(&self.db_main); }
-    if self::Data::should_init(init_flag, 32) { self.item_random_property_points.init(&self.db_main); }
-    self
+    if self::Data::should_init(init_flag, 1) { self.expansions.init(self.backend.as_ref())?; }
+    if self::Data::should_init(init_flag, 2) { self.languages.init(self.backend.as_ref())?; }
+    if self::Data::should_init(init_flag, 3) { self.localization.init(self.backend.as_ref())?; }
+    if self::Data::should_init(init_flag, 4) { self.races.init(self.backend.as_ref())?; }
+    if self::Data::should_init(init_flag, 5) { self.professions.init(self.backend.as_ref())?; }
+    if self::Data::should_init(init_flag, 6) { self.servers.write().unwrap().init(self.backend.as_ref())?; }
+    if self::Data::should_init(init_flag, 7) { self.hero_classes.init(self.backend.as_ref())?; }
+    if self::Data::should_init(init_flag, 8) { self.spells.init(self.backend.as_ref())?; }
+    if self::Data::should_init(init_flag, 9) { self.dispel_types.init(self.backend.as_ref())?; }
+    if self::Data::should_init(init_flag, 10) { self.power_types.init(self.backend.as_ref())?; }
+    if self::Data::should_init(init_flag, 11) { self.stat_types.init(self.backend.as_ref())?; }
+    if self::Data::should_init(init_flag, 12) { self.spell_effects.init(self.backend.as_ref())?; }
+    if self::Data::should_init(init_flag, 13) { self.npcs.init(self.backend.as_ref())?; }
+    if self::Data::should_init(init_flag, 14) { self.icons.init(self.backend.as_ref())?; }
+    if self::Data::should_init(init_flag, 15) { self.items.init(self.backend.as_ref())?; }
+    if self::Data::should_init(init_flag, 16) { self.gems.init(self.backend.as_ref())?; }
+    if self::Data::should_init(init_flag, 17) { self.enchants.init(self.backend.as_ref())?; }
+    if self::Data::should_init(init_flag, 18) { self.item_bondings.init(self.backend.as_ref())?; }
+    if self::Data::should_init(init_flag, 19) { self.item_classes.init(self.backend.as_ref())?; }
+    if self::Data::should_init(init_flag, 20) { self.item_damages.init(self.backend.as_ref())?; }
+    if self::Data::should_init(init_flag, 21) { self.item_damage_types.init(self.backend.as_ref())?; }
+    if self::Data::should_init(init_flag, 22) { self.item_effects.init(self.backend.as_ref())?; }
+    if self::Data::should_init(init_flag, 23) { self.item_inventory_types.init(self.backend.as_ref())?; }
+    if self::Data::should_init(init_flag, 24) { self.item_qualities.init(self.backend.as_ref())?; }
+    if self::Data::should_init(init_flag, 25) { self.item_random_properties.init(self.backend.as_ref())?; }
+    if self::Data::should_init(init_flag, 26) { self.item_sheaths.init(self.backend.as_ref())?; }
+    if self::Data::should_init(init_flag, 27) { self.item_sockets.init(self.backend.as_ref())?; }
+    if self::Data::should_init(init_flag, 28) { self.item_stats.init(self.backend.as_ref())?; }
+    if self::Data::should_init(init_flag, 29) { self.itemset_names.init(self.backend.as_ref())?; }
+    if self::Data::should_init(init_flag, 30) { self.itemset_effects.init(self.backend.as_ref())?; }
+    if self::Data::should_init(init_flag, 31) { self.titles.init(self.backend.as_ref())?; }
+    if self::Data::should_init(init_flag, 32) { self.item_random_property_points.init(self.backend.as_ref())?; }
+    // Built from items, item stats and localization, hence after those are loaded
+    if self::Data::should_init(init_flag, 33) { self.item_search_index = ItemSearchIndex::build(&self.items, &self.item_stats, &self.localization); }
+    Ok(self)
+  }
+
+  // A new snapshot of the static tables, sharing the servers of this one
+  pub fn reload(&self) -> Result<Self, String>
+  {
+    let mut data = Data::with_backend(Arc::clone(&self.backend));
+    data.servers = Arc::clone(&self.servers);
+    data.init(None)
   }
 
   fn should_init(init_flag: u8, trigger_flag: u8) -> bool {
@@ -137,196 +159,108 @@ impl Data {
 
 // Initializer for the collections
 trait Init {
-  fn init(&mut self, db: &MySQLConnection);
+  fn init(&mut self, repository: &dyn DataBackend) -> Result<(), String>;
 }
 
 impl Init for HashMap<u8, Expansion> {
-  fn init(&mut self, db: &MySQLConnection) {
-    db.select("SELECT * FROM data_expansion", &|mut row| {
-      Expansion {
-        id: row.take(0).unwrap(),
-        localization_id: row.take(1).unwrap(),
-      }
-    }).iter().for_each(|result| { self.insert(result.id, result.to_owned()); });
+  fn init(&mut self, repository: &dyn DataBackend) -> Result<(), String> {
+    repository.load_expansions()?.iter().for_each(|result| { self.insert(result.id, result.to_owned()); });
+    Ok(())
   }
 }
 
 impl Init for HashMap<u8, Language> {
-  fn init(&mut self, db: &MySQLConnection) {
-    db.select("SELECT * FROM data_language", &|mut row| {
-      Language {
-        id: row.take(0).unwrap(),
-        name: row.take(1).unwrap(),
-        short_code: row.take(2).unwrap(),
-      }
-    }).iter().for_each(|result| { self.insert(result.id, result.to_owned()); });
+  fn init(&mut self, repository: &dyn DataBackend) -> Result<(), String> {
+    repository.load_languages()?.iter().for_each(|result| { self.insert(result.id, result.to_owned()); });
+    Ok(())
   }
 }
 
-impl Init for Vec<HashMap<u32, Localization>> {
-  fn init(&mut self, db: &MySQLConnection) {
-    let mut last_language_id = 0;
-    db.select("SELECT * FROM data_localization ORDER BY language_id, id", &|mut row| {
-      Localization {
-        language_id: row.take(0).unwrap(),
-        id: row.take(1).unwrap(),
-        content: row.take(2).unwrap(),
-      }
-    }).iter().for_each(|result| {
-      if result.language_id != last_language_id {
-        self.push(HashMap::new());
-        last_language_id = result.language_id;
-      }
-      self.get_mut(result.language_id as usize - 1).unwrap().insert(result.id, result.to_owned());
+// Keyed by language id, as languages are not required to be contiguous
+impl Init for HashMap<u8, HashMap<u32, Localization>> {
+  fn init(&mut self, repository: &dyn DataBackend) -> Result<(), String> {
+    repository.load_localizations()?.iter().for_each(|result| {
+      self.entry(result.language_id).or_insert_with(HashMap::new).insert(result.id, result.to_owned());
     });
+    Ok(())
   }
 }
 
 impl Init for HashMap<u8, Race> {
-  fn init(&mut self, db: &MySQLConnection) {
-    db.select("SELECT * FROM data_race", &|mut row| {
-      Race {
-        id: row.take(0).unwrap(),
-        localization_id: row.take(1).unwrap(),
-        faction: row.take(2).unwrap()
-      }
-    }).iter().for_each(|result| { self.insert(result.id, result.to_owned()); });
+  fn init(&mut self, repository: &dyn DataBackend) -> Result<(), String> {
+    repository.load_races()?.iter().for_each(|result| { self.insert(result.id, result.to_owned()); });
+    Ok(())
   }
 }
 
 impl Init for HashMap<u16, Profession> {
-  fn init(&mut self, db: &MySQLConnection) {
-    db.select("SELECT * FROM data_profession", &|mut row| {
-      Profession {
-        id: row.take(0).unwrap(),
-        localization_id: row.take(1).unwrap(),
-        icon: row.take(2).unwrap(),
-      }
-    }).iter().for_each(|result| { self.insert(result.id, result.to_owned()); });
+  fn init(&mut self, repository: &dyn DataBackend) -> Result<(), String> {
+    repository.load_professions()?.iter().for_each(|result| { self.insert(result.id, result.to_owned()); });
+    Ok(())
   }
 }
 
 impl Init for HashMap<u32, Server> {
-  fn init(&mut self, db: &MySQLConnection) {
-    db.select("SELECT * FROM data_server", &|mut row| {
-      Server {
-        id: row.take(0).unwrap(),
-        expansion_id: row.take(1).unwrap(),
-        name: row.take(2).unwrap(),
-        owner: row.take_opt(3).unwrap().ok(),
-      }
-    }).iter().for_each(|result| { self.insert(result.id, result.to_owned()); });
+  fn init(&mut self, repository: &dyn DataBackend) -> Result<(), String> {
+    repository.load_servers()?.iter().for_each(|result| { self.insert(result.id, result.to_owned()); });
+    Ok(())
   }
 }
 
 impl Init for HashMap<u8, HeroClass> {
-  fn init(&mut self, db: &MySQLConnection) {
-    db.select("SELECT * FROM data_hero_class", &|mut row| {
-      HeroClass {
-        id: row.take(0).unwrap(),
-        localization_id: row.take(1).unwrap(),
-        color: row.take(2).unwrap(),
-        talents: [HeroClassTalent { icon: 0, localization_id: 0 }; 3]
+  fn init(&mut self, repository: &dyn DataBackend) -> Result<(), String> {
+    repository.load_hero_classes()?.iter().for_each(|result| { self.insert(result.id, result.to_owned()); });
+
+    repository.load_hero_class_specs()?.iter().for_each(|(hero_class_id, index, talent)| {
+      // Specs of unknown hero classes are skipped
+      if let Some(hero_class) = self.get_mut(hero_class_id) {
+        hero_class.talents[*index as usize] = *talent;
       }
-    }).iter().for_each(|result| { self.insert(result.id, result.to_owned()); });
-
-    db.select("SELECT * FROM data_hero_class_spec", &|mut row| {
-      let hero_class_id: u8 = row.take(0).unwrap();
-      let index: u8 = row.take(1).unwrap();
-      let icon: u16 = row.take(2).unwrap();
-      let localization_id: u32 = row.take(3).unwrap();
-      (hero_class_id, index, icon, localization_id)
-    }).iter().for_each(|result| {
-      self.get_mut(&result.0).unwrap().talents[result.1 as usize] = HeroClassTalent {
-        icon: result.2,
-        localization_id: result.3
-      };
     });
+    Ok(())
   }
 }
 
 impl Init for Vec<HashMap<u32, Spell>> {
-  fn init(&mut self, db: &MySQLConnection) {
+  fn init(&mut self, repository: &dyn DataBackend) -> Result<(), String> {
     let mut last_expansion_id = 0;
-    db.select("SELECT * FROM data_spell ORDER BY expansion_id, id", &|mut row| {
-      Spell {
-        expansion_id: row.take(0).unwrap(),
-        id: row.take(1).unwrap(),
-        localization_id: row.take(2).unwrap(),
-        subtext_localization_id: row.take(3).unwrap(),
-        cost: row.take(4).unwrap(),
-        cost_in_percent: row.take(5).unwrap(),
-        power_type: row.take(6).unwrap(),
-        cast_time: row.take(7).unwrap(),
-        school_mask: row.take(8).unwrap(),
-        dispel_type: row.take(9).unwrap(),
-        range_max: row.take(10).unwrap(),
-        cooldown: row.take(11).unwrap(),
-        duration: row.take(12).unwrap(),
-        icon: row.take(13).unwrap(),
-        description_localization_id: row.take(14).unwrap(),
-        aura_localization_id: row.take(15).unwrap(),
-      }
-    }).iter().for_each(|result| {
+    repository.load_spells()?.iter().for_each(|result| {
       if result.expansion_id != last_expansion_id {
         self.push(HashMap::new());
         last_expansion_id = result.expansion_id;
       }
       self.get_mut(result.expansion_id as usize - 1).unwrap().insert(result.id, result.to_owned());
     });
+    Ok(())
   }
 }
 
 impl Init for HashMap<u8, DispelType> {
-  fn init(&mut self, db: &MySQLConnection) {
-    db.select("SELECT * FROM data_spell_dispel_type", &|mut row| {
-      DispelType {
-        id: row.take(0).unwrap(),
-        localization_id: row.take(1).unwrap(),
-        color: row.take(2).unwrap(),
-      }
-    }).iter().for_each(|result| { self.insert(result.id, result.to_owned()); });
+  fn init(&mut self, repository: &dyn DataBackend) -> Result<(), String> {
+    repository.load_dispel_types()?.iter().for_each(|result| { self.insert(result.id, result.to_owned()); });
+    Ok(())
   }
 }
 
 impl Init for HashMap<u8, PowerType> {
-  fn init(&mut self, db: &MySQLConnection) {
-    db.select("SELECT * FROM data_spell_power_type", &|mut row| {
-      PowerType {
-        id: row.take(0).unwrap(),
-        localization_id: row.take(1).unwrap(),
-        color: row.take(2).unwrap(),
-      }
-    }).iter().for_each(|result| { self.insert(result.id, result.to_owned()); });
+  fn init(&mut self, repository: &dyn DataBackend) -> Result<(), String> {
+    repository.load_power_types()?.iter().for_each(|result| { self.insert(result.id, result.to_owned()); });
+    Ok(())
   }
 }
 
 impl Init for HashMap<u8, StatType> {
-  fn init(&mut self, db: &MySQLConnection) {
-    db.select("SELECT * FROM data_stat_type", &|mut row| {
-      StatType {
-        id: row.take(0).unwrap(),
-        localization_id: row.take(1).unwrap(),
-      }
-    }).iter().for_each(|result| { self.insert(result.id, result.to_owned()); });
+  fn init(&mut self, repository: &dyn DataBackend) -> Result<(), String> {
+    repository.load_stat_types()?.iter().for_each(|result| { self.insert(result.id, result.to_owned()); });
+    Ok(())
   }
 }
 
 impl Init for Vec<HashMap<u32, Vec<SpellEffect>>> {
-  fn init(&mut self, db: &MySQLConnection) {
+  fn init(&mut self, repository: &dyn DataBackend) -> Result<(), String> {
     let mut last_expansion_id = 0;
     let mut last_spell_id = 0;
-    db.select("SELECT * FROM data_spell_effect ORDER BY expansion_id, spell_id, id", &|mut row| {
-      SpellEffect {
-        id: row.take(0).unwrap(),
-        expansion_id: row.take(1).unwrap(),
-        spell_id: row.take(2).unwrap(),
-        points_lower: row.take(3).unwrap(),
-        points_upper: row.take(4).unwrap(),
-        chain_targets: row.take(5).unwrap(),
-        radius: row.take(6).unwrap(),
-      }
-    }).iter().for_each(|result| {
+    repository.load_spell_effects()?.iter().for_each(|result| {
       if result.expansion_id != last_expansion_id {
         self.push(HashMap::new());
         last_expansion_id = result.expansion_id;
@@ -338,164 +272,94 @@ impl Init for Vec<HashMap<u32, Vec<SpellEffect>>> {
       }
       expansion_vec.get_mut(&result.spell_id).unwrap().push(result.to_owned());
     });
+    Ok(())
   }
 }
 
 impl Init for Vec<HashMap<u32, NPC>> {
-  fn init(&mut self, db: &MySQLConnection) {
+  fn init(&mut self, repository: &dyn DataBackend) -> Result<(), String> {
     let mut last_expansion_id = 0;
-    db.select("SELECT * FROM data_npc ORDER BY expansion_id, id", &|mut row| {
-      NPC {
-        expansion_id: row.take(0).unwrap(),
-        id: row.take(1).unwrap(),
-        localization_id: row.take(2).unwrap(),
-        is_boss: row.take(3).unwrap(),
-        friend: row.take(4).unwrap(),
-        family: row.take(5).unwrap(),
-      }
-    }).iter().for_each(|result| {
+    repository.load_npcs()?.iter().for_each(|result| {
       if result.expansion_id != last_expansion_id {
         self.push(HashMap::new());
         last_expansion_id = result.expansion_id;
       }
       self.get_mut(result.expansion_id as usize - 1).unwrap().insert(result.id, result.to_owned());
     });
+    Ok(())
   }
 }
 
 impl Init for HashMap<u16, Icon> {
-  fn init(&mut self, db: &MySQLConnection) {
-    db.select("SELECT * FROM data_icon ORDER BY id", &|mut row| {
-      Icon {
-        id: row.take(0).unwrap(),
-        name: row.take(1).unwrap(),
-      }
-    }).iter().for_each(|icon| {
+  fn init(&mut self, repository: &dyn DataBackend) -> Result<(), String> {
+    repository.load_icons()?.iter().for_each(|icon| {
       self.insert(icon.id, icon.to_owned());
     });
+    Ok(())
   }
 }
 
 impl Init for Vec<HashMap<u32, Item>> {
-  fn init(&mut self, db: &MySQLConnection) {
+  fn init(&mut self, repository: &dyn DataBackend) -> Result<(), String> {
     let mut last_expansion_id = 0;
-    db.select("SELECT * FROM data_item ORDER BY expansion_id, id", &|mut row| {
-      Item {
-        expansion_id: row.take(0).unwrap(),
-        id: row.take(1).unwrap(),
-        localization_id: row.take(2).unwrap(),
-        icon: row.take(3).unwrap(),
-        quality: row.take(4).unwrap(),
-        inventory_type: row.take(5).unwrap(),
-        class_id: row.take(6).unwrap(),
-        required_level: row.take_opt(7).unwrap().ok(),
-        bonding: row.take_opt(8).unwrap().ok(),
-        sheath: row.take_opt(9).unwrap().ok(),
-        itemset: row.take_opt(10).unwrap().ok(),
-        max_durability: row.take_opt(11).unwrap().ok(),
-        item_level: row.take_opt(12).unwrap().ok(),
-        delay: row.take_opt(13).unwrap().ok(),
-      }
-    }).iter().for_each(|result| {
+    repository.load_items()?.iter().for_each(|result| {
       if result.expansion_id != last_expansion_id {
         self.push(HashMap::new());
         last_expansion_id = result.expansion_id;
       }
       self.get_mut(result.expansion_id as usize - 1).unwrap().insert(result.id, result.to_owned());
     });
+    Ok(())
   }
 }
 
 impl Init for Vec<HashMap<u32, Gem>> {
-  fn init(&mut self, db: &MySQLConnection) {
+  fn init(&mut self, repository: &dyn DataBackend) -> Result<(), String> {
     let mut last_expansion_id = 0;
-    db.select("SELECT * FROM data_gem ORDER BY expansion_id, item_id", &|mut row| {
-      Gem {
-        expansion_id: row.take(0).unwrap(),
-        item_id: row.take(1).unwrap(),
-        enchant_id: row.take(2).unwrap(),
-        flag: row.take(3).unwrap(),
-      }
-    }).iter().for_each(|result| {
+    repository.load_gems()?.iter().for_each(|result| {
       if result.expansion_id != last_expansion_id {
         self.push(HashMap::new());
         last_expansion_id = result.expansion_id;
       }
       self.get_mut(result.expansion_id as usize - 2).unwrap().insert(result.item_id, result.to_owned());
     });
+    Ok(())
   }
 }
 
 impl Init for Vec<HashMap<u32, Enchant>> {
-  fn init(&mut self, db: &MySQLConnection) {
+  fn init(&mut self, repository: &dyn DataBackend) -> Result<(), String> {
     let mut last_expansion_id = 0;
-    db.select("SELECT * FROM data_enchant ORDER BY expansion_id, id", &|mut row| {
-      let mut stats = Vec::new();
-      for i in (3..8).step_by(2) {
-        let stat_type = row.take_opt(i).unwrap().ok();
-        let stat_value = row.take_opt(i + 1).unwrap().ok();
-        if stat_type.is_none() {
-          break;
-        }
-        stats.push(Stat {
-          stat_type: stat_type.unwrap(),
-          stat_value: stat_value.unwrap(),
-        });
-      }
-      Enchant {
-        expansion_id: row.take(0).unwrap(),
-        id: row.take(1).unwrap(),
-        localization_id: row.take(2).unwrap(),
-        stats,
-      }
-    }).iter().for_each(|result| {
+    repository.load_enchants()?.iter().for_each(|result| {
       if result.expansion_id != last_expansion_id {
         self.push(HashMap::new());
         last_expansion_id = result.expansion_id;
       }
       self.get_mut(result.expansion_id as usize - 1).unwrap().insert(result.id, result.to_owned());
     });
+    Ok(())
   }
 }
 
 impl Init for HashMap<u8, ItemBonding> {
-  fn init(&mut self, db: &MySQLConnection) {
-    db.select("SELECT * FROM data_item_bonding", &|mut row| {
-      ItemBonding {
-        id: row.take(0).unwrap(),
-        localization_id: row.take(1).unwrap(),
-      }
-    }).iter().for_each(|result| { self.insert(result.id, result.to_owned()); });
+  fn init(&mut self, repository: &dyn DataBackend) -> Result<(), String> {
+    repository.load_item_bondings()?.iter().for_each(|result| { self.insert(result.id, result.to_owned()); });
+    Ok(())
   }
 }
 
 impl Init for HashMap<u8, ItemClass> {
-  fn init(&mut self, db: &MySQLConnection) {
-    db.select("SELECT * FROM data_item_class", &|mut row| {
-      ItemClass {
-        id: row.take(0).unwrap(),
-        item_class: row.take(1).unwrap(),
-        item_sub_class: row.take(2).unwrap(),
-        localization_id: row.take(3).unwrap(),
-      }
-    }).iter().for_each(|result| { self.insert(result.id, result.to_owned()); });
+  fn init(&mut self, repository: &dyn DataBackend) -> Result<(), String> {
+    repository.load_item_classes()?.iter().for_each(|result| { self.insert(result.id, result.to_owned()); });
+    Ok(())
   }
 }
 
 impl Init for Vec<HashMap<u32, Vec<ItemDamage>>> {
-  fn init(&mut self, db: &MySQLConnection) {
+  fn init(&mut self, repository: &dyn DataBackend) -> Result<(), String> {
     let mut last_expansion_id = 0;
     let mut last_item_id = 0;
-    db.select("SELECT * FROM data_item_dmg ORDER BY expansion_id, item_id, id", &|mut row| {
-      ItemDamage {
-        id: row.take(0).unwrap(),
-        expansion_id: row.take(1).unwrap(),
-        item_id: row.take(2).unwrap(),
-        dmg_type: row.take_opt(3).unwrap().ok(),
-        dmg_min: row.take(4).unwrap(),
-        dmg_max: row.take(5).unwrap(),
-      }
-    }).iter().for_each(|result| {
+    repository.load_item_damages()?.iter().for_each(|result| {
       if result.expansion_id != last_expansion_id {
         self.push(HashMap::new());
         last_expansion_id = result.expansion_id;
@@ -507,32 +371,22 @@ impl Init for Vec<HashMap<u32, Vec<ItemDamage>>> {
       }
       item_damage_map.get_mut(&result.item_id).unwrap().push(result.to_owned());
     });
+    Ok(())
   }
 }
 
 impl Init for HashMap<u8, ItemDamageType> {
-  fn init(&mut self, db: &MySQLConnection) {
-    db.select("SELECT * FROM data_item_dmg_type", &|mut row| {
-      ItemDamageType {
-        id: row.take(0).unwrap(),
-        localization_id: row.take(1).unwrap(),
-      }
-    }).iter().for_each(|result| { self.insert(result.id, result.to_owned()); });
+  fn init(&mut self, repository: &dyn DataBackend) -> Result<(), String> {
+    repository.load_item_damage_types()?.iter().for_each(|result| { self.insert(result.id, result.to_owned()); });
+    Ok(())
   }
 }
 
 impl Init for Vec<HashMap<u32, Vec<ItemEffect>>> {
-  fn init(&mut self, db: &MySQLConnection) {
+  fn init(&mut self, repository: &dyn DataBackend) -> Result<(), String> {
     let mut last_expansion_id = 0;
     let mut last_item_id = 0;
-    db.select("SELECT id, expansion_id, item_id, spell_id FROM data_item_effect ORDER BY expansion_id, item_id, id", &|mut row| {
-      ItemEffect {
-        id: row.take(0).unwrap(),
-        expansion_id: row.take(1).unwrap(),
-        item_id: row.take(2).unwrap(),
-        spell_id: row.take(3).unwrap(),
-      }
-    }).iter().for_each(|result| {
+    repository.load_item_effects()?.iter().for_each(|result| {
       if result.expansion_id != last_expansion_id {
         self.push(HashMap::new());
         last_expansion_id = result.expansion_id;
@@ -544,120 +398,64 @@ impl Init for Vec<HashMap<u32, Vec<ItemEffect>>> {
       }
       item_effect_map.get_mut(&result.item_id).unwrap().push(result.to_owned());
     });
+    Ok(())
   }
 }
 
 impl Init for HashMap<u8, ItemInventoryType> {
-  fn init(&mut self, db: &MySQLConnection) {
-    db.select("SELECT * FROM data_item_inventory_type", &|mut row| {
-      ItemInventoryType {
-        id: row.take(0).unwrap(),
-        localization_id: row.take(1).unwrap(),
-      }
-    }).iter().for_each(|result| { self.insert(result.id, result.to_owned()); });
+  fn init(&mut self, repository: &dyn DataBackend) -> Result<(), String> {
+    repository.load_item_inventory_types()?.iter().for_each(|result| { self.insert(result.id, result.to_owned()); });
+    Ok(())
   }
 }
 
 impl Init for HashMap<u8, ItemQuality> {
-  fn init(&mut self, db: &MySQLConnection) {
-    db.select("SELECT * FROM data_item_quality", &|mut row| {
-      ItemQuality {
-        id: row.take(0).unwrap(),
-        localization_id: row.take(1).unwrap(),
-        color: row.take(2).unwrap(),
-      }
-    }).iter().for_each(|result| { self.insert(result.id, result.to_owned()); });
+  fn init(&mut self, repository: &dyn DataBackend) -> Result<(), String> {
+    repository.load_item_qualities()?.iter().for_each(|result| { self.insert(result.id, result.to_owned()); });
+    Ok(())
   }
 }
 
 impl Init for Vec<HashMap<i16, ItemRandomProperty>> {
-  fn init(&mut self, db: &MySQLConnection) {
+  fn init(&mut self, repository: &dyn DataBackend) -> Result<(), String> {
     let mut last_expansion_id = 0;
-    db.select("SELECT * FROM data_item_random_property ORDER BY expansion_id, id", &|mut row| {
-      let mut enchant_ids = Vec::new();
-      for i in 3..8 {
-        let enchant_id = row.take_opt(i).unwrap().ok();
-        if enchant_id.is_some() {
-          enchant_ids.push(enchant_id.unwrap());
-        }
-      }
-      let mut scaling_coefficients = Vec::new();
-      for i in 8..13 {
-        let coefficient = row.take_opt(i).unwrap().ok();
-        if coefficient.is_some() {
-          scaling_coefficients.push(coefficient.unwrap());
-        }
-      }
-      ItemRandomProperty {
-        expansion_id: row.take(0).unwrap(),
-        id: row.take(1).unwrap(),
-        localization_id: row.take(2).unwrap(),
-        enchant_ids,
-        scaling_coefficients
-      }
-    }).iter().for_each(|result| {
+    repository.load_item_random_properties()?.iter().for_each(|result| {
       if result.expansion_id != last_expansion_id {
         self.push(HashMap::new());
         last_expansion_id = result.expansion_id;
       }
       self.get_mut(result.expansion_id as usize - 1).unwrap().insert(result.id, result.to_owned());
     });
+    Ok(())
   }
 }
 
 impl Init for HashMap<u8, ItemSheath> {
-  fn init(&mut self, db: &MySQLConnection) {
-    db.select("SELECT * FROM data_item_sheath", &|mut row| {
-      ItemSheath {
-        id: row.take(0).unwrap(),
-        localization_id: row.take(1).unwrap(),
-      }
-    }).iter().for_each(|result| { self.insert(result.id, result.to_owned()); });
+  fn init(&mut self, repository: &dyn DataBackend) -> Result<(), String> {
+    repository.load_item_sheaths()?.iter().for_each(|result| { self.insert(result.id, result.to_owned()); });
+    Ok(())
   }
 }
 
 impl Init for Vec<HashMap<u32, ItemSocket>> {
-  fn init(&mut self, db: &MySQLConnection) {
+  fn init(&mut self, repository: &dyn DataBackend) -> Result<(), String> {
     let mut last_expansion_id = 0;
-    db.select("SELECT * FROM data_item_socket ORDER BY expansion_id, item_id", &|mut row| {
-      let mut slots = Vec::new();
-      for i in 3..6 {
-        let slot = row.take_opt(i).unwrap().ok();
-        if slot.is_some() {
-          slots.push(slot.unwrap());
-        }
-      }
-      ItemSocket {
-        expansion_id: row.take(0).unwrap(),
-        item_id: row.take(1).unwrap(),
-        bonus: row.take(2).unwrap(),
-        slots,
-      }
-    }).iter().for_each(|result| {
+    repository.load_item_sockets()?.iter().for_each(|result| {
       if result.expansion_id != last_expansion_id {
         self.push(HashMap::new());
         last_expansion_id = result.expansion_id;
       }
       self.get_mut(result.expansion_id as usize - 2).unwrap().insert(result.item_id, result.to_owned());
     });
+    Ok(())
   }
 }
 
 impl Init for Vec<HashMap<u32, Vec<ItemStat>>> {
-  fn init(&mut self, db: &MySQLConnection) {
+  fn init(&mut self, repository: &dyn DataBackend) -> Result<(), String> {
     let mut last_expansion_id = 0;
     let mut last_item_id = 0;
-    db.select("SELECT * FROM data_item_stat WHERE stat_type IN (1,2,3,4,5,6,27,28,29,30,31) OR stat_type = 34 OR (expansion_id > 1 AND stat_type IN (7,8,37,22,23,24,10,11,12,42,38,39,40,41)) OR (expansion_id>2 AND stat_type IN (9,13,21,43)) ORDER BY expansion_id, item_id", &|mut row| {
-      ItemStat {
-        id: row.take(0).unwrap(),
-        expansion_id: row.take(1).unwrap(),
-        item_id: row.take(2).unwrap(),
-        stat: Stat {
-          stat_type: row.take(3).unwrap(),
-          stat_value: row.take(4).unwrap(),
-        },
-      }
-    }).iter().for_each(|result| {
+    repository.load_item_stats()?.iter().for_each(|result| {
       if result.expansion_id != last_expansion_id {
         self.push(HashMap::new());
         last_expansion_id = result.expansion_id;
@@ -669,41 +467,29 @@ impl Init for Vec<HashMap<u32, Vec<ItemStat>>> {
       }
       expansion_vec.get_mut(&result.item_id).unwrap().push(result.to_owned());
     });
+    Ok(())
   }
 }
 
 impl Init for Vec<HashMap<u16, ItemsetName>> {
-  fn init(&mut self, db: &MySQLConnection) {
+  fn init(&mut self, repository: &dyn DataBackend) -> Result<(), String> {
     let mut last_expansion_id = 0;
-    db.select("SELECT * FROM data_itemset_name ORDER BY expansion_id, id", &|mut row| {
-      ItemsetName {
-        expansion_id: row.take(0).unwrap(),
-        id: row.take(1).unwrap(),
-        localization_id: row.take(2).unwrap(),
-      }
-    }).iter().for_each(|result| {
+    repository.load_itemset_names()?.iter().for_each(|result| {
       if result.expansion_id != last_expansion_id {
         self.push(HashMap::new());
         last_expansion_id = result.expansion_id;
       }
       self.get_mut(result.expansion_id as usize - 1).unwrap().insert(result.id, result.to_owned());
     });
+    Ok(())
   }
 }
 
 impl Init for Vec<HashMap<u16, Vec<ItemsetEffect>>> {
-  fn init(&mut self, db: &MySQLConnection) {
+  fn init(&mut self, repository: &dyn DataBackend) -> Result<(), String> {
     let mut last_expansion_id = 0;
     let mut last_itemset_id = 0;
-    db.select("SELECT * FROM data_itemset_effect ORDER BY expansion_id, itemset_id, id", &|mut row| {
-      ItemsetEffect {
-        id: row.take(0).unwrap(),
-        expansion_id: row.take(1).unwrap(),
-        itemset_id: row.take(2).unwrap(),
-        threshold: row.take(3).unwrap(),
-        spell_id: row.take(4).unwrap(),
-      }
-    }).iter().for_each(|result| {
+    repository.load_itemset_effects()?.iter().for_each(|result| {
       if result.expansion_id != last_expansion_id {
         self.push(HashMap::new());
         last_expansion_id = result.expansion_id;
@@ -715,50 +501,21 @@ impl Init for Vec<HashMap<u16, Vec<ItemsetEffect>>> {
       }
       expansion_vec.get_mut(&result.itemset_id).unwrap().push(result.to_owned());
     });
+    Ok(())
   }
 }
 
 impl Init for HashMap<u16, Title> {
-  fn init(&mut self, db: &MySQLConnection) {
-    db.select("SELECT * FROM data_title", &|mut row| {
-      Title {
-        id: row.take(0).unwrap(),
-        localization_id: row.take(1).unwrap()
-      }
-    }).iter().for_each(|result| { self.insert(result.id, result.to_owned()); });
+  fn init(&mut self, repository: &dyn DataBackend) -> Result<(), String> {
+    repository.load_titles()?.iter().for_each(|result| { self.insert(result.id, result.to_owned()); });
+    Ok(())
   }
 }
 
 impl Init for HashMap<u8, Vec<ItemRandomPropertyPoints>> {
-  fn init(&mut self, db: &MySQLConnection) {
+  fn init(&mut self, repository: &dyn DataBackend) -> Result<(), String> {
     let mut current_vec = Vec::new();
-    db.select("SELECT * FROM data_item_random_property_points ORDER BY expansion_id, item_level", &|mut row| {
-      ItemRandomPropertyPoints {
-        item_level: row.take(0).unwrap(),
-        expansion_id: row.take(1).unwrap(),
-        epic: [
-          row.take(2).unwrap(),
-          row.take(3).unwrap(),
-          row.take(4).unwrap(),
-          row.take(5).unwrap(),
-          row.take(6).unwrap(),
-        ],
-        rare: [
-          row.take(7).unwrap(),
-          row.take(8).unwrap(),
-          row.take(9).unwrap(),
-          row.take(10).unwrap(),
-          row.take(11).unwrap(),
-        ],
-        good: [
-          row.take(12).unwrap(),
-          row.take(13).unwrap(),
-          row.take(14).unwrap(),
-          row.take(15).unwrap(),
-          row.take(16).unwrap(),
-        ]
-      }
-    }).iter().for_each(|result| {
+    repository.load_item_random_property_points()?.iter().for_each(|result| {
       if result.item_level == 300 {
         current_vec.push(result.clone());
         self.insert(result.expansion_id, current_vec.to_owned());
@@ -767,5 +524,6 @@ impl Init for HashMap<u8, Vec<ItemRandomPropertyPoints>> {
         current_vec.push(result.to_owned());
       }
     });
+    Ok(())
   }
 }
\ No newline at end of file
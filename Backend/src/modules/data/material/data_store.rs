@@ -0,0 +1,30 @@
+use std::sync::{Arc, RwLock};
+
+use crate::modules::data::dto::DataReloadStatus;
+use crate::modules::data::material::Data;
+
+// Holds the current snapshot of the static tables.
+// Requests work on the snapshot they started with, a reload swaps in a new one for subsequent requests.
+#[derive(Debug, Clone)]
+pub struct DataStore {
+  current: Arc<RwLock<Arc<Data>>>,
+  pub reload_status: Arc<RwLock<DataReloadStatus>>,
+}
+
+impl DataStore {
+  pub fn new(data: Data) -> Self
+  {
+    DataStore {
+      current: Arc::new(RwLock::new(Arc::new(data))),
+      reload_status: Arc::new(RwLock::new(DataReloadStatus::default())),
+    }
+  }
+
+  pub fn snapshot(&self) -> Arc<Data> {
+    Arc::clone(&self.current.read().unwrap())
+  }
+
+  pub fn swap(&self, data: Data) {
+    *self.current.write().unwrap() = Arc::new(data);
+  }
+}
\ No newline at end of file
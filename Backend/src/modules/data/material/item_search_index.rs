@@ -0,0 +1,115 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::modules::data::domain_value::{Item, ItemStat, Localization};
+use crate::modules::data::material::ENGLISH;
+
+// Items are identified by (expansion_id, item_id)
+pub type ItemKey = (u8, u32);
+
+#[derive(Debug, Default)]
+pub struct ItemSearchIndex {
+  items: Vec<ItemKey>,
+  // Lower cased names per language, missing translations fall back to english
+  names: HashMap<u8, HashMap<ItemKey, String>>,
+  expansions: HashMap<u8, HashSet<ItemKey>>,
+  qualities: HashMap<u8, HashSet<ItemKey>>,
+  inventory_types: HashMap<u8, HashSet<ItemKey>>,
+  classes: HashMap<u8, HashSet<ItemKey>>,
+  stat_types: HashMap<u8, HashSet<ItemKey>>,
+  itemset_items: HashSet<ItemKey>,
+}
+
+impl ItemSearchIndex {
+  pub fn build(items: &[HashMap<u32, Item>], item_stats: &[HashMap<u32, Vec<ItemStat>>], localization: &HashMap<u8, HashMap<u32, Localization>>) -> Self {
+    let mut index = ItemSearchIndex::default();
+    for item in items.iter().flat_map(|expansion_items| expansion_items.values()) {
+      let key = (item.expansion_id, item.id);
+      index.items.push(key);
+      index.expansions.entry(item.expansion_id).or_insert_with(HashSet::new).insert(key);
+      index.qualities.entry(item.quality).or_insert_with(HashSet::new).insert(key);
+      index.classes.entry(item.class_id).or_insert_with(HashSet::new).insert(key);
+      if let Some(inventory_type) = item.inventory_type {
+        index.inventory_types.entry(inventory_type).or_insert_with(HashSet::new).insert(key);
+      }
+      if item.itemset.is_some() {
+        index.itemset_items.insert(key);
+      }
+
+      let english_name = localization.get(&ENGLISH).and_then(|english| english.get(&item.localization_id));
+      for (language_id, language) in localization.iter() {
+        if let Some(name) = language.get(&item.localization_id).or(english_name) {
+          index.names.entry(*language_id).or_insert_with(HashMap::new).insert(key, name.content.to_lowercase());
+        }
+      }
+    }
+
+    for (expansion_index, expansion_stats) in item_stats.iter().enumerate() {
+      for (item_id, stats) in expansion_stats.iter() {
+        for stat in stats.iter() {
+          index.stat_types.entry(stat.stat.stat_type).or_insert_with(HashSet::new)
+            .insert((expansion_index as u8 + 1, *item_id));
+        }
+      }
+    }
+
+    index
+  }
+
+  pub fn len(&self) -> usize {
+    self.items.len()
+  }
+
+  pub fn name(&self, language_id: u8, key: &ItemKey) -> Option<&str> {
+    self.names.get(&language_id)
+      .and_then(|names| names.get(key))
+      .map(|name| name.as_str())
+  }
+
+  pub fn find(&self, language_id: u8, name: Option<&str>, expansion_id: Option<u8>, quality: Option<u8>,
+              inventory_type: Option<u8>, class_id: Option<u8>, stat_types: &[u8], itemset: Option<bool>) -> Vec<ItemKey> {
+    let mut postings: Vec<&HashSet<ItemKey>> = Vec::new();
+    let lookups = vec![
+      (&self.expansions, expansion_id),
+      (&self.qualities, quality),
+      (&self.inventory_types, inventory_type),
+      (&self.classes, class_id),
+    ];
+    for (posting_map, value) in lookups {
+      if let Some(value) = value {
+        match posting_map.get(&value) {
+          Some(posting) => postings.push(posting),
+          None => return Vec::new()
+        }
+      }
+    }
+    for stat_type in stat_types {
+      match self.stat_types.get(stat_type) {
+        Some(posting) => postings.push(posting),
+        None => return Vec::new()
+      }
+    }
+    if itemset == Some(true) {
+      postings.push(&self.itemset_items);
+    }
+
+    // The smallest posting drives the intersection
+    postings.sort_by_key(|posting| posting.len());
+    let mut result: Vec<ItemKey> = match postings.first() {
+      Some(smallest) => smallest.iter().cloned().collect(),
+      None => self.items.clone()
+    };
+    for posting in postings.iter().skip(1) {
+      result.retain(|key| posting.contains(key));
+    }
+    if itemset == Some(false) {
+      result.retain(|key| !self.itemset_items.contains(key));
+    }
+    if let Some(name) = name {
+      let name = name.to_lowercase();
+      result.retain(|key| self.name(language_id, key)
+        .map(|item_name| item_name.contains(&name))
+        .unwrap_or(false));
+    }
+    result
+  }
+}
\ No newline at end of file
@@ -0,0 +1,53 @@
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Mutex;
+
+use crate::modules::data::domain_value::Localization;
+
+pub const ENGLISH: u8 = 1;
+
+// Resolves the requested language, then english, then a placeholder.
+// Misses are remembered per requested language for the lifetime of the snapshot.
+#[derive(Debug, Default)]
+pub struct LocalizationResolver {
+  fallbacks: Mutex<HashMap<u8, BTreeSet<u32>>>,
+  placeholders: Mutex<HashMap<u8, BTreeSet<u32>>>,
+}
+
+impl LocalizationResolver {
+  pub fn resolve(&self, localization: &HashMap<u8, HashMap<u32, Localization>>, language_id: u8, localization_id: u32) -> String {
+    let lookup = |language_id: u8| localization.get(&language_id).and_then(|entries| entries.get(&localization_id));
+    if let Some(entry) = lookup(language_id) {
+      return entry.content.clone();
+    }
+
+    if let Some(entry) = lookup(ENGLISH) {
+      self.fallbacks.lock().unwrap().entry(language_id).or_insert_with(BTreeSet::new).insert(localization_id);
+      return entry.content.clone();
+    }
+
+    self.placeholders.lock().unwrap().entry(language_id).or_insert_with(BTreeSet::new).insert(localization_id);
+    placeholder(localization_id)
+  }
+
+  pub fn fallbacks(&self, language_id: u8) -> Vec<u32> {
+    self.fallbacks.lock().unwrap().get(&language_id)
+      .map(|ids| ids.iter().cloned().collect())
+      .unwrap_or_else(Vec::new)
+  }
+
+  pub fn placeholders(&self, language_id: u8) -> Vec<u32> {
+    self.placeholders.lock().unwrap().get(&language_id)
+      .map(|ids| ids.iter().cloned().collect())
+      .unwrap_or_else(Vec::new)
+  }
+
+  pub fn requested_languages(&self) -> BTreeSet<u8> {
+    let mut language_ids: BTreeSet<u8> = self.fallbacks.lock().unwrap().keys().cloned().collect();
+    language_ids.extend(self.placeholders.lock().unwrap().keys());
+    language_ids
+  }
+}
+
+pub fn placeholder(localization_id: u32) -> String {
+  format!("[{}]", localization_id)
+}
\ No newline at end of file
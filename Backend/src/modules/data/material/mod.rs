@@ -1,3 +1,11 @@
 pub use self::data::Data;
+pub use self::data_store::DataStore;
+pub use self::item_search_index::{ItemKey, ItemSearchIndex};
+pub use self::localization_resolver::{ENGLISH, LocalizationResolver, placeholder};
+pub use self::po_file::{PoEntry, PoFile};
 
-mod data;
\ No newline at end of file
+mod data;
+mod data_store;
+mod item_search_index;
+mod localization_resolver;
+mod po_file;
@@ -0,0 +1,185 @@
+use crate::modules::data::dto::TranslationFailure;
+
+// Minimal gettext PO support: the localization id is the msgctxt, the english text the msgid.
+// Only single form entries are supported, as data_localization has no plural forms.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PoEntry {
+  pub references: Vec<String>,
+  pub fuzzy: bool,
+  pub localization_id: u32,
+  pub source: String,
+  pub translation: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PoFile {
+  pub language: String,
+  pub entries: Vec<PoEntry>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Field {
+  Context,
+  Source,
+  Translation,
+}
+
+impl PoFile {
+  pub fn write(&self) -> String {
+    let mut result = String::new();
+    result += "msgid \"\"\n";
+    result += "msgstr \"\"\n";
+    result += "\"Content-Type: text/plain; charset=UTF-8\\n\"\n";
+    result += &format!("\"Language: {}\\n\"\n", escape(&self.language));
+    for entry in self.entries.iter() {
+      result += "\n";
+      if !entry.references.is_empty() {
+        result += &format!("#: {}\n", entry.references.join(" "));
+      }
+      if entry.fuzzy {
+        result += "#, fuzzy\n";
+      }
+      result += &format!("msgctxt \"{}\"\n", entry.localization_id);
+      result += &format!("msgid \"{}\"\n", escape(&entry.source));
+      result += &format!("msgstr \"{}\"\n", escape(&entry.translation));
+    }
+    result
+  }
+
+  pub fn parse(content: &str) -> Result<Self, TranslationFailure> {
+    let mut po_file = PoFile { language: String::new(), entries: Vec::new() };
+    let mut entry = RawEntry::default();
+    let mut field = None;
+
+    for (index, line) in content.lines().enumerate() {
+      let line_number = index + 1;
+      let line = line.trim();
+      if line.is_empty() {
+        entry.finish(&mut po_file, line_number)?;
+        field = None;
+      } else if line.starts_with("#:") {
+        entry.finish(&mut po_file, line_number)?;
+        entry.references.extend(line[2..].split_whitespace().map(|reference| reference.to_owned()));
+      } else if line.starts_with("#,") {
+        entry.finish(&mut po_file, line_number)?;
+        entry.fuzzy |= line[2..].split(',').any(|flag| flag.trim() == "fuzzy");
+      } else if line.starts_with('#') {
+        continue;
+      } else if line.starts_with('"') {
+        let value = unquote(line, line_number)?;
+        match field {
+          Some(field) => entry.append(field, &value),
+          None => return Err(TranslationFailure::InvalidFile(line_number, "String without keyword".to_owned()))
+        }
+      } else {
+        let (keyword, value) = line.split_at(line.find(char::is_whitespace).unwrap_or(line.len()));
+        let next_field = match keyword {
+          "msgctxt" => Field::Context,
+          "msgid" => Field::Source,
+          "msgstr" => Field::Translation,
+          _ => return Err(TranslationFailure::InvalidFile(line_number, format!("Unsupported keyword '{}'", keyword)))
+        };
+        if next_field != Field::Translation && entry.translation.is_some() {
+          entry.finish(&mut po_file, line_number)?;
+        }
+        entry.set(next_field, unquote(value.trim(), line_number)?);
+        field = Some(next_field);
+      }
+    }
+    entry.finish(&mut po_file, content.lines().count() + 1)?;
+    Ok(po_file)
+  }
+}
+
+#[derive(Debug, Default)]
+struct RawEntry {
+  references: Vec<String>,
+  fuzzy: bool,
+  context: Option<String>,
+  source: Option<String>,
+  translation: Option<String>,
+}
+
+impl RawEntry {
+  fn set(&mut self, field: Field, value: String) {
+    match field {
+      Field::Context => self.context = Some(value),
+      Field::Source => self.source = Some(value),
+      Field::Translation => self.translation = Some(value),
+    }
+  }
+
+  fn append(&mut self, field: Field, value: &str) {
+    let target = match field {
+      Field::Context => &mut self.context,
+      Field::Source => &mut self.source,
+      Field::Translation => &mut self.translation,
+    };
+    target.get_or_insert_with(String::new).push_str(value);
+  }
+
+  // An entry ends with its msgstr, anything before is only collected
+  fn finish(&mut self, po_file: &mut PoFile, line_number: usize) -> Result<(), TranslationFailure> {
+    if self.translation.is_none() {
+      if self.context.is_some() || self.source.is_some() {
+        return Err(TranslationFailure::InvalidFile(line_number, "Entry without msgstr".to_owned()));
+      }
+      return Ok(());
+    }
+
+    let entry = std::mem::replace(self, RawEntry::default());
+    let source = entry.source.ok_or_else(|| TranslationFailure::InvalidFile(line_number, "Entry without msgid".to_owned()))?;
+    match entry.context {
+      // The header carries the meta data of the file
+      None if source.is_empty() => {
+        po_file.language = entry.translation.unwrap().lines()
+          .find(|header| header.starts_with("Language:"))
+          .map(|header| header["Language:".len()..].trim().to_owned())
+          .unwrap_or_default();
+      },
+      None => return Err(TranslationFailure::InvalidFile(line_number, "Entry without msgctxt".to_owned())),
+      Some(context) => {
+        let localization_id = context.parse::<u32>()
+          .map_err(|_| TranslationFailure::InvalidFile(line_number, format!("Invalid localization id '{}'", context)))?;
+        po_file.entries.push(PoEntry {
+          references: entry.references,
+          fuzzy: entry.fuzzy,
+          localization_id,
+          source,
+          translation: entry.translation.unwrap(),
+        });
+      }
+    }
+    Ok(())
+  }
+}
+
+fn escape(value: &str) -> String {
+  value.replace('\\', "\\\\")
+    .replace('"', "\\\"")
+    .replace('\n', "\\n")
+    .replace('\t', "\\t")
+}
+
+fn unquote(value: &str, line_number: usize) -> Result<String, TranslationFailure> {
+  if value.len() < 2 || !value.starts_with('"') || !value.ends_with('"') {
+    return Err(TranslationFailure::InvalidFile(line_number, "Expected a quoted string".to_owned()));
+  }
+
+  let mut result = String::new();
+  let mut chars = value[1..value.len() - 1].chars();
+  while let Some(character) = chars.next() {
+    if character != '\\' {
+      result.push(character);
+      continue;
+    }
+    match chars.next() {
+      Some('n') => result.push('\n'),
+      Some('t') => result.push('\t'),
+      Some('"') => result.push('"'),
+      Some('\\') => result.push('\\'),
+      _ => return Err(TranslationFailure::InvalidFile(line_number, "Invalid escape sequence".to_owned()))
+    }
+  }
+  Ok(result)
+}
\ No newline at end of file
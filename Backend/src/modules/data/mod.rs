@@ -1,4 +1,4 @@
-pub use self::material::Data;
+pub use self::material::{Data, DataStore};
 pub use self::domain_value::Stat;
 
 #[cfg(test)]
@@ -8,6 +8,7 @@ mod material;
 mod domain_value;
 mod dto;
 mod language;
+mod repository;
 
 pub mod tools;
 pub mod transfer;
@@ -0,0 +1,7 @@
+use std::fmt::Debug;
+
+use crate::modules::data::repository::{DataTableRepository, ServerRepository, TranslationRepository};
+
+pub trait DataBackend: DataTableRepository + ServerRepository + TranslationRepository + Debug + Send + Sync {}
+
+impl<T: DataTableRepository + ServerRepository + TranslationRepository + Debug + Send + Sync> DataBackend for T {}
\ No newline at end of file
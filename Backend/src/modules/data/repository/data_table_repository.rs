@@ -0,0 +1,39 @@
+use crate::modules::data::domain_value::{DispelType, Enchant, Expansion, Gem, HeroClass, HeroClassTalent, Icon, Item, ItemBonding, ItemClass, ItemDamage, ItemDamageType, ItemEffect, ItemInventoryType, ItemQuality, ItemRandomProperty, ItemRandomPropertyPoints, ItemSheath, ItemSocket, ItemStat, ItemsetEffect, ItemsetName, Language, Localization, NPC, PowerType, Profession, Race, Server, Spell, SpellEffect, StatType, Title};
+
+// The static tables, read whenever the data is initialized or reloaded
+pub trait DataTableRepository {
+  fn load_expansions(&self) -> Result<Vec<Expansion>, String>;
+  fn load_languages(&self) -> Result<Vec<Language>, String>;
+  fn load_localizations(&self) -> Result<Vec<Localization>, String>;
+  fn load_races(&self) -> Result<Vec<Race>, String>;
+  fn load_professions(&self) -> Result<Vec<Profession>, String>;
+  fn load_servers(&self) -> Result<Vec<Server>, String>;
+  fn load_hero_classes(&self) -> Result<Vec<HeroClass>, String>;
+  // Hero class id and index of the talent tree
+  fn load_hero_class_specs(&self) -> Result<Vec<(u8, u8, HeroClassTalent)>, String>;
+  fn load_spells(&self) -> Result<Vec<Spell>, String>;
+  fn load_dispel_types(&self) -> Result<Vec<DispelType>, String>;
+  fn load_power_types(&self) -> Result<Vec<PowerType>, String>;
+  fn load_stat_types(&self) -> Result<Vec<StatType>, String>;
+  fn load_spell_effects(&self) -> Result<Vec<SpellEffect>, String>;
+  fn load_npcs(&self) -> Result<Vec<NPC>, String>;
+  fn load_icons(&self) -> Result<Vec<Icon>, String>;
+  fn load_items(&self) -> Result<Vec<Item>, String>;
+  fn load_gems(&self) -> Result<Vec<Gem>, String>;
+  fn load_enchants(&self) -> Result<Vec<Enchant>, String>;
+  fn load_item_bondings(&self) -> Result<Vec<ItemBonding>, String>;
+  fn load_item_classes(&self) -> Result<Vec<ItemClass>, String>;
+  fn load_item_damages(&self) -> Result<Vec<ItemDamage>, String>;
+  fn load_item_damage_types(&self) -> Result<Vec<ItemDamageType>, String>;
+  fn load_item_effects(&self) -> Result<Vec<ItemEffect>, String>;
+  fn load_item_inventory_types(&self) -> Result<Vec<ItemInventoryType>, String>;
+  fn load_item_qualities(&self) -> Result<Vec<ItemQuality>, String>;
+  fn load_item_random_properties(&self) -> Result<Vec<ItemRandomProperty>, String>;
+  fn load_item_sheaths(&self) -> Result<Vec<ItemSheath>, String>;
+  fn load_item_sockets(&self) -> Result<Vec<ItemSocket>, String>;
+  fn load_item_stats(&self) -> Result<Vec<ItemStat>, String>;
+  fn load_itemset_names(&self) -> Result<Vec<ItemsetName>, String>;
+  fn load_itemset_effects(&self) -> Result<Vec<ItemsetEffect>, String>;
+  fn load_titles(&self) -> Result<Vec<Title>, String>;
+  fn load_item_random_property_points(&self) -> Result<Vec<ItemRandomPropertyPoints>, String>;
+}
\ No newline at end of file
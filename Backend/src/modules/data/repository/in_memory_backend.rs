@@ -0,0 +1,238 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
+
+use crate::modules::data::domain_value::{DispelType, Enchant, Expansion, Gem, HeroClass, HeroClassTalent, Icon, Item, ItemBonding, ItemClass, ItemDamage, ItemDamageType, ItemEffect, ItemInventoryType, ItemQuality, ItemRandomProperty, ItemRandomPropertyPoints, ItemSheath, ItemSocket, ItemStat, ItemsetEffect, ItemsetName, Language, Localization, NPC, PowerType, Profession, Race, Server, Spell, SpellEffect, StatType, Title};
+use crate::modules::data::material::PoEntry;
+use crate::modules::data::repository::{DataTableRepository, ServerRepository, TranslationRepository};
+
+// The content of the data tables, e.g. to run the data module without a database
+#[derive(Debug, Clone, Default)]
+pub struct DataTables {
+  pub expansions: Vec<Expansion>,
+  pub languages: Vec<Language>,
+  pub localizations: Vec<Localization>,
+  pub races: Vec<Race>,
+  pub professions: Vec<Profession>,
+  pub servers: Vec<Server>,
+  pub hero_classes: Vec<HeroClass>,
+  pub hero_class_specs: Vec<(u8, u8, HeroClassTalent)>,
+  pub spells: Vec<Spell>,
+  pub dispel_types: Vec<DispelType>,
+  pub power_types: Vec<PowerType>,
+  pub stat_types: Vec<StatType>,
+  pub spell_effects: Vec<SpellEffect>,
+  pub npcs: Vec<NPC>,
+  pub icons: Vec<Icon>,
+  pub items: Vec<Item>,
+  pub gems: Vec<Gem>,
+  pub enchants: Vec<Enchant>,
+  pub item_bondings: Vec<ItemBonding>,
+  pub item_classes: Vec<ItemClass>,
+  pub item_damages: Vec<ItemDamage>,
+  pub item_damage_types: Vec<ItemDamageType>,
+  pub item_effects: Vec<ItemEffect>,
+  pub item_inventory_types: Vec<ItemInventoryType>,
+  pub item_qualities: Vec<ItemQuality>,
+  pub item_random_properties: Vec<ItemRandomProperty>,
+  pub item_sheaths: Vec<ItemSheath>,
+  pub item_sockets: Vec<ItemSocket>,
+  pub item_stats: Vec<ItemStat>,
+  pub itemset_names: Vec<ItemsetName>,
+  pub itemset_effects: Vec<ItemsetEffect>,
+  pub titles: Vec<Title>,
+  pub item_random_property_points: Vec<ItemRandomPropertyPoints>,
+  // Keyed by language id and localization id
+  pub localization_sources: BTreeMap<(u8, u32), String>,
+}
+
+#[derive(Debug, Default)]
+pub struct InMemoryDataBackend {
+  tables: Mutex<DataTables>,
+}
+
+impl InMemoryDataBackend {
+  pub fn new(tables: DataTables) -> Self {
+    InMemoryDataBackend { tables: Mutex::new(tables) }
+  }
+}
+
+impl DataTableRepository for InMemoryDataBackend {
+  fn load_expansions(&self) -> Result<Vec<Expansion>, String> {
+    Ok(self.tables.lock().unwrap().expansions.clone())
+  }
+
+  fn load_languages(&self) -> Result<Vec<Language>, String> {
+    Ok(self.tables.lock().unwrap().languages.clone())
+  }
+
+  fn load_localizations(&self) -> Result<Vec<Localization>, String> {
+    Ok(self.tables.lock().unwrap().localizations.clone())
+  }
+
+  fn load_races(&self) -> Result<Vec<Race>, String> {
+    Ok(self.tables.lock().unwrap().races.clone())
+  }
+
+  fn load_professions(&self) -> Result<Vec<Profession>, String> {
+    Ok(self.tables.lock().unwrap().professions.clone())
+  }
+
+  fn load_servers(&self) -> Result<Vec<Server>, String> {
+    Ok(self.tables.lock().unwrap().servers.clone())
+  }
+
+  fn load_hero_classes(&self) -> Result<Vec<HeroClass>, String> {
+    Ok(self.tables.lock().unwrap().hero_classes.clone())
+  }
+
+  fn load_hero_class_specs(&self) -> Result<Vec<(u8, u8, HeroClassTalent)>, String> {
+    Ok(self.tables.lock().unwrap().hero_class_specs.clone())
+  }
+
+  fn load_spells(&self) -> Result<Vec<Spell>, String> {
+    Ok(self.tables.lock().unwrap().spells.clone())
+  }
+
+  fn load_dispel_types(&self) -> Result<Vec<DispelType>, String> {
+    Ok(self.tables.lock().unwrap().dispel_types.clone())
+  }
+
+  fn load_power_types(&self) -> Result<Vec<PowerType>, String> {
+    Ok(self.tables.lock().unwrap().power_types.clone())
+  }
+
+  fn load_stat_types(&self) -> Result<Vec<StatType>, String> {
+    Ok(self.tables.lock().unwrap().stat_types.clone())
+  }
+
+  fn load_spell_effects(&self) -> Result<Vec<SpellEffect>, String> {
+    Ok(self.tables.lock().unwrap().spell_effects.clone())
+  }
+
+  fn load_npcs(&self) -> Result<Vec<NPC>, String> {
+    Ok(self.tables.lock().unwrap().npcs.clone())
+  }
+
+  fn load_icons(&self) -> Result<Vec<Icon>, String> {
+    Ok(self.tables.lock().unwrap().icons.clone())
+  }
+
+  fn load_items(&self) -> Result<Vec<Item>, String> {
+    Ok(self.tables.lock().unwrap().items.clone())
+  }
+
+  fn load_gems(&self) -> Result<Vec<Gem>, String> {
+    Ok(self.tables.lock().unwrap().gems.clone())
+  }
+
+  fn load_enchants(&self) -> Result<Vec<Enchant>, String> {
+    Ok(self.tables.lock().unwrap().enchants.clone())
+  }
+
+  fn load_item_bondings(&self) -> Result<Vec<ItemBonding>, String> {
+    Ok(self.tables.lock().unwrap().item_bondings.clone())
+  }
+
+  fn load_item_classes(&self) -> Result<Vec<ItemClass>, String> {
+    Ok(self.tables.lock().unwrap().item_classes.clone())
+  }
+
+  fn load_item_damages(&self) -> Result<Vec<ItemDamage>, String> {
+    Ok(self.tables.lock().unwrap().item_damages.clone())
+  }
+
+  fn load_item_damage_types(&self) -> Result<Vec<ItemDamageType>, String> {
+    Ok(self.tables.lock().unwrap().item_damage_types.clone())
+  }
+
+  fn load_item_effects(&self) -> Result<Vec<ItemEffect>, String> {
+    Ok(self.tables.lock().unwrap().item_effects.clone())
+  }
+
+  fn load_item_inventory_types(&self) -> Result<Vec<ItemInventoryType>, String> {
+    Ok(self.tables.lock().unwrap().item_inventory_types.clone())
+  }
+
+  fn load_item_qualities(&self) -> Result<Vec<ItemQuality>, String> {
+    Ok(self.tables.lock().unwrap().item_qualities.clone())
+  }
+
+  fn load_item_random_properties(&self) -> Result<Vec<ItemRandomProperty>, String> {
+    Ok(self.tables.lock().unwrap().item_random_properties.clone())
+  }
+
+  fn load_item_sheaths(&self) -> Result<Vec<ItemSheath>, String> {
+    Ok(self.tables.lock().unwrap().item_sheaths.clone())
+  }
+
+  fn load_item_sockets(&self) -> Result<Vec<ItemSocket>, String> {
+    Ok(self.tables.lock().unwrap().item_sockets.clone())
+  }
+
+  fn load_item_stats(&self) -> Result<Vec<ItemStat>, String> {
+    Ok(self.tables.lock().unwrap().item_stats.clone())
+  }
+
+  fn load_itemset_names(&self) -> Result<Vec<ItemsetName>, String> {
+    Ok(self.tables.lock().unwrap().itemset_names.clone())
+  }
+
+  fn load_itemset_effects(&self) -> Result<Vec<ItemsetEffect>, String> {
+    Ok(self.tables.lock().unwrap().itemset_effects.clone())
+  }
+
+  fn load_titles(&self) -> Result<Vec<Title>, String> {
+    Ok(self.tables.lock().unwrap().titles.clone())
+  }
+
+  fn load_item_random_property_points(&self) -> Result<Vec<ItemRandomPropertyPoints>, String> {
+    Ok(self.tables.lock().unwrap().item_random_property_points.clone())
+  }
+}
+
+impl ServerRepository for InMemoryDataBackend {
+  fn claim_server(&self, server_id: u32, owner: u32) -> bool {
+    let mut tables = self.tables.lock().unwrap();
+    match tables.servers.iter_mut().find(|server| server.id == server_id && server.owner.is_none()) {
+      Some(server) => {
+        server.owner = Some(owner);
+        true
+      },
+      None => false,
+    }
+  }
+
+  fn transfer_server(&self, server_id: u32, owner: u32, new_owner: u32) -> bool {
+    let mut tables = self.tables.lock().unwrap();
+    match tables.servers.iter_mut().find(|server| server.id == server_id && server.owner == Some(owner)) {
+      Some(server) => {
+        server.owner = Some(new_owner);
+        true
+      },
+      None => false,
+    }
+  }
+}
+
+impl TranslationRepository for InMemoryDataBackend {
+  fn load_translation_sources(&self, language_id: u8) -> Result<HashMap<u32, String>, String> {
+    Ok(self.tables.lock().unwrap().localization_sources.iter()
+      .filter(|((source_language_id, _), _)| *source_language_id == language_id)
+      .map(|((_, id), source)| (*id, source.clone()))
+      .collect())
+  }
+
+  fn store_translations(&self, language_id: u8, entries: &[&PoEntry]) -> Result<(), String> {
+    let mut tables = self.tables.lock().unwrap();
+    for entry in entries.iter() {
+      tables.localizations.retain(|localization| localization.language_id != language_id || localization.id != entry.localization_id);
+      tables.localizations.push(Localization {
+        language_id,
+        id: entry.localization_id,
+        content: entry.translation.clone(),
+      });
+      tables.localization_sources.insert((language_id, entry.localization_id), entry.source.clone());
+    }
+    Ok(())
+  }
+}
\ No newline at end of file
@@ -0,0 +1,13 @@
+pub use self::data_backend::DataBackend;
+pub use self::data_table_repository::DataTableRepository;
+pub use self::in_memory_backend::{DataTables, InMemoryDataBackend};
+pub use self::mysql_backend::MySQLDataBackend;
+pub use self::server_repository::ServerRepository;
+pub use self::translation_repository::TranslationRepository;
+
+mod data_backend;
+mod data_table_repository;
+mod in_memory_backend;
+mod mysql_backend;
+mod server_repository;
+mod translation_repository;
\ No newline at end of file
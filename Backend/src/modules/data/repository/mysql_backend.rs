@@ -0,0 +1,490 @@
+use std::collections::HashMap;
+
+use mysql_connection::material::MySQLConnection;
+use mysql_connection::tools::{Execute, Select, StartTransaction, TakeColumn};
+
+use crate::modules::data::domain_value::{DispelType, Enchant, Expansion, Gem, HeroClass, HeroClassTalent, Icon, Item, ItemBonding, ItemClass, ItemDamage, ItemDamageType, ItemEffect, ItemInventoryType, ItemQuality, ItemRandomProperty, ItemRandomPropertyPoints, ItemSheath, ItemSocket, ItemStat, ItemsetEffect, ItemsetName, Language, Localization, NPC, PowerType, Profession, Race, Server, Spell, SpellEffect, Stat, StatType, Title};
+use crate::modules::data::material::PoEntry;
+use crate::modules::data::repository::{DataTableRepository, ServerRepository, TranslationRepository};
+
+#[derive(Debug)]
+pub struct MySQLDataBackend {
+  pub db_main: MySQLConnection,
+}
+
+impl MySQLDataBackend {
+  pub fn new(db_main: MySQLConnection) -> Self {
+    MySQLDataBackend { db_main }
+  }
+}
+
+impl DataTableRepository for MySQLDataBackend {
+  fn load_expansions(&self) -> Result<Vec<Expansion>, String> {
+    self.db_main.try_select("SELECT * FROM data_expansion", &|mut row| {
+      Ok(Expansion {
+        id: row.take_column(0)?,
+        localization_id: row.take_column(1)?,
+      })
+    }).map_err(|err| err.to_string())
+  }
+
+  fn load_languages(&self) -> Result<Vec<Language>, String> {
+    self.db_main.try_select("SELECT * FROM data_language", &|mut row| {
+      Ok(Language {
+        id: row.take_column(0)?,
+        name: row.take_column(1)?,
+        short_code: row.take_column(2)?,
+      })
+    }).map_err(|err| err.to_string())
+  }
+
+  fn load_localizations(&self) -> Result<Vec<Localization>, String> {
+    self.db_main.try_select("SELECT * FROM data_localization ORDER BY language_id, id", &|mut row| {
+      Ok(Localization {
+        language_id: row.take_column(0)?,
+        id: row.take_column(1)?,
+        content: row.take_column(2)?,
+      })
+    }).map_err(|err| err.to_string())
+  }
+
+  fn load_races(&self) -> Result<Vec<Race>, String> {
+    self.db_main.try_select("SELECT * FROM data_race", &|mut row| {
+      Ok(Race {
+        id: row.take_column(0)?,
+        localization_id: row.take_column(1)?,
+        faction: row.take_column(2)?
+      })
+    }).map_err(|err| err.to_string())
+  }
+
+  fn load_professions(&self) -> Result<Vec<Profession>, String> {
+    self.db_main.try_select("SELECT * FROM data_profession", &|mut row| {
+      Ok(Profession {
+        id: row.take_column(0)?,
+        localization_id: row.take_column(1)?,
+        icon: row.take_column(2)?,
+      })
+    }).map_err(|err| err.to_string())
+  }
+
+  fn load_servers(&self) -> Result<Vec<Server>, String> {
+    self.db_main.try_select("SELECT * FROM data_server", &|mut row| {
+      Ok(Server {
+        id: row.take_column(0)?,
+        expansion_id: row.take_column(1)?,
+        name: row.take_column(2)?,
+        owner: row.take_column(3)?,
+      })
+    }).map_err(|err| err.to_string())
+  }
+
+  fn load_hero_classes(&self) -> Result<Vec<HeroClass>, String> {
+    self.db_main.try_select("SELECT * FROM data_hero_class", &|mut row| {
+      Ok(HeroClass {
+        id: row.take_column(0)?,
+        localization_id: row.take_column(1)?,
+        color: row.take_column(2)?,
+        talents: [HeroClassTalent { icon: 0, localization_id: 0 }; 3]
+      })
+    }).map_err(|err| err.to_string())
+  }
+
+  fn load_hero_class_specs(&self) -> Result<Vec<(u8, u8, HeroClassTalent)>, String> {
+    self.db_main.try_select("SELECT * FROM data_hero_class_spec", &|mut row| {
+      let hero_class_id: u8 = row.take_column(0)?;
+      let index: u8 = row.take_column(1)?;
+      let talent = HeroClassTalent {
+        icon: row.take_column(2)?,
+        localization_id: row.take_column(3)?,
+      };
+      Ok((hero_class_id, index, talent))
+    }).map_err(|err| err.to_string())
+  }
+
+  fn load_spells(&self) -> Result<Vec<Spell>, String> {
+    self.db_main.try_select("SELECT * FROM data_spell ORDER BY expansion_id, id", &|mut row| {
+      Ok(Spell {
+        expansion_id: row.take_column(0)?,
+        id: row.take_column(1)?,
+        localization_id: row.take_column(2)?,
+        subtext_localization_id: row.take_column(3)?,
+        cost: row.take_column(4)?,
+        cost_in_percent: row.take_column(5)?,
+        power_type: row.take_column(6)?,
+        cast_time: row.take_column(7)?,
+        school_mask: row.take_column(8)?,
+        dispel_type: row.take_column(9)?,
+        range_max: row.take_column(10)?,
+        cooldown: row.take_column(11)?,
+        duration: row.take_column(12)?,
+        icon: row.take_column(13)?,
+        description_localization_id: row.take_column(14)?,
+        aura_localization_id: row.take_column(15)?,
+      })
+    }).map_err(|err| err.to_string())
+  }
+
+  fn load_dispel_types(&self) -> Result<Vec<DispelType>, String> {
+    self.db_main.try_select("SELECT * FROM data_spell_dispel_type", &|mut row| {
+      Ok(DispelType {
+        id: row.take_column(0)?,
+        localization_id: row.take_column(1)?,
+        color: row.take_column(2)?,
+      })
+    }).map_err(|err| err.to_string())
+  }
+
+  fn load_power_types(&self) -> Result<Vec<PowerType>, String> {
+    self.db_main.try_select("SELECT * FROM data_spell_power_type", &|mut row| {
+      Ok(PowerType {
+        id: row.take_column(0)?,
+        localization_id: row.take_column(1)?,
+        color: row.take_column(2)?,
+      })
+    }).map_err(|err| err.to_string())
+  }
+
+  fn load_stat_types(&self) -> Result<Vec<StatType>, String> {
+    self.db_main.try_select("SELECT * FROM data_stat_type", &|mut row| {
+      Ok(StatType {
+        id: row.take_column(0)?,
+        localization_id: row.take_column(1)?,
+      })
+    }).map_err(|err| err.to_string())
+  }
+
+  fn load_spell_effects(&self) -> Result<Vec<SpellEffect>, String> {
+    self.db_main.try_select("SELECT * FROM data_spell_effect ORDER BY expansion_id, spell_id, id", &|mut row| {
+      Ok(SpellEffect {
+        id: row.take_column(0)?,
+        expansion_id: row.take_column(1)?,
+        spell_id: row.take_column(2)?,
+        points_lower: row.take_column(3)?,
+        points_upper: row.take_column(4)?,
+        chain_targets: row.take_column(5)?,
+        radius: row.take_column(6)?,
+        aura: row.take_column(7)?,
+        misc_value: row.take_column(8)?,
+      })
+    }).map_err(|err| err.to_string())
+  }
+
+  fn load_npcs(&self) -> Result<Vec<NPC>, String> {
+    self.db_main.try_select("SELECT * FROM data_npc ORDER BY expansion_id, id", &|mut row| {
+      Ok(NPC {
+        expansion_id: row.take_column(0)?,
+        id: row.take_column(1)?,
+        localization_id: row.take_column(2)?,
+        is_boss: row.take_column(3)?,
+        friend: row.take_column(4)?,
+        family: row.take_column(5)?,
+      })
+    }).map_err(|err| err.to_string())
+  }
+
+  fn load_icons(&self) -> Result<Vec<Icon>, String> {
+    self.db_main.try_select("SELECT * FROM data_icon ORDER BY id", &|mut row| {
+      Ok(Icon {
+        id: row.take_column(0)?,
+        name: row.take_column(1)?,
+      })
+    }).map_err(|err| err.to_string())
+  }
+
+  fn load_items(&self) -> Result<Vec<Item>, String> {
+    self.db_main.try_select("SELECT * FROM data_item ORDER BY expansion_id, id", &|mut row| {
+      Ok(Item {
+        expansion_id: row.take_column(0)?,
+        id: row.take_column(1)?,
+        localization_id: row.take_column(2)?,
+        icon: row.take_column(3)?,
+        quality: row.take_column(4)?,
+        inventory_type: row.take_column(5)?,
+        class_id: row.take_column(6)?,
+        required_level: row.take_column(7)?,
+        bonding: row.take_column(8)?,
+        sheath: row.take_column(9)?,
+        itemset: row.take_column(10)?,
+        max_durability: row.take_column(11)?,
+        item_level: row.take_column(12)?,
+        delay: row.take_column(13)?,
+        allowable_class: row.take_column(14)?,
+      })
+    }).map_err(|err| err.to_string())
+  }
+
+  fn load_gems(&self) -> Result<Vec<Gem>, String> {
+    self.db_main.try_select("SELECT * FROM data_gem ORDER BY expansion_id, item_id", &|mut row| {
+      Ok(Gem {
+        expansion_id: row.take_column(0)?,
+        item_id: row.take_column(1)?,
+        enchant_id: row.take_column(2)?,
+        flag: row.take_column(3)?,
+      })
+    }).map_err(|err| err.to_string())
+  }
+
+  fn load_enchants(&self) -> Result<Vec<Enchant>, String> {
+    self.db_main.try_select("SELECT * FROM data_enchant ORDER BY expansion_id, id", &|mut row| {
+      let mut stats = Vec::new();
+      for i in (3..8).step_by(2) {
+        let stat_type: Option<u8> = row.take_column(i)?;
+        let stat_value: Option<u16> = row.take_column(i + 1)?;
+        match (stat_type, stat_value) {
+          (Some(stat_type), Some(stat_value)) => stats.push(Stat { stat_type, stat_value }),
+          _ => break
+        }
+      }
+      Ok(Enchant {
+        expansion_id: row.take_column(0)?,
+        id: row.take_column(1)?,
+        localization_id: row.take_column(2)?,
+        stats,
+      })
+    }).map_err(|err| err.to_string())
+  }
+
+  fn load_item_bondings(&self) -> Result<Vec<ItemBonding>, String> {
+    self.db_main.try_select("SELECT * FROM data_item_bonding", &|mut row| {
+      Ok(ItemBonding {
+        id: row.take_column(0)?,
+        localization_id: row.take_column(1)?,
+      })
+    }).map_err(|err| err.to_string())
+  }
+
+  fn load_item_classes(&self) -> Result<Vec<ItemClass>, String> {
+    self.db_main.try_select("SELECT * FROM data_item_class", &|mut row| {
+      Ok(ItemClass {
+        id: row.take_column(0)?,
+        item_class: row.take_column(1)?,
+        item_sub_class: row.take_column(2)?,
+        localization_id: row.take_column(3)?,
+      })
+    }).map_err(|err| err.to_string())
+  }
+
+  fn load_item_damages(&self) -> Result<Vec<ItemDamage>, String> {
+    self.db_main.try_select("SELECT * FROM data_item_dmg ORDER BY expansion_id, item_id, id", &|mut row| {
+      Ok(ItemDamage {
+        id: row.take_column(0)?,
+        expansion_id: row.take_column(1)?,
+        item_id: row.take_column(2)?,
+        dmg_type: row.take_column(3)?,
+        dmg_min: row.take_column(4)?,
+        dmg_max: row.take_column(5)?,
+      })
+    }).map_err(|err| err.to_string())
+  }
+
+  fn load_item_damage_types(&self) -> Result<Vec<ItemDamageType>, String> {
+    self.db_main.try_select("SELECT * FROM data_item_dmg_type", &|mut row| {
+      Ok(ItemDamageType {
+        id: row.take_column(0)?,
+        localization_id: row.take_column(1)?,
+      })
+    }).map_err(|err| err.to_string())
+  }
+
+  fn load_item_effects(&self) -> Result<Vec<ItemEffect>, String> {
+    self.db_main.try_select("SELECT id, expansion_id, item_id, spell_id FROM data_item_effect ORDER BY expansion_id, item_id, id", &|mut row| {
+      Ok(ItemEffect {
+        id: row.take_column(0)?,
+        expansion_id: row.take_column(1)?,
+        item_id: row.take_column(2)?,
+        spell_id: row.take_column(3)?,
+      })
+    }).map_err(|err| err.to_string())
+  }
+
+  fn load_item_inventory_types(&self) -> Result<Vec<ItemInventoryType>, String> {
+    self.db_main.try_select("SELECT * FROM data_item_inventory_type", &|mut row| {
+      Ok(ItemInventoryType {
+        id: row.take_column(0)?,
+        localization_id: row.take_column(1)?,
+      })
+    }).map_err(|err| err.to_string())
+  }
+
+  fn load_item_qualities(&self) -> Result<Vec<ItemQuality>, String> {
+    self.db_main.try_select("SELECT * FROM data_item_quality", &|mut row| {
+      Ok(ItemQuality {
+        id: row.take_column(0)?,
+        localization_id: row.take_column(1)?,
+        color: row.take_column(2)?,
+      })
+    }).map_err(|err| err.to_string())
+  }
+
+  fn load_item_random_properties(&self) -> Result<Vec<ItemRandomProperty>, String> {
+    self.db_main.try_select("SELECT * FROM data_item_random_property ORDER BY expansion_id, id", &|mut row| {
+      let mut enchant_ids = Vec::new();
+      for i in 3..8 {
+        if let Some(enchant_id) = row.take_column(i)? {
+          enchant_ids.push(enchant_id);
+        }
+      }
+      let mut scaling_coefficients = Vec::new();
+      for i in 8..13 {
+        if let Some(coefficient) = row.take_column(i)? {
+          scaling_coefficients.push(coefficient);
+        }
+      }
+      Ok(ItemRandomProperty {
+        expansion_id: row.take_column(0)?,
+        id: row.take_column(1)?,
+        localization_id: row.take_column(2)?,
+        enchant_ids,
+        scaling_coefficients
+      })
+    }).map_err(|err| err.to_string())
+  }
+
+  fn load_item_sheaths(&self) -> Result<Vec<ItemSheath>, String> {
+    self.db_main.try_select("SELECT * FROM data_item_sheath", &|mut row| {
+      Ok(ItemSheath {
+        id: row.take_column(0)?,
+        localization_id: row.take_column(1)?,
+      })
+    }).map_err(|err| err.to_string())
+  }
+
+  fn load_item_sockets(&self) -> Result<Vec<ItemSocket>, String> {
+    self.db_main.try_select("SELECT * FROM data_item_socket ORDER BY expansion_id, item_id", &|mut row| {
+      let mut slots = Vec::new();
+      for i in 3..6 {
+        if let Some(slot) = row.take_column(i)? {
+          slots.push(slot);
+        }
+      }
+      Ok(ItemSocket {
+        expansion_id: row.take_column(0)?,
+        item_id: row.take_column(1)?,
+        bonus: row.take_column(2)?,
+        slots,
+      })
+    }).map_err(|err| err.to_string())
+  }
+
+  fn load_item_stats(&self) -> Result<Vec<ItemStat>, String> {
+    self.db_main.try_select("SELECT * FROM data_item_stat WHERE stat_type IN (1,2,3,4,5,6,27,28,29,30,31) OR stat_type = 34 OR (expansion_id > 1 AND stat_type IN (7,8,37,22,23,24,10,11,12,42,38,39,40,41)) OR (expansion_id>2 AND stat_type IN (9,13,21,43)) ORDER BY expansion_id, item_id", &|mut row| {
+      Ok(ItemStat {
+        id: row.take_column(0)?,
+        expansion_id: row.take_column(1)?,
+        item_id: row.take_column(2)?,
+        stat: Stat {
+          stat_type: row.take_column(3)?,
+          stat_value: row.take_column(4)?,
+        },
+      })
+    }).map_err(|err| err.to_string())
+  }
+
+  fn load_itemset_names(&self) -> Result<Vec<ItemsetName>, String> {
+    self.db_main.try_select("SELECT * FROM data_itemset_name ORDER BY expansion_id, id", &|mut row| {
+      Ok(ItemsetName {
+        expansion_id: row.take_column(0)?,
+        id: row.take_column(1)?,
+        localization_id: row.take_column(2)?,
+      })
+    }).map_err(|err| err.to_string())
+  }
+
+  fn load_itemset_effects(&self) -> Result<Vec<ItemsetEffect>, String> {
+    self.db_main.try_select("SELECT * FROM data_itemset_effect ORDER BY expansion_id, itemset_id, id", &|mut row| {
+      Ok(ItemsetEffect {
+        id: row.take_column(0)?,
+        expansion_id: row.take_column(1)?,
+        itemset_id: row.take_column(2)?,
+        threshold: row.take_column(3)?,
+        spell_id: row.take_column(4)?,
+      })
+    }).map_err(|err| err.to_string())
+  }
+
+  fn load_titles(&self) -> Result<Vec<Title>, String> {
+    self.db_main.try_select("SELECT * FROM data_title", &|mut row| {
+      Ok(Title {
+        id: row.take_column(0)?,
+        localization_id: row.take_column(1)?
+      })
+    }).map_err(|err| err.to_string())
+  }
+
+  fn load_item_random_property_points(&self) -> Result<Vec<ItemRandomPropertyPoints>, String> {
+    self.db_main.try_select("SELECT * FROM data_item_random_property_points ORDER BY expansion_id, item_level", &|mut row| {
+      Ok(ItemRandomPropertyPoints {
+        item_level: row.take_column(0)?,
+        expansion_id: row.take_column(1)?,
+        epic: [
+          row.take_column(2)?,
+          row.take_column(3)?,
+          row.take_column(4)?,
+          row.take_column(5)?,
+          row.take_column(6)?,
+        ],
+        rare: [
+          row.take_column(7)?,
+          row.take_column(8)?,
+          row.take_column(9)?,
+          row.take_column(10)?,
+          row.take_column(11)?,
+        ],
+        good: [
+          row.take_column(12)?,
+          row.take_column(13)?,
+          row.take_column(14)?,
+          row.take_column(15)?,
+          row.take_column(16)?,
+        ]
+      })
+    }).map_err(|err| err.to_string())
+  }
+}
+
+impl ServerRepository for MySQLDataBackend {
+  fn claim_server(&self, server_id: u32, owner: u32) -> bool {
+    self.db_main.execute_wparams("UPDATE data_server SET owner=:owner WHERE id=:id AND owner IS NULL", params!(
+      "owner" => owner,
+      "id" => server_id
+    ))
+  }
+
+  fn transfer_server(&self, server_id: u32, owner: u32, new_owner: u32) -> bool {
+    self.db_main.execute_wparams("UPDATE data_server SET owner=:new_owner WHERE id=:id AND owner=:owner", params!(
+      "new_owner" => new_owner,
+      "id" => server_id,
+      "owner" => owner
+    ))
+  }
+}
+
+impl TranslationRepository for MySQLDataBackend {
+  fn load_translation_sources(&self, language_id: u8) -> Result<HashMap<u32, String>, String> {
+    self.db_main.try_select_wparams("SELECT id, source FROM data_localization_source WHERE language_id=:language_id", &|mut row| {
+      Ok((row.take_named("id")?, row.take_named("source")?))
+    }, params!("language_id" => language_id))
+      .map(|sources| sources.into_iter().collect())
+      .map_err(|err| err.to_string())
+  }
+
+  fn store_translations(&self, language_id: u8, entries: &[&PoEntry]) -> Result<(), String> {
+    let mut transaction = self.db_main.start_transaction().map_err(|err| err.to_string())?;
+    for entry in entries.iter() {
+      transaction.execute_wparams("INSERT INTO data_localization (`language_id`, `id`, `content`) VALUES (:language_id, :id, :content) \
+        ON DUPLICATE KEY UPDATE `content`=:content", params!(
+        "language_id" => language_id,
+        "id" => entry.localization_id,
+        "content" => entry.translation.clone()
+      )).map_err(|err| err.to_string())?;
+      transaction.execute_wparams("INSERT INTO data_localization_source (`language_id`, `id`, `source`) VALUES (:language_id, :id, :source) \
+        ON DUPLICATE KEY UPDATE `source`=:source", params!(
+        "language_id" => language_id,
+        "id" => entry.localization_id,
+        "source" => entry.source.clone()
+      )).map_err(|err| err.to_string())?;
+    }
+    transaction.commit().map_err(|err| err.to_string())
+  }
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+pub trait ServerRepository {
+  // Only succeeds if the server is not owned yet
+  fn claim_server(&self, server_id: u32, owner: u32) -> bool;
+  // Only succeeds if the server is still owned by the owner
+  fn transfer_server(&self, server_id: u32, owner: u32, new_owner: u32) -> bool;
+}
\ No newline at end of file
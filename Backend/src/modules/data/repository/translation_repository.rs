@@ -0,0 +1,10 @@
+use std::collections::HashMap;
+
+use crate::modules::data::material::PoEntry;
+
+pub trait TranslationRepository {
+  // The english content each translation has been made from, as recorded by the import
+  fn load_translation_sources(&self, language_id: u8) -> Result<HashMap<u32, String>, String>;
+  // Either all translations are stored together with their sources or none
+  fn store_translations(&self, language_id: u8, entries: &[&PoEntry]) -> Result<(), String>;
+}
\ No newline at end of file
@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use crate::modules::data::{Data, DataStore};
+use crate::modules::data::repository::InMemoryDataBackend;
+use crate::modules::data::tools::ReloadData;
+
+#[test]
+fn swapped_snapshot_serves_subsequent_requests() {
+  let store = DataStore::new(in_memory_data());
+  let snapshot = store.snapshot();
+  store.swap(in_memory_data());
+  assert!(!Arc::ptr_eq(&snapshot, &store.snapshot()));
+}
+
+#[test]
+fn reload_is_rejected_while_in_progress() {
+  let store = DataStore::new(in_memory_data());
+  store.reload_status.write().unwrap().reloading = true;
+  assert!(store.reload().is_err());
+  assert!(store.get_reload_status().reloading);
+}
+
+fn in_memory_data() -> Data {
+  Data::with_backend(Arc::new(InMemoryDataBackend::default()))
+}
\ No newline at end of file
@@ -3,7 +3,7 @@ use crate::modules::data::tools::RetrieveDispelType;
 
 #[test]
 fn get_dispel_type() {
-  let data = Data::default().init(Some(9));
+  let data = Data::default().init(Some(9)).unwrap();
   let dispel_type = data.get_dispel_type(1);
   assert!(dispel_type.is_some());
   assert_eq!(dispel_type.unwrap().id, 1);
@@ -13,7 +13,7 @@ fn get_dispel_type() {
 
 #[test]
 fn get_all_dispel_types() {
-  let data = Data::default().init(Some(9));
+  let data = Data::default().init(Some(9)).unwrap();
   let dispel_types = data.get_all_dispel_types();
   assert!(dispel_types.len() > 0);
 }
\ No newline at end of file
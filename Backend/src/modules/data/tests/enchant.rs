@@ -3,7 +3,7 @@ use crate::modules::data::tools::RetrieveEnchant;
 
 #[test]
 fn get_enchant() {
-  let data = Data::default().init(Some(17));
+  let data = Data::default().init(Some(17)).unwrap();
   let enchant = data.get_enchant(1, 1);
   assert!(enchant.is_some());
   let unpacked_enchant = enchant.unwrap();
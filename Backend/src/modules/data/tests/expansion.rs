@@ -3,7 +3,7 @@ use crate::modules::data::tools::RetrieveExpansion;
 
 #[test]
 fn get_expansion() {
-  let data = Data::default().init(Some(1));
+  let data = Data::default().init(Some(1)).unwrap();
   let expansion = data.get_expansion(1);
   assert!(expansion.is_some());
   assert_eq!(expansion.unwrap().id, 1);
@@ -13,7 +13,7 @@ fn get_expansion() {
 
 #[test]
 fn get_all_expansions() {
-  let data = Data::default().init(Some(1));
+  let data = Data::default().init(Some(1)).unwrap();
   let expansions = data.get_all_expansions();
   assert!(expansions.len() > 0);
 }
\ No newline at end of file
@@ -3,7 +3,7 @@ use crate::modules::data::tools::RetrieveGem;
 
 #[test]
 fn get_gem() {
-  let data = Data::default().init(Some(16));
+  let data = Data::default().init(Some(16)).unwrap();
   let gem = data.get_gem(2, 22459);
   assert!(gem.is_some());
   let unpacked_gem = gem.unwrap();
@@ -3,7 +3,7 @@ use crate::modules::data::tools::RetrieveHeroClass;
 
 #[test]
 fn get_hero_class() {
-  let data = Data::default().init(Some(7));
+  let data = Data::default().init(Some(7)).unwrap();
   let hero_class = data.get_hero_class(1);
   assert!(hero_class.is_some());
   assert_eq!(hero_class.unwrap().id, 1);
@@ -13,7 +13,7 @@ fn get_hero_class() {
 
 #[test]
 fn get_all_hero_classs() {
-  let data = Data::default().init(Some(7));
+  let data = Data::default().init(Some(7)).unwrap();
   let hero_classes = data.get_all_hero_classes();
   assert!(hero_classes.len() > 0);
 }
\ No newline at end of file
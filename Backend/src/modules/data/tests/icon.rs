@@ -3,7 +3,7 @@ use crate::modules::data::tools::RetrieveIcon;
 
 #[test]
 fn get_icon() {
-  let data = Data::default().init(Some(14));
+  let data = Data::default().init(Some(14)).unwrap();
   let icon = data.get_icon(1);
   assert!(icon.is_some());
   assert_eq!(icon.unwrap().id, 1);
@@ -3,7 +3,7 @@ use crate::modules::data::tools::RetrieveItem;
 
 #[test]
 fn get_item() {
-  let data = Data::default().init(Some(15));
+  let data = Data::default().init(Some(15)).unwrap();
   let item = data.get_item(1, 25);
   assert!(item.is_some());
   let unpacked_item = item.unwrap();
@@ -3,7 +3,7 @@ use crate::modules::data::tools::RetrieveItemBonding;
 
 #[test]
 fn get_item_bonding() {
-  let data = Data::default().init(Some(18));
+  let data = Data::default().init(Some(18)).unwrap();
   let item_bonding = data.get_item_bonding(1);
   assert!(item_bonding.is_some());
   assert_eq!(item_bonding.unwrap().id, 1);
@@ -13,7 +13,7 @@ fn get_item_bonding() {
 
 #[test]
 fn get_all_item_bondings() {
-  let data = Data::default().init(Some(18));
+  let data = Data::default().init(Some(18)).unwrap();
   let item_bondings = data.get_all_item_bondings();
   assert!(item_bondings.len() > 0);
 }
\ No newline at end of file
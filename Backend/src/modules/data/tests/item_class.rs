@@ -3,7 +3,7 @@ use crate::modules::data::tools::RetrieveItemClass;
 
 #[test]
 fn get_item_class() {
-  let data = Data::default().init(Some(19));
+  let data = Data::default().init(Some(19)).unwrap();
   let item_class = data.get_item_class(1);
   assert!(item_class.is_some());
   assert_eq!(item_class.unwrap().id, 1);
@@ -13,7 +13,7 @@ fn get_item_class() {
 
 #[test]
 fn get_all_item_classes() {
-  let data = Data::default().init(Some(19));
+  let data = Data::default().init(Some(19)).unwrap();
   let item_classs = data.get_all_item_classes();
   assert!(item_classs.len() > 0);
 }
\ No newline at end of file
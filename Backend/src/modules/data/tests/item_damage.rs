@@ -3,7 +3,7 @@ use crate::modules::data::tools::RetrieveItemDamage;
 
 #[test]
 fn get_item_damage() {
-  let data = Data::default().init(Some(20));
+  let data = Data::default().init(Some(20)).unwrap();
   let item_damage = data.get_item_damage(1, 25);
   assert!(item_damage.is_some());
   let unpacked_item_damage_vec = item_damage.unwrap();
@@ -3,7 +3,7 @@ use crate::modules::data::tools::RetrieveItemDamageType;
 
 #[test]
 fn get_item_damage_type() {
-  let data = Data::default().init(Some(21));
+  let data = Data::default().init(Some(21)).unwrap();
   let item_damage_type = data.get_item_damage_type(1);
   assert!(item_damage_type.is_some());
   assert_eq!(item_damage_type.unwrap().id, 1);
@@ -13,7 +13,7 @@ fn get_item_damage_type() {
 
 #[test]
 fn get_all_item_damage_types() {
-  let data = Data::default().init(Some(21));
+  let data = Data::default().init(Some(21)).unwrap();
   let item_damage_types = data.get_all_item_damage_types();
   assert!(item_damage_types.len() > 0);
 }
\ No newline at end of file
@@ -3,7 +3,7 @@ use crate::modules::data::tools::RetrieveItemEffect;
 
 #[test]
 fn get_item_effect() {
-  let data = Data::default().init(Some(22));
+  let data = Data::default().init(Some(22)).unwrap();
   let item_effect = data.get_item_effect(1, 117);
   assert!(item_effect.is_some());
   let unpacked_item_effect_vec = item_effect.unwrap();
@@ -3,7 +3,7 @@ use crate::modules::data::tools::RetrieveItemQuality;
 
 #[test]
 fn get_item_quality() {
-  let data = Data::default().init(Some(24));
+  let data = Data::default().init(Some(24)).unwrap();
   let item_quality = data.get_item_quality(1);
   assert!(item_quality.is_some());
   assert_eq!(item_quality.unwrap().id, 1);
@@ -13,7 +13,7 @@ fn get_item_quality() {
 
 #[test]
 fn get_all_item_qualities() {
-  let data = Data::default().init(Some(24));
+  let data = Data::default().init(Some(24)).unwrap();
   let item_qualities = data.get_all_item_qualities();
   assert!(item_qualities.len() > 0);
 }
\ No newline at end of file
@@ -3,7 +3,7 @@ use crate::modules::data::tools::RetrieveItemRandomProperty;
 
 #[test]
 fn get_item_random_property() {
-  let data = Data::default().init(Some(25));
+  let data = Data::default().init(Some(25)).unwrap();
   let item_random_property = data.get_item_random_property(1, 5);
   assert!(item_random_property.is_some());
   let unpacked_item_random_property = item_random_property.unwrap();
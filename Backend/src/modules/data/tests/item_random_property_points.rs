@@ -3,7 +3,7 @@ use crate::modules::data::tools::RetrieveItemRandomPropertyPoints;
 
 #[test]
 fn get_item_random_property_points() {
-  let data = Data::default().init(Some(32));
+  let data = Data::default().init(Some(32)).unwrap();
   let item_random_property = data.get_item_random_property_points(2, 5);
   assert!(item_random_property.is_some());
   let unpacked_item_random_property = item_random_property.unwrap();
@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+
+use crate::dto::{RangeFilter, TableFilter};
+use crate::modules::data::Data;
+use crate::modules::data::domain_value::{Item, ItemStat, Localization, Stat};
+use crate::modules::data::dto::ItemSearchFilter;
+use crate::modules::data::material::ItemSearchIndex;
+use crate::modules::data::tools::SearchItem;
+
+fn item(id: u32, quality: u8, itemset: Option<u16>) -> Item {
+  Item {
+    expansion_id: 1,
+    id,
+    localization_id: id,
+    icon: 1,
+    quality,
+    inventory_type: Some(1),
+    class_id: 4,
+    required_level: Some(60),
+    bonding: None,
+    sheath: None,
+    itemset,
+    max_durability: None,
+    item_level: Some(60 + id as u16),
+    delay: None,
+    allowable_class: None,
+  }
+}
+
+fn localization(language_id: u8, id: u32, content: &str) -> Localization {
+  Localization { id, language_id, content: content.to_owned() }
+}
+
+fn filter() -> ItemSearchFilter {
+  ItemSearchFilter {
+    page: 0,
+    page_size: None,
+    cursor: None,
+    name: TableFilter { filter: None, sorting: None },
+    expansion_id: TableFilter { filter: None, sorting: None },
+    quality: TableFilter { filter: None, sorting: None },
+    inventory_type: TableFilter { filter: None, sorting: None },
+    class_id: TableFilter { filter: None, sorting: None },
+    item_level: TableFilter { filter: None, sorting: None },
+    required_level: TableFilter { filter: None, sorting: None },
+    stat_types: None,
+    itemset: TableFilter { filter: None, sorting: None },
+    hero_class_id: None,
+  }
+}
+
+#[test]
+fn item_search_index() {
+  let mut items = HashMap::new();
+  items.insert(1, item(1, 4, Some(201)));
+  items.insert(2, item(2, 3, None));
+  items.insert(3, item(3, 4, None));
+  let mut item_stats = HashMap::new();
+  item_stats.insert(1, vec![ItemStat { id: 1, expansion_id: 1, item_id: 1, stat: Stat { stat_type: 7, stat_value: 10 } }]);
+  item_stats.insert(3, vec![ItemStat { id: 2, expansion_id: 1, item_id: 3, stat: Stat { stat_type: 7, stat_value: 5 } }]);
+  let mut english = HashMap::new();
+  english.insert(1, localization(1, 1, "Dragonstalker's Helm"));
+  english.insert(2, localization(1, 2, "Lionheart Helm"));
+  english.insert(3, localization(1, 3, "Dragonstalker's Legguards"));
+  let mut german = HashMap::new();
+  german.insert(1, localization(2, 1, "Helm des Drachenjägers"));
+
+  let mut localization = HashMap::new();
+  localization.insert(1, english);
+  localization.insert(2, german);
+
+  let index = ItemSearchIndex::build(&[items], &[item_stats], &localization);
+  assert_eq!(index.len(), 3);
+
+  let mut result = index.find(1, Some("dragonstalker"), None, None, None, None, &[], None);
+  result.sort();
+  assert_eq!(result, vec![(1, 1), (1, 3)]);
+  assert_eq!(index.find(1, None, None, Some(4), None, None, &[7], Some(true)), vec![(1, 1)]);
+  assert_eq!(index.find(1, None, None, Some(4), None, None, &[7], Some(false)), vec![(1, 3)]);
+  assert!(index.find(1, None, None, None, None, None, &[8], None).is_empty());
+
+  // Missing translations fall back to english
+  assert_eq!(index.find(2, Some("drachen"), None, None, None, None, &[], None), vec![(1, 1)]);
+  assert_eq!(index.find(2, Some("lionheart"), None, None, None, None, &[], None), vec![(1, 2)]);
+}
+
+#[test]
+fn range_filter() {
+  let range = RangeFilter { min: Some(60), max: None };
+  assert!(range.contains(&60));
+  assert!(range.contains(&70));
+  assert!(!range.contains(&59));
+}
+
+#[test]
+fn search_items() {
+  let data = Data::default().init(Some(3)).unwrap().init(Some(15)).unwrap().init(Some(28)).unwrap().init(Some(33)).unwrap();
+  let mut item_filter = filter();
+  item_filter.expansion_id.filter = Some(1);
+  item_filter.item_level = TableFilter { filter: Some(RangeFilter { min: Some(60), max: Some(70) }), sorting: Some(false) };
+  item_filter.page_size = Some(20);
+  let result = data.search_items(1, item_filter).unwrap();
+  assert!(result.num_items > 20);
+  assert_eq!(result.result.len(), 20);
+  assert!(result.result.iter().all(|item| item.base.expansion_id == 1
+    && item.base.item_level.map(|item_level| item_level >= 60 && item_level <= 70).unwrap_or(false)));
+  assert!(result.result.windows(2).all(|items| items[0].base.item_level >= items[1].base.item_level));
+  assert!(result.result.iter().all(|item| !item.localization.is_empty()));
+}
+
+#[test]
+fn item_usable_by_hero_class() {
+  let mut restricted = item(1, 4, None);
+  assert!(restricted.is_usable_by(1));
+  restricted.allowable_class = Some(0b101);
+  assert!(restricted.is_usable_by(1));
+  assert!(!restricted.is_usable_by(2));
+  assert!(restricted.is_usable_by(3));
+  assert!(!restricted.is_usable_by(0));
+}
+
+#[test]
+fn search_items_by_hero_class() {
+  let mut data = Data::default().init(Some(3)).unwrap().init(Some(15)).unwrap().init(Some(28)).unwrap().init(Some(33)).unwrap();
+  let item_id = *data.items[0].keys().min().unwrap();
+  data.items[0].get_mut(&item_id).unwrap().allowable_class = Some(0b10);
+  let mut item_filter = filter();
+  item_filter.expansion_id.filter = Some(1);
+  item_filter.page_size = Some(100);
+  let num_items = data.search_items(1, item_filter.clone()).unwrap().num_items;
+
+  item_filter.hero_class_id = Some(1);
+  assert_eq!(data.search_items(1, item_filter.clone()).unwrap().num_items, num_items - 1);
+  item_filter.hero_class_id = Some(2);
+  assert_eq!(data.search_items(1, item_filter).unwrap().num_items, num_items);
+}
+
+#[test]
+fn search_items_by_cursor() {
+  let data = Data::default().init(Some(3)).unwrap().init(Some(15)).unwrap().init(Some(28)).unwrap().init(Some(33)).unwrap();
+  let mut item_filter = filter();
+  item_filter.expansion_id.filter = Some(1);
+  item_filter.item_level = TableFilter { filter: Some(RangeFilter { min: Some(60), max: Some(70) }), sorting: Some(false) };
+  item_filter.page_size = Some(20);
+  let first_page = data.search_items(1, item_filter.clone()).unwrap();
+  item_filter.page = 1;
+  let second_page = data.search_items(1, item_filter.clone()).unwrap();
+
+  item_filter.page = 0;
+  item_filter.cursor = first_page.next_cursor.clone();
+  assert!(item_filter.cursor.is_some());
+  let next_page = data.search_items(1, item_filter.clone()).unwrap();
+  assert_eq!(next_page.num_items, first_page.num_items);
+  assert_eq!(next_page.result.iter().map(|item| item.base.id).collect::<Vec<u32>>(),
+             second_page.result.iter().map(|item| item.base.id).collect::<Vec<u32>>());
+
+  // A cursor does not continue a different order
+  item_filter.item_level.sorting = Some(true);
+  assert!(data.search_items(1, item_filter.clone()).is_err());
+  item_filter.cursor = Some("zz".to_owned());
+  assert!(data.search_items(1, item_filter).is_err());
+}
\ No newline at end of file
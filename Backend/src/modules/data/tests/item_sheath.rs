@@ -3,7 +3,7 @@ use crate::modules::data::tools::RetrieveItemSheath;
 
 #[test]
 fn get_item_sheath() {
-  let data = Data::default().init(Some(26));
+  let data = Data::default().init(Some(26)).unwrap();
   let item_sheath = data.get_item_sheath(1);
   assert!(item_sheath.is_some());
   assert_eq!(item_sheath.unwrap().id, 1);
@@ -13,7 +13,7 @@ fn get_item_sheath() {
 
 #[test]
 fn get_all_item_sheaths() {
-  let data = Data::default().init(Some(26));
+  let data = Data::default().init(Some(26)).unwrap();
   let item_sheaths = data.get_all_item_sheaths();
   assert!(item_sheaths.len() > 0);
 }
\ No newline at end of file
@@ -3,7 +3,7 @@ use crate::modules::data::tools::RetrieveItemSocket;
 
 #[test]
 fn get_item_socket() {
-  let data = Data::default().init(Some(27));
+  let data = Data::default().init(Some(27)).unwrap();
   let item_socket = data.get_item_socket(2, 21846);
   assert!(item_socket.is_some());
   let unpacked_item_socket = item_socket.unwrap();
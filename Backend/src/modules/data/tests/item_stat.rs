@@ -3,7 +3,7 @@ use crate::modules::data::tools::RetrieveItemStat;
 
 #[test]
 fn get_item_stats() {
-  let data = Data::default().init(Some(28));
+  let data = Data::default().init(Some(28)).unwrap();
   let item_stats = data.get_item_stats(1, 940);
   assert!(item_stats.is_some());
   let item_stats_vec = item_stats.unwrap();
@@ -3,7 +3,7 @@ use crate::modules::data::tools::RetrieveItemsetEffect;
 
 #[test]
 fn get_itemset_effects() {
-  let data = Data::default().init(Some(30));
+  let data = Data::default().init(Some(30)).unwrap();
   let itemset_effects = data.get_itemset_effects(1, 1);
   assert!(itemset_effects.is_some());
   let itemset_effects_vec = itemset_effects.unwrap();
@@ -12,4 +12,19 @@ fn get_itemset_effects() {
   assert_eq!(itemset_effects_vec[0].itemset_id, 1);
   let no_itemset_effects = data.get_itemset_effects(0, 0);
   assert!(no_itemset_effects.is_none());
+}
+
+#[test]
+fn get_active_itemset_effects() {
+  let data = Data::default().init(Some(30)).unwrap();
+  let itemset_effects_vec = data.get_itemset_effects(1, 1).unwrap();
+  let min_threshold = itemset_effects_vec.iter().map(|itemset_effect| itemset_effect.threshold).min().unwrap();
+  let max_threshold = itemset_effects_vec.iter().map(|itemset_effect| itemset_effect.threshold).max().unwrap();
+
+  if let Some(below_threshold) = min_threshold.checked_sub(1) {
+    assert!(data.get_active_itemset_effects(1, 1, below_threshold).unwrap().is_empty());
+  }
+  assert!(data.get_active_itemset_effects(1, 1, min_threshold).unwrap().iter().all(|itemset_effect| itemset_effect.threshold == min_threshold));
+  assert_eq!(data.get_active_itemset_effects(1, 1, max_threshold).unwrap().len(), itemset_effects_vec.len());
+  assert!(data.get_active_itemset_effects(0, 1, max_threshold).is_none());
 }
\ No newline at end of file
@@ -3,7 +3,7 @@ use crate::modules::data::tools::RetrieveItemsetName;
 
 #[test]
 fn get_itemset_name() {
-  let data = Data::default().init(Some(29));
+  let data = Data::default().init(Some(29)).unwrap();
   let itemset_name = data.get_itemset_name(1, 1);
   assert!(itemset_name.is_some());
   let unpacked_itemset_name = itemset_name.unwrap();
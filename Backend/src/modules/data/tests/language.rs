@@ -3,7 +3,7 @@ use crate::modules::data::tools::RetrieveLanguage;
 
 #[test]
 fn get_language() {
-  let data = Data::default().init(Some(2));
+  let data = Data::default().init(Some(2)).unwrap();
   let language = data.get_language(1);
   assert!(language.is_some());
   assert_eq!(language.unwrap().id, 1);
@@ -13,7 +13,7 @@ fn get_language() {
 
 #[test]
 fn get_all_languages() {
-  let data = Data::default().init(Some(2));
+  let data = Data::default().init(Some(2)).unwrap();
   let languages = data.get_all_languages();
   assert!(languages.len() > 0);
 }
\ No newline at end of file
@@ -1,9 +1,13 @@
+use std::collections::HashMap;
+
 use crate::modules::data::Data;
+use crate::modules::data::domain_value::Localization;
+use crate::modules::data::material::{LocalizationResolver, placeholder};
 use crate::modules::data::tools::RetrieveLocalization;
 
 #[test]
 fn get_localization() {
-  let data = Data::default().init(Some(3));
+  let data = Data::default().init(Some(3)).unwrap();
   let localization = data.get_localization(1, 1);
   assert!(localization.is_some());
   let unwrapped_localization = localization.unwrap();
@@ -11,4 +15,37 @@ fn get_localization() {
   assert_eq!(unwrapped_localization.language_id, 1);
   let no_localization = data.get_localization(0, 0);
   assert!(no_localization.is_none());
+}
+
+#[test]
+fn localization_fallback_chain() {
+  let mut english = HashMap::new();
+  english.insert(1, Localization { id: 1, language_id: 1, content: "Warrior".to_owned() });
+  english.insert(2, Localization { id: 2, language_id: 1, content: "Mage".to_owned() });
+  let mut german = HashMap::new();
+  german.insert(1, Localization { id: 1, language_id: 3, content: "Krieger".to_owned() });
+  let mut localization = HashMap::new();
+  localization.insert(1, english);
+  localization.insert(3, german);
+
+  let resolver = LocalizationResolver::default();
+  assert_eq!(resolver.resolve(&localization, 3, 1), "Krieger");
+  assert_eq!(resolver.resolve(&localization, 3, 2), "Mage");
+  assert_eq!(resolver.resolve(&localization, 3, 42), placeholder(42));
+  assert_eq!(resolver.resolve(&localization, 1, 42), placeholder(42));
+  assert_eq!(resolver.fallbacks(3), vec![2]);
+  assert_eq!(resolver.placeholders(3), vec![42]);
+  assert!(resolver.fallbacks(1).is_empty());
+  assert_eq!(resolver.placeholders(1), vec![42]);
+}
+
+#[test]
+fn get_localization_coverage() {
+  let data = Data::default().init(Some(2)).unwrap().init(Some(3)).unwrap();
+  assert_eq!(data.localize(1, 0), placeholder(0));
+  let coverage = data.get_localization_coverage();
+  assert_eq!(coverage.len(), data.languages.len());
+  let english = coverage.iter().find(|language_coverage| language_coverage.language_id == 1).unwrap();
+  assert!(english.num_entries > 0);
+  assert_eq!(english.placeholders, vec![0]);
 }
\ No newline at end of file
@@ -2,14 +2,20 @@ mod expansion;
 mod language;
 mod localization;
 mod race;
+mod repository;
 mod profession;
 mod server;
+mod server_ownership;
+mod data_store;
 mod hero_class;
 mod spell;
 mod dispel_type;
 mod power_type;
 mod stat_type;
 mod spell_effect;
+mod spell_effect_stat;
+mod spell_template;
+mod spell_description;
 mod icon;
 mod item;
 mod gem;
@@ -23,10 +29,12 @@ mod item_effect;
 mod item_inventory_type;
 mod item_quality;
 mod item_random_property;
+mod item_search;
 mod item_sheath;
 mod item_socket;
 mod item_stat;
 mod itemset_name;
 mod itemset_effect;
 mod title;
+mod translation;
 mod item_random_property_points;
\ No newline at end of file
@@ -3,7 +3,7 @@ use crate::modules::data::tools::RetrieveNPC;
 
 #[test]
 fn get_npc() {
-  let data = Data::default().init(Some(13));
+  let data = Data::default().init(Some(13)).unwrap();
   let npc = data.get_npc(1, 1);
   assert!(npc.is_some());
   let unpacked_npc = npc.unwrap();
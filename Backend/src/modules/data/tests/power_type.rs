@@ -3,7 +3,7 @@ use crate::modules::data::tools::RetrievePowerType;
 
 #[test]
 fn get_power_type() {
-  let data = Data::default().init(Some(10));
+  let data = Data::default().init(Some(10)).unwrap();
   let power_type = data.get_power_type(1);
   assert!(power_type.is_some());
   assert_eq!(power_type.unwrap().id, 1);
@@ -13,7 +13,7 @@ fn get_power_type() {
 
 #[test]
 fn get_all_power_types() {
-  let data = Data::default().init(Some(10));
+  let data = Data::default().init(Some(10)).unwrap();
   let power_types = data.get_all_power_types();
   assert!(power_types.len() > 0);
 }
\ No newline at end of file
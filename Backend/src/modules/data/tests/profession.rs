@@ -3,7 +3,7 @@ use crate::modules::data::tools::RetrieveProfession;
 
 #[test]
 fn get_profession() {
-  let data = Data::default().init(Some(5));
+  let data = Data::default().init(Some(5)).unwrap();
   let profession = data.get_profession(182);
   assert!(profession.is_some());
   assert_eq!(profession.unwrap().id, 182);
@@ -13,7 +13,7 @@ fn get_profession() {
 
 #[test]
 fn get_all_professions() {
-  let data = Data::default().init(Some(5));
+  let data = Data::default().init(Some(5)).unwrap();
   let professions = data.get_all_professions();
   assert!(professions.len() > 0);
 }
\ No newline at end of file
@@ -3,7 +3,7 @@ use crate::modules::data::tools::RetrieveRace;
 
 #[test]
 fn get_race() {
-  let data = Data::default().init(Some(4));
+  let data = Data::default().init(Some(4)).unwrap();
   let race = data.get_race(1);
   assert!(race.is_some());
   assert_eq!(race.unwrap().id, 1);
@@ -13,7 +13,7 @@ fn get_race() {
 
 #[test]
 fn get_all_races() {
-  let data = Data::default().init(Some(4));
+  let data = Data::default().init(Some(4)).unwrap();
   let races = data.get_all_races();
   assert!(races.len() > 0);
 }
\ No newline at end of file
@@ -0,0 +1,22 @@
+use std::sync::Arc;
+
+use crate::modules::data::Data;
+use crate::modules::data::domain_value::{HeroClass, HeroClassTalent};
+use crate::modules::data::repository::{DataTables, InMemoryDataBackend};
+use crate::modules::data::tools::RetrieveHeroClass;
+
+#[test]
+fn hero_class_specs() {
+  let mut tables = DataTables::default();
+  tables.hero_classes.push(HeroClass { id: 1, localization_id: 1, color: "C79C6E".to_owned(), talents: [HeroClassTalent { icon: 0, localization_id: 0 }; 3] });
+  tables.hero_class_specs.push((1, 2, HeroClassTalent { icon: 42, localization_id: 7 }));
+  // Specs of unknown hero classes are skipped
+  tables.hero_class_specs.push((99, 0, HeroClassTalent { icon: 42, localization_id: 7 }));
+  let data = Data::with_backend(Arc::new(InMemoryDataBackend::new(tables))).init(Some(7)).unwrap();
+
+  let hero_class = data.get_hero_class(1).unwrap();
+  assert_eq!(hero_class.talents[2].icon, 42);
+  assert_eq!(hero_class.talents[2].localization_id, 7);
+  assert_eq!(hero_class.talents[0].icon, 0);
+  assert!(data.get_hero_class(99).is_none());
+}
\ No newline at end of file
@@ -3,7 +3,7 @@ use crate::modules::data::tools::RetrieveServer;
 
 #[test]
 fn get_server() {
-  let data = Data::default().init(Some(6));
+  let data = Data::default().init(Some(6)).unwrap();
   let server = data.get_server(1);
   assert!(server.is_some());
   assert_eq!(server.unwrap().id, 1);
@@ -13,7 +13,7 @@ fn get_server() {
 
 #[test]
 fn get_all_servers() {
-  let data = Data::default().init(Some(6));
+  let data = Data::default().init(Some(6)).unwrap();
   let servers = data.get_all_servers();
   assert!(servers.len() > 0);
 }
\ No newline at end of file
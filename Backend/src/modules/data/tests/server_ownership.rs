@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use crate::modules::data::Data;
+use crate::modules::data::domain_value::Server;
+use crate::modules::data::dto::DataFailure;
+use crate::modules::data::repository::{DataTables, InMemoryDataBackend};
+use crate::modules::data::tools::{RetrieveServer, ServerOwnership};
+
+#[test]
+fn claim_and_transfer_server() {
+  let owner_id = 1;
+  let new_owner_id = 2;
+  let mut tables = DataTables::default();
+  tables.servers.push(Server { id: 1, expansion_id: 1, name: "ServerOwnershipTest".to_owned(), owner: None });
+  let data = Data::with_backend(Arc::new(InMemoryDataBackend::new(tables))).init(Some(6)).unwrap();
+  let server_id = data.get_server_by_name("ServerOwnershipTest".to_string()).unwrap().id;
+
+  assert!(data.get_owned_servers(owner_id).is_empty());
+  assert_eq!(data.claim_server(owner_id, 0).err(), Some(DataFailure::InvalidInput));
+  assert!(data.claim_server(owner_id, server_id).is_ok());
+  assert_eq!(data.claim_server(new_owner_id, server_id).err(), Some(DataFailure::ServerAlreadyOwned));
+  assert_eq!(data.get_owned_servers(owner_id).len(), 1);
+
+  assert_eq!(data.transfer_server(new_owner_id, server_id, new_owner_id), Err(DataFailure::NotServerOwner));
+  assert!(data.transfer_server(owner_id, server_id, new_owner_id).is_ok());
+  assert!(data.get_owned_servers(owner_id).is_empty());
+  assert_eq!(data.get_owned_servers(new_owner_id)[0].id, server_id);
+
+  // The ownership must survive a reload of the data
+  let reloaded_data = Data::with_backend(Arc::clone(&data.backend)).init(Some(6)).unwrap();
+  assert_eq!(reloaded_data.get_owned_servers(new_owner_id)[0].id, server_id);
+}
\ No newline at end of file
@@ -3,7 +3,7 @@ use crate::modules::data::tools::RetrieveSpell;
 
 #[test]
 fn get_spell() {
-  let data = Data::default().init(Some(8));
+  let data = Data::default().init(Some(8)).unwrap();
   let spell = data.get_spell(1, 1);
   assert!(spell.is_some());
   let unpacked_spell = spell.unwrap();
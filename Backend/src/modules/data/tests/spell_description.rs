@@ -0,0 +1,45 @@
+use crate::modules::data::Data;
+use crate::modules::data::domain_value::{Spell, SpellCaster};
+use crate::modules::data::tools::{RetrieveLocalization, SpellDescription};
+
+fn init_data() -> Data {
+  Data::default().init(Some(3)).unwrap().init(Some(8)).unwrap().init(Some(12)).unwrap()
+}
+
+fn wotlk_spells_with<'a>(data: &'a Data, pattern: &'a str) -> impl Iterator<Item=&'a Spell> + 'a {
+  data.spells[2].values().filter(move |spell| data.localize(1, spell.description_localization_id).contains(pattern))
+}
+
+fn describe(data: &Data, spell_id: u32, caster: &SpellCaster) -> String {
+  data.get_localized_spell_description_for(3, 1, spell_id, caster).unwrap()
+}
+
+#[test]
+fn gender() {
+  let data = init_data();
+  let male = SpellCaster { is_female: false, ..SpellCaster::default() };
+  let female = SpellCaster { is_female: true, ..SpellCaster::default() };
+  let spell = wotlk_spells_with(&data, "$g").find(|spell| describe(&data, spell.id, &male) != describe(&data, spell.id, &female));
+  assert!(spell.is_some());
+  let spell_id = spell.unwrap().id;
+  assert!(!describe(&data, spell_id, &male).contains("$g"));
+  assert!(!describe(&data, spell_id, &female).contains("$g"));
+  assert_eq!(data.get_localized_spell_description(3, 1, spell_id).unwrap(), describe(&data, spell_id, &male));
+}
+
+#[test]
+fn known_spell_condition() {
+  let data = init_data();
+  let condition = wotlk_spells_with(&data, "$?s").find_map(|spell| {
+    let template = data.localize(1, spell.description_localization_id);
+    let start = template.find("$?s")? + 3;
+    let condition_spell_id = template[start..].chars().take_while(|c| c.is_ascii_digit()).collect::<String>().parse::<u32>().ok()?;
+    let unknown = describe(&data, spell.id, &SpellCaster::default());
+    let known = describe(&data, spell.id, &SpellCaster { known_spell_ids: vec![condition_spell_id], ..SpellCaster::default() });
+    if known != unknown { Some((known, unknown)) } else { None }
+  });
+  assert!(condition.is_some());
+  let (known, unknown) = condition.unwrap();
+  assert!(!known.contains("$?"));
+  assert!(!unknown.contains("$?"));
+}
\ No newline at end of file
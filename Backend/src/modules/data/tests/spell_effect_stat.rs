@@ -0,0 +1,98 @@
+use crate::modules::data::Data;
+use crate::modules::data::domain_value::SpellEffect;
+use crate::modules::data::tools::{get_spell_effect_stat, RetrieveLocalization, SpellDescription};
+
+fn spell_effect(aura: u16, misc_value: i32, points_upper: i32) -> SpellEffect {
+  SpellEffect {
+    id: 1,
+    expansion_id: 2,
+    spell_id: 1,
+    points_lower: points_upper - 1,
+    points_upper,
+    chain_targets: 0,
+    radius: 0,
+    aura,
+    misc_value,
+  }
+}
+
+fn assert_stat(aura: u16, misc_value: i32, points_upper: i32, stat_type: u8, stat_value: u16) {
+  let stat = get_spell_effect_stat(&spell_effect(aura, misc_value, points_upper));
+  assert!(stat.is_some());
+  let stat = stat.unwrap();
+  assert_eq!(stat.stat_type, stat_type);
+  assert_eq!(stat.stat_value, stat_value);
+}
+
+#[test]
+fn ratings() {
+  // Critical strike rating, melee and ranged
+  assert_stat(189, 0x300, 14, 8, 14);
+  // Spell critical strike rating
+  assert_stat(189, 0x400, 14, 24, 14);
+  // Hit rating, including spells
+  assert_stat(189, 0xE0, 10, 7, 10);
+  // Spell hit rating
+  assert_stat(189, 0x80, 8, 23, 8);
+  assert_stat(189, 0x1C000, 15, 39, 15);
+  assert_stat(189, 0x60000, 20, 37, 20);
+  assert_stat(189, 0x80000, 20, 42, 20);
+  assert_stat(189, 0x2, 10, 22, 10);
+  assert_stat(189, 0x4, 10, 10, 10);
+  assert_stat(189, 0x8, 10, 11, 10);
+  assert_stat(189, 0x10, 10, 12, 10);
+}
+
+#[test]
+fn spell_power() {
+  // Damage and healing done by magical spells and effects
+  assert_stat(13, 126, 23, 13, 23);
+  assert_stat(135, 0, 23, 14, 23);
+  // School specific damage
+  assert_stat(13, 2, 30, 15, 30);
+  assert_stat(13, 4, 30, 18, 30);
+  assert_stat(13, 8, 30, 17, 30);
+  assert_stat(13, 16, 30, 16, 30);
+  assert_stat(13, 32, 30, 20, 30);
+  assert_stat(13, 64, 30, 19, 30);
+}
+
+#[test]
+fn physical() {
+  assert_stat(99, 0, 40, 9, 40);
+  assert_stat(124, 0, 40, 25, 40);
+  assert_stat(51, 0, 2, 26, 2);
+  assert_stat(158, 0, 25, 44, 25);
+  // Attacks ignore armor of the opponent
+  assert_stat(123, 1, -140, 41, 140);
+}
+
+#[test]
+fn regeneration() {
+  assert_stat(85, 0, 6, 21, 6);
+  assert_stat(84, 0, 4, 43, 4);
+  // Rage or energy regeneration is no stat
+  assert!(get_spell_effect_stat(&spell_effect(85, 1, 6)).is_none());
+}
+
+#[test]
+fn unknown_aura() {
+  assert!(get_spell_effect_stat(&spell_effect(0, 0, 10)).is_none());
+  assert!(get_spell_effect_stat(&spell_effect(42, 0, 10)).is_none());
+  assert!(get_spell_effect_stat(&spell_effect(123, 1, 140)).is_none());
+}
+
+
+#[test]
+fn backfilled_spell_effects() {
+  let data = Data::default().init(Some(3)).unwrap().init(Some(8)).unwrap().init(Some(12)).unwrap();
+  // Equip effects of TBC items, whose aura is derived from the description by patch 00109
+  let spell = data.spells[1].values()
+    .filter(|spell| spell.duration <= 0)
+    .find(|spell| data.localize(1, spell.description_localization_id) == "Increases your hit rating by $s1.");
+  assert!(spell.is_some());
+  let spell = spell.unwrap();
+  let points_upper = data.spell_effects[1].get(&spell.id).unwrap()[0].points_upper;
+  let stats = data.parse_stats(2, spell.id);
+  assert!(stats.iter().any(|stat| stat.stat_type == 7 && stat.stat_value == points_upper as u16));
+}
\ No newline at end of file
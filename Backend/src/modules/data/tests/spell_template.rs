@@ -0,0 +1,82 @@
+use crate::modules::data::tools::{Condition, SpellTemplate, SpellTemplateContext};
+
+struct TestContext {
+  female: bool,
+}
+
+impl SpellTemplateContext for TestContext {
+  fn get_value(&self, spell_id: Option<u32>, variable: char, index: Option<u8>) -> Option<f64> {
+    match (spell_id, variable, index) {
+      (None, 's', Some(1)) => Some(25.0),
+      (None, 's', Some(2)) => Some(-1.0),
+      (None, 'm', Some(1)) => Some(-500.0),
+      (None, 'o', Some(1)) => Some(120.0),
+      (None, 'x', Some(1)) => Some(3.0),
+      (None, 'a', Some(1)) => Some(10.0),
+      (None, 'd', _) => Some(15000.0),
+      (Some(12345), 's', Some(1)) => Some(40.0),
+      (Some(12345), 'd', _) => Some(6000.0),
+      _ => None
+    }
+  }
+
+  fn format_duration(&self, duration: u32) -> String {
+    format!("{} sec", duration / 1000)
+  }
+
+  fn is_condition_met(&self, condition: &Condition) -> bool {
+    condition.kind == 's' && condition.id == 1
+  }
+
+  fn is_female(&self) -> bool {
+    self.female
+  }
+}
+
+fn evaluate(template: &str) -> String {
+  SpellTemplate::parse(template).evaluate(&TestContext { female: false })
+}
+
+#[test]
+fn references() {
+  assert_eq!(evaluate("Increases damage done by up to $s1."), "Increases damage done by up to 25.");
+  assert_eq!(evaluate("Hits $x1 targets within $a1 yards for $o1 over $d."), "Hits 3 targets within 10 yards for 120 over 15 sec.");
+  assert_eq!(evaluate("Reduces armor by $S2."), "Reduces armor by 1.");
+  assert_eq!(evaluate("Grants $12345s1 mana over $12345d."), "Grants 40 mana over 6 sec.");
+}
+
+#[test]
+fn expressions() {
+  assert_eq!(evaluate("Reduces the cast time by ${$m1/-1000}.1 sec."), "Reduces the cast time by 0.5 sec.");
+  assert_eq!(evaluate("Reduces the cast time by ${$m1/-1000}.2 sec."), "Reduces the cast time by 0.50 sec.");
+  assert_eq!(evaluate("Restores ${50/10} mana."), "Restores 5 mana.");
+  assert_eq!(evaluate("Deals ${($s1+5)*2} damage."), "Deals 60 damage.");
+  assert_eq!(evaluate("Deals ${$12345s1*15/100} damage."), "Deals 6 damage.");
+  assert_eq!(evaluate("Lasts $/1000;m1 sec."), "Lasts 0.5 sec.");
+  assert_eq!(evaluate("Heals $*2;s1 health."), "Heals 50 health.");
+}
+
+#[test]
+fn plural_and_gender() {
+  assert_eq!(evaluate("Applies $s2 $lstack:stacks;."), "Applies 1 stack.");
+  assert_eq!(evaluate("Applies $s1 $lstack:stacks;."), "Applies 25 stacks.");
+  assert_eq!(evaluate("$ghis:her; weapon"), "his weapon");
+  assert_eq!(SpellTemplate::parse("$ghis:her; weapon").evaluate(&TestContext { female: true }), "her weapon");
+}
+
+#[test]
+fn conditionals() {
+  assert_eq!(evaluate("$?s1[Known][Unknown]"), "Known");
+  assert_eq!(evaluate("$?s2[Known][Unknown]"), "Unknown");
+  assert_eq!(evaluate("$?!s2[Not known]"), "Not known");
+  assert_eq!(evaluate("$?s2[First]?s1[Second][Third]"), "Second");
+  assert_eq!(evaluate("$?s1[Deals $s1 [fire] damage][Nothing]."), "Deals 25 [fire] damage.");
+}
+
+#[test]
+fn unresolved() {
+  assert_eq!(evaluate("Costs $5 and $s3."), "Costs $5 and $s3.");
+  assert_eq!(evaluate("Chance $h%."), "Chance $h%.");
+  assert_eq!(evaluate("Broken ${$s1+}"), "Broken ${$s1+}");
+  assert_eq!(evaluate("Unclosed $lstack:stacks"), "Unclosed $lstack:stacks");
+}
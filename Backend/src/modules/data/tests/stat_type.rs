@@ -3,7 +3,7 @@ use crate::modules::data::tools::RetrieveStatType;
 
 #[test]
 fn get_stat_type() {
-  let data = Data::default().init(Some(11));
+  let data = Data::default().init(Some(11)).unwrap();
   let stat_type = data.get_stat_type(1);
   assert!(stat_type.is_some());
   assert_eq!(stat_type.unwrap().id, 1);
@@ -13,7 +13,7 @@ fn get_stat_type() {
 
 #[test]
 fn get_all_stat_types() {
-  let data = Data::default().init(Some(11));
+  let data = Data::default().init(Some(11)).unwrap();
   let stat_types = data.get_all_stat_types();
   assert!(stat_types.len() > 0);
 }
\ No newline at end of file
@@ -3,7 +3,7 @@ use crate::modules::data::tools::RetrieveTitle;
 
 #[test]
 fn get_title() {
-  let data = Data::default().init(Some(31));
+  let data = Data::default().init(Some(31)).unwrap();
   let title = data.get_title(1);
   assert!(title.is_some());
   assert_eq!(title.unwrap().id, 1);
@@ -13,7 +13,7 @@ fn get_title() {
 
 #[test]
 fn get_all_titles() {
-  let data = Data::default().init(Some(31));
+  let data = Data::default().init(Some(31)).unwrap();
   let titles = data.get_all_titles();
   assert!(titles.len() > 0);
 }
\ No newline at end of file
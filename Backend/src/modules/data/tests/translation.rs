@@ -0,0 +1,89 @@
+use std::sync::Arc;
+
+use crate::modules::data::Data;
+use crate::modules::data::domain_value::{Language, Localization};
+use crate::modules::data::dto::TranslationFailure;
+use crate::modules::data::material::{PoEntry, PoFile};
+use crate::modules::data::repository::{DataTables, InMemoryDataBackend};
+use crate::modules::data::tools::{Translate, validate_placeholders};
+
+#[test]
+fn po_file_round_trip() {
+  let po_file = PoFile {
+    language: "de".to_owned(),
+    entries: vec![
+      PoEntry {
+        references: vec!["data_item".to_owned(), "data_spell".to_owned()],
+        fuzzy: false,
+        localization_id: 42,
+        source: "Increases \"damage\"\nby $s1.".to_owned(),
+        translation: String::new(),
+      },
+      PoEntry {
+        references: Vec::new(),
+        fuzzy: true,
+        localization_id: 7,
+        source: "%s the Explorer".to_owned(),
+        translation: "%s der Forscher".to_owned(),
+      },
+    ],
+  };
+  assert_eq!(PoFile::parse(&po_file.write()), Ok(po_file));
+}
+
+#[test]
+fn parse_po_file() {
+  let content = "msgid \"\"\nmsgstr \"\"\n\"Language: fr\\n\"\n\n# Reviewed\nmsgctxt \"12\"\nmsgid \"Hello \"\n\"World\"\nmsgstr \"Bonjour \"\n\"le monde\"\nmsgctxt \"13\"\nmsgid \"Bye\"\nmsgstr \"Au revoir\"";
+  let po_file = PoFile::parse(content).unwrap();
+  assert_eq!(po_file.language, "fr");
+  assert_eq!(po_file.entries.len(), 2);
+  assert_eq!(po_file.entries[0].localization_id, 12);
+  assert_eq!(po_file.entries[0].source, "Hello World");
+  assert_eq!(po_file.entries[0].translation, "Bonjour le monde");
+  assert_eq!(po_file.entries[1].translation, "Au revoir");
+
+  assert_eq!(PoFile::parse("msgctxt \"1\"\nmsgid \"Hello\"\n\n"), Err(TranslationFailure::InvalidFile(3, "Entry without msgstr".to_owned())));
+  assert_eq!(PoFile::parse("msgctxt \"one\"\nmsgid \"Hello\"\nmsgstr \"Hallo\""),
+             Err(TranslationFailure::InvalidFile(4, "Invalid localization id 'one'".to_owned())));
+  assert!(PoFile::parse("msgid_plural \"Hellos\"").is_err());
+}
+
+#[test]
+fn placeholders_are_validated() {
+  assert!(validate_placeholders("%s the Explorer", "%s der Forscher").is_ok());
+  assert!(validate_placeholders("%s the Explorer", "der Forscher").is_err());
+  assert!(validate_placeholders("Deals $s1 damage over $d.", "Verursacht über $d $S1 Schaden.").is_ok());
+  assert!(validate_placeholders("Deals $s1 damage over $d.", "Verursacht $s2 Schaden über $d.").is_err());
+  assert!(validate_placeholders("Deals ${$m1/10}.1 damage", "Verursacht ${ $m1 / 10 }.1 Schaden").is_ok());
+  assert!(validate_placeholders("Restores $/10;s1 health", "Stellt $/10;s1 Gesundheit wieder her").is_ok());
+  assert!(validate_placeholders("Restores $/10;s1 health", "Stellt $s1 Gesundheit wieder her").is_err());
+  assert!(validate_placeholders("$s1 $lpoint:points;", "$s1 $lPunkt:Punkte;").is_ok());
+  assert!(validate_placeholders("$?s12345[Empowered][Normal]", "$?s12345[Verstärkt][Normal]").is_ok());
+  assert!(validate_placeholders("Costs 100%", "Kostet 100%").is_ok());
+  assert!(validate_placeholders("$s1 and $s1", "$s1").is_err());
+}
+
+#[test]
+fn import_rejects_file_of_other_language() {
+  let mut tables = DataTables::default();
+  tables.languages.push(Language { id: 1, name: "English".to_owned(), short_code: "en".to_owned() });
+  tables.languages.push(Language { id: 2, name: "German".to_owned(), short_code: "de".to_owned() });
+  tables.localizations.push(Localization { id: 12, language_id: 1, content: "Hello".to_owned() });
+  let data = Data::with_backend(Arc::new(InMemoryDataBackend::new(tables))).init(None).unwrap();
+
+  let mut po_file = PoFile {
+    language: "fr".to_owned(),
+    entries: vec![PoEntry {
+      references: Vec::new(),
+      fuzzy: false,
+      localization_id: 12,
+      source: "Hello".to_owned(),
+      translation: "Bonjour".to_owned(),
+    }],
+  };
+  assert_eq!(data.import_translations(2, &po_file), Err(TranslationFailure::InvalidFile(1, "The file is written for 'fr', not for 'de'".to_owned())));
+
+  po_file.language = "DE".to_owned();
+  po_file.entries[0].translation = "Hallo".to_owned();
+  assert_eq!(data.import_translations(2, &po_file).map(|result| result.num_imported), Ok(1));
+}
\ No newline at end of file
@@ -0,0 +1,197 @@
+use std::cmp::Ordering;
+
+use crate::dto::{SearchCursor, SearchResult};
+use crate::modules::data::Data;
+use crate::modules::data::domain_value::{Item, Localized};
+use crate::modules::data::dto::{DataFailure, ItemSearchFilter};
+use crate::modules::data::material::{ItemKey, ItemSearchIndex};
+use crate::modules::data::tools::{RetrieveItem, RetrieveLocalization};
+
+const DEFAULT_PAGE_SIZE: u32 = 10;
+const MAX_PAGE_SIZE: u32 = 100;
+
+pub trait SearchItem {
+  fn search_items(&self, language_id: u8, filter: ItemSearchFilter) -> Result<SearchResult<Localized<Item>>, DataFailure>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+enum SortColumn {
+  Name,
+  Expansion,
+  Quality,
+  InventoryType,
+  Class,
+  ItemLevel,
+  RequiredLevel,
+  Itemset,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct SortKey<'a> {
+  name: Option<&'a str>,
+  expansion_id: u8,
+  quality: u8,
+  inventory_type: Option<u8>,
+  class_id: u8,
+  item_level: Option<u16>,
+  required_level: Option<u8>,
+  itemset: Option<u16>,
+  key: ItemKey,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ItemSearchCursor {
+  name: Option<String>,
+  expansion_id: u8,
+  quality: u8,
+  inventory_type: Option<u8>,
+  class_id: u8,
+  item_level: Option<u16>,
+  required_level: Option<u8>,
+  itemset: Option<u16>,
+  key: ItemKey,
+  // A cursor only continues the order it was created for
+  sortings: Vec<(SortColumn, bool)>,
+}
+
+impl<'a> SortKey<'a> {
+  fn new(index: &'a ItemSearchIndex, language_id: u8, key: ItemKey, item: &Item) -> Self {
+    SortKey {
+      name: index.name(language_id, &key),
+      expansion_id: item.expansion_id,
+      quality: item.quality,
+      inventory_type: item.inventory_type,
+      class_id: item.class_id,
+      item_level: item.item_level,
+      required_level: item.required_level,
+      itemset: item.itemset,
+      key,
+    }
+  }
+
+  fn compare(&self, other: &SortKey, sortings: &[(SortColumn, bool)]) -> Ordering {
+    sortings.iter()
+      .map(|(column, ascending)| {
+        let ordering = match column {
+          SortColumn::Name => self.name.cmp(&other.name),
+          SortColumn::Expansion => self.expansion_id.cmp(&other.expansion_id),
+          SortColumn::Quality => self.quality.cmp(&other.quality),
+          SortColumn::InventoryType => self.inventory_type.cmp(&other.inventory_type),
+          SortColumn::Class => self.class_id.cmp(&other.class_id),
+          SortColumn::ItemLevel => self.item_level.cmp(&other.item_level),
+          SortColumn::RequiredLevel => self.required_level.cmp(&other.required_level),
+          SortColumn::Itemset => self.itemset.cmp(&other.itemset),
+        };
+        if *ascending { ordering } else { ordering.reverse() }
+      })
+      .find(|ordering| *ordering != Ordering::Equal)
+      // Ties are broken by the item key, which keeps the order total and the cursor unambiguous
+      .unwrap_or_else(|| self.key.cmp(&other.key))
+  }
+
+  fn encode_cursor(&self, sortings: &[(SortColumn, bool)]) -> String {
+    ItemSearchCursor {
+      name: self.name.map(|name| name.to_owned()),
+      expansion_id: self.expansion_id,
+      quality: self.quality,
+      inventory_type: self.inventory_type,
+      class_id: self.class_id,
+      item_level: self.item_level,
+      required_level: self.required_level,
+      itemset: self.itemset,
+      key: self.key,
+      sortings: sortings.to_vec(),
+    }.to_cursor()
+  }
+}
+
+impl ItemSearchCursor {
+  fn as_sort_key(&self) -> SortKey {
+    SortKey {
+      name: self.name.as_ref().map(|name| name.as_str()),
+      expansion_id: self.expansion_id,
+      quality: self.quality,
+      inventory_type: self.inventory_type,
+      class_id: self.class_id,
+      item_level: self.item_level,
+      required_level: self.required_level,
+      itemset: self.itemset,
+      key: self.key,
+    }
+  }
+}
+
+impl SearchItem for Data {
+  fn search_items(&self, language_id: u8, filter: ItemSearchFilter) -> Result<SearchResult<Localized<Item>>, DataFailure> {
+    let index = &self.item_search_index;
+    let stat_types = filter.stat_types.clone().unwrap_or_else(Vec::new);
+    let mut item_keys: Vec<ItemKey> = index.find(language_id, filter.name.filter.as_ref().map(|name| name.as_str()),
+                                                 filter.expansion_id.filter, filter.quality.filter, filter.inventory_type.filter,
+                                                 filter.class_id.filter, &stat_types, filter.itemset.filter);
+    let item = |key: &ItemKey| self.items.get(key.0 as usize - 1).and_then(|items| items.get(&key.1)).unwrap();
+    if let Some(item_level) = filter.item_level.filter.as_ref() {
+      item_keys.retain(|key| item(key).item_level.map(|value| item_level.contains(&value)).unwrap_or(false));
+    }
+    if let Some(required_level) = filter.required_level.filter.as_ref() {
+      item_keys.retain(|key| item(key).required_level.map(|value| required_level.contains(&value)).unwrap_or(false));
+    }
+    if let Some(hero_class_id) = filter.hero_class_id {
+      item_keys.retain(|key| item(key).is_usable_by(hero_class_id));
+    }
+    let num_items = item_keys.len();
+
+    let sortings: Vec<(SortColumn, bool)> = vec![
+      (SortColumn::Name, filter.name.sorting),
+      (SortColumn::Expansion, filter.expansion_id.sorting),
+      (SortColumn::Quality, filter.quality.sorting),
+      (SortColumn::InventoryType, filter.inventory_type.sorting),
+      (SortColumn::Class, filter.class_id.sorting),
+      (SortColumn::ItemLevel, filter.item_level.sorting),
+      (SortColumn::RequiredLevel, filter.required_level.sorting),
+      (SortColumn::Itemset, filter.itemset.sorting),
+    ].into_iter().filter_map(|(column, sorting)| sorting.map(|ascending| (column, ascending))).collect();
+    let sort_key = |key: &ItemKey| SortKey::new(index, language_id, *key, item(key));
+    let compare = |left: &ItemKey, right: &ItemKey| sort_key(left).compare(&sort_key(right), &sortings);
+
+    // Keyset pagination continues after the cursor, otherwise the page is used as offset
+    let page_size = filter.page_size.unwrap_or(DEFAULT_PAGE_SIZE).max(1).min(MAX_PAGE_SIZE) as usize;
+    let mut offset = (filter.page as usize) * page_size;
+    if let Some(cursor) = filter.cursor.as_ref() {
+      let cursor = ItemSearchCursor::from_cursor(cursor)
+        .filter(|cursor| cursor.sortings == sortings)
+        .ok_or(DataFailure::InvalidInput)?;
+      let cursor_key = cursor.as_sort_key();
+      item_keys.retain(|key| sort_key(key).compare(&cursor_key, &sortings) == Ordering::Greater);
+      offset = 0;
+    }
+
+    // Only the requested page has to be ordered
+    let num_remaining = item_keys.len();
+    let page_end = std::cmp::min(offset + page_size, num_remaining);
+    if offset >= num_remaining {
+      item_keys.clear();
+    } else {
+      if page_end < num_remaining {
+        item_keys.select_nth_unstable_by(page_end, compare);
+        item_keys.truncate(page_end);
+      }
+      item_keys.sort_unstable_by(compare);
+      item_keys.drain(..offset);
+    }
+
+    let next_cursor = if page_end < num_remaining {
+      item_keys.last().map(|key| sort_key(key).encode_cursor(&sortings))
+    } else {
+      None
+    };
+
+    Ok(SearchResult {
+      result: item_keys.iter().filter_map(|key| self.get_item(key.0, key.1)).map(|item| Localized {
+        localization: self.localize(language_id, item.localization_id),
+        base: item,
+      }).collect(),
+      num_items,
+      next_cursor,
+    })
+  }
+}
\ No newline at end of file
@@ -3,6 +3,7 @@ use crate::modules::data::domain_value::ItemsetEffect;
 
 pub trait RetrieveItemsetEffect {
   fn get_itemset_effects(&self, expansion_id: u8, itemset_id: u16) -> Option<Vec<ItemsetEffect>>;
+  fn get_active_itemset_effects(&self, expansion_id: u8, itemset_id: u16, num_equipped_items: u8) -> Option<Vec<ItemsetEffect>>;
 }
 
 impl RetrieveItemsetEffect for Data {
@@ -14,4 +15,10 @@ impl RetrieveItemsetEffect for Data {
     self.itemset_effects.get(expansion_id as usize - 1)
       .and_then(|map| map.get(&itemset_id).and_then(|itemset_effects| Some(itemset_effects.clone())))
   }
+
+  fn get_active_itemset_effects(&self, expansion_id: u8, itemset_id: u16, num_equipped_items: u8) -> Option<Vec<ItemsetEffect>> {
+    self.get_itemset_effects(expansion_id, itemset_id)
+      .and_then(|itemset_effects| Some(itemset_effects.into_iter()
+        .filter(|itemset_effect| itemset_effect.threshold <= num_equipped_items).collect()))
+  }
 }
\ No newline at end of file
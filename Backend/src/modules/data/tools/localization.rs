@@ -1,18 +1,32 @@
 use crate::modules::data::Data;
 use crate::modules::data::domain_value::Localization;
+use crate::modules::data::dto::LocalizationCoverage;
 
 pub trait RetrieveLocalization {
   fn get_localization(&self, language_id: u8, localization_id: u32) -> Option<Localization>;
+  fn localize(&self, language_id: u8, localization_id: u32) -> String;
+  fn get_localization_coverage(&self) -> Vec<LocalizationCoverage>;
 }
 
 impl RetrieveLocalization for Data {
   fn get_localization(&self, language_id: u8, localization_id: u32) -> Option<Localization> {
-    if language_id == 0 {
-      return None;
-    }
-
-    self.localization.get(language_id as usize - 1)
+    self.localization.get(&language_id)
         .and_then(|map| map.get(&localization_id)
             .and_then(|localization| Some(localization.clone())))
   }
+
+  fn localize(&self, language_id: u8, localization_id: u32) -> String {
+    self.localization_resolver.resolve(&self.localization, language_id, localization_id)
+  }
+
+  fn get_localization_coverage(&self) -> Vec<LocalizationCoverage> {
+    let mut language_ids = self.localization_resolver.requested_languages();
+    language_ids.extend(self.languages.keys());
+    language_ids.into_iter().map(|language_id| LocalizationCoverage {
+      language_id,
+      num_entries: self.localization.get(&language_id).map(|entries| entries.len()).unwrap_or(0),
+      fallbacks: self.localization_resolver.fallbacks(language_id),
+      placeholders: self.localization_resolver.placeholders(language_id),
+    }).collect()
+  }
 }
\ No newline at end of file
@@ -13,6 +13,7 @@ pub use self::item_effect::RetrieveItemEffect;
 pub use self::item_inventory_type::RetrieveItemInventoryType;
 pub use self::item_quality::RetrieveItemQuality;
 pub use self::item_random_property::RetrieveItemRandomProperty;
+pub use self::item_search::SearchItem;
 pub use self::item_sheath::RetrieveItemSheath;
 pub use self::item_socket::RetrieveItemSocket;
 pub use self::item_stat::RetrieveItemStat;
@@ -24,20 +25,28 @@ pub use self::npc::RetrieveNPC;
 pub use self::power_type::RetrievePowerType;
 pub use self::profession::RetrieveProfession;
 pub use self::race::RetrieveRace;
+pub use self::reload::ReloadData;
 pub use self::server::RetrieveServer;
+pub use self::server_ownership::ServerOwnership;
 pub use self::spell::RetrieveSpell;
 pub use self::spell_effect::RetrieveSpellEffect;
 pub use self::stat_type::RetrieveStatType;
 pub use self::spell_description::SpellDescription;
+pub use self::spell_effect_stat::get_spell_effect_stat;
+pub use self::spell_template::{Condition, SpellTemplate, SpellTemplateContext, VARIABLES};
 pub use self::title::RetrieveTitle;
+pub use self::translation::{Translate, validate_placeholders};
+pub use self::translation_command::run_translation_command;
 pub use self::item_random_property_points::RetrieveItemRandomPropertyPoints;
 
 mod expansion;
 mod language;
 mod localization;
 mod race;
+mod reload;
 mod profession;
 mod server;
+mod server_ownership;
 mod hero_class;
 mod spell;
 mod dispel_type;
@@ -57,11 +66,16 @@ mod item_effect;
 mod item_inventory_type;
 mod item_quality;
 mod item_random_property;
+mod item_search;
 mod item_sheath;
 mod item_socket;
 mod item_stat;
 mod itemset_name;
 mod itemset_effect;
 mod spell_description;
+mod spell_effect_stat;
+mod spell_template;
 mod title;
+mod translation;
+mod translation_command;
 mod item_random_property_points;
\ No newline at end of file
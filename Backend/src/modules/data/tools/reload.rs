@@ -0,0 +1,44 @@
+use std::thread;
+
+use crate::modules::data::DataStore;
+use crate::modules::data::dto::{DataFailure, DataReloadStatus};
+
+pub trait ReloadData {
+  fn reload(&self) -> Result<(), DataFailure>;
+  fn get_reload_status(&self) -> DataReloadStatus;
+}
+
+impl ReloadData for DataStore {
+  // Loads a new snapshot in the background and swaps it in once all tables are loaded
+  fn reload(&self) -> Result<(), DataFailure> {
+    {
+      let mut reload_status = self.reload_status.write().unwrap();
+      if reload_status.reloading {
+        return Err(DataFailure::ReloadInProgress);
+      }
+      reload_status.reloading = true;
+    }
+
+    let store = self.clone();
+    thread::spawn(move || {
+      let current = store.snapshot();
+      // The current snapshot is kept if any table fails to load
+      let result = current.reload().map(|data| store.swap(data));
+
+      let mut reload_status = store.reload_status.write().unwrap();
+      reload_status.reloading = false;
+      match result {
+        Ok(()) => {
+          reload_status.last_reload = Some(time_util::now());
+          reload_status.last_failure = None;
+        },
+        Err(reason) => reload_status.last_failure = Some(reason)
+      }
+    });
+    Ok(())
+  }
+
+  fn get_reload_status(&self) -> DataReloadStatus {
+    self.reload_status.read().unwrap().clone()
+  }
+}
\ No newline at end of file
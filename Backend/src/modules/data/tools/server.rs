@@ -9,17 +9,17 @@ pub trait RetrieveServer {
 
 impl RetrieveServer for Data {
   fn get_server(&self, id: u32) -> Option<AvailableServer> {
-    self.servers.get(&id)
+    self.servers.read().unwrap().get(&id)
       .and_then(|server| Some(AvailableServer::from_server(server)))
   }
 
   fn get_server_by_name(&self, server_name: String) -> Option<AvailableServer> {
-    self.servers.iter()
+    self.servers.read().unwrap().iter()
         .find(|(_, server)| server.name == server_name)
         .map(|(_, server)| AvailableServer::from_server(server))
   }
 
   fn get_all_servers(&self) -> Vec<AvailableServer> {
-    self.servers.iter().map(|(_, server)| AvailableServer::from_server(server)).collect()
+    self.servers.read().unwrap().iter().map(|(_, server)| AvailableServer::from_server(server)).collect()
   }
-}
+}
\ No newline at end of file
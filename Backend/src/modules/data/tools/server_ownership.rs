@@ -0,0 +1,53 @@
+use crate::modules::data::Data;
+use crate::modules::data::dto::{AvailableServer, DataFailure};
+use crate::modules::data::repository::ServerRepository;
+
+pub trait ServerOwnership {
+  fn get_owned_servers(&self, member_id: u32) -> Vec<AvailableServer>;
+  fn claim_server(&self, member_id: u32, server_id: u32) -> Result<AvailableServer, DataFailure>;
+  fn transfer_server(&self, member_id: u32, server_id: u32, new_owner_id: u32) -> Result<(), DataFailure>;
+}
+
+impl ServerOwnership for Data {
+  fn get_owned_servers(&self, member_id: u32) -> Vec<AvailableServer> {
+    let mut owned_servers: Vec<AvailableServer> = self.servers.read().unwrap().values()
+      .filter(|server| server.owner.contains(&member_id))
+      .map(AvailableServer::from_server)
+      .collect();
+    owned_servers.sort_by(|left, right| left.id.cmp(&right.id));
+    owned_servers
+  }
+
+  fn claim_server(&self, member_id: u32, server_id: u32) -> Result<AvailableServer, DataFailure> {
+    let mut servers = self.servers.write().unwrap();
+    let server = servers.get_mut(&server_id).ok_or(DataFailure::InvalidInput)?;
+    if server.owner.is_some() {
+      return Err(DataFailure::ServerAlreadyOwned);
+    }
+
+    if !self.backend.claim_server(server_id, member_id) {
+      return Err(DataFailure::Database("claim_server".to_owned()));
+    }
+
+    server.owner = Some(member_id);
+    Ok(AvailableServer::from_server(server))
+  }
+
+  fn transfer_server(&self, member_id: u32, server_id: u32, new_owner_id: u32) -> Result<(), DataFailure> {
+    let mut servers = self.servers.write().unwrap();
+    let server = servers.get_mut(&server_id).ok_or(DataFailure::InvalidInput)?;
+    if !server.owner.contains(&member_id) {
+      return Err(DataFailure::NotServerOwner);
+    }
+    if member_id == new_owner_id {
+      return Ok(());
+    }
+
+    if !self.backend.transfer_server(server_id, member_id, new_owner_id) {
+      return Err(DataFailure::Database("transfer_server".to_owned()));
+    }
+
+    server.owner = Some(new_owner_id);
+    Ok(())
+  }
+}
\ No newline at end of file
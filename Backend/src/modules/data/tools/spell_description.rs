@@ -1,197 +1,89 @@
 use language::domain_value::Language;
 use language::material::Dictionary;
 use language::tools::Get;
-use regex::Regex;
 
 use crate::modules::data::{Data, Stat};
-use crate::modules::data::tools::{RetrieveLocalization, RetrieveSpell, RetrieveSpellEffect};
+use crate::modules::data::domain_value::SpellCaster;
+use crate::modules::data::tools::{Condition, get_spell_effect_stat, RetrieveLocalization, RetrieveSpell, RetrieveSpellEffect, SpellTemplate, SpellTemplateContext};
 
 pub trait SpellDescription {
   fn get_localized_spell_description(&self, expansion_id: u8, language_id: u8, spell_id: u32) -> Option<String>;
+  fn get_localized_spell_description_for(&self, expansion_id: u8, language_id: u8, spell_id: u32, caster: &SpellCaster) -> Option<String>;
   fn parse_stats(&self, expansion_id: u8, spell_id: u32) -> Vec<Stat>;
 }
 
 impl SpellDescription for Data {
   fn get_localized_spell_description(&self, expansion_id: u8, language_id: u8, spell_id: u32) -> Option<String> {
-    lazy_static! {
-      static ref RE: Regex = Regex::new(r"\$(\d+)(s\d|x\d|a\d|o\d|m\d|M\d|d|o)").unwrap();
-    }
+    self.get_localized_spell_description_for(expansion_id, language_id, spell_id, &SpellCaster::default())
+  }
+
+  fn get_localized_spell_description_for(&self, expansion_id: u8, language_id: u8, spell_id: u32, caster: &SpellCaster) -> Option<String> {
+    let spell = self.get_spell(expansion_id, spell_id)?;
+    let template = self.localize(language_id, spell.description_localization_id);
+    let context = DataTemplateContext { data: self, expansion_id, language_id, spell_id, caster };
+    Some(SpellTemplate::parse(&template).evaluate(&context))
+  }
 
+  fn parse_stats(&self, expansion_id: u8, spell_id: u32) -> Vec<Stat> {
     let spell_res = self.get_spell(expansion_id, spell_id);
     if spell_res.is_none() {
-      return None;
+      return Vec::new();
     }
-    let spell = spell_res.unwrap();
-    let mut template = self.get_localization(language_id, spell.description_localization_id)
-      .and_then(|localization| Some(localization.content)).unwrap();
-
-    let spell_effects = self.get_spell_effects(expansion_id, spell_id).unwrap();
-    template = template.replace("$d1", &format_duration(&self.dictionary, language_id, spell.duration.abs() as u32));
-    template = template.replace("$d", &format_duration(&self.dictionary, language_id, spell.duration.abs() as u32));
-    let spell_effects_len = spell_effects.len();
-    for i in 0..spell_effects_len {
-      if i + 1 == spell_effects_len {
-        template = template.replace(&format!("$s{}", i + 2), &spell_effects[i].points_upper.abs().to_string());
-      }
 
-      template = template.replace(&format!("$s{}", i + 1), &spell_effects[i].points_upper.abs().to_string());
-      template = template.replace(&format!("${{$m{}/-1000}}.1", i + 1), &format!("{:.1}", (spell_effects[i].points_upper as f64 / 1000.0).abs()));
-      template = template.replace(&format!("${{$m{}/-1000}}.2", i + 1), &format!("{:.2}", (spell_effects[i].points_upper as f64 / 1000.0).abs()));
-      template = template.replace(&format!("${{$m{}/1000}}", i + 1), &format!("{:.1}", (spell_effects[i].points_upper as f64 / 1000.0).abs()));
-      template = template.replace(&format!("${{$m{}/-1000}}", i + 1), &format!("{:.1}", (spell_effects[i].points_upper as f64 / 1000.0).abs()));
-      template = template.replace(&format!("${{$m{}/-10}}", i + 1), &format!("{:.1}", (spell_effects[i].points_upper as f64 / 10.0).abs()));
-      template = template.replace(&format!("$/1000;s{}", i + 1), &format!("{:.1}", (spell_effects[i].points_upper as f64 / 1000.0).abs()));
-      template = template.replace(&format!("$/1000;S{}", i + 1), &format!("{:.1}", (spell_effects[i].points_upper as f64 / 1000.0).abs()));
-      template = template.replace(&format!("$/10;s{}", i + 1), &format!("{:.1}", (spell_effects[i].points_upper as f64 / 10.0).abs()));
-      template = template.replace(&format!("$M{}", i + 1), &spell_effects[i].points_upper.to_string());
-      template = template.replace(&format!("$m{}", i + 1), &spell_effects[i].points_upper.to_string());
-      template = template.replace(&format!("$o{}", i + 1), &spell_effects[i].points_upper.to_string());
-      template = template.replace(&format!("$x{}", i + 1), &spell_effects[i].chain_targets.to_string());
-      template = template.replace(&format!("$a{}", i + 1), &spell_effects[i].radius.to_string());
-      template = template.replace("${50/10}", "5");
-
-      template = template.replace(&format!("$o"), &spell_effects[i].points_upper.to_string()); // ?!
-      if RE.is_match(&template) {
-        let mut temp_res = template.clone();
-        for capture in RE.captures_iter(&template) {
-          if capture.len() < 2 {
-            continue;
-          }
-
-          let inner_spell_id_res = capture[1].parse::<u32>();
-          if inner_spell_id_res.is_err() {
-            continue;
-          }
-          let inner_spell_id = inner_spell_id_res.unwrap();
-
-          let inner_spell_res = self.get_spell(expansion_id, inner_spell_id);
-          if inner_spell_res.is_none() {
-            continue;
-          }
-          let inner_spell = inner_spell_res.unwrap();
-          temp_res = temp_res.replace(&format!("${}d1", capture[1].to_string()), &format_duration(&self.dictionary, language_id, inner_spell.duration.abs() as u32));
-          temp_res = temp_res.replace(&format!("${}d", capture[1].to_string()), &format_duration(&self.dictionary, language_id, inner_spell.duration.abs() as u32));
-          let inner_spell_effects = self.get_spell_effects(expansion_id, inner_spell_id).unwrap();
-          let inner_spell_effects_len = inner_spell_effects.len();
-          for i in 0..inner_spell_effects_len {
-            temp_res = temp_res.replace(&format!("${}s{}", capture[1].to_string(), i + 1), &inner_spell_effects[i].points_upper.abs().to_string());
-            temp_res = temp_res.replace(&format!("${{${}m{}/-1000}}.1", capture[1].to_string(), i + 1), &format!("{:.1}", (inner_spell_effects[i].points_upper as f64 / 1000.0).abs()));
-            temp_res = temp_res.replace(&format!("${{${}m{}/-1000}}.2", capture[1].to_string(), i + 1), &format!("{:.2}", (inner_spell_effects[i].points_upper as f64 / 1000.0).abs()));
-            temp_res = temp_res.replace(&format!("${{${}m{}*15/100}}", capture[1].to_string(), i + 1), &format!("{:.1}", (inner_spell_effects[i].points_upper as f64 * 0.15).abs()));
-            temp_res = temp_res.replace(&format!("${{${}m{}*45/100}}", capture[1].to_string(), i + 1), &format!("{:.1}", (inner_spell_effects[i].points_upper as f64 * 0.45).abs()));
-            temp_res = temp_res.replace(&format!("${}{{$m{}/1000}}", capture[1].to_string(), i + 1), &format!("{:.1}", (inner_spell_effects[i].points_upper as f64 / 1000.0).abs()));
-            temp_res = temp_res.replace(&format!("${}{{$m{}/-1000}}", capture[1].to_string(), i + 1), &format!("{:.1}", (inner_spell_effects[i].points_upper as f64 / 1000.0).abs()));
-            temp_res = temp_res.replace(&format!("${}{{$m{}/-10}}", capture[1].to_string(), i + 1), &format!("{:.1}", (inner_spell_effects[i].points_upper as f64 / 10.0).abs()));
-            temp_res = temp_res.replace(&format!("${}{{$m{}*15/100}}", capture[1].to_string(), i + 1), &format!("{:.1}", (inner_spell_effects[i].points_upper as f64 * 0.15).abs()));
-            temp_res = temp_res.replace(&format!("${}{{$m{}*45/100}}", capture[1].to_string(), i + 1), &format!("{:.1}", (inner_spell_effects[i].points_upper as f64 * 0.45).abs()));
-            temp_res = temp_res.replace(&format!("${}/1000;s{}", capture[1].to_string(), i + 1), &format!("{:.1}", (inner_spell_effects[i].points_upper as f64 / 1000.0).abs()));
-            temp_res = temp_res.replace(&format!("${}/1000;S{}", capture[1].to_string(), i + 1), &format!("{:.1}", (inner_spell_effects[i].points_upper as f64 / 1000.0).abs()));
-            temp_res = temp_res.replace(&format!("${}/10;s{}", capture[1].to_string(), i + 1), &format!("{:.1}", (inner_spell_effects[i].points_upper as f64 / 10.0).abs()));
-            temp_res = temp_res.replace(&format!("${}o{}", capture[1].to_string(), i + 1), &inner_spell_effects[i].points_upper.abs().to_string());
-            temp_res = temp_res.replace(&format!("${}x{}", capture[1].to_string(), i + 1), &inner_spell_effects[i].chain_targets.to_string());
-            temp_res = temp_res.replace(&format!("${}a{}", capture[1].to_string(), i + 1), &inner_spell_effects[i].radius.to_string());
-            temp_res = temp_res.replace(&format!("${}o", capture[1].to_string()), &inner_spell_effects[i].points_upper.abs().to_string()); // ?!
-          }
-
-          if inner_spell_effects_len == 1 {
-            for i in 0..10 {
-              temp_res = temp_res.replace(&format!("${}s{}", capture[1].to_string(), i + 1), &inner_spell_effects[0].points_upper.abs().to_string());
-              temp_res = temp_res.replace(&format!("${{${}m{}/-1000}}.1", capture[1].to_string(), i + 1), &format!("{:.1}", (inner_spell_effects[0].points_upper as f64 / 1000.0).abs()));
-              temp_res = temp_res.replace(&format!("${{${}m{}/-1000}}.2", capture[1].to_string(), i + 1), &format!("{:.2}", (inner_spell_effects[0].points_upper as f64 / 1000.0).abs()));
-              temp_res = temp_res.replace(&format!("${{${}m{}*15/100}}", capture[1].to_string(), i + 1), &format!("{:.1}", (inner_spell_effects[0].points_upper as f64 * 0.15).abs()));
-              temp_res = temp_res.replace(&format!("${{${}m{}*45/100}}", capture[1].to_string(), i + 1), &format!("{:.1}", (inner_spell_effects[0].points_upper as f64 * 0.45).abs()));
-              temp_res = temp_res.replace(&format!("${}{{$m{}/1000}}", capture[1].to_string(), i + 1), &format!("{:.1}", (inner_spell_effects[0].points_upper as f64 / 1000.0).abs()));
-              temp_res = temp_res.replace(&format!("${}{{$m{}/-1000}}", capture[1].to_string(), i + 1), &format!("{:.1}", (inner_spell_effects[0].points_upper as f64 / 1000.0).abs()));
-              temp_res = temp_res.replace(&format!("${}{{$m{}/-10}}", capture[1].to_string(), i + 1), &format!("{:.1}", (inner_spell_effects[0].points_upper as f64 / 10.0).abs()));
-              temp_res = temp_res.replace(&format!("${}{{$m{}*15/100}}", capture[1].to_string(), i + 1), &format!("{:.1}", (inner_spell_effects[0].points_upper as f64 * 0.15).abs()));
-              temp_res = temp_res.replace(&format!("${}{{$m{}*45/100}}", capture[1].to_string(), i + 1), &format!("{:.1}", (inner_spell_effects[0].points_upper as f64 * 0.45).abs()));
-              temp_res = temp_res.replace(&format!("${}/1000;s{}", capture[1].to_string(), i + 1), &format!("{:.1}", (inner_spell_effects[0].points_upper as f64 / 1000.0).abs()));
-              temp_res = temp_res.replace(&format!("${}/1000;S{}", capture[1].to_string(), i + 1), &format!("{:.1}", (inner_spell_effects[0].points_upper as f64 / 1000.0).abs()));
-              temp_res = temp_res.replace(&format!("${}/10;s{}", capture[1].to_string(), i + 1), &format!("{:.1}", (inner_spell_effects[0].points_upper as f64 / 10.0).abs()));
-            }
-          }
-        }
-        template = temp_res.to_owned();
-      }
+    // Effects that only last for some time, e.g. procs and use effects, are not permanent stats
+    if spell_res.unwrap().duration > 0 {
+      return Vec::new();
     }
 
-    Some(template)
+    self.get_spell_effects(expansion_id, spell_id)
+      .map(|spell_effects| spell_effects.iter().filter_map(get_spell_effect_stat).collect())
+      .unwrap_or_else(Vec::new)
   }
+}
 
-  fn parse_stats(&self, expansion_id: u8, spell_id: u32) -> Vec<Stat> {
-    lazy_static! {
-      static ref RE_DURATIONAL: Regex = Regex::new(r"for \d+ seconds.").unwrap();
-    }
-
-    let mut stats = Vec::new();
-    if let Some(spell_desc) = self.get_localized_spell_description(expansion_id, 1, spell_id) {
-      let spell_desc = spell_desc.to_lowercase();
-      if RE_DURATIONAL.is_match(&spell_desc) {
-        return stats;
-      }
+struct DataTemplateContext<'a> {
+  data: &'a Data,
+  expansion_id: u8,
+  language_id: u8,
+  spell_id: u32,
+  caster: &'a SpellCaster,
+}
 
-      let spell_effects = self.get_spell_effects(expansion_id, spell_id).unwrap();
-      if spell_desc.contains("increases") || spell_desc.contains("improves") {
-        if spell_desc.contains("spell critical strike rating") {
-          stats.push(Stat { stat_type: 24, stat_value: spell_effects[0].points_upper.abs() as u16 });
-        } else if spell_desc.contains("critical strike rating") {
-          stats.push(Stat { stat_type: 8, stat_value: spell_effects[0].points_upper.abs() as u16 });
-        } else if spell_desc.contains("spell hit rating") {
-          stats.push(Stat { stat_type: 23, stat_value: spell_effects[0].points_upper.abs() as u16 });
-        } else if spell_desc.contains("hit rating") {
-          stats.push(Stat { stat_type: 7, stat_value: spell_effects[0].points_upper.abs() as u16 });
-        } else if spell_desc.contains("resilience rating") {
-          stats.push(Stat { stat_type: 39, stat_value: spell_effects[0].points_upper.abs() as u16 });
-        } else if spell_desc.contains("damage and healing done by") {
-          stats.push(Stat { stat_type: 13, stat_value: spell_effects[0].points_upper.abs() as u16 });
-          stats.push(Stat { stat_type: 14, stat_value: spell_effects[0].points_upper.abs() as u16 });
-        } else if spell_desc.contains("healing done by up to") && spell_desc.contains("damage done by up to") {
-          stats.push(Stat { stat_type: 14, stat_value: spell_effects[0].points_upper.abs() as u16 });
-          stats.push(Stat { stat_type: 13, stat_value: spell_effects[1].points_upper.abs() as u16 });
-        } else if spell_desc.contains("spell haste rating") {
-          stats.push(Stat { stat_type: 42, stat_value: spell_effects[0].points_upper.abs() as u16 });
-        } else if spell_desc.contains("haste rating") {
-          stats.push(Stat { stat_type: 37, stat_value: spell_effects[0].points_upper.abs() as u16 });
-        } else if spell_desc.contains("ranged attack power") {
-          stats.push(Stat { stat_type: 25, stat_value: spell_effects[0].points_upper.abs() as u16 });
-        } else if spell_desc.contains("attack power") {
-          stats.push(Stat { stat_type: 9, stat_value: spell_effects[0].points_upper.abs() as u16 });
-        } else if spell_desc.contains("defense rating") {
-          stats.push(Stat { stat_type: 22, stat_value: spell_effects[0].points_upper.abs() as u16 });
-        } else if spell_desc.contains("dodge rating") {
-          stats.push(Stat { stat_type: 10, stat_value: spell_effects[0].points_upper.abs() as u16 });
-        } else if spell_desc.contains("parry rating") {
-          stats.push(Stat { stat_type: 11, stat_value: spell_effects[0].points_upper.abs() as u16 });
-        } else if spell_desc.contains("block rating") {
-          stats.push(Stat { stat_type: 12, stat_value: spell_effects[0].points_upper.abs() as u16 });
-        } else if spell_desc.contains("block chance") {
-          stats.push(Stat { stat_type: 26, stat_value: spell_effects[0].points_upper.abs() as u16 });
-        } else if spell_desc.contains("damage done by shadow spells") {
-          stats.push(Stat { stat_type: 20, stat_value: spell_effects[0].points_upper.abs() as u16 });
-        } else if spell_desc.contains("damage done by arcane spells") {
-          stats.push(Stat { stat_type: 19, stat_value: spell_effects[0].points_upper.abs() as u16 });
-        } else if spell_desc.contains("damage done by fire spells") {
-          stats.push(Stat { stat_type: 18, stat_value: spell_effects[0].points_upper.abs() as u16 });
-        } else if spell_desc.contains("damage done by nature spells") {
-          stats.push(Stat { stat_type: 17, stat_value: spell_effects[0].points_upper.abs() as u16 });
-        } else if spell_desc.contains("damage done by frost spells") {
-          stats.push(Stat { stat_type: 16, stat_value: spell_effects[0].points_upper.abs() as u16 });
-        } else if spell_desc.contains("damage done by holy spells") {
-          stats.push(Stat { stat_type: 15, stat_value: spell_effects[0].points_upper.abs() as u16 });
-        } else if spell_desc.contains("the block value of your shield") {
-          stats.push(Stat { stat_type: 44, stat_value: spell_effects[0].points_upper.abs() as u16 });
+impl<'a> SpellTemplateContext for DataTemplateContext<'a> {
+  fn get_value(&self, spell_id: Option<u32>, variable: char, index: Option<u8>) -> Option<f64> {
+    let spell_id = spell_id.unwrap_or(self.spell_id);
+    match variable {
+      'd' => self.data.get_spell(self.expansion_id, spell_id).map(|spell| spell.duration as f64),
+      'r' => self.data.get_spell(self.expansion_id, spell_id).map(|spell| spell.range_max as f64),
+      _ => {
+        let spell_effects = self.data.get_spell_effects(self.expansion_id, spell_id)?;
+        // Empty effects are not stored, hence indices beyond fall back to the last effect
+        let index = index.unwrap_or(1).max(1) as usize;
+        let spell_effect = spell_effects.get(index - 1).or_else(|| spell_effects.last())?;
+        match variable {
+          's' | 'm' | 'o' | 'b' => Some(spell_effect.points_upper as f64),
+          'x' => Some(spell_effect.chain_targets as f64),
+          'a' => Some(spell_effect.radius as f64),
+          _ => None
         }
-      } else if spell_desc.contains("restores") {
-        if spell_desc.contains("mana per") {
-          stats.push(Stat { stat_type: 21, stat_value: spell_effects[0].points_upper.abs() as u16 });
-        } else if spell_desc.contains("health per") {
-          stats.push(Stat { stat_type: 43, stat_value: spell_effects[0].points_upper.abs() as u16 });
-        }
-      } else if spell_desc.contains("attacks ignore") && spell_desc.contains("opponent's armor") {
-        stats.push(Stat { stat_type: 41, stat_value: spell_effects[0].points_upper.abs() as u16 });
       }
     }
-    stats
+  }
+
+  fn format_duration(&self, duration: u32) -> String {
+    format_duration(&self.data.dictionary, self.language_id, duration)
+  }
+
+  // Known spells and active auras are not told apart, both are spells of the caster
+  fn is_condition_met(&self, condition: &Condition) -> bool {
+    match condition.kind {
+      's' | 'a' => self.caster.known_spell_ids.contains(&condition.id),
+      _ => false
+    }
+  }
+
+  fn is_female(&self) -> bool {
+    self.caster.is_female
   }
 }
 
@@ -222,5 +114,5 @@ fn format_duration(dictionary: &Dictionary, language_id: u8, duration: u32) -> S
     return dictionary.get("second", language);
   }
 
-  return str_util::strformat::fmt(dictionary.get("milliseconds", language), &[&(duration / (24 * 60 * 60 * 1000)).to_string()]);
+  return str_util::strformat::fmt(dictionary.get("milliseconds", language), &[&duration.to_string()]);
 }
\ No newline at end of file
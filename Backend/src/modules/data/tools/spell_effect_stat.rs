@@ -0,0 +1,109 @@
+use crate::modules::data::domain_value::SpellEffect;
+use crate::modules::data::Stat;
+
+// Aura types as found in the Spell.dbc
+const AURA_MOD_DAMAGE_DONE: u16 = 13;
+const AURA_MOD_RESISTANCE: u16 = 22;
+const AURA_MOD_STAT: u16 = 29;
+const AURA_MOD_BLOCK_PERCENT: u16 = 51;
+const AURA_MOD_REGEN: u16 = 84;
+const AURA_MOD_POWER_REGEN: u16 = 85;
+const AURA_MOD_ATTACK_POWER: u16 = 99;
+const AURA_MOD_TARGET_RESISTANCE: u16 = 123;
+const AURA_MOD_RANGED_ATTACK_POWER: u16 = 124;
+const AURA_MOD_HEALING_DONE: u16 = 135;
+const AURA_MOD_SHIELD_BLOCK_VALUE: u16 = 158;
+const AURA_MOD_RATING: u16 = 189;
+
+// Combat rating bits of the misc value of AURA_MOD_RATING
+const RATING_DEFENSE: i32 = 1 << 1;
+const RATING_DODGE: i32 = 1 << 2;
+const RATING_PARRY: i32 = 1 << 3;
+const RATING_BLOCK: i32 = 1 << 4;
+const RATING_HIT_MELEE: i32 = 1 << 5;
+const RATING_HIT_SPELL: i32 = 1 << 7;
+const RATING_CRIT_MELEE: i32 = 1 << 8;
+const RATING_CRIT_SPELL: i32 = 1 << 10;
+const RATING_RESILIENCE: i32 = (1 << 14) | (1 << 15) | (1 << 16);
+const RATING_HASTE_MELEE: i32 = 1 << 17;
+const RATING_HASTE_SPELL: i32 = 1 << 19;
+const RATING_EXPERTISE: i32 = 1 << 23;
+const RATING_ARMOR_PENETRATION: i32 = 1 << 24;
+
+// School mask of all magic schools
+const SCHOOL_MASK_MAGIC: i32 = 126;
+
+#[derive(Debug, Clone, Copy)]
+enum MiscValue {
+  Any,
+  Equals(i32),
+  AnyBit(i32),
+}
+
+impl MiscValue {
+  fn matches(&self, misc_value: i32) -> bool {
+    match *self {
+      MiscValue::Any => true,
+      MiscValue::Equals(value) => misc_value == value,
+      MiscValue::AnyBit(mask) => misc_value & mask != 0,
+    }
+  }
+}
+
+// The first matching entry determines the stat of a spell effect.
+// Hence, melee ratings take precedence over spell ratings if both are modified.
+static AURA_STAT_MAPPING: [(u16, MiscValue, u8); 38] = [
+  (AURA_MOD_STAT, MiscValue::Equals(0), 27),
+  (AURA_MOD_STAT, MiscValue::Equals(1), 28),
+  (AURA_MOD_STAT, MiscValue::Equals(2), 29),
+  (AURA_MOD_STAT, MiscValue::Equals(3), 30),
+  (AURA_MOD_STAT, MiscValue::Equals(4), 31),
+  (AURA_MOD_RESISTANCE, MiscValue::Equals(2), 1),
+  (AURA_MOD_RESISTANCE, MiscValue::Equals(4), 2),
+  (AURA_MOD_RESISTANCE, MiscValue::Equals(8), 3),
+  (AURA_MOD_RESISTANCE, MiscValue::Equals(16), 4),
+  (AURA_MOD_RESISTANCE, MiscValue::Equals(32), 5),
+  (AURA_MOD_RESISTANCE, MiscValue::Equals(64), 6),
+  (AURA_MOD_RATING, MiscValue::AnyBit(RATING_HIT_MELEE), 7),
+  (AURA_MOD_RATING, MiscValue::AnyBit(RATING_HIT_SPELL), 23),
+  (AURA_MOD_RATING, MiscValue::AnyBit(RATING_CRIT_MELEE), 8),
+  (AURA_MOD_RATING, MiscValue::AnyBit(RATING_CRIT_SPELL), 24),
+  (AURA_MOD_RATING, MiscValue::AnyBit(RATING_HASTE_MELEE), 37),
+  (AURA_MOD_RATING, MiscValue::AnyBit(RATING_HASTE_SPELL), 42),
+  (AURA_MOD_RATING, MiscValue::AnyBit(RATING_DEFENSE), 22),
+  (AURA_MOD_RATING, MiscValue::AnyBit(RATING_DODGE), 10),
+  (AURA_MOD_RATING, MiscValue::AnyBit(RATING_PARRY), 11),
+  (AURA_MOD_RATING, MiscValue::AnyBit(RATING_BLOCK), 12),
+  (AURA_MOD_RATING, MiscValue::AnyBit(RATING_RESILIENCE), 39),
+  (AURA_MOD_RATING, MiscValue::AnyBit(RATING_EXPERTISE), 38),
+  (AURA_MOD_RATING, MiscValue::AnyBit(RATING_ARMOR_PENETRATION), 41),
+  (AURA_MOD_DAMAGE_DONE, MiscValue::Equals(SCHOOL_MASK_MAGIC), 13),
+  (AURA_MOD_DAMAGE_DONE, MiscValue::Equals(2), 15),
+  (AURA_MOD_DAMAGE_DONE, MiscValue::Equals(4), 18),
+  (AURA_MOD_DAMAGE_DONE, MiscValue::Equals(8), 17),
+  (AURA_MOD_DAMAGE_DONE, MiscValue::Equals(16), 16),
+  (AURA_MOD_DAMAGE_DONE, MiscValue::Equals(32), 20),
+  (AURA_MOD_DAMAGE_DONE, MiscValue::Equals(64), 19),
+  (AURA_MOD_HEALING_DONE, MiscValue::Any, 14),
+  (AURA_MOD_ATTACK_POWER, MiscValue::Any, 9),
+  (AURA_MOD_RANGED_ATTACK_POWER, MiscValue::Any, 25),
+  (AURA_MOD_BLOCK_PERCENT, MiscValue::Any, 26),
+  (AURA_MOD_SHIELD_BLOCK_VALUE, MiscValue::Any, 44),
+  (AURA_MOD_POWER_REGEN, MiscValue::Equals(0), 21),
+  (AURA_MOD_REGEN, MiscValue::Any, 43),
+];
+
+// Armor penetration of older expansions reduces the armor of the target
+static AURA_STAT_MAPPING_NEGATIVE: [(u16, MiscValue, u8); 1] = [
+  (AURA_MOD_TARGET_RESISTANCE, MiscValue::Equals(1), 41),
+];
+
+pub fn get_spell_effect_stat(spell_effect: &SpellEffect) -> Option<Stat> {
+  let mapping: &[(u16, MiscValue, u8)] = if spell_effect.points_upper < 0 { &AURA_STAT_MAPPING_NEGATIVE } else { &AURA_STAT_MAPPING };
+  mapping.iter()
+    .find(|(aura, misc_value, _)| *aura == spell_effect.aura && misc_value.matches(spell_effect.misc_value))
+    .map(|(_, _, stat_type)| Stat {
+      stat_type: *stat_type,
+      stat_value: spell_effect.points_upper.abs() as u16,
+    })
+}
@@ -0,0 +1,414 @@
+// Parser and evaluator for the templates of spell descriptions, e.g.
+// "Increases damage done by up to $s1 for $d." or "Reduces the cast time by ${$m1/-1000}.1 sec."
+// Anything that can not be resolved is kept as is.
+
+pub trait SpellTemplateContext {
+  // Variables are passed in lower case, the index is the effect index starting with 1
+  fn get_value(&self, spell_id: Option<u32>, variable: char, index: Option<u8>) -> Option<f64>;
+  fn format_duration(&self, duration: u32) -> String;
+  fn is_condition_met(&self, _condition: &Condition) -> bool { false }
+  fn is_female(&self) -> bool { false }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Condition {
+  pub negated: bool,
+  pub kind: char,
+  pub id: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Reference {
+  spell_id: Option<u32>,
+  variable: char,
+  index: Option<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Expression {
+  Number(f64),
+  Reference(Reference),
+  Negate(Box<Expression>),
+  Binary(Box<Expression>, char, Box<Expression>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+  Text(String),
+  Reference(Reference, String),
+  Expression(Expression, Option<usize>, String),
+  Plural(String, String),
+  Gender(String, String),
+  Conditional(Condition, Vec<Node>, Vec<Node>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+  Number(f64),
+  Reference(Reference),
+  Operator(char),
+  OpenParenthesis,
+  CloseParenthesis,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpellTemplate {
+  nodes: Vec<Node>,
+}
+
+pub const VARIABLES: &str = "abdehmnorstuxABDEHMNORSTUX";
+
+impl SpellTemplate {
+  pub fn parse(template: &str) -> Self {
+    let mut parser = Parser { chars: template.chars().collect(), position: 0 };
+    SpellTemplate { nodes: parser.parse_nodes(false) }
+  }
+
+  pub fn evaluate(&self, context: &dyn SpellTemplateContext) -> String {
+    let mut result = String::new();
+    let mut last_number = None;
+    evaluate_nodes(&self.nodes, context, &mut result, &mut last_number);
+    result
+  }
+}
+
+struct Parser {
+  chars: Vec<char>,
+  position: usize,
+}
+
+impl Parser {
+  fn peek(&self) -> Option<char> {
+    self.chars.get(self.position).cloned()
+  }
+
+  fn peek_at(&self, offset: usize) -> Option<char> {
+    self.chars.get(self.position + offset).cloned()
+  }
+
+  fn source(&self, start: usize) -> String {
+    self.chars[start..self.position].iter().collect()
+  }
+
+  fn parse_nodes(&mut self, in_branch: bool) -> Vec<Node> {
+    let mut nodes = Vec::new();
+    let mut text = String::new();
+    let mut depth = 0;
+    while let Some(current) = self.peek() {
+      if in_branch {
+        if current == '[' {
+          depth += 1;
+        } else if current == ']' {
+          if depth == 0 {
+            break;
+          }
+          depth -= 1;
+        }
+      }
+
+      if current != '$' {
+        text.push(current);
+        self.position += 1;
+        continue;
+      }
+
+      let start = self.position;
+      match self.parse_placeholder() {
+        Some(node) => {
+          if !text.is_empty() {
+            nodes.push(Node::Text(std::mem::take(&mut text)));
+          }
+          nodes.push(node);
+        },
+        None => {
+          self.position = start + 1;
+          text.push('$');
+        }
+      }
+    }
+    if !text.is_empty() {
+      nodes.push(Node::Text(text));
+    }
+    nodes
+  }
+
+  // Expects the position to be at the '$'
+  fn parse_placeholder(&mut self) -> Option<Node> {
+    let start = self.position;
+    self.position += 1;
+    match self.peek()? {
+      '{' => {
+        self.position += 1;
+        let expression_start = self.position;
+        let mut depth = 0;
+        loop {
+          match self.peek()? {
+            '{' => depth += 1,
+            '}' if depth == 0 => break,
+            '}' => depth -= 1,
+            _ => {}
+          }
+          self.position += 1;
+        }
+        let expression_source: String = self.chars[expression_start..self.position].iter().collect();
+        self.position += 1;
+        match tokenize(&expression_source).and_then(|tokens| parse_expression(&tokens)) {
+          Some(expression) => {
+            let decimals = self.parse_decimals();
+            Some(Node::Expression(expression, decimals, self.source(start)))
+          },
+          None => Some(Node::Text(self.source(start)))
+        }
+      },
+      operator @ '/' | operator @ '*' => {
+        self.position += 1;
+        let number = self.parse_number()?;
+        if self.peek()? != ';' {
+          return None;
+        }
+        self.position += 1;
+        let reference = self.parse_reference()?;
+        let expression = Expression::Binary(Box::new(Expression::Reference(reference)), operator, Box::new(Expression::Number(number)));
+        Some(Node::Expression(expression, None, self.source(start)))
+      },
+      '?' => {
+        self.position += 1;
+        self.parse_conditional()
+      },
+      'l' | 'L' => {
+        self.position += 1;
+        let (singular, plural) = self.parse_alternatives()?;
+        Some(Node::Plural(singular, plural))
+      },
+      'g' | 'G' => {
+        self.position += 1;
+        let (male, female) = self.parse_alternatives()?;
+        Some(Node::Gender(male, female))
+      },
+      _ => {
+        let reference = self.parse_reference()?;
+        Some(Node::Reference(reference, self.source(start)))
+      }
+    }
+  }
+
+  // References look like "s1", "d" or "12345s1"
+  fn parse_reference(&mut self) -> Option<Reference> {
+    let spell_id = if self.peek()?.is_ascii_digit() { Some(self.parse_number()? as u32) } else { None };
+    let variable = self.peek().filter(|variable| VARIABLES.contains(*variable))?;
+    self.position += 1;
+    let index = self.peek().and_then(|index| index.to_digit(10)).map(|index| index as u8);
+    if index.is_some() {
+      self.position += 1;
+    }
+    Some(Reference { spell_id, variable: variable.to_ascii_lowercase(), index })
+  }
+
+  fn parse_number(&mut self) -> Option<f64> {
+    let start = self.position;
+    while self.peek().map(|current| current.is_ascii_digit() || current == '.').unwrap_or(false) {
+      self.position += 1;
+    }
+    self.source(start).parse::<f64>().ok()
+  }
+
+  // A trailing ".2" after an expression determines the number of decimals
+  fn parse_decimals(&mut self) -> Option<usize> {
+    if self.peek() == Some('.') && self.peek_at(1).map(|digit| digit.is_ascii_digit()).unwrap_or(false) {
+      let decimals = self.peek_at(1).unwrap().to_digit(10).unwrap() as usize;
+      self.position += 2;
+      return Some(decimals);
+    }
+    None
+  }
+
+  // "first:second;"
+  fn parse_alternatives(&mut self) -> Option<(String, String)> {
+    let start = self.position;
+    while self.peek()? != ';' {
+      self.position += 1;
+    }
+    let content = self.source(start);
+    self.position += 1;
+    let mut split = content.splitn(2, ':');
+    let first = split.next()?.to_owned();
+    let second = split.next()?.to_owned();
+    Some((first, second))
+  }
+
+  // "$?s12345[then][else]", where the else branch is optional and may be another condition
+  fn parse_conditional(&mut self) -> Option<Node> {
+    let negated = self.peek()? == '!';
+    if negated {
+      self.position += 1;
+    }
+    let kind = self.peek().filter(|kind| kind.is_ascii_alphabetic())?;
+    self.position += 1;
+    let id = self.parse_number()? as u32;
+    let then_branch = self.parse_branch()?;
+    let else_branch = match self.peek() {
+      Some('[') => self.parse_branch()?,
+      Some('?') => {
+        self.position += 1;
+        vec![self.parse_conditional()?]
+      },
+      _ => Vec::new()
+    };
+    Some(Node::Conditional(Condition { negated, kind: kind.to_ascii_lowercase(), id }, then_branch, else_branch))
+  }
+
+  fn parse_branch(&mut self) -> Option<Vec<Node>> {
+    if self.peek()? != '[' {
+      return None;
+    }
+    self.position += 1;
+    let nodes = self.parse_nodes(true);
+    if self.peek()? != ']' {
+      return None;
+    }
+    self.position += 1;
+    Some(nodes)
+  }
+}
+
+fn tokenize(input: &str) -> Option<Vec<Token>> {
+  let mut parser = Parser { chars: input.chars().collect(), position: 0 };
+  let mut tokens = Vec::new();
+  while let Some(current) = parser.peek() {
+    match current {
+      ' ' => parser.position += 1,
+      '+' | '-' | '*' | '/' => {
+        tokens.push(Token::Operator(current));
+        parser.position += 1;
+      },
+      '(' => {
+        tokens.push(Token::OpenParenthesis);
+        parser.position += 1;
+      },
+      ')' => {
+        tokens.push(Token::CloseParenthesis);
+        parser.position += 1;
+      },
+      '$' => {
+        parser.position += 1;
+        tokens.push(Token::Reference(parser.parse_reference()?));
+      },
+      _ if current.is_ascii_digit() || current == '.' => tokens.push(Token::Number(parser.parse_number()?)),
+      _ => return None
+    }
+  }
+  Some(tokens)
+}
+
+fn parse_expression(tokens: &[Token]) -> Option<Expression> {
+  let mut position = 0;
+  let expression = parse_sum(tokens, &mut position)?;
+  if position != tokens.len() {
+    return None;
+  }
+  Some(expression)
+}
+
+fn parse_sum(tokens: &[Token], position: &mut usize) -> Option<Expression> {
+  let mut left = parse_product(tokens, position)?;
+  while let Some(Token::Operator(operator)) = tokens.get(*position) {
+    if *operator != '+' && *operator != '-' {
+      break;
+    }
+    *position += 1;
+    let right = parse_product(tokens, position)?;
+    left = Expression::Binary(Box::new(left), *operator, Box::new(right));
+  }
+  Some(left)
+}
+
+fn parse_product(tokens: &[Token], position: &mut usize) -> Option<Expression> {
+  let mut left = parse_unary(tokens, position)?;
+  while let Some(Token::Operator(operator)) = tokens.get(*position) {
+    if *operator != '*' && *operator != '/' {
+      break;
+    }
+    *position += 1;
+    let right = parse_unary(tokens, position)?;
+    left = Expression::Binary(Box::new(left), *operator, Box::new(right));
+  }
+  Some(left)
+}
+
+fn parse_unary(tokens: &[Token], position: &mut usize) -> Option<Expression> {
+  let token = tokens.get(*position)?;
+  *position += 1;
+  match token {
+    Token::Number(number) => Some(Expression::Number(*number)),
+    Token::Reference(reference) => Some(Expression::Reference(reference.clone())),
+    Token::Operator('-') => Some(Expression::Negate(Box::new(parse_unary(tokens, position)?))),
+    Token::Operator('+') => parse_unary(tokens, position),
+    Token::OpenParenthesis => {
+      let expression = parse_sum(tokens, position)?;
+      if tokens.get(*position)? != &Token::CloseParenthesis {
+        return None;
+      }
+      *position += 1;
+      Some(expression)
+    },
+    _ => None
+  }
+}
+
+fn evaluate_expression(expression: &Expression, context: &dyn SpellTemplateContext) -> Option<f64> {
+  match expression {
+    Expression::Number(number) => Some(*number),
+    Expression::Reference(reference) => context.get_value(reference.spell_id, reference.variable, reference.index),
+    Expression::Negate(inner) => evaluate_expression(inner, context).map(|value| -value),
+    Expression::Binary(left, operator, right) => {
+      let left = evaluate_expression(left, context)?;
+      let right = evaluate_expression(right, context)?;
+      match operator {
+        '+' => Some(left + right),
+        '-' => Some(left - right),
+        '*' => Some(left * right),
+        '/' if right != 0.0 => Some(left / right),
+        _ => None
+      }
+    }
+  }
+}
+
+fn evaluate_nodes(nodes: &[Node], context: &dyn SpellTemplateContext, result: &mut String, last_number: &mut Option<f64>) {
+  for node in nodes {
+    match node {
+      Node::Text(text) => result.push_str(text),
+      Node::Reference(reference, source) => match context.get_value(reference.spell_id, reference.variable, reference.index) {
+        Some(value) if reference.variable == 'd' => result.push_str(&context.format_duration(value.abs() as u32)),
+        Some(value) => {
+          result.push_str(&format_number(value, None));
+          *last_number = Some(value.abs());
+        },
+        None => result.push_str(source)
+      },
+      Node::Expression(expression, decimals, source) => match evaluate_expression(expression, context) {
+        Some(value) => {
+          result.push_str(&format_number(value, *decimals));
+          *last_number = Some(value.abs());
+        },
+        None => result.push_str(source)
+      },
+      Node::Plural(singular, plural) => result.push_str(if *last_number == Some(1.0) { singular } else { plural }),
+      Node::Gender(male, female) => result.push_str(if context.is_female() { female } else { male }),
+      Node::Conditional(condition, then_branch, else_branch) => {
+        let is_met = context.is_condition_met(condition) != condition.negated;
+        evaluate_nodes(if is_met { then_branch } else { else_branch }, context, result, last_number);
+      }
+    }
+  }
+}
+
+// The wording of the description carries the sign, hence only the magnitude is displayed
+fn format_number(value: f64, decimals: Option<usize>) -> String {
+  let value = value.abs();
+  match decimals {
+    Some(decimals) => format!("{:.*}", decimals, value),
+    None if value.fract() == 0.0 => format!("{}", value as i64),
+    None => format!("{:.1}", value)
+  }
+}
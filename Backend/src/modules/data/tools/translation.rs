@@ -0,0 +1,247 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use crate::modules::data::Data;
+use crate::modules::data::dto::{TranslationCoverage, TranslationFailure, TranslationImportResult, TranslationRejection};
+use crate::modules::data::material::{ENGLISH, PoEntry, PoFile};
+use crate::modules::data::repository::TranslationRepository;
+use crate::modules::data::tools::{RetrieveLanguage, VARIABLES};
+
+// Length of data_localization.content
+const MAX_CONTENT_LENGTH: usize = 256;
+
+pub trait Translate {
+  fn get_localization_tables(&self) -> Vec<(&'static str, BTreeSet<u32>)>;
+  fn get_translation_sources(&self, language_id: u8) -> Result<HashMap<u32, String>, TranslationFailure>;
+  fn get_translation_coverage(&self, language_id: u8) -> Result<Vec<TranslationCoverage>, TranslationFailure>;
+  fn export_translations(&self, language_id: u8) -> Result<PoFile, TranslationFailure>;
+  fn import_translations(&self, language_id: u8, po_file: &PoFile) -> Result<TranslationImportResult, TranslationFailure>;
+}
+
+impl Translate for Data {
+  fn get_localization_tables(&self) -> Vec<(&'static str, BTreeSet<u32>)> {
+    vec![
+      ("data_expansion", self.expansions.values().map(|expansion| expansion.localization_id).collect()),
+      ("data_race", self.races.values().map(|race| race.localization_id).collect()),
+      ("data_profession", self.professions.values().map(|profession| profession.localization_id).collect()),
+      ("data_hero_class", self.hero_classes.values().map(|hero_class| hero_class.localization_id).collect()),
+      ("data_hero_class_spec", self.hero_classes.values()
+        .flat_map(|hero_class| hero_class.talents.iter().map(|talent| talent.localization_id))
+        .collect()),
+      ("data_spell", self.spells.iter().flat_map(|spells| spells.values())
+        .flat_map(|spell| vec![spell.localization_id, spell.subtext_localization_id, spell.description_localization_id, spell.aura_localization_id])
+        .collect()),
+      ("data_spell_dispel_type", self.dispel_types.values().map(|dispel_type| dispel_type.localization_id).collect()),
+      ("data_spell_power_type", self.power_types.values().map(|power_type| power_type.localization_id).collect()),
+      ("data_stat_type", self.stat_types.values().map(|stat_type| stat_type.localization_id).collect()),
+      ("data_npc", self.npcs.iter().flat_map(|npcs| npcs.values()).map(|npc| npc.localization_id).collect()),
+      ("data_item", self.items.iter().flat_map(|items| items.values()).map(|item| item.localization_id).collect()),
+      ("data_enchant", self.enchants.iter().flat_map(|enchants| enchants.values()).map(|enchant| enchant.localization_id).collect()),
+      ("data_item_bonding", self.item_bondings.values().map(|item_bonding| item_bonding.localization_id).collect()),
+      ("data_item_class", self.item_classes.values().map(|item_class| item_class.localization_id).collect()),
+      ("data_item_damage_type", self.item_damage_types.values().map(|item_damage_type| item_damage_type.localization_id).collect()),
+      ("data_item_inventory_type", self.item_inventory_types.values().map(|item_inventory_type| item_inventory_type.localization_id).collect()),
+      ("data_item_quality", self.item_qualities.values().map(|item_quality| item_quality.localization_id).collect()),
+      ("data_item_random_property", self.item_random_properties.iter().flat_map(|properties| properties.values())
+        .map(|item_random_property| item_random_property.localization_id)
+        .collect()),
+      ("data_item_sheath", self.item_sheaths.values().map(|item_sheath| item_sheath.localization_id).collect()),
+      ("data_itemset_name", self.itemset_names.iter().flat_map(|itemset_names| itemset_names.values())
+        .map(|itemset_name| itemset_name.localization_id)
+        .collect()),
+      ("data_title", self.titles.values().map(|title| title.localization_id).collect()),
+    ]
+  }
+
+  // The english content each translation has been made from, as recorded by the import
+  fn get_translation_sources(&self, language_id: u8) -> Result<HashMap<u32, String>, TranslationFailure> {
+    self.backend.load_translation_sources(language_id).map_err(TranslationFailure::Database)
+  }
+
+  fn get_translation_coverage(&self, language_id: u8) -> Result<Vec<TranslationCoverage>, TranslationFailure> {
+    let english = self.localization.get(&ENGLISH).ok_or_else(|| TranslationFailure::InvalidLanguage(ENGLISH.to_string()))?;
+    let translations = self.localization.get(&language_id);
+    let sources = self.get_translation_sources(language_id)?;
+
+    Ok(self.get_localization_tables().into_iter().map(|(table, localization_ids)| {
+      let source_ids: Vec<u32> = localization_ids.into_iter().filter(|id| english.contains_key(id)).collect();
+      let translated_ids: Vec<u32> = source_ids.iter().cloned()
+        .filter(|id| translations.map(|translations| translations.contains_key(id)).unwrap_or(false))
+        .collect();
+      TranslationCoverage {
+        language_id,
+        table: table.to_owned(),
+        num_source: source_ids.len(),
+        num_translated: translated_ids.len(),
+        num_outdated: translated_ids.iter().filter(|id| is_outdated(&sources, &english[*id].content, id)).count(),
+      }
+    }).collect())
+  }
+
+  // Exports everything that is either not translated yet or whose english source changed,
+  // the latter is marked as fuzzy and carries the previous translation
+  fn export_translations(&self, language_id: u8) -> Result<PoFile, TranslationFailure> {
+    let language = self.get_language(language_id)
+      .filter(|language| language.id != ENGLISH)
+      .ok_or_else(|| TranslationFailure::InvalidLanguage(language_id.to_string()))?;
+    let english = self.localization.get(&ENGLISH).ok_or_else(|| TranslationFailure::InvalidLanguage(ENGLISH.to_string()))?;
+    let translations = self.localization.get(&language_id);
+    let sources = self.get_translation_sources(language_id)?;
+
+    let mut references: HashMap<u32, Vec<String>> = HashMap::new();
+    for (table, localization_ids) in self.get_localization_tables() {
+      for localization_id in localization_ids {
+        references.entry(localization_id).or_insert_with(Vec::new).push(table.to_owned());
+      }
+    }
+
+    let sorted_english: BTreeMap<&u32, &String> = english.iter().map(|(id, localization)| (id, &localization.content)).collect();
+    let entries = sorted_english.into_iter().filter_map(|(id, source)| {
+      let translation = translations.and_then(|translations| translations.get(id));
+      let fuzzy = match translation {
+        None => false,
+        Some(_) if is_outdated(&sources, source, id) => true,
+        Some(_) => return None
+      };
+      Some(PoEntry {
+        references: references.get(id).cloned().unwrap_or_else(Vec::new),
+        fuzzy,
+        localization_id: *id,
+        source: source.clone(),
+        translation: translation.map(|translation| translation.content.clone()).unwrap_or_default(),
+      })
+    }).collect();
+
+    Ok(PoFile { language: language.short_code, entries })
+  }
+
+  fn import_translations(&self, language_id: u8, po_file: &PoFile) -> Result<TranslationImportResult, TranslationFailure> {
+    let language = self.get_language(language_id)
+      .filter(|language| language.id != ENGLISH)
+      .ok_or_else(|| TranslationFailure::InvalidLanguage(language_id.to_string()))?;
+    // The language is declared in the header of the file
+    if po_file.language.to_lowercase() != language.short_code.to_lowercase() {
+      return Err(TranslationFailure::InvalidFile(1, format!("The file is written for '{}', not for '{}'", po_file.language, language.short_code)));
+    }
+    let english = self.localization.get(&ENGLISH).ok_or_else(|| TranslationFailure::InvalidLanguage(ENGLISH.to_string()))?;
+
+    let mut result = TranslationImportResult::default();
+    let mut accepted = Vec::new();
+    for entry in po_file.entries.iter() {
+      // Fuzzy entries have not been reviewed yet
+      if entry.translation.is_empty() || entry.fuzzy {
+        result.num_skipped += 1;
+        continue;
+      }
+
+      let reject = |reason: String| TranslationRejection { localization_id: entry.localization_id, reason };
+      let verdict = match english.get(&entry.localization_id) {
+        None => Err(reject("Unknown localization id".to_owned())),
+        Some(source) if source.content != entry.source => Err(reject("The english source changed since the export".to_owned())),
+        Some(_) if entry.translation.chars().count() > MAX_CONTENT_LENGTH =>
+          Err(reject(format!("Longer than {} characters", MAX_CONTENT_LENGTH))),
+        Some(_) => validate_placeholders(&entry.source, &entry.translation).map_err(reject)
+      };
+      match verdict {
+        Ok(()) => accepted.push(entry),
+        Err(rejection) => result.rejections.push(rejection)
+      }
+    }
+
+    // All accepted translations are written together or not at all
+    self.backend.store_translations(language_id, &accepted).map_err(TranslationFailure::Database)?;
+
+    result.num_imported = accepted.len();
+    Ok(result)
+  }
+}
+
+// Translations imported before sources have been recorded are assumed to be up to date
+fn is_outdated(sources: &HashMap<u32, String>, source: &str, localization_id: &u32) -> bool {
+  sources.get(localization_id).map(|recorded| recorded != source).unwrap_or(false)
+}
+
+// Translations must keep every format argument and spell template placeholder of their source
+pub fn validate_placeholders(source: &str, translation: &str) -> Result<(), String> {
+  let expected = placeholders(source);
+  let found = placeholders(translation);
+  if expected == found {
+    return Ok(());
+  }
+
+  let missing: Vec<&String> = expected.iter().filter(|placeholder| !found.contains(placeholder)).collect();
+  let unexpected: Vec<&String> = found.iter().filter(|placeholder| !expected.contains(placeholder)).collect();
+  if missing.is_empty() && unexpected.is_empty() {
+    return Err("Placeholders are used a different number of times".to_owned());
+  }
+  Err(format!("Missing placeholders {:?}, unexpected placeholders {:?}", missing, unexpected))
+}
+
+// Sorted, so that translations may reorder them
+fn placeholders(text: &str) -> Vec<String> {
+  let chars: Vec<char> = text.chars().collect();
+  let mut result = Vec::new();
+  let mut position = 0;
+  while position < chars.len() {
+    let start = position;
+    position += 1;
+    match chars[start] {
+      '%' => {
+        if chars.get(position) == Some(&'%') {
+          position += 1;
+          continue;
+        }
+        while chars.get(position).map(|current| current.is_ascii_digit() || *current == '$').unwrap_or(false) {
+          position += 1;
+        }
+        if chars.get(position).map(|current| "sdiuf".contains(*current)).unwrap_or(false) {
+          position += 1;
+          result.push(chars[start..position].iter().collect());
+        } else {
+          position = start + 1;
+        }
+      },
+      '$' => match chars.get(position).cloned() {
+        Some('{') => {
+          let end = chars[position..].iter().position(|current| *current == '}').map(|offset| position + offset + 1).unwrap_or(chars.len());
+          result.push(chars[start..end].iter().filter(|current| !current.is_whitespace()).collect());
+          position = end;
+        },
+        // Branches, plural and gender forms are translated, only their kind has to be kept
+        Some('?') => {
+          position += 1;
+          while chars.get(position).map(|current| current.is_ascii_alphanumeric() || *current == '!').unwrap_or(false) {
+            position += 1;
+          }
+          result.push(chars[start..position].iter().collect::<String>().to_lowercase());
+        },
+        Some('l') | Some('L') | Some('g') | Some('G') => {
+          result.push(format!("${}", chars[position].to_ascii_lowercase()));
+          position = chars[position..].iter().position(|current| *current == ';').map(|offset| position + offset + 1).unwrap_or(chars.len());
+        },
+        _ => {
+          if chars.get(position) == Some(&'/') || chars.get(position) == Some(&'*') {
+            while chars.get(position).map(|current| *current != ';').unwrap_or(false) {
+              position += 1;
+            }
+            position += 1;
+          }
+          while chars.get(position).map(|current| current.is_ascii_digit()).unwrap_or(false) {
+            position += 1;
+          }
+          if chars.get(position).map(|current| VARIABLES.contains(*current)).unwrap_or(false) {
+            position += 1;
+            if chars.get(position).map(|current| current.is_ascii_digit()).unwrap_or(false) {
+              position += 1;
+            }
+            result.push(chars[start..position.min(chars.len())].iter().collect::<String>().to_lowercase());
+          } else {
+            position = start + 1;
+          }
+        }
+      },
+      _ => {}
+    }
+  }
+  result.sort();
+  result
+}
\ No newline at end of file
@@ -0,0 +1,63 @@
+use std::fs;
+
+use crate::modules::data::Data;
+use crate::modules::data::dto::TranslationFailure;
+use crate::modules::data::material::PoFile;
+use crate::modules::data::tools::{RetrieveLanguage, Translate};
+
+// backend localization coverage [<language>]      Prints per table how much of the english content is translated
+// backend localization export <language> <file>   Writes missing and outdated translations as PO file
+// backend localization import <language> <file>   Imports the reviewed translations of a PO file
+pub fn run_translation_command(data: &Data, args: &[String]) -> Result<(), TranslationFailure> {
+  match args.first().map(|arg| arg.as_str()) {
+    Some("coverage") => {
+      let mut language_ids: Vec<u8> = match args.get(1) {
+        Some(language) => vec![parse_language(data, language)?],
+        None => data.get_all_languages().iter().map(|language| language.id).collect()
+      };
+      language_ids.sort();
+      for language_id in language_ids {
+        for coverage in data.get_translation_coverage(language_id)? {
+          println!("{} {}: {}/{} translated, {} outdated", data.get_language(language_id).unwrap().short_code, coverage.table,
+                   coverage.num_translated, coverage.num_source, coverage.num_outdated);
+        }
+      }
+      Ok(())
+    },
+    Some("export") => {
+      let (language_id, path) = language_and_path(data, args, "export")?;
+      let po_file = data.export_translations(language_id)?;
+      fs::write(path, po_file.write()).map_err(|err| TranslationFailure::Io(format!("Writing {} failed: {}", path, err)))?;
+      println!("Exported {} entries", po_file.entries.len());
+      Ok(())
+    },
+    Some("import") => {
+      let (language_id, path) = language_and_path(data, args, "import")?;
+      let content = fs::read_to_string(path).map_err(|err| TranslationFailure::Io(format!("Reading {} failed: {}", path, err)))?;
+      let result = data.import_translations(language_id, &PoFile::parse(&content)?)?;
+      for rejection in result.rejections.iter() {
+        println!("Rejected {}: {}", rejection.localization_id, rejection.reason);
+      }
+      println!("Imported {} entries, skipped {} untranslated or fuzzy entries and rejected {} entries",
+               result.num_imported, result.num_skipped, result.rejections.len());
+      Ok(())
+    },
+    _ => Err(TranslationFailure::InvalidCommand("Usage: backend localization coverage|export|import".to_owned()))
+  }
+}
+
+fn language_and_path<'a>(data: &Data, args: &'a [String], command: &str) -> Result<(u8, &'a str), TranslationFailure> {
+  match (args.get(1), args.get(2)) {
+    (Some(language), Some(path)) => Ok((parse_language(data, language)?, path.as_str())),
+    _ => Err(TranslationFailure::InvalidCommand(format!("Usage: backend localization {} <language> <file>", command)))
+  }
+}
+
+// Languages are given by id or by short code
+fn parse_language(data: &Data, language: &str) -> Result<u8, TranslationFailure> {
+  language.parse::<u8>().ok()
+    .and_then(|language_id| data.get_language(language_id))
+    .or_else(|| data.get_language_by_short_code(language.to_owned()))
+    .map(|language| language.id)
+    .ok_or_else(|| TranslationFailure::InvalidLanguage(language.to_owned()))
+}
\ No newline at end of file
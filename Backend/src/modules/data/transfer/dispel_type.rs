@@ -1,21 +1,23 @@
 use rocket::State;
 use rocket_contrib::json::Json;
 
-use crate::modules::data::Data;
+use crate::modules::data::DataStore;
 use crate::modules::data::domain_value::DispelType;
 use crate::modules::data::tools::RetrieveDispelType;
 
 #[openapi]
 #[get("/dispel_type/<id>")]
-pub fn get_dispel_type(me: State<Data>, id: u8) -> Option<Json<DispelType>>
+pub fn get_dispel_type(me: State<DataStore>, id: u8) -> Option<Json<DispelType>>
 {
+  let me = me.snapshot();
   me.get_dispel_type(id)
     .and_then(|dispel_type| Some(Json(dispel_type)))
 }
 
 #[openapi]
 #[get("/dispel_type")]
-pub fn get_all_dispel_types(me: State<Data>) -> Json<Vec<DispelType>>
+pub fn get_all_dispel_types(me: State<DataStore>) -> Json<Vec<DispelType>>
 {
+  let me = me.snapshot();
   Json(me.get_all_dispel_types())
 }
\ No newline at end of file
@@ -1,13 +1,14 @@
 use rocket::State;
 use rocket_contrib::json::Json;
 
-use crate::modules::data::Data;
+use crate::modules::data::DataStore;
 use crate::modules::data::domain_value::Gem;
 use crate::modules::data::tools::RetrieveGem;
 
 #[openapi]
 #[get("/gem/<expansion_id>/<gem_id>")]
-pub fn get_gem(me: State<Data>, expansion_id: u8, gem_id: u32) -> Option<Json<Gem>> {
+pub fn get_gem(me: State<DataStore>, expansion_id: u8, gem_id: u32) -> Option<Json<Gem>> {
+  let me = me.snapshot();
   me.get_gem(expansion_id, gem_id)
     .and_then(|result| Some(Json(result)))
 }
\ No newline at end of file
@@ -1,21 +1,23 @@
 use rocket::State;
 use rocket_contrib::json::Json;
 
-use crate::modules::data::Data;
+use crate::modules::data::DataStore;
 use crate::modules::data::domain_value::ItemClass;
 use crate::modules::data::tools::RetrieveItemClass;
 
 #[openapi]
 #[get("/item_class/<id>")]
-pub fn get_item_class(me: State<Data>, id: u8) -> Option<Json<ItemClass>>
+pub fn get_item_class(me: State<DataStore>, id: u8) -> Option<Json<ItemClass>>
 {
+  let me = me.snapshot();
   me.get_item_class(id)
     .and_then(|item_class| Some(Json(item_class)))
 }
 
 #[openapi]
 #[get("/item_class")]
-pub fn get_all_item_classes(me: State<Data>) -> Json<Vec<ItemClass>>
+pub fn get_all_item_classes(me: State<DataStore>) -> Json<Vec<ItemClass>>
 {
+  let me = me.snapshot();
   Json(me.get_all_item_classes())
 }
\ No newline at end of file
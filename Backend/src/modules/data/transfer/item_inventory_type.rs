@@ -1,21 +1,23 @@
 use rocket::State;
 use rocket_contrib::json::Json;
 
-use crate::modules::data::Data;
+use crate::modules::data::DataStore;
 use crate::modules::data::domain_value::ItemInventoryType;
 use crate::modules::data::tools::RetrieveItemInventoryType;
 
 #[openapi]
 #[get("/item_inventory_type/<id>")]
-pub fn get_item_inventory_type(me: State<Data>, id: u8) -> Option<Json<ItemInventoryType>>
+pub fn get_item_inventory_type(me: State<DataStore>, id: u8) -> Option<Json<ItemInventoryType>>
 {
+  let me = me.snapshot();
   me.get_item_inventory_type(id)
     .and_then(|item_inventory_type| Some(Json(item_inventory_type)))
 }
 
 #[openapi]
 #[get("/item_inventory_type")]
-pub fn get_all_item_inventory_types(me: State<Data>) -> Json<Vec<ItemInventoryType>>
+pub fn get_all_item_inventory_types(me: State<DataStore>) -> Json<Vec<ItemInventoryType>>
 {
+  let me = me.snapshot();
   Json(me.get_all_item_inventory_types())
 }
\ No newline at end of file
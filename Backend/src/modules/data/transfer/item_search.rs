@@ -0,0 +1,17 @@
+use rocket::State;
+use rocket_contrib::json::Json;
+
+use crate::dto::SearchResult;
+use crate::modules::data::DataStore;
+use crate::modules::data::domain_value::{Item, Localized};
+use crate::modules::data::dto::{DataFailure, ItemSearchFilter};
+use crate::modules::data::guard::Language;
+use crate::modules::data::tools::SearchItem;
+
+#[openapi]
+#[post("/item_search", format = "application/json", data = "<filter>")]
+pub fn search_items(me: State<DataStore>, language: Language, filter: Json<ItemSearchFilter>) -> Result<Json<SearchResult<Localized<Item>>>, DataFailure>
+{
+  let me = me.snapshot();
+  me.search_items(language.0, filter.into_inner()).map(Json)
+}
\ No newline at end of file
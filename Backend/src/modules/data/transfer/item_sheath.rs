@@ -1,21 +1,23 @@
 use rocket::State;
 use rocket_contrib::json::Json;
 
-use crate::modules::data::Data;
+use crate::modules::data::DataStore;
 use crate::modules::data::domain_value::ItemSheath;
 use crate::modules::data::tools::RetrieveItemSheath;
 
 #[openapi]
 #[get("/item_sheath/<id>")]
-pub fn get_item_sheath(me: State<Data>, id: u8) -> Option<Json<ItemSheath>>
+pub fn get_item_sheath(me: State<DataStore>, id: u8) -> Option<Json<ItemSheath>>
 {
+  let me = me.snapshot();
   me.get_item_sheath(id)
     .and_then(|item_sheath| Some(Json(item_sheath)))
 }
 
 #[openapi]
 #[get("/item_sheath")]
-pub fn get_all_item_sheaths(me: State<Data>) -> Json<Vec<ItemSheath>>
+pub fn get_all_item_sheaths(me: State<DataStore>) -> Json<Vec<ItemSheath>>
 {
+  let me = me.snapshot();
   Json(me.get_all_item_sheaths())
 }
\ No newline at end of file
@@ -1,13 +1,14 @@
 use rocket::State;
 use rocket_contrib::json::Json;
 
-use crate::modules::data::Data;
+use crate::modules::data::DataStore;
 use crate::modules::data::domain_value::ItemsetEffect;
 use crate::modules::data::tools::RetrieveItemsetEffect;
 
 #[openapi]
 #[get("/itemset_effect/<expansion_id>/<itemset_id>")]
-pub fn get_itemset_effects(me: State<Data>, expansion_id: u8, itemset_id: u16) -> Option<Json<Vec<ItemsetEffect>>> {
+pub fn get_itemset_effects(me: State<DataStore>, expansion_id: u8, itemset_id: u16) -> Option<Json<Vec<ItemsetEffect>>> {
+  let me = me.snapshot();
   me.get_itemset_effects(expansion_id, itemset_id)
     .and_then(|result| Some(Json(result)))
 }
\ No newline at end of file
@@ -1,13 +1,22 @@
 use rocket::State;
 use rocket_contrib::json::Json;
 
-use crate::modules::data::Data;
+use crate::modules::data::DataStore;
 use crate::modules::data::domain_value::Localization;
+use crate::modules::data::dto::LocalizationCoverage;
 use crate::modules::data::tools::RetrieveLocalization;
 
 #[openapi]
 #[get("/localization/<language_id>/<localization_id>")]
-pub fn get_localization(me: State<Data>, language_id: u8, localization_id: u32) -> Option<Json<Localization>> {
+pub fn get_localization(me: State<DataStore>, language_id: u8, localization_id: u32) -> Option<Json<Localization>> {
+  let me = me.snapshot();
   me.get_localization(language_id, localization_id)
     .and_then(|result| Some(Json(result)))
+}
+
+#[openapi]
+#[get("/localization/coverage")]
+pub fn get_localization_coverage(me: State<DataStore>) -> Json<Vec<LocalizationCoverage>> {
+  let me = me.snapshot();
+  Json(me.get_localization_coverage())
 }
\ No newline at end of file
@@ -4,6 +4,7 @@ pub mod localization;
 pub mod race;
 pub mod profession;
 pub mod server;
+pub mod server_ownership;
 pub mod hero_class;
 pub mod spell;
 pub mod dispel_type;
@@ -23,10 +24,12 @@ pub mod item_effect;
 pub mod item_inventory_type;
 pub mod item_quality;
 pub mod item_random_property;
+pub mod item_search;
 pub mod item_sheath;
 pub mod item_socket;
 pub mod item_stat;
 pub mod itemset_name;
 pub mod itemset_effect;
 pub mod title;
-pub mod item_random_property_points;
\ No newline at end of file
+pub mod item_random_property_points;
+pub mod reload;
\ No newline at end of file
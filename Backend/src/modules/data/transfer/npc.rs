@@ -1,13 +1,14 @@
 use rocket::State;
 use rocket_contrib::json::Json;
 
-use crate::modules::data::Data;
+use crate::modules::data::DataStore;
 use crate::modules::data::domain_value::NPC;
 use crate::modules::data::tools::RetrieveNPC;
 
 #[openapi]
 #[get("/npc/<expansion_id>/<npc_id>")]
-pub fn get_npc(me: State<Data>, expansion_id: u8, npc_id: u32) -> Option<Json<NPC>> {
+pub fn get_npc(me: State<DataStore>, expansion_id: u8, npc_id: u32) -> Option<Json<NPC>> {
+  let me = me.snapshot();
   me.get_npc(expansion_id, npc_id)
     .and_then(|result| Some(Json(result)))
 }
\ No newline at end of file
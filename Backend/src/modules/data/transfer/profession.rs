@@ -1,21 +1,23 @@
 use rocket::State;
 use rocket_contrib::json::Json;
 
-use crate::modules::data::Data;
+use crate::modules::data::DataStore;
 use crate::modules::data::domain_value::Profession;
 use crate::modules::data::tools::RetrieveProfession;
 
 #[openapi]
 #[get("/profession/<id>")]
-pub fn get_profession(me: State<Data>, id: u16) -> Option<Json<Profession>>
+pub fn get_profession(me: State<DataStore>, id: u16) -> Option<Json<Profession>>
 {
+  let me = me.snapshot();
   me.get_profession(id)
     .and_then(|profession| Some(Json(profession)))
 }
 
 #[openapi]
 #[get("/profession")]
-pub fn get_all_professions(me: State<Data>) -> Json<Vec<Profession>>
+pub fn get_all_professions(me: State<DataStore>) -> Json<Vec<Profession>>
 {
+  let me = me.snapshot();
   Json(me.get_all_professions())
 }
\ No newline at end of file
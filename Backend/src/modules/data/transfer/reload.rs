@@ -0,0 +1,21 @@
+use rocket::State;
+use rocket_contrib::json::Json;
+
+use crate::modules::account::guard::DataEditor;
+use crate::modules::data::DataStore;
+use crate::modules::data::dto::{DataFailure, DataReloadStatus};
+use crate::modules::data::tools::ReloadData;
+
+#[openapi]
+#[post("/reload")]
+pub fn reload(me: State<DataStore>, _data_editor: DataEditor) -> Result<(), DataFailure>
+{
+  me.reload()
+}
+
+#[openapi]
+#[get("/reload")]
+pub fn get_reload_status(me: State<DataStore>, _data_editor: DataEditor) -> Json<DataReloadStatus>
+{
+  Json(me.get_reload_status())
+}
\ No newline at end of file
@@ -1,21 +1,23 @@
 use rocket::State;
 use rocket_contrib::json::Json;
 
-use crate::modules::data::Data;
+use crate::modules::data::DataStore;
 use crate::modules::data::dto::AvailableServer;
 use crate::modules::data::tools::RetrieveServer;
 
 #[openapi]
 #[get("/server/<id>")]
-pub fn get_server(me: State<Data>, id: u32) -> Option<Json<AvailableServer>>
+pub fn get_server(me: State<DataStore>, id: u32) -> Option<Json<AvailableServer>>
 {
+  let me = me.snapshot();
   me.get_server(id)
     .and_then(|server| Some(Json(server)))
 }
 
 #[openapi]
 #[get("/server")]
-pub fn get_all_servers(me: State<Data>) -> Json<Vec<AvailableServer>>
+pub fn get_all_servers(me: State<DataStore>) -> Json<Vec<AvailableServer>>
 {
+  let me = me.snapshot();
   Json(me.get_all_servers())
 }
\ No newline at end of file
@@ -0,0 +1,37 @@
+use rocket::State;
+use rocket_contrib::json::Json;
+
+use crate::modules::account::Account;
+use crate::modules::account::guard::{Authenticate, ServerManager};
+use crate::modules::account::tools::GetAccountInformation;
+use crate::modules::data::DataStore;
+use crate::modules::data::dto::{AvailableServer, DataFailure, ServerTransfer};
+use crate::modules::data::tools::ServerOwnership;
+
+#[openapi]
+#[get("/server_owner")]
+pub fn get_owned_servers(me: State<DataStore>, auth: Authenticate) -> Json<Vec<AvailableServer>>
+{
+  let me = me.snapshot();
+  Json(me.get_owned_servers(auth.0))
+}
+
+#[openapi]
+#[post("/server_owner/<server_id>")]
+pub fn claim_server(me: State<DataStore>, server_manager: ServerManager, server_id: u32) -> Result<Json<AvailableServer>, DataFailure>
+{
+  let me = me.snapshot();
+  me.claim_server(server_manager.0, server_id)
+    .and_then(|server| Ok(Json(server)))
+}
+
+#[openapi]
+#[put("/server_owner/<server_id>", format = "application/json", data = "<params>")]
+pub fn transfer_server(me: State<DataStore>, account: State<Account>, auth: Authenticate, server_id: u32, params: Json<ServerTransfer>) -> Result<(), DataFailure>
+{
+  let me = me.snapshot();
+  if account.get(params.new_owner_id).is_err() {
+    return Err(DataFailure::InvalidInput);
+  }
+  me.transfer_server(auth.0, server_id, params.new_owner_id)
+}
\ No newline at end of file
@@ -0,0 +1,3 @@
+pub use self::patch::Patch;
+
+mod patch;
\ No newline at end of file
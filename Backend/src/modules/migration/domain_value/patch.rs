@@ -0,0 +1,41 @@
+use std::fs::File;
+use std::io::Read;
+use std::path::PathBuf;
+
+use crate::modules::migration::dto::MigrationFailure;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Patch {
+  pub version: u32,
+  pub name: String,
+  pub path: PathBuf,
+}
+
+impl Patch {
+  // Patches are named like 00042_some_change.zip
+  pub fn from_path(path: PathBuf) -> Option<Self> {
+    let file_name = path.file_name()?.to_str()?;
+    if !file_name.ends_with(".zip") || file_name.len() < 6 || file_name.as_bytes()[5] != b'_' {
+      return None;
+    }
+    let version = file_name.get(..5)?.parse::<u32>().ok()?;
+    let name = file_name[6..file_name.len() - 4].to_owned();
+    Some(Patch { version, name, path })
+  }
+
+  // Each archive contains a single SQL file with the same name
+  pub fn read_sql(&self) -> Result<String, MigrationFailure> {
+    let invalid_patch = |reason: String| MigrationFailure::InvalidPatch(self.path.display().to_string(), reason);
+    let file = File::open(&self.path).map_err(|err| invalid_patch(err.to_string()))?;
+    let mut archive = zip::ZipArchive::new(file).map_err(|err| invalid_patch(err.to_string()))?;
+    for index in 0..archive.len() {
+      let mut entry = archive.by_index(index).map_err(|err| invalid_patch(err.to_string()))?;
+      if entry.name().ends_with(".sql") {
+        let mut sql = String::new();
+        entry.read_to_string(&mut sql).map_err(|err| invalid_patch(err.to_string()))?;
+        return Ok(sql);
+      }
+    }
+    Err(invalid_patch("Archive does not contain a SQL file".to_owned()))
+  }
+}
\ No newline at end of file
@@ -0,0 +1,30 @@
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MigrationFailure {
+  Database(String),
+  InvalidPatch(String, String),
+  DuplicateVersion(u32),
+  PatchFailed(u32, String),
+  SchemaMismatch { expected: u32, found: Option<u32> },
+  InvalidCommand(String),
+}
+
+impl fmt::Display for MigrationFailure {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      MigrationFailure::Database(reason) => write!(f, "Database error: {}", reason),
+      MigrationFailure::InvalidPatch(path, reason) => write!(f, "Invalid patch {}: {}", path, reason),
+      MigrationFailure::DuplicateVersion(version) => write!(f, "Patch version {:05} exists more than once", version),
+      MigrationFailure::PatchFailed(version, reason) => write!(f, "Applying patch {:05} failed: {}", version, reason),
+      MigrationFailure::SchemaMismatch { expected, found: None } =>
+        write!(f, "No patches have been recorded, but schema version {:05} is expected. Run 'backend migrate', \
+          or 'backend migrate baseline <version>' if the database has been set up with merger.sh", expected),
+      MigrationFailure::SchemaMismatch { expected, found: Some(found) } if found < expected =>
+        write!(f, "Schema version {:05} is outdated, {:05} is expected. Run 'backend migrate' to apply the pending patches", found, expected),
+      MigrationFailure::SchemaMismatch { expected, found: Some(found) } =>
+        write!(f, "Schema version {:05} is newer than the expected version {:05}. Deploy a matching backend", found, expected),
+      MigrationFailure::InvalidCommand(usage) => write!(f, "{}", usage),
+    }
+  }
+}
\ No newline at end of file
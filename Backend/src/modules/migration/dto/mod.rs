@@ -0,0 +1,3 @@
+pub use self::migration_failure::MigrationFailure;
+
+mod migration_failure;
\ No newline at end of file
@@ -0,0 +1,46 @@
+use std::env;
+use std::path::PathBuf;
+
+use mysql_connection::material::MySQLConnection;
+use mysql_connection::tools::Execute;
+
+use crate::modules::migration::dto::MigrationFailure;
+
+// Version of the latest patch in Database/patches, i.e. the schema this code has been written against
+// Generated by build.rs
+include!(concat!(env!("OUT_DIR"), "/schema_version.rs"));
+
+const DEFAULT_PATCHES_DIR: &str = "../Database/patches";
+
+#[derive(Debug)]
+pub struct Migration {
+  pub db_main: MySQLConnection,
+  pub patches_dir: PathBuf,
+}
+
+impl Default for Migration {
+  fn default() -> Self
+  {
+    // Not connected to main directly, as the very first patch creates it
+    Migration {
+      db_main: MySQLConnection::new(""),
+      patches_dir: PathBuf::from(env::var("DB_PATCHES_DIR").unwrap_or_else(|_| DEFAULT_PATCHES_DIR.to_owned())),
+    }
+  }
+}
+
+impl Migration {
+  pub fn init(self) -> Result<Self, MigrationFailure>
+  {
+    // Same defaults as in the first patch, so that it does not matter who creates the database
+    self.db_main.execute_script("CREATE DATABASE IF NOT EXISTS `main` DEFAULT CHARACTER SET utf8 COLLATE utf8_unicode_ci; \
+      CREATE TABLE IF NOT EXISTS `main`.`migration_patch` ( \
+        `version` INT(11) UNSIGNED NOT NULL, \
+        `name` VARCHAR(255) NOT NULL, \
+        `applied_at` BIGINT(20) UNSIGNED NOT NULL, \
+        PRIMARY KEY (`version`) \
+      ) ENGINE=InnoDB DEFAULT CHARSET=utf8 COLLATE=utf8_unicode_ci;")
+      .map_err(|err| MigrationFailure::Database(err.to_string()))?;
+    Ok(self)
+  }
+}
\ No newline at end of file
@@ -0,0 +1,3 @@
+pub use self::migration::{Migration, EXPECTED_SCHEMA_VERSION};
+
+mod migration;
\ No newline at end of file
@@ -0,0 +1,11 @@
+pub use self::material::{Migration, EXPECTED_SCHEMA_VERSION};
+pub use self::dto::MigrationFailure;
+
+#[cfg(test)]
+mod tests;
+
+mod material;
+mod domain_value;
+mod dto;
+
+pub mod tools;
\ No newline at end of file
@@ -0,0 +1 @@
+mod patches;
\ No newline at end of file
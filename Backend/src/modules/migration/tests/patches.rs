@@ -0,0 +1,32 @@
+use std::path::{Path, PathBuf};
+
+use crate::modules::migration::EXPECTED_SCHEMA_VERSION;
+use crate::modules::migration::domain_value::Patch;
+use crate::modules::migration::tools::find_patches;
+
+#[test]
+fn patch_from_path() {
+  let patch = Patch::from_path(PathBuf::from("patches/00042_some_change.zip")).unwrap();
+  assert_eq!(patch.version, 42);
+  assert_eq!(patch.name, "some_change");
+  assert!(Patch::from_path(PathBuf::from("patches/00042_some_change.sql")).is_none());
+  assert!(Patch::from_path(PathBuf::from("patches/merge.zip")).is_none());
+  assert!(Patch::from_path(PathBuf::from("patches/1-73")).is_none());
+}
+
+#[test]
+fn patches_match_expected_schema_version() {
+  let patches = find_patches(Path::new("../Database/patches")).unwrap();
+  // Every version from the first patch on exists exactly once
+  for (index, patch) in patches.iter().enumerate() {
+    assert_eq!(patch.version, index as u32);
+  }
+  assert_eq!(patches.last().unwrap().version, EXPECTED_SCHEMA_VERSION);
+}
+
+#[test]
+fn read_patch_sql() {
+  let patches = find_patches(Path::new("../Database/patches")).unwrap();
+  let sql = patches.first().unwrap().read_sql().unwrap();
+  assert!(sql.contains("CREATE DATABASE"));
+}
\ No newline at end of file
@@ -0,0 +1,61 @@
+use mysql_connection::tools::{Execute, Select, TakeColumn};
+
+use crate::modules::migration::domain_value::Patch;
+use crate::modules::migration::dto::MigrationFailure;
+use crate::modules::migration::material::Migration;
+use crate::modules::migration::tools::find_patches;
+
+pub trait ApplyMigrations {
+  fn applied_versions(&self) -> Result<Vec<u32>, MigrationFailure>;
+  fn pending_patches(&self) -> Result<Vec<Patch>, MigrationFailure>;
+  fn apply_pending(&self) -> Result<Vec<Patch>, MigrationFailure>;
+  fn baseline(&self, version: u32) -> Result<Vec<Patch>, MigrationFailure>;
+}
+
+impl ApplyMigrations for Migration {
+  fn applied_versions(&self) -> Result<Vec<u32>, MigrationFailure> {
+    self.db_main.try_select("SELECT version FROM `main`.`migration_patch` ORDER BY version", &|mut row| row.take_column(0))
+      .map_err(|err| MigrationFailure::Database(err.to_string()))
+  }
+
+  fn pending_patches(&self) -> Result<Vec<Patch>, MigrationFailure> {
+    let applied_versions = self.applied_versions()?;
+    Ok(find_patches(&self.patches_dir)?.into_iter()
+      .filter(|patch| !applied_versions.contains(&patch.version))
+      .collect())
+  }
+
+  // Patches are applied in order and each is recorded right after it succeeded
+  // Schema changes can not be rolled back in MySQL, so a failing patch stops the run and has to be fixed manually
+  fn apply_pending(&self) -> Result<Vec<Patch>, MigrationFailure> {
+    let pending_patches = self.pending_patches()?;
+    for patch in pending_patches.iter() {
+      let sql = patch.read_sql()?;
+      self.db_main.execute_script(&sql)
+        .map_err(|err| MigrationFailure::PatchFailed(patch.version, err.to_string()))?;
+      record_patch(self, patch)?;
+    }
+    Ok(pending_patches)
+  }
+
+  // Records patches that have been applied by other means, e.g. merger.sh, without running them
+  fn baseline(&self, version: u32) -> Result<Vec<Patch>, MigrationFailure> {
+    let patches: Vec<Patch> = self.pending_patches()?.into_iter()
+      .filter(|patch| patch.version <= version)
+      .collect();
+    for patch in patches.iter() {
+      record_patch(self, patch)?;
+    }
+    Ok(patches)
+  }
+}
+
+fn record_patch(migration: &Migration, patch: &Patch) -> Result<(), MigrationFailure> {
+  if !migration.db_main.execute_wparams("INSERT INTO `main`.`migration_patch` (`version`, `name`, `applied_at`) VALUES (:version, :name, UNIX_TIMESTAMP())", params!(
+    "version" => patch.version,
+    "name" => patch.name.clone()
+  )) {
+    return Err(MigrationFailure::Database(format!("Recording patch {:05} failed", patch.version)));
+  }
+  Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,25 @@
+use mysql_connection::tools::{Select, TakeColumn};
+
+use crate::modules::migration::dto::MigrationFailure;
+use crate::modules::migration::material::{Migration, EXPECTED_SCHEMA_VERSION};
+
+pub trait CheckSchemaVersion {
+  fn schema_version(&self) -> Result<Option<u32>, MigrationFailure>;
+  fn check_schema_version(&self) -> Result<u32, MigrationFailure>;
+}
+
+impl CheckSchemaVersion for Migration {
+  fn schema_version(&self) -> Result<Option<u32>, MigrationFailure> {
+    // MAX returns NULL if nothing has been recorded yet
+    self.db_main.try_select_value("SELECT MAX(version) FROM `main`.`migration_patch`", &|mut row| row.take_column::<Option<u32>>(0))
+      .map(Option::flatten)
+      .map_err(|err| MigrationFailure::Database(err.to_string()))
+  }
+
+  fn check_schema_version(&self) -> Result<u32, MigrationFailure> {
+    match self.schema_version()? {
+      Some(version) if version == EXPECTED_SCHEMA_VERSION => Ok(version),
+      found => Err(MigrationFailure::SchemaMismatch { expected: EXPECTED_SCHEMA_VERSION, found })
+    }
+  }
+}
\ No newline at end of file
@@ -0,0 +1,36 @@
+use crate::modules::migration::dto::MigrationFailure;
+use crate::modules::migration::material::{Migration, EXPECTED_SCHEMA_VERSION};
+use crate::modules::migration::tools::{ApplyMigrations, CheckSchemaVersion};
+
+// backend migrate                    Applies all pending patches
+// backend migrate status             Prints the current and the expected schema version and the pending patches
+// backend migrate baseline <version> Records all patches up to <version> as applied without running them
+pub fn run_command(migration: &Migration, args: &[String]) -> Result<(), MigrationFailure> {
+  match args.first().map(|arg| arg.as_str()) {
+    None => {
+      let applied = migration.apply_pending()?;
+      for patch in applied.iter() {
+        println!("Applied {:05}_{}", patch.version, patch.name);
+      }
+      println!("Applied {} patches", applied.len());
+      migration.check_schema_version().map(|_| ())
+    },
+    Some("status") => {
+      let version = migration.schema_version()?;
+      println!("Schema version: {}", version.map(|version| format!("{:05}", version)).unwrap_or_else(|| "none".to_owned()));
+      println!("Expected version: {:05}", EXPECTED_SCHEMA_VERSION);
+      for patch in migration.pending_patches()? {
+        println!("Pending {:05}_{}", patch.version, patch.name);
+      }
+      Ok(())
+    },
+    Some("baseline") => {
+      let version = args.get(1).and_then(|version| version.parse::<u32>().ok())
+        .ok_or_else(|| MigrationFailure::InvalidCommand("Usage: backend migrate baseline <version>".to_owned()))?;
+      let recorded = migration.baseline(version)?;
+      println!("Recorded {} patches as applied", recorded.len());
+      Ok(())
+    },
+    Some(command) => Err(MigrationFailure::InvalidCommand(format!("Unknown command '{}', expected status or baseline", command)))
+  }
+}
\ No newline at end of file
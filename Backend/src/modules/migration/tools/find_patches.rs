@@ -0,0 +1,31 @@
+use std::fs;
+use std::path::Path;
+
+use crate::modules::migration::domain_value::Patch;
+use crate::modules::migration::dto::MigrationFailure;
+
+// Older patches are archived in sub directories, hence they are searched as well
+pub fn find_patches(dir: &Path) -> Result<Vec<Patch>, MigrationFailure> {
+  let mut patches = Vec::new();
+  collect_patches(dir, &mut patches)?;
+  patches.sort_by(|left, right| left.version.cmp(&right.version));
+  for window in patches.windows(2) {
+    if window[0].version == window[1].version {
+      return Err(MigrationFailure::DuplicateVersion(window[0].version));
+    }
+  }
+  Ok(patches)
+}
+
+fn collect_patches(dir: &Path, patches: &mut Vec<Patch>) -> Result<(), MigrationFailure> {
+  let entries = fs::read_dir(dir).map_err(|err| MigrationFailure::InvalidPatch(dir.display().to_string(), err.to_string()))?;
+  for entry in entries {
+    let path = entry.map_err(|err| MigrationFailure::InvalidPatch(dir.display().to_string(), err.to_string()))?.path();
+    if path.is_dir() {
+      collect_patches(&path, patches)?;
+    } else if let Some(patch) = Patch::from_path(path) {
+      patches.push(patch);
+    }
+  }
+  Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,9 @@
+pub use self::apply_migrations::ApplyMigrations;
+pub use self::check_schema_version::CheckSchemaVersion;
+pub use self::command::run_command;
+pub use self::find_patches::find_patches;
+
+mod apply_migrations;
+mod check_schema_version;
+mod command;
+mod find_patches;
\ No newline at end of file
@@ -1,4 +1,5 @@
 pub mod tooltip;
 pub mod armory;
 pub mod account;
-pub mod data;
\ No newline at end of file
+pub mod data;
+pub mod migration;
\ No newline at end of file
@@ -1,5 +1,6 @@
 #[derive(Debug, Clone, Serialize, JsonSchema)]
 pub struct SetEffect {
   pub threshold: u8,
+  pub active: bool,
   pub description: String,
 }
\ No newline at end of file
@@ -1,6 +1,5 @@
-use mysql_connection::tools::Execute;
-
 use crate::modules::armory::Armory;
+use crate::modules::armory::repository::InMemoryArmoryBackend;
 use crate::modules::armory::dto::{CharacterDto, CharacterGearDto, CharacterGuildDto, CharacterHistoryDto, CharacterInfoDto, CharacterItemDto, GuildDto};
 use crate::modules::armory::tools::SetCharacter;
 use crate::modules::data::Data;
@@ -11,8 +10,8 @@ use crate::modules::tooltip::Tooltip;
 #[test]
 fn character_tooltip() {
   let tooltip = Tooltip::default().init();
-  let data = Data::default().init(None);
-  let armory = Armory::default();
+  let data = Data::default().init(None).unwrap();
+  let armory = Armory::with_backend(Box::new(InMemoryArmoryBackend::default()));
 
   let character_info_dto = CharacterInfoDto {
     gear: CharacterGearDto {
@@ -89,12 +88,4 @@ fn character_tooltip() {
   assert_eq!(tooltip.faction, false);
   assert_eq!(tooltip.hero_class_id, character_info_dto.hero_class_id);
   assert_eq!(tooltip.expansion_id, server.expansion_id);
-
-  let character_history = character.last_update.unwrap();
-  armory.db_main.execute_wparams("DELETE FROM armory_item WHERE id=:id", params!("id" => character_history.character_info.gear.main_hand.unwrap().id));
-  armory.db_main.execute_wparams("DELETE FROM armory_gear WHERE id=:id", params!("id" => character_history.character_info.gear.id));
-  armory.db_main.execute_wparams("DELETE FROM armory_character_info WHERE id=:id", params!("id" => character_history.character_info.id));
-  armory.db_main.execute_wparams("DELETE FROM armory_character_history WHERE id=:id", params!("id" => character_history.id));
-  armory.db_main.execute_wparams("DELETE FROM armory_character WHERE id=:id", params!("id" => character.id));
-  armory.db_main.execute_wparams("DELETE FROM armory_guild WHERE id=:id", params!("id" => character_history.character_guild.unwrap().guild_id));
 }
\ No newline at end of file
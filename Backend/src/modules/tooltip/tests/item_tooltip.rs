@@ -1,6 +1,5 @@
-use mysql_connection::tools::Execute;
-
 use crate::modules::armory::Armory;
+use crate::modules::armory::repository::InMemoryArmoryBackend;
 use crate::modules::armory::dto::{CharacterDto, CharacterGearDto, CharacterHistoryDto, CharacterInfoDto, CharacterItemDto};
 use crate::modules::armory::tools::SetCharacter;
 use crate::modules::data::Data;
@@ -12,7 +11,7 @@ use crate::modules::tooltip::Tooltip;
 #[test]
 fn avenger_breastplate() {
   let tooltip = Tooltip::default().init();
-  let data = Data::default().init(None);
+  let data = Data::default().init(None).unwrap();
 
   let result = tooltip.get_item(&data, 1, 1, 21389);
   assert!(result.is_ok());
@@ -107,7 +106,7 @@ fn avenger_breastplate() {
 #[test]
 fn shadowmourne_socket() {
   let tooltip = Tooltip::default().init();
-  let data = Data::default().init(None);
+  let data = Data::default().init(None).unwrap();
 
   let result = tooltip.get_item(&data, 1, 3, 49623);
   assert!(result.is_ok());
@@ -122,7 +121,7 @@ fn shadowmourne_socket() {
 #[test]
 fn thunderfury_weapon_stat() {
   let tooltip = Tooltip::default().init();
-  let data = Data::default().init(None);
+  let data = Data::default().init(None).unwrap();
 
   let result = tooltip.get_item(&data, 1, 1, 19019);
   assert!(result.is_ok());
@@ -142,8 +141,8 @@ fn thunderfury_weapon_stat() {
 #[test]
 fn shadowmourne_socketed_and_enchanted() {
   let tooltip = Tooltip::default().init();
-  let data = Data::default().init(None);
-  let armory = Armory::default();
+  let data = Data::default().init(None).unwrap();
+  let armory = Armory::with_backend(Box::new(InMemoryArmoryBackend::default()));
 
   let character_info_dto = CharacterInfoDto {
     gear: CharacterGearDto {
@@ -211,11 +210,4 @@ fn shadowmourne_socketed_and_enchanted() {
   assert_eq!(item_tooltip.socket.as_ref().unwrap().slots[1].item.as_ref().unwrap().effect, "+7 Spell Power");
   assert!(item_tooltip.socket.as_ref().unwrap().slots[2].item.is_some());
   assert_eq!(item_tooltip.socket.as_ref().unwrap().slots[2].item.as_ref().unwrap().effect, "+7 Spell Power");
-
-  let character_history = character.last_update.unwrap();
-  armory.db_main.execute_wparams("DELETE FROM armory_item WHERE id=:id", params!("id" => character_history.character_info.gear.main_hand.unwrap().id));
-  armory.db_main.execute_wparams("DELETE FROM armory_gear WHERE id=:id", params!("id" => character_history.character_info.gear.id));
-  armory.db_main.execute_wparams("DELETE FROM armory_character_info WHERE id=:id", params!("id" => character_history.character_info.id));
-  armory.db_main.execute_wparams("DELETE FROM armory_character_history WHERE id=:id", params!("id" => character_history.id));
-  armory.db_main.execute_wparams("DELETE FROM armory_character WHERE id=:id", params!("id" => character_history.character_id));
 }
\ No newline at end of file
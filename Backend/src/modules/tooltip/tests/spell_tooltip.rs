@@ -5,7 +5,7 @@ use crate::modules::tooltip::Tooltip;
 #[test]
 fn thunderfury_effect() {
     let tooltip = Tooltip::default().init();
-    let data = Data::default().init(None);
+    let data = Data::default().init(None).unwrap();
 
     let result = tooltip.get_spell(&data, 1, 1, 21992);
     assert!(result.is_ok());
@@ -75,7 +75,7 @@ fn add_item(data: &Data, expansion_id: u8, language_id: u8, vec: &mut Vec<Charac
   if let Some(character_item) = item {
     let item_template = data.get_item(expansion_id, character_item.item_id).unwrap();
     vec.push(CharacterTooltipItem {
-      name: data.get_localization(language_id, item_template.localization_id).unwrap().content,
+      name: data.localize(language_id, item_template.localization_id),
       quality: item_template.quality
     });
   }
@@ -16,12 +16,7 @@ impl RetrieveGuildTooltip for Tooltip {
         }
         let guild = guild.unwrap();
 
-        let characters = armory.characters.read().unwrap();
-        let num_member = characters.iter()
-            .filter(|(_, character)| character.last_update.is_some())
-            .filter(|(_, character)| character.last_update.as_ref().unwrap().character_guild.is_some())
-            .filter(|(_, character)| character.last_update.as_ref().unwrap().character_guild.as_ref().unwrap().guild_id == guild_id)
-            .count();
+        let num_member = armory.character_search_index.read().unwrap().guild_members(guild_id).len();
 
         Ok(GuildTooltip {
             guild_id,
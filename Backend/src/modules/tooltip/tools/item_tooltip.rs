@@ -2,6 +2,7 @@ use crate::modules::armory::Armory;
 use crate::modules::armory::domain_value::CharacterItem;
 use crate::modules::armory::tools::GetCharacterHistory;
 use crate::modules::data::Data;
+use crate::modules::data::domain_value::SpellCaster;
 use crate::modules::data::tools::{RetrieveEnchant, RetrieveGem, RetrieveIcon, RetrieveItem, RetrieveItemBonding, RetrieveItemClass, RetrieveItemDamage, RetrieveItemDamageType, RetrieveItemEffect, RetrieveItemInventoryType, RetrieveItemsetEffect, RetrieveItemsetName, RetrieveItemSheath, RetrieveItemSocket, RetrieveItemStat, RetrieveLocalization, RetrieveStatType, SpellDescription, RetrieveItemRandomProperty, RetrieveItemRandomPropertyPoints};
 use crate::modules::tooltip::domain_value::{ItemSet, SetEffect, SocketSlot, SocketSlotItem, Stat, WeaponDamage, WeaponStat};
 use crate::modules::tooltip::dto::TooltipFailure;
@@ -16,102 +17,7 @@ pub trait RetrieveItemTooltip {
 
 impl RetrieveItemTooltip for Tooltip {
   fn get_item(&self, data: &Data, language_id: u8, expansion_id: u8, item_id: u32) -> Result<ItemTooltip, TooltipFailure> {
-    let item_stat_effects = [7,8,37,23,24,10,11,12,42,38,39,40,41,9,13,21,43,22];
-    let item_res = data.get_item(expansion_id, item_id);
-    if item_res.is_none() {
-      return Err(TooltipFailure::InvalidInput);
-    }
-    let item = item_res.unwrap();
-
-    let mut weapon_stat = None;
-    if item.delay.is_some() {
-      weapon_stat = Some(WeaponStat {
-        delay: item.delay.unwrap(),
-        damage_sources: data.get_item_damage(expansion_id, item_id).unwrap().iter().map(|item_damage| WeaponDamage {
-          damage_min: item_damage.dmg_min,
-          damage_max: item_damage.dmg_max,
-          damage_type: item_damage.dmg_type.and_then(|damage_type_id| data.get_item_damage_type(damage_type_id).and_then(|item_damage_type| data.get_localization(language_id, item_damage_type.localization_id).and_then(|localization| Some(localization.content)))),
-        }).collect(),
-      });
-    }
-
-    let socket = data.get_item_socket(expansion_id, item_id)
-      .and_then(|item_socket| Some(Socket {
-        socket_bonus: data.get_enchant(expansion_id, item_socket.bonus).and_then(|enchant| data.get_localization(language_id, enchant.localization_id).and_then(|localization| Some(localization.content))).unwrap(),
-        slots: item_socket.slots.iter().map(|slot_flag| SocketSlot {
-          flag: slot_flag.to_owned(),
-          item: None,
-        }).collect(),
-      }));
-
-    let item_stats = data.get_item_stats(expansion_id, item_id);
-    let stats: Option<Vec<Stat>> = item_stats.as_ref()
-      .and_then(|inner_item_stats| Some(inner_item_stats.iter().filter(|stat| stat.stat.stat_type != 34 && !item_stat_effects.contains(&stat.stat.stat_type)).map(|item_stat| Stat {
-        value: item_stat.stat.stat_value,
-        name: data.get_stat_type(item_stat.stat.stat_type).and_then(|stat_type| data.get_localization(language_id, stat_type.localization_id).and_then(|localization| Some(localization.content))).unwrap(),
-      }).collect()));
-
-    let armor = item_stats.as_ref().and_then(|inner_item_stats| inner_item_stats.iter().find(|item_stat| item_stat.stat.stat_type == 34).and_then(|armor| Some(armor.stat.stat_value)));
-
-    let mut item_set = item.itemset.and_then(|itemset_id| data.get_itemset_name(expansion_id, itemset_id).and_then(|itemset_name| Some(ItemSet {
-      name: data.get_localization(language_id, itemset_name.localization_id).and_then(|localization| Some(localization.content)).unwrap(),
-      set_items: data.get_itemset_item_ids(expansion_id, itemset_id).unwrap().iter().map(|item_id| {
-        let item = data.get_item(expansion_id, *item_id).unwrap();
-        SetItem {
-          item_id: item_id.clone(),
-          active: false,
-          item_level: item.item_level.unwrap(),
-          inventory_type: item.inventory_type.unwrap(),
-          name: data.get_localization(language_id, item.localization_id).and_then(|localization| Some(localization.content)).unwrap(),
-        }
-      }).collect(),
-      set_effects: data.get_itemset_effects(expansion_id, itemset_id).unwrap().iter().map(|itemset_effect| SetEffect {
-        threshold: itemset_effect.threshold,
-        description: data.get_localized_spell_description(expansion_id, language_id, itemset_effect.spell_id).unwrap(),
-      }).collect::<Vec<SetEffect>>(),
-    })));
-
-    if item_set.is_some() {
-      item_set.as_mut().unwrap().set_effects.sort_by(|left, right| left.threshold.cmp(&right.threshold));
-    }
-
-    let mut item_effects = data.get_item_effect(expansion_id, item_id)
-      .and_then(|inner_item_effects| Some(inner_item_effects
-        .iter().map(|item_effect| data.get_localized_spell_description(expansion_id, language_id, item_effect.spell_id).unwrap()).collect()));
-
-    if item_stats.is_some() {
-      let inner_item_stats = item_stats.as_ref().unwrap();
-      inner_item_stats.iter().filter(|item_stat| item_stat_effects.contains(&item_stat.stat.stat_type))
-        .for_each(|item_stat| {
-          if item_effects.is_none() {
-            item_effects = Some(Vec::new());
-          }
-
-          item_effects.as_mut().unwrap().insert(0, get_item_stat_effect_localization(data, language_id, item_stat.stat.stat_type)
-            .replace("$s1", &item_stat.stat.stat_value.to_string()))
-        });
-    }
-
-    Ok(ItemTooltip {
-      item_id,
-      name: data.get_localization(language_id, item.localization_id).unwrap().content,
-      icon: data.get_icon(item.icon).unwrap().name,
-      quality: item.quality,
-      required_level: item.required_level,
-      item_level: item.item_level,
-      durability: item.max_durability,
-      bonding: item.bonding.and_then(|bonding_type| data.get_item_bonding(bonding_type).and_then(|item_bonding| data.get_localization(language_id, item_bonding.localization_id).and_then(|localization| Some(localization.content)))),
-      inventory_type: item.inventory_type.and_then(|inventory_type_id| data.get_item_inventory_type(inventory_type_id).and_then(|inventory_type| data.get_localization(language_id, inventory_type.localization_id).and_then(|localization| Some(localization.content)))),
-      sheath_type: item.sheath.and_then(|sheath_id| data.get_item_sheath(sheath_id).and_then(|item_sheath| data.get_localization(language_id, item_sheath.localization_id).and_then(|localization| Some(localization.content)))),
-      sub_class: data.get_item_class(item.class_id).and_then(|item_class| data.get_localization(language_id, item_class.localization_id).and_then(|localization| Some(localization.content))).unwrap(),
-      enchant: None,
-      weapon_stat,
-      socket,
-      item_effects,
-      stats,
-      armor,
-      item_set,
-    })
+    get_item_for(data, language_id, expansion_id, item_id, &SpellCaster::default())
   }
 
   fn get_character_item(&self, data: &Data, armory: &Armory, language_id: u8, item_id: u32, character_history_id: u32) -> Result<ItemTooltip, TooltipFailure> {
@@ -121,10 +27,11 @@ impl RetrieveItemTooltip for Tooltip {
     }
     let character_history = character_history_res.unwrap();
     let character = self.get_character(data, armory, language_id, character_history.character_id).unwrap();
+    let caster = SpellCaster { is_female: character_history.character_info.gender, ..SpellCaster::default() };
     let character_gear = character_history.character_info.gear;
     let expansion_id = character.expansion_id;
 
-    let item_tooltip_res = self.get_item(data, language_id, expansion_id, item_id);
+    let item_tooltip_res = get_item_for(data, language_id, expansion_id, item_id, &caster);
     if item_tooltip_res.is_err() {
       return Err(item_tooltip_res.err().unwrap());
     }
@@ -152,6 +59,13 @@ impl RetrieveItemTooltip for Tooltip {
       check_is_active(item_tooltip.item_set.as_mut().unwrap().set_items.as_mut(), &character_gear.off_hand);
       check_is_active(item_tooltip.item_set.as_mut().unwrap().set_items.as_mut(), &character_gear.ternary_hand);
 
+      // A set bonus is active once enough pieces of the set are equipped
+      let item_set = item_tooltip.item_set.as_mut().unwrap();
+      let num_active_items = item_set.set_items.iter().filter(|set_item| set_item.active).count();
+      for set_effect in item_set.set_effects.iter_mut() {
+        set_effect.active = set_effect.threshold as usize <= num_active_items;
+      }
+
       // For PvP sets all seasons count, but it should always only be one of each category be shown.
       // Gray items are shown of none of the type is equipped of another or same season
       // The gray item shown is equivalent ot the item name
@@ -229,74 +143,175 @@ impl RetrieveItemTooltip for Tooltip {
   }
 }
 
+// Spell descriptions of the item are worded for the given caster
+fn get_item_for(data: &Data, language_id: u8, expansion_id: u8, item_id: u32, caster: &SpellCaster) -> Result<ItemTooltip, TooltipFailure> {
+  let item_stat_effects = [7,8,37,23,24,10,11,12,42,38,39,40,41,9,13,21,43,22];
+  let item_res = data.get_item(expansion_id, item_id);
+  if item_res.is_none() {
+    return Err(TooltipFailure::InvalidInput);
+  }
+  let item = item_res.unwrap();
+
+  let mut weapon_stat = None;
+  if item.delay.is_some() {
+    weapon_stat = Some(WeaponStat {
+      delay: item.delay.unwrap(),
+      damage_sources: data.get_item_damage(expansion_id, item_id).unwrap().iter().map(|item_damage| WeaponDamage {
+        damage_min: item_damage.dmg_min,
+        damage_max: item_damage.dmg_max,
+        damage_type: item_damage.dmg_type.and_then(|damage_type_id| data.get_item_damage_type(damage_type_id).and_then(|item_damage_type| Some(data.localize(language_id, item_damage_type.localization_id)))),
+      }).collect(),
+    });
+  }
+
+  let socket = data.get_item_socket(expansion_id, item_id)
+    .and_then(|item_socket| Some(Socket {
+      socket_bonus: data.get_enchant(expansion_id, item_socket.bonus).and_then(|enchant| Some(data.localize(language_id, enchant.localization_id))).unwrap(),
+      slots: item_socket.slots.iter().map(|slot_flag| SocketSlot {
+        flag: slot_flag.to_owned(),
+        item: None,
+      }).collect(),
+    }));
+
+  let item_stats = data.get_item_stats(expansion_id, item_id);
+  let stats: Option<Vec<Stat>> = item_stats.as_ref()
+    .and_then(|inner_item_stats| Some(inner_item_stats.iter().filter(|stat| stat.stat.stat_type != 34 && !item_stat_effects.contains(&stat.stat.stat_type)).map(|item_stat| Stat {
+      value: item_stat.stat.stat_value,
+      name: data.get_stat_type(item_stat.stat.stat_type).and_then(|stat_type| Some(data.localize(language_id, stat_type.localization_id))).unwrap(),
+    }).collect()));
+
+  let armor = item_stats.as_ref().and_then(|inner_item_stats| inner_item_stats.iter().find(|item_stat| item_stat.stat.stat_type == 34).and_then(|armor| Some(armor.stat.stat_value)));
+
+  let mut item_set = item.itemset.and_then(|itemset_id| data.get_itemset_name(expansion_id, itemset_id).and_then(|itemset_name| Some(ItemSet {
+    name: data.localize(language_id, itemset_name.localization_id),
+    set_items: data.get_itemset_item_ids(expansion_id, itemset_id).unwrap().iter().map(|item_id| {
+      let item = data.get_item(expansion_id, *item_id).unwrap();
+      SetItem {
+        item_id: item_id.clone(),
+        active: false,
+        item_level: item.item_level.unwrap(),
+        inventory_type: item.inventory_type.unwrap(),
+        name: data.localize(language_id, item.localization_id),
+      }
+    }).collect(),
+    set_effects: data.get_itemset_effects(expansion_id, itemset_id).unwrap().iter().map(|itemset_effect| SetEffect {
+      threshold: itemset_effect.threshold,
+      active: false,
+      description: data.get_localized_spell_description_for(expansion_id, language_id, itemset_effect.spell_id, caster).unwrap(),
+    }).collect::<Vec<SetEffect>>(),
+  })));
+
+  if item_set.is_some() {
+    item_set.as_mut().unwrap().set_effects.sort_by(|left, right| left.threshold.cmp(&right.threshold));
+  }
+
+  let mut item_effects = data.get_item_effect(expansion_id, item_id)
+    .and_then(|inner_item_effects| Some(inner_item_effects
+      .iter().map(|item_effect| data.get_localized_spell_description_for(expansion_id, language_id, item_effect.spell_id, caster).unwrap()).collect()));
+
+  if item_stats.is_some() {
+    let inner_item_stats = item_stats.as_ref().unwrap();
+    inner_item_stats.iter().filter(|item_stat| item_stat_effects.contains(&item_stat.stat.stat_type))
+      .for_each(|item_stat| {
+        if item_effects.is_none() {
+          item_effects = Some(Vec::new());
+        }
+
+        item_effects.as_mut().unwrap().insert(0, get_item_stat_effect_localization(data, language_id, item_stat.stat.stat_type)
+          .replace("$s1", &item_stat.stat.stat_value.to_string()))
+      });
+  }
+
+  Ok(ItemTooltip {
+    item_id,
+    name: data.localize(language_id, item.localization_id),
+    icon: data.get_icon(item.icon).unwrap().name,
+    quality: item.quality,
+    required_level: item.required_level,
+    item_level: item.item_level,
+    durability: item.max_durability,
+    bonding: item.bonding.and_then(|bonding_type| data.get_item_bonding(bonding_type).and_then(|item_bonding| Some(data.localize(language_id, item_bonding.localization_id)))),
+    inventory_type: item.inventory_type.and_then(|inventory_type_id| data.get_item_inventory_type(inventory_type_id).and_then(|inventory_type| Some(data.localize(language_id, inventory_type.localization_id)))),
+    sheath_type: item.sheath.and_then(|sheath_id| data.get_item_sheath(sheath_id).and_then(|item_sheath| Some(data.localize(language_id, item_sheath.localization_id)))),
+    sub_class: data.get_item_class(item.class_id).and_then(|item_class| Some(data.localize(language_id, item_class.localization_id))).unwrap(),
+    enchant: None,
+    weapon_stat,
+    socket,
+    item_effects,
+    stats,
+    armor,
+    item_set,
+  })
+}
+
 fn get_item_stat_effect_localization(data: &Data, language_id: u8, stat_type: u8) -> String {
   // Hit rating
   if stat_type == 7 {
-    return data.get_localization(language_id, 94916).unwrap().content.to_owned();
+    return data.localize(language_id, 94916);
   }
   // Critical strike rating
   if stat_type == 8 {
-    return data.get_localization(language_id, 94896).unwrap().content.to_owned();
+    return data.localize(language_id, 94896);
   }
   // Attack power
   if stat_type == 9 {
-    return data.get_localization(language_id, 72837).unwrap().content.to_owned();
+    return data.localize(language_id, 72837);
   }
   // Dodge rating
   if stat_type == 10 {
-    return data.get_localization(language_id, 94902).unwrap().content.to_owned();
+    return data.localize(language_id, 94902);
   }
   // Parry rating
   if stat_type == 11 {
-    return data.get_localization(language_id, 94929).unwrap().content.to_owned();
+    return data.localize(language_id, 94929);
   }
   // Block rating
   if stat_type == 12 {
-    return data.get_localization(language_id, 119756).unwrap().content.to_owned();
+    return data.localize(language_id, 119756);
   }
   // Mana regeneration
   if stat_type == 21 {
-    return data.get_localization(language_id, 74829).unwrap().content.to_owned();
+    return data.localize(language_id, 74829);
   }
   // Defense rating
   if stat_type == 22 {
-    return data.get_localization(language_id, 94562).unwrap().content.to_owned();
+    return data.localize(language_id, 94562);
   }
   // Spell hit rating
   if stat_type == 23 {
-    return data.get_localization(language_id, 119761).unwrap().content.to_owned();
+    return data.localize(language_id, 119761);
   }
   // Spell critical strike rating
   if stat_type == 24 {
-    return data.get_localization(language_id, 119759).unwrap().content.to_owned();
+    return data.localize(language_id, 119759);
   }
   // Haste rating
   if stat_type == 37 {
-    return data.get_localization(language_id, 119758).unwrap().content.to_owned();
+    return data.localize(language_id, 119758);
   }
   // Expertise rating
   if stat_type == 38 {
-    return data.get_localization(language_id, 119757).unwrap().content.to_owned();
+    return data.localize(language_id, 119757);
   }
   // Resilience rating
   if stat_type == 39 {
-    return data.get_localization(language_id, 119762).unwrap().content.to_owned();
+    return data.localize(language_id, 119762);
   }
   // Spell penetration rating
   if stat_type == 40 {
-    return data.get_localization(language_id, 119763).unwrap().content.to_owned();
+    return data.localize(language_id, 119763);
   }
   // Armor penetration rating
   if stat_type == 41 {
-    return data.get_localization(language_id, 118332).unwrap().content.to_owned();
+    return data.localize(language_id, 118332);
   }
   // Spell haste rating
   if stat_type == 42 {
-    return data.get_localization(language_id, 119760).unwrap().content.to_owned();
+    return data.localize(language_id, 119760);
   }
   // Health regeneration
   if stat_type == 43 {
-    return data.get_localization(language_id, 74816).unwrap().content.to_owned();
+    return data.localize(language_id, 74816);
   }
   return "!?!".to_owned();
 }
@@ -324,8 +339,7 @@ fn try_apply_enchant(data: &Data, expansion_id: u8, language_id: u8, enchant: &m
     return;
   }
   *enchant = data.get_enchant(expansion_id, item_res.enchant_id.unwrap())
-    .and_then(|enchant| data.get_localization(language_id, enchant.localization_id)
-      .and_then(|localization| Some(localization.content)));
+    .and_then(|enchant| Some(data.localize(language_id, enchant.localization_id)));
 }
 
 fn try_fill_socket(data: &Data, expansion_id: u8, language_id: u8, socket: &mut Socket, item: &Option<CharacterItem>, item_id: u32) {
@@ -354,8 +368,7 @@ fn try_fill_socket(data: &Data, expansion_id: u8, language_id: u8, socket: &mut
     let socket_item = Some(SocketSlotItem {
       icon: data.get_icon(gem_item.icon).unwrap().name,
       effect: data.get_enchant(expansion_id, gem.enchant_id)
-        .and_then(|enchant| data.get_localization(language_id, enchant.localization_id)
-          .and_then(|localization| Some(localization.content))).unwrap(),
+        .and_then(|enchant| Some(data.localize(language_id, enchant.localization_id))).unwrap(),
       flag: gem.flag,
     });
 
@@ -382,7 +395,7 @@ fn try_apply_random_item_property(data: &Data, expansion_id: u8, language_id: u8
 
   let random_property_id = item_res.random_property_id.as_ref().unwrap();
   let item_random_property = data.get_item_random_property(expansion_id, *random_property_id).unwrap();
-  let property_suffix = data.get_localization(language_id, item_random_property.localization_id).unwrap().content;
+  let property_suffix = data.localize(language_id, item_random_property.localization_id);
   let data_item = data.get_item(expansion_id, item_res.item_id).unwrap();
   let property_points = data_item.item_level
     .and_then(|level| data.get_item_random_property_points(expansion_id, level));
@@ -396,7 +409,7 @@ fn try_apply_random_item_property(data: &Data, expansion_id: u8, language_id: u8
   for i in 0..item_random_property.enchant_ids.len() {
     let enchant_id = item_random_property.enchant_ids[i];
     let enchant = data.get_enchant(expansion_id, enchant_id).unwrap();
-    let effect_value = data.get_localization(language_id, enchant.localization_id).unwrap().content;
+    let effect_value = data.localize(language_id, enchant.localization_id);
     if *random_property_id < 0 {
       let coefficient_value = item_random_property.scaling_coefficients[i];
       let scaling_factor;
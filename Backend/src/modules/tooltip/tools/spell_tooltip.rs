@@ -21,14 +21,14 @@ impl RetrieveSpellTooltip for Tooltip {
       spell_cost = Some(SpellCost {
         cost: spell.cost,
         cost_in_percent: spell.cost_in_percent,
-        power_type: data.get_power_type(spell.power_type).and_then(|power_type| data.get_localization(language_id, power_type.localization_id)).unwrap().content,
+        power_type: data.get_power_type(spell.power_type).and_then(|power_type| Some(data.localize(language_id, power_type.localization_id))).unwrap(),
       });
     }
 
     Ok(SpellTooltip {
-      name: data.get_localization(language_id, spell.localization_id).unwrap().content,
+      name: data.localize(language_id, spell.localization_id),
       icon: data.get_icon(spell.icon).unwrap().name,
-      subtext: data.get_localization(language_id, spell.subtext_localization_id).unwrap().content,
+      subtext: data.localize(language_id, spell.subtext_localization_id),
       spell_cost,
       range: spell.range_max,
       description: data.get_localized_spell_description(expansion_id, language_id, spell_id).unwrap(),
@@ -2,7 +2,7 @@ use rocket::State;
 use rocket_contrib::json::Json;
 
 use crate::modules::armory::Armory;
-use crate::modules::data::Data;
+use crate::modules::data::DataStore;
 use crate::modules::tooltip::dto::TooltipFailure;
 use crate::modules::tooltip::material::CharacterTooltip;
 use crate::modules::tooltip::tools::RetrieveCharacterTooltip;
@@ -11,7 +11,8 @@ use crate::modules::data::guard::Language;
 
 #[openapi]
 #[get("/character/<id>")]
-pub fn get_character(me: State<Tooltip>, data: State<Data>, armory: State<Armory>, language: Language, id: u32) -> Result<Json<CharacterTooltip>, TooltipFailure>
+pub fn get_character(me: State<Tooltip>, data: State<DataStore>, armory: State<Armory>, language: Language, id: u32) -> Result<Json<CharacterTooltip>, TooltipFailure>
 {
+  let data = data.snapshot();
   me.get_character(&data, &armory, language.0, id).and_then(|tooltip| Ok(Json(tooltip)))
 }
@@ -1,7 +1,7 @@
 use rocket::State;
 use rocket_contrib::json::Json;
 
-use crate::modules::data::Data;
+use crate::modules::data::DataStore;
 use crate::modules::data::guard::{Expansion, Language};
 use crate::modules::tooltip::dto::TooltipFailure;
 use crate::modules::tooltip::material::SpellTooltip;
@@ -10,7 +10,8 @@ use crate::modules::tooltip::Tooltip;
 
 #[openapi]
 #[get("/spell/<id>")]
-pub fn get_spell(me: State<Tooltip>, data: State<Data>, language: Language, expansion: Expansion, id: u32) -> Result<Json<SpellTooltip>, TooltipFailure>
+pub fn get_spell(me: State<Tooltip>, data: State<DataStore>, language: Language, expansion: Expansion, id: u32) -> Result<Json<SpellTooltip>, TooltipFailure>
 {
+  let data = data.snapshot();
   me.get_spell(&data, language.0, expansion.0, id).and_then(|tooltip| Ok(Json(tooltip)))
 }
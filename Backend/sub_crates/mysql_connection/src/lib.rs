@@ -1,8 +1,9 @@
 extern crate dotenv;
 extern crate mysql;
 
-pub use mysql::params;
+pub use mysql::{params, Row, Value};
 
 pub mod material;
 pub mod tools;
+mod tests;
 
@@ -1,3 +1,7 @@
 pub use self::mysql_connection::MySQLConnection;
+pub use self::mysql_error::MySQLError;
+pub use self::mysql_transaction::MySQLTransaction;
 
-mod mysql_connection;
\ No newline at end of file
+mod mysql_connection;
+mod mysql_error;
+mod mysql_transaction;
\ No newline at end of file
@@ -1,10 +1,20 @@
 extern crate dotenv;
 
 use std::env;
+use std::thread;
+use std::time::Duration;
 
 use dotenv::dotenv;
 
-#[derive(Debug)]
+use crate::material::MySQLError;
+
+// A connection is requested up to this many times before giving up
+const CONNECTION_ATTEMPTS: u32 = 4;
+// Doubled after each failed attempt
+const INITIAL_BACKOFF_MS: u64 = 50;
+
+// Clones share the same pool
+#[derive(Debug, Clone)]
 pub struct MySQLConnection {
   pub con: mysql::Pool
 }
@@ -23,4 +33,26 @@ impl MySQLConnection {
       con: mysql::Pool::new(dns).unwrap()
     }
   }
+
+  pub fn get_connection(&self) -> Result<mysql::PooledConn, MySQLError> {
+    self.with_retry(|| self.con.get_conn())
+  }
+
+  pub(crate) fn with_retry<T>(&self, acquire: impl Fn() -> mysql::Result<T>) -> Result<T, MySQLError> {
+    let mut backoff = INITIAL_BACKOFF_MS;
+    let mut attempt = 1;
+    loop {
+      match acquire() {
+        Ok(value) => return Ok(value),
+        Err(err) => {
+          if attempt >= CONNECTION_ATTEMPTS {
+            return Err(MySQLError::Connection(err.to_string()));
+          }
+          thread::sleep(Duration::from_millis(backoff));
+          backoff *= 2;
+          attempt += 1;
+        }
+      }
+    }
+  }
 }
\ No newline at end of file
@@ -0,0 +1,70 @@
+use mysql;
+use mysql::prelude::Queryable;
+
+use crate::material::MySQLError;
+
+// Everything executed on this transaction is rolled back when it is dropped without calling commit
+pub struct MySQLTransaction {
+  tx: mysql::Transaction<'static>
+}
+
+impl MySQLTransaction {
+  pub(crate) fn new(tx: mysql::Transaction<'static>) -> Self {
+    MySQLTransaction { tx }
+  }
+
+  pub fn execute(&mut self, query_str: &str) -> Result<(), MySQLError>
+  {
+    self.tx.exec_drop(query_str, ()).map_err(|err| MySQLError::Query { query: query_str.to_owned(), reason: err.to_string() })
+  }
+
+  pub fn execute_wparams(&mut self, query_str: &str, params: std::vec::Vec<(std::string::String, mysql::Value)>) -> Result<(), MySQLError>
+  {
+    self.tx.exec_drop(query_str, params).map_err(|err| MySQLError::Query { query: query_str.to_owned(), reason: err.to_string() })
+  }
+
+  pub fn select_wparams<T>(&mut self, query_str: &str, process_row: &dyn Fn(mysql::Row) -> T, params: std::vec::Vec<(std::string::String, mysql::Value)>) -> Result<Vec<T>, MySQLError>
+  {
+    self.tx.exec_map(query_str, params, process_row).map_err(|err| MySQLError::Query { query: query_str.to_owned(), reason: err.to_string() })
+  }
+
+  pub fn select_wparams_value<T>(&mut self, query_str: &str, process_row: &dyn Fn(mysql::Row) -> T, params: std::vec::Vec<(std::string::String, mysql::Value)>) -> Result<Option<T>, MySQLError>
+  {
+    self.select_wparams(query_str, process_row, params).map(|mut values| values.pop())
+  }
+
+  // Like select_wparams, but a row that fails to map is returned instead of being dropped
+  pub fn try_select_wparams<T>(&mut self, query_str: &str, process_row: &dyn Fn(mysql::Row) -> Result<T, MySQLError>, params: std::vec::Vec<(std::string::String, mysql::Value)>) -> Result<Vec<T>, MySQLError>
+  {
+    self.select_wparams(query_str, process_row, params)?
+      .into_iter()
+      .collect::<Result<Vec<T>, MySQLError>>()
+      .map_err(|err| err.with_query(query_str))
+  }
+
+  pub fn try_select_wparams_value<T>(&mut self, query_str: &str, process_row: &dyn Fn(mysql::Row) -> Result<T, MySQLError>, params: std::vec::Vec<(std::string::String, mysql::Value)>) -> Result<Option<T>, MySQLError>
+  {
+    self.try_select_wparams(query_str, process_row, params).map(|mut values| values.pop())
+  }
+
+  // Id generated by the last INSERT, or the one assigned through LAST_INSERT_ID(expr)
+  pub fn last_insert_id(&self) -> Option<u64>
+  {
+    self.tx.last_insert_id()
+  }
+
+  pub fn affected_rows(&self) -> u64
+  {
+    self.tx.affected_rows()
+  }
+
+  pub fn commit(self) -> Result<(), MySQLError>
+  {
+    self.tx.commit().map_err(|err| MySQLError::Transaction(err.to_string()))
+  }
+
+  pub fn rollback(self) -> Result<(), MySQLError>
+  {
+    self.tx.rollback().map_err(|err| MySQLError::Transaction(err.to_string()))
+  }
+}
\ No newline at end of file
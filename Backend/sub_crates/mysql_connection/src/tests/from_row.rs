@@ -0,0 +1,23 @@
+#[cfg(test)]
+mod tests {
+  use crate::material::MySQLError;
+  use crate::tools::{FromRow, select_columns};
+
+  struct Item;
+
+  impl FromRow for Item {
+    fn columns() -> Vec<&'static str> {
+      vec!["id", "item_id"]
+    }
+
+    fn from_row(_row: &mut mysql::Row, _prefix: &str) -> Result<Self, MySQLError> {
+      Ok(Item)
+    }
+  }
+
+  #[test]
+  fn select_columns_are_aliased_with_prefix() {
+    assert_eq!(select_columns::<Item>("ai1", "head_"), "ai1.`id` AS `head_id`, ai1.`item_id` AS `head_item_id`");
+    assert_eq!(select_columns::<Item>("ai", ""), "ai.`id` AS `id`, ai.`item_id` AS `item_id`");
+  }
+}
\ No newline at end of file
@@ -0,0 +1,2 @@
+pub mod from_row;
+pub mod mysql_error;
\ No newline at end of file
@@ -0,0 +1,22 @@
+#[cfg(test)]
+mod tests {
+  use crate::material::MySQLError;
+
+  #[test]
+  fn column_error_receives_query() {
+    let err = MySQLError::column("item_id", "NULL").with_query("SELECT * FROM armory_item");
+    assert_eq!(err, MySQLError::Column {
+      query: "SELECT * FROM armory_item".to_owned(),
+      column: "item_id".to_owned(),
+      reason: "NULL".to_owned(),
+    });
+    assert!(err.to_string().contains("item_id"));
+    assert!(err.to_string().contains("SELECT * FROM armory_item"));
+  }
+
+  #[test]
+  fn connection_error_keeps_its_reason() {
+    let err = MySQLError::Connection("timeout".to_owned()).with_query("SELECT 1");
+    assert_eq!(err, MySQLError::Connection("timeout".to_owned()));
+  }
+}
\ No newline at end of file
@@ -1,21 +1,34 @@
 use mysql;
 
-use crate::material::MySQLConnection;
+use crate::material::{MySQLConnection, MySQLError};
 use mysql::prelude::Queryable;
 
 pub trait Execute {
   fn execute(&self, query_str: &str) -> bool;
   fn execute_wparams(&self, query_str: &str, params: std::vec::Vec<(std::string::String, mysql::Value)>) -> bool;
+  // Runs several ';' separated statements, e.g. the content of a database patch
+  fn execute_script(&self, script: &str) -> Result<(), MySQLError>;
 }
 
 impl Execute for MySQLConnection {
   fn execute(&self, query_str: &str) -> bool
   {
-    self.con.get_conn().unwrap().exec_drop(query_str, ()).is_ok()
+    self.get_connection()
+      .map(|mut conn| conn.exec_drop(query_str, ()).is_ok())
+      .unwrap_or(false)
   }
 
   fn execute_wparams(&self, query_str: &str, params: std::vec::Vec<(std::string::String, mysql::Value)>) -> bool
   {
-    self.con.get_conn().unwrap().exec_drop(query_str, params).is_ok()
+    self.get_connection()
+      .map(|mut conn| conn.exec_drop(query_str, params).is_ok())
+      .unwrap_or(false)
+  }
+
+  fn execute_script(&self, script: &str) -> Result<(), MySQLError>
+  {
+    self.get_connection()?
+      .query_drop(script)
+      .map_err(|err| MySQLError::Query { query: script.chars().take(256).collect(), reason: err.to_string() })
   }
 }
\ No newline at end of file
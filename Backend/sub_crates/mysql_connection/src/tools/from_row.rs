@@ -0,0 +1,45 @@
+use mysql;
+
+use crate::material::MySQLError;
+use crate::tools::TakeColumn;
+
+// Decodes a struct by column name. Nested structs of joined tables are decoded with an extended prefix,
+// e.g. the column "head_item_id" is the column "item_id" of the struct decoded with prefix "head_".
+pub trait FromRow: Sized {
+  // The columns of the own table, without prefix
+  fn columns() -> Vec<&'static str>;
+  fn from_row(row: &mut mysql::Row, prefix: &str) -> Result<Self, MySQLError>;
+
+  // A LEFT JOIN without match yields NULL in every column of the joined table
+  fn from_row_opt(row: &mut mysql::Row, prefix: &str, key_column: &str) -> Result<Option<Self>, MySQLError> {
+    if row.is_null(&format!("{}{}", prefix, key_column))? {
+      return Ok(None);
+    }
+    Self::from_row(row, prefix).map(Some)
+  }
+}
+
+// Select list of the own columns of T, e.g. "ai1.id AS head_id, ai1.item_id AS head_item_id"
+pub fn select_columns<T: FromRow>(table_alias: &str, prefix: &str) -> String {
+  T::columns().iter()
+    .map(|column| format!("{}.`{}` AS `{}{}`", table_alias, column, prefix, column))
+    .collect::<Vec<String>>()
+    .join(", ")
+}
+
+// Implements FromRow from a single column list, each column is bound to a variable of the same name for the build expression
+#[macro_export]
+macro_rules! impl_from_row {
+  ($type:ty, |$row:ident, $prefix:ident| [$($column:ident),*] => $build:expr) => {
+    impl $crate::tools::FromRow for $type {
+      fn columns() -> Vec<&'static str> {
+        vec![$(stringify!($column)),*]
+      }
+
+      fn from_row($row: &mut $crate::Row, $prefix: &str) -> Result<Self, $crate::material::MySQLError> {
+        $(let $column = <$crate::Row as $crate::tools::TakeColumn>::take_named($row, &format!("{}{}", $prefix, stringify!($column)))?;)*
+        Ok($build)
+      }
+    }
+  };
+}
\ No newline at end of file
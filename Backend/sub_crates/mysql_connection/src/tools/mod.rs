@@ -1,7 +1,13 @@
 pub use self::execute::Execute;
 pub use self::exists::Exists;
+pub use self::from_row::{FromRow, select_columns};
 pub use self::select::Select;
+pub use self::take_column::TakeColumn;
+pub use self::transaction::StartTransaction;
 
 mod execute;
 mod exists;
-mod select;
\ No newline at end of file
+mod from_row;
+mod select;
+mod take_column;
+mod transaction;
\ No newline at end of file
@@ -0,0 +1,49 @@
+use mysql;
+use mysql::prelude::FromValue;
+
+use crate::material::MySQLError;
+
+pub trait TakeColumn {
+  fn take_column<T: FromValue>(&mut self, index: usize) -> Result<T, MySQLError>;
+  fn take_named<T: FromValue>(&mut self, column: &str) -> Result<T, MySQLError>;
+  fn is_null(&self, column: &str) -> Result<bool, MySQLError>;
+}
+
+impl TakeColumn for mysql::Row {
+  // Nullable columns are read as Option<T>
+  fn take_column<T: FromValue>(&mut self, index: usize) -> Result<T, MySQLError>
+  {
+    let column = self.columns_ref().get(index)
+      .map(|column| column.name_str().into_owned())
+      .unwrap_or_else(|| index.to_string());
+    match self.take_opt(index) {
+      Some(Ok(value)) => Ok(value),
+      Some(Err(err)) => Err(MySQLError::column(&column, &err.to_string())),
+      None => Err(MySQLError::column(&column, "Column does not exist or has already been taken"))
+    }
+  }
+
+  fn take_named<T: FromValue>(&mut self, column: &str) -> Result<T, MySQLError>
+  {
+    let index = column_index(self, column)?;
+    self.take_column(index)
+  }
+
+  fn is_null(&self, column: &str) -> Result<bool, MySQLError>
+  {
+    let index = column_index(self, column)?;
+    Ok(self.as_ref(index).map(|value| value == &mysql::Value::NULL).unwrap_or(true))
+  }
+}
+
+// Joined tables share column names, a name that is not unique has to be aliased in the query
+fn column_index(row: &mysql::Row, column: &str) -> Result<usize, MySQLError> {
+  let mut indices = row.columns_ref().iter().enumerate()
+    .filter(|(_, row_column)| row_column.name_str() == column)
+    .map(|(index, _)| index);
+  match (indices.next(), indices.next()) {
+    (Some(index), None) => Ok(index),
+    (None, _) => Err(MySQLError::column(column, "Column is not part of the result")),
+    (Some(_), Some(_)) => Err(MySQLError::column(column, "Column name is ambiguous"))
+  }
+}
\ No newline at end of file
@@ -0,0 +1,15 @@
+use mysql;
+
+use crate::material::{MySQLConnection, MySQLError, MySQLTransaction};
+
+pub trait StartTransaction {
+  fn start_transaction(&self) -> Result<MySQLTransaction, MySQLError>;
+}
+
+impl StartTransaction for MySQLConnection {
+  fn start_transaction(&self) -> Result<MySQLTransaction, MySQLError>
+  {
+    self.with_retry(|| self.con.start_transaction(mysql::TxOpts::default()))
+      .map(MySQLTransaction::new)
+  }
+}
\ No newline at end of file
@@ -4,6 +4,7 @@ extern crate rand_distr;
 extern crate sha3 as sha;
 
 pub use self::tools::bcrypt;
+pub use self::tools::password;
 pub use self::tools::random;
 pub use self::tools::sha3;
 pub use self::tools::strformat;
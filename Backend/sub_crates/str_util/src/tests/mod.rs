@@ -1,3 +1,4 @@
 pub mod hash;
+pub mod password;
 pub mod random;
 pub mod strformat;
\ No newline at end of file
@@ -0,0 +1,32 @@
+#[cfg(test)]
+mod tests {
+  use crate::{password, sha3};
+
+  #[test]
+  fn hash_and_verify() {
+    let hash = password::hash("Secret123", "salt");
+    assert!(hash.starts_with("bcrypt-sha3$"));
+    assert!(!password::needs_rehash(&hash));
+    assert!(password::verify("Secret123", "salt", &hash));
+    assert!(!password::verify("Secret123", "pepper", &hash));
+    assert!(!password::verify("Secret124", "salt", &hash));
+  }
+
+  #[test]
+  fn long_passwords() {
+    let password = "a".repeat(80);
+    let hash = password::hash(&password, "salt");
+    assert!(password::verify(&password, "salt", &hash));
+    assert!(!password::verify(&"a".repeat(81), "salt", &hash));
+    assert!(!password::verify(&password, "pepper", &hash));
+  }
+
+  #[test]
+  fn verify_legacy() {
+    let legacy_hash = sha3::hash(&["Secret123", "salt"]);
+    assert!(password::needs_rehash(&legacy_hash));
+    assert!(password::verify("Secret123", "salt", &legacy_hash));
+    assert!(!password::verify("Secret124", "salt", &legacy_hash));
+    assert!(!password::verify("Secret123", "salt", &legacy_hash[1..]));
+  }
+}
\ No newline at end of file
@@ -6,4 +6,8 @@ pub fn hash(input: &[&str]) -> String {
 
 pub fn hash_one(input: &str) -> String {
   bc::hash(input, bc::DEFAULT_COST).unwrap()
+}
+
+pub fn verify(input: &[&str], hash: &str) -> bool {
+  bc::verify(input.concat(), hash).unwrap_or(false)
 }
\ No newline at end of file
@@ -1,4 +1,5 @@
 pub mod sha3;
 pub mod random;
 pub mod strformat;
-pub mod bcrypt;
\ No newline at end of file
+pub mod bcrypt;
+pub mod password;
\ No newline at end of file
@@ -0,0 +1,26 @@
+use crate::{bcrypt, sha3};
+
+// Stored passwords are prefixed by the scheme that produced them.
+// Hashes without a known prefix are legacy salted SHA3 hashes.
+// Bcrypt only considers the first 72 bytes, hence password and salt are hashed with SHA3 first.
+const BCRYPT_SHA3_PREFIX: &str = "bcrypt-sha3$";
+
+pub fn hash(password: &str, salt: &str) -> String {
+  format!("{}{}", BCRYPT_SHA3_PREFIX, bcrypt::hash_one(&sha3::hash_256(&[password, salt])))
+}
+
+pub fn verify(password: &str, salt: &str, stored_hash: &str) -> bool {
+  if stored_hash.starts_with(BCRYPT_SHA3_PREFIX) {
+    return bcrypt::verify(&[&sha3::hash_256(&[password, salt])], &stored_hash[BCRYPT_SHA3_PREFIX.len()..]);
+  }
+  constant_time_eq(sha3::hash(&[password, salt]).as_bytes(), stored_hash.as_bytes())
+}
+
+pub fn needs_rehash(stored_hash: &str) -> bool {
+  !stored_hash.starts_with(BCRYPT_SHA3_PREFIX)
+}
+
+// Does not stop at the first difference, so that the comparison does not leak how much of the hash matched
+fn constant_time_eq(left: &[u8], right: &[u8]) -> bool {
+  left.len() == right.len() && left.iter().zip(right.iter()).fold(0, |diff, (l, r)| diff | (l ^ r)) == 0
+}
\ No newline at end of file
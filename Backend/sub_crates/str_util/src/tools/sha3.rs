@@ -1,8 +1,16 @@
-use sha::{Digest, Sha3_512};
+use sha::{Digest, Sha3_256, Sha3_512};
 
 pub fn hash(input: &[&str]) -> String
 {
   let mut hasher = Sha3_512::new();
   hasher.input(input.concat());
   format!("{:x}", hasher.result())
+}
+
+// Fits into the 72 bytes that bcrypt considers
+pub fn hash_256(input: &[&str]) -> String
+{
+  let mut hasher = Sha3_256::new();
+  hasher.input(input.concat());
+  format!("{:x}", hasher.result())
 }
\ No newline at end of file
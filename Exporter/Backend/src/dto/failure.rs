@@ -4,12 +4,13 @@ use rocket::{Request, Response};
 use rocket::response::Responder;
 use rocket::http::Status;
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub enum Failure {
   ConsentAlreadyGiven,
   NoConsentGivenYet,
   NotTheGuildMaster,
-  Database
+  Database,
+  NotTheCharacterOwner
 }
 
 impl Responder<'static> for Failure {
@@ -19,6 +20,7 @@ impl Responder<'static> for Failure {
       Failure::NoConsentGivenYet => Status::new(521, "NoConsentGivenYet"),
       Failure::NotTheGuildMaster => Status::new(522, "NotTheGuildMaster"),
       Failure::Database => Status::new(523, "Database"),
+      Failure::NotTheCharacterOwner => Status::new(524, "NotTheCharacterOwner"),
     };
     Response::build()
       .status(status)
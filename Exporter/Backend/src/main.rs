@@ -15,7 +15,7 @@ use dotenv::dotenv;
 
 use modules::ConsentManager;
 
-use crate::modules::{ArmoryExporter, TransportLayer, CharacterDto};
+use crate::modules::{ArmoryExporter, TransportLayer};
 use std::sync::mpsc;
 
 mod dto;
@@ -53,22 +53,21 @@ fn main() {
   let mut transport_layer = TransportLayer::default().init();
   let mut armory_exporter = ArmoryExporter::default().init();
 
-  let (s_char, r_char) = mpsc::channel::<(u32, CharacterDto)>();
   let (s_char_consent, r_char_consent) = mpsc::channel::<(bool, u32)>();
   let (s_guild_consent, r_guild_consent) = mpsc::channel::<(bool, u32)>();
 
   *consent_manager.sender_character_consent.get_mut().unwrap() = Some(s_char_consent.to_owned());
   *consent_manager.sender_guild_consent.get_mut().unwrap() = Some(s_guild_consent.to_owned());
-  armory_exporter.sender_character = Some(s_char.to_owned());
   transport_layer.receiver_character_consent = Some(r_char_consent);
   transport_layer.receiver_guild_consent = Some(r_guild_consent);
-  transport_layer.receiver_character = Some(r_char);
+  // The consent has to be broadcast before the transport layer decides on the first upload
+  let consent_manager = consent_manager.init();
 
   thread::spawn(move || transport_layer.run());
   thread::spawn(move || armory_exporter.run());
 
   rocket::ignite()
-    .manage(consent_manager.init())
+    .manage(consent_manager)
     .mount("/API/consent_manager/", routes![
       modules::consent_manager::transfer::character::get_characters,
       modules::consent_manager::transfer::character::give_consent,
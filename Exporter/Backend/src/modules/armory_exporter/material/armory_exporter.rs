@@ -1,18 +1,15 @@
 use std::collections::HashMap;
-use std::sync::mpsc::Sender;
 
 use mysql_connection::material::MySQLConnection;
 use mysql_connection::tools::Select;
 
 use crate::modules::armory_exporter::domain_value::MetaTalent;
-use crate::modules::CharacterDto;
 use std::env;
 
 #[derive(Debug)]
 pub struct ArmoryExporter {
   pub db_characters: MySQLConnection,
   pub db_lp_consent: MySQLConnection,
-  pub sender_character: Option<Sender<(u32, CharacterDto)>>,
   pub last_fetch_time: u64,
   pub gem_enchant_id_to_item_id: HashMap<u32, u32>,
   pub spell_id_to_meta_talent: HashMap<u32, MetaTalent>,
@@ -23,7 +20,6 @@ impl Default for ArmoryExporter {
     ArmoryExporter {
       db_characters: MySQLConnection::new_with_dns(env::var("CHARACTER_MYSQL_DNS").unwrap().as_str()),
       db_lp_consent: MySQLConnection::new_with_dns(env::var("LP_CONSENT_MYSQL_DNS").unwrap().as_str()),
-      sender_character: None,
       last_fetch_time: 0,
       gem_enchant_id_to_item_id: HashMap::new(),
       spell_id_to_meta_talent: HashMap::new(),
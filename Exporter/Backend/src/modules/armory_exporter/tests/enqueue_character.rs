@@ -0,0 +1,33 @@
+use mysql_connection::tools::{Execute, Select};
+
+use crate::modules::{ArmoryExporter, CharacterDto};
+use crate::modules::armory_exporter::tools::EnqueueCharacter;
+
+// Not a character of the fixtures, the outbox does not validate its entries
+const CHARACTER_ID: u32 = 1000099;
+
+fn pending_entries(armory_exporter: &ArmoryExporter) -> Vec<(Option<u32>, String)> {
+  armory_exporter.db_lp_consent.select_wparams("SELECT guild_id, payload FROM character_outbox WHERE character_id=:character_id AND ISNULL(dead_lettered_when)", &|mut row| {
+    let guild_id: Option<u32> = row.take(0).unwrap();
+    let payload: String = row.take(1).unwrap();
+    (guild_id, payload)
+  }, params!("character_id" => CHARACTER_ID))
+}
+
+#[test]
+fn enqueue_supersedes_pending_exports() {
+  let armory_exporter = ArmoryExporter::default();
+  let first = CharacterDto { server_uid: 1, character_history: None };
+  let second = CharacterDto { server_uid: 2, character_history: None };
+
+  assert!(armory_exporter.enqueue_character(CHARACTER_ID, Some(800001), &first));
+  assert_eq!(pending_entries(&armory_exporter), vec![(Some(800001), serde_json::to_string(&first).unwrap())]);
+
+  assert!(armory_exporter.enqueue_character(CHARACTER_ID, None, &second));
+  let pending = pending_entries(&armory_exporter);
+  assert_eq!(pending, vec![(None, serde_json::to_string(&second).unwrap())]);
+  assert_eq!(serde_json::from_str::<CharacterDto>(&pending[0].1).unwrap().server_uid, 2);
+
+  // Cleanup
+  armory_exporter.db_lp_consent.execute_wparams("DELETE FROM character_outbox WHERE character_id=:character_id", params!("character_id" => CHARACTER_ID));
+}
\ No newline at end of file
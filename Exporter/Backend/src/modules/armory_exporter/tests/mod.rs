@@ -0,0 +1 @@
+mod enqueue_character;
\ No newline at end of file
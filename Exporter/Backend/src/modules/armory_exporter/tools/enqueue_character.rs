@@ -0,0 +1,29 @@
+use crate::modules::{ArmoryExporter, CharacterDto};
+use mysql_connection::tools::StartTransaction;
+
+pub trait EnqueueCharacter {
+  fn enqueue_character(&self, character_id: u32, guild_id: Option<u32>, character: &CharacterDto) -> bool;
+}
+
+impl EnqueueCharacter for ArmoryExporter {
+  // Pending exports of this character are superseded, as only its latest state is of interest.
+  // Both happen in one transaction, so that a failed insert does not lose the pending export.
+  fn enqueue_character(&self, character_id: u32, guild_id: Option<u32>, character: &CharacterDto) -> bool {
+    let mut transaction = match self.db_lp_consent.start_transaction() {
+      Ok(transaction) => transaction,
+      Err(_) => return false
+    };
+    transaction.execute_wparams("DELETE FROM character_outbox WHERE character_id=:character_id AND ISNULL(dead_lettered_when)",
+                                params!(
+                                  "character_id" => character_id
+                                ))
+      .and_then(|()| transaction.execute_wparams("INSERT INTO character_outbox (`character_id`, `guild_id`, `payload`) VALUES (:character_id, :guild_id, :payload)",
+                                                 params!(
+                                                   "character_id" => character_id,
+                                                   "guild_id" => guild_id,
+                                                   "payload" => serde_json::to_string(character).unwrap()
+                                                 )))
+      .and_then(|()| transaction.commit())
+      .is_ok()
+  }
+}
\ No newline at end of file
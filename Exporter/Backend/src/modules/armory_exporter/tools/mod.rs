@@ -4,6 +4,7 @@ pub use self::character_item::RetrieveCharacterItems;
 pub use self::character_guild::RetrieveCharacterGuild;
 pub use self::update_meta_data::UpdateMetaData;
 pub use self::character_talent::RetrieveCharacterTalents;
+pub use self::enqueue_character::EnqueueCharacter;
 
 mod character;
 mod character_skill;
@@ -11,5 +12,6 @@ mod character_item;
 mod character_guild;
 mod update_meta_data;
 mod character_talent;
+mod enqueue_character;
 
 pub mod run;
\ No newline at end of file
@@ -1,13 +1,11 @@
-use std::collections::hash_map::DefaultHasher;
-use std::hash::{Hash, Hasher};
 use std::ops::Shr;
 use std::{thread, env};
 use std::time::Duration;
 
 use crate::modules::{ArmoryExporter, CharacterDto};
 use crate::modules::armory_exporter::domain_value::CharacterItemTable;
-use crate::modules::armory_exporter::tools::{RetrieveCharacterGuild, RetrieveCharacterItems, RetrieveCharacterSkills, RetrieveRecentOfflineCharacters, UpdateMetaData, RetrieveCharacterTalents};
-use crate::modules::transport_layer::{CharacterFacialDto, CharacterGearDto, CharacterGuildDto, CharacterHistoryDto, CharacterInfoDto, CharacterItemDto, GuildDto};
+use crate::modules::armory_exporter::tools::{EnqueueCharacter, RetrieveCharacterGuild, RetrieveCharacterItems, RetrieveCharacterSkills, RetrieveRecentOfflineCharacters, UpdateMetaData, RetrieveCharacterTalents};
+use crate::modules::transport_layer::{CharacterFacialDto, CharacterGearDto, CharacterGuildDto, CharacterHistoryDto, CharacterInfoDto, CharacterItemDto, GuildDto, get_server_uid};
 use crate::Run;
 use std::collections::HashMap;
 
@@ -18,11 +16,11 @@ impl Run for ArmoryExporter {
     loop {
       thread::sleep(sleep_duration_rate);
       println!("Exporting next batch of characters...");
+      // Everything logging out from now on is part of the next batch
+      let fetch_time = time_util::now();
       let offline_characters= self.get_recent_offline_characters();
-      if !offline_characters.is_empty() {
-        self.last_fetch_time = time_util::now();
-      }
-      offline_characters.iter().for_each(|character_table| {
+      let mut all_enqueued = true;
+      for character_table in offline_characters.iter() {
         println!("Processing {} ({})", character_table.name, character_table.character_id);
         let professions = self.get_profession_skills(character_table.character_id);
         let gear = self.get_character_items(character_table.character_id);
@@ -31,7 +29,7 @@ impl Run for ArmoryExporter {
 
         let character_title;
         if character_table.chosen_title == 0 { character_title = None; } else { character_title = Some(character_table.chosen_title as u16); }
-        let _ = self.sender_character.as_ref().unwrap().send((character_table.character_id, CharacterDto {
+        all_enqueued &= self.enqueue_character(character_table.character_id, guild.as_ref().map(|char_guild_table| char_guild_table.guild_id), &CharacterDto {
           server_uid: get_server_uid(character_table.character_id),
           character_history: Some(CharacterHistoryDto {
             character_info: CharacterInfoDto {
@@ -83,21 +81,18 @@ impl Run for ArmoryExporter {
               facial_hair: (character_table.playerbytes2 % 256 as u32) as u8,
             }),
           }),
-        }));
-      });
+        });
+      }
 
-      self.update_meta_data();
+      // Otherwise the batch is fetched again, the outbox only keeps the latest export of a character
+      if !offline_characters.is_empty() && all_enqueued {
+        self.last_fetch_time = fetch_time;
+        self.update_meta_data();
+      }
     }
   }
 }
 
-fn get_server_uid(id: u32) -> u64 {
-  let salt = env::var("UID_SALT").unwrap();
-  let mut hasher = DefaultHasher::new();
-  (id.to_string() + &salt).hash(&mut hasher);
-  hasher.finish()
-}
-
 fn get_item_slot(slot_id: u32, gear: &Vec<CharacterItemTable>, enchant_id_to_item_id: &HashMap<u32, u32>) -> Option<CharacterItemDto> {
   gear.iter().find(|item| item.slot == slot_id)
     .and_then(|char_item_table| {
@@ -10,6 +10,7 @@ use mysql_connection::tools::Select;
 pub struct ConsentManager {
   pub db_lp_consent: MySQLConnection,
   pub db_characters: MySQLConnection,
+  pub opt_in_mode: bool,
   pub character_consent: RwLock<BTreeSet<u32>>,
   pub guild_consent: RwLock<BTreeSet<u32>>,
   pub sender_character_consent: Mutex<Option<Sender<(bool, u32)>>>,
@@ -21,6 +22,7 @@ impl Default for ConsentManager {
     ConsentManager {
       db_lp_consent: MySQLConnection::new_with_dns(env::var("LP_CONSENT_MYSQL_DNS").unwrap().as_str()),
       db_characters: MySQLConnection::new_with_dns(env::var("CHARACTER_MYSQL_DNS").unwrap().as_str()),
+      opt_in_mode: env::var("OPT_IN_MODE").ok().and_then(|opt_in_mode| opt_in_mode.parse::<bool>().ok()).unwrap_or(false),
       character_consent: RwLock::new(BTreeSet::new()),
       guild_consent: RwLock::new(BTreeSet::new()),
       sender_character_consent: Mutex::new(None),
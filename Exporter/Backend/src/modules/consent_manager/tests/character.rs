@@ -1,27 +1,40 @@
-use crate::modules::ConsentManager;
+use crate::dto::Failure;
 use crate::modules::consent_manager::tools::CharacterConsent;
+use crate::modules::consent_manager::tests::{consent_manager, FIXTURE_ACCOUNT_ID, FIXTURE_OFFICER_ID, FIXTURE_OTHER_ACCOUNT_ID, FIXTURE_OTHER_MEMBER_ID};
 use mysql_connection::tools::Execute;
 
 #[test]
 fn character_consent() {
-  let consent_manager = ConsentManager::default().init();
-  let character_id = 2^30;
+  let consent_manager = consent_manager();
+  let character_id = FIXTURE_OFFICER_ID;
 
   assert!(!consent_manager.has_given_consent(character_id));
-  let result = consent_manager.give_consent(character_id);
+  let result = consent_manager.give_consent(FIXTURE_ACCOUNT_ID, character_id);
   assert!(result.is_ok());
   assert!(consent_manager.has_given_consent(character_id));
 
-  let consent_manager2 = ConsentManager::default().init();
+  let consent_manager2 = super::consent_manager();
   assert!(consent_manager2.has_given_consent(character_id));
 
-  let result2 = consent_manager.withdraw_consent(character_id);
+  let result2 = consent_manager.withdraw_consent(FIXTURE_ACCOUNT_ID, character_id);
   assert!(result2.is_ok());
   assert!(!consent_manager.has_given_consent(character_id));
 
-  let consent_manager3 = ConsentManager::default().init();
+  let consent_manager3 = super::consent_manager();
   assert!(!consent_manager3.has_given_consent(character_id));
 
   // Cleanup
   consent_manager.db_lp_consent.execute_wparams("DELETE FROM character_consent WHERE character_id=:character_id", params!("character_id" => character_id));
+  consent_manager.db_lp_consent.execute_wparams("DELETE FROM deletion_queue WHERE is_guild=0 AND entity_id=:character_id", params!("character_id" => character_id));
+}
+
+#[test]
+fn character_consent_requires_ownership() {
+  let consent_manager = consent_manager();
+
+  assert!(consent_manager.owns_character(FIXTURE_ACCOUNT_ID, FIXTURE_OFFICER_ID));
+  assert!(!consent_manager.owns_character(FIXTURE_OTHER_ACCOUNT_ID, FIXTURE_OFFICER_ID));
+  assert_eq!(consent_manager.give_consent(FIXTURE_ACCOUNT_ID, FIXTURE_OTHER_MEMBER_ID), Err(Failure::NotTheCharacterOwner));
+  assert!(!consent_manager.has_given_consent(FIXTURE_OTHER_MEMBER_ID));
+  assert_eq!(consent_manager.withdraw_consent(FIXTURE_ACCOUNT_ID, FIXTURE_OTHER_MEMBER_ID), Err(Failure::NotTheCharacterOwner));
 }
\ No newline at end of file
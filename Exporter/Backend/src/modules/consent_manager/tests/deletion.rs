@@ -0,0 +1,95 @@
+use crate::modules::ConsentManager;
+use crate::modules::consent_manager::tools::{CharacterConsent, QueueDeletion};
+use crate::modules::consent_manager::tests::{consent_manager, FIXTURE_ACCOUNT_ID, FIXTURE_GUILD_ID, FIXTURE_GUILD_MASTER_ID};
+use mysql_connection::tools::{Execute, Exists, StartTransaction};
+
+fn is_queued(consent_manager: &ConsentManager, is_guild: bool, entity_id: u32) -> bool {
+  consent_manager.db_lp_consent.exists_wparams("SELECT id FROM deletion_queue WHERE is_guild=:is_guild AND entity_id=:entity_id",
+                                               params!(
+                                                 "is_guild" => is_guild,
+                                                 "entity_id" => entity_id
+                                               ))
+}
+
+fn clear_queue(consent_manager: &ConsentManager, is_guild: bool, entity_id: u32) {
+  consent_manager.db_lp_consent.execute_wparams("DELETE FROM deletion_queue WHERE is_guild=:is_guild AND entity_id=:entity_id",
+                                                params!(
+                                                  "is_guild" => is_guild,
+                                                  "entity_id" => entity_id
+                                                ));
+}
+
+#[test]
+fn revokes_export() {
+  let mut consent_manager = consent_manager();
+  consent_manager.opt_in_mode = true;
+  assert!(consent_manager.revokes_export(true));
+  assert!(!consent_manager.revokes_export(false));
+
+  consent_manager.opt_in_mode = false;
+  assert!(!consent_manager.revokes_export(true));
+  assert!(consent_manager.revokes_export(false));
+}
+
+#[test]
+fn character_deletion_is_queued() {
+  let mut consent_manager = consent_manager();
+  let character_id = FIXTURE_GUILD_MASTER_ID;
+
+  // Opt in: Withdrawing revokes the export
+  consent_manager.opt_in_mode = true;
+  assert!(CharacterConsent::give_consent(&consent_manager, FIXTURE_ACCOUNT_ID, character_id).is_ok());
+  assert!(!is_queued(&consent_manager, false, character_id));
+  assert!(CharacterConsent::withdraw_consent(&consent_manager, FIXTURE_ACCOUNT_ID, character_id).is_ok());
+  assert!(is_queued(&consent_manager, false, character_id));
+  clear_queue(&consent_manager, false, character_id);
+
+  // Opt out: Giving consent is opting out
+  consent_manager.opt_in_mode = false;
+  assert!(CharacterConsent::give_consent(&consent_manager, FIXTURE_ACCOUNT_ID, character_id).is_ok());
+  assert!(is_queued(&consent_manager, false, character_id));
+  clear_queue(&consent_manager, false, character_id);
+  assert!(CharacterConsent::withdraw_consent(&consent_manager, FIXTURE_ACCOUNT_ID, character_id).is_ok());
+  assert!(!is_queued(&consent_manager, false, character_id));
+
+  // Cleanup
+  consent_manager.db_lp_consent.execute_wparams("DELETE FROM character_consent WHERE character_id=:character_id", params!("character_id" => character_id));
+}
+
+#[test]
+fn queue_character_deletion() {
+  let consent_manager = consent_manager();
+  let character_id = FIXTURE_GUILD_MASTER_ID + 1;
+
+  let mut transaction = consent_manager.db_lp_consent.start_transaction().unwrap();
+  assert!(consent_manager.queue_character_deletion(&mut transaction, character_id).is_ok());
+  assert!(!is_queued(&consent_manager, false, character_id));
+  assert!(transaction.commit().is_ok());
+  assert!(is_queued(&consent_manager, false, character_id));
+  assert!(!is_queued(&consent_manager, true, character_id));
+  clear_queue(&consent_manager, false, character_id);
+}
+
+#[test]
+fn rolled_back_deletion_is_not_queued() {
+  let consent_manager = consent_manager();
+  let character_id = FIXTURE_GUILD_MASTER_ID + 2;
+
+  let mut transaction = consent_manager.db_lp_consent.start_transaction().unwrap();
+  assert!(consent_manager.queue_character_deletion(&mut transaction, character_id).is_ok());
+  assert!(transaction.rollback().is_ok());
+  assert!(!is_queued(&consent_manager, false, character_id));
+}
+
+#[test]
+fn guild_deletion_is_queued() {
+  let consent_manager = consent_manager();
+  // The fixture guild is used by the guild consent tests, hence only the queue itself is checked here
+  let guild_id = FIXTURE_GUILD_ID + 1;
+  let mut transaction = consent_manager.db_lp_consent.start_transaction().unwrap();
+  assert!(consent_manager.queue_guild_deletion(&mut transaction, guild_id).is_ok());
+  assert!(transaction.commit().is_ok());
+  assert!(is_queued(&consent_manager, true, guild_id));
+  assert!(!is_queued(&consent_manager, false, guild_id));
+  clear_queue(&consent_manager, true, guild_id);
+}
\ No newline at end of file
@@ -1,33 +1,56 @@
-use crate::modules::ConsentManager;
+use crate::dto::Failure;
 use crate::modules::consent_manager::tools::GuildConsent;
+use crate::modules::consent_manager::tests::{consent_manager, FIXTURE_ACCOUNT_ID, FIXTURE_GUILD_ID, FIXTURE_GUILD_MASTER_ID, FIXTURE_OFFICER_ID, FIXTURE_OTHER_ACCOUNT_ID, FIXTURE_OTHER_MEMBER_ID};
 use mysql_connection::tools::Execute;
 
 #[test]
 fn guild_consent_happy() {
-  let consent_manager = ConsentManager::default().init();
-  let guild_id = 2^30;
-  let character_id = 2^30;
+  let consent_manager = consent_manager();
+  let guild_id = FIXTURE_GUILD_ID;
+  let character_id = FIXTURE_GUILD_MASTER_ID;
 
   assert!(!consent_manager.has_given_consent(guild_id));
-  let result = consent_manager.give_consent(guild_id, character_id);
+  let result = consent_manager.give_consent(FIXTURE_ACCOUNT_ID, guild_id, character_id);
   assert!(result.is_ok());
   assert!(consent_manager.has_given_consent(guild_id));
 
-  let consent_manager2 = ConsentManager::default().init();
+  let consent_manager2 = super::consent_manager();
   assert!(consent_manager2.has_given_consent(guild_id));
 
-  let result2 = consent_manager.withdraw_consent(guild_id, character_id);
+  let result2 = consent_manager.withdraw_consent(FIXTURE_ACCOUNT_ID, guild_id, character_id);
   assert!(result2.is_ok());
   assert!(!consent_manager.has_given_consent(guild_id));
 
-  let consent_manager3 = ConsentManager::default().init();
+  let consent_manager3 = super::consent_manager();
   assert!(!consent_manager3.has_given_consent(guild_id));
 
   // Cleanup
   consent_manager.db_lp_consent.execute_wparams("DELETE FROM guild_consent WHERE guild_id=:guild_id", params!("guild_id" => guild_id));
+  consent_manager.db_lp_consent.execute_wparams("DELETE FROM deletion_queue WHERE is_guild=1 AND entity_id=:guild_id", params!("guild_id" => guild_id));
 }
 
 #[test]
 fn guild_consent_unhappy() {
-  // TODO: Test that if the character_id is not the guild master, it should not be possible to give consent
+  let consent_manager = consent_manager();
+  let guild_id = FIXTURE_GUILD_ID;
+
+  assert!(consent_manager.is_guild_master(FIXTURE_GUILD_MASTER_ID, guild_id));
+  assert!(!consent_manager.is_guild_master(FIXTURE_OFFICER_ID, guild_id));
+  assert!(!consent_manager.is_guild_master(FIXTURE_OTHER_MEMBER_ID, guild_id));
+  assert!(!consent_manager.is_guild_master(FIXTURE_GUILD_MASTER_ID, guild_id + 1));
+
+  // Only the guild master may give consent, and only through the account owning the guild master
+  assert_eq!(consent_manager.give_consent(FIXTURE_ACCOUNT_ID, guild_id, FIXTURE_OFFICER_ID), Err(Failure::NotTheGuildMaster));
+  assert_eq!(consent_manager.give_consent(FIXTURE_OTHER_ACCOUNT_ID, guild_id, FIXTURE_GUILD_MASTER_ID), Err(Failure::NotTheCharacterOwner));
+  assert_eq!(consent_manager.give_consent(FIXTURE_OTHER_ACCOUNT_ID, guild_id, FIXTURE_OTHER_MEMBER_ID), Err(Failure::NotTheGuildMaster));
+  assert!(!consent_manager.has_given_consent(guild_id));
+
+  assert!(consent_manager.give_consent(FIXTURE_ACCOUNT_ID, guild_id, FIXTURE_GUILD_MASTER_ID).is_ok());
+  assert_eq!(consent_manager.withdraw_consent(FIXTURE_ACCOUNT_ID, guild_id, FIXTURE_OFFICER_ID), Err(Failure::NotTheGuildMaster));
+  assert_eq!(consent_manager.withdraw_consent(FIXTURE_OTHER_ACCOUNT_ID, guild_id, FIXTURE_GUILD_MASTER_ID), Err(Failure::NotTheCharacterOwner));
+  assert!(consent_manager.has_given_consent(guild_id));
+
+  // Cleanup
+  consent_manager.db_lp_consent.execute_wparams("DELETE FROM guild_consent WHERE guild_id=:guild_id", params!("guild_id" => guild_id));
+  consent_manager.db_lp_consent.execute_wparams("DELETE FROM deletion_queue WHERE is_guild=1 AND entity_id=:guild_id", params!("guild_id" => guild_id));
 }
\ No newline at end of file
@@ -1,2 +1,28 @@
+use std::sync::{mpsc, Once};
+
+use mysql_connection::tools::Execute;
+
+use crate::modules::ConsentManager;
+
 mod character;
-mod guild;
\ No newline at end of file
+mod guild;
+mod deletion;
+
+// Ids seeded by Database/fixtures/characters.sql
+const FIXTURE_ACCOUNT_ID: u32 = 900001;
+const FIXTURE_OTHER_ACCOUNT_ID: u32 = 900002;
+const FIXTURE_GUILD_MASTER_ID: u32 = 1000001;
+const FIXTURE_OFFICER_ID: u32 = 1000002;
+const FIXTURE_OTHER_MEMBER_ID: u32 = 1000003;
+const FIXTURE_GUILD_ID: u32 = 800001;
+
+static LOAD_FIXTURES: Once = Once::new();
+
+// Broadcasts are not received in the tests, the senders only have to exist
+fn consent_manager() -> ConsentManager {
+  let consent_manager = ConsentManager::default();
+  LOAD_FIXTURES.call_once(|| assert!(consent_manager.db_characters.execute_script(include_str!("../../../../../Database/fixtures/characters.sql"))));
+  *consent_manager.sender_character_consent.lock().unwrap() = Some(mpsc::channel().0);
+  *consent_manager.sender_guild_consent.lock().unwrap() = Some(mpsc::channel().0);
+  consent_manager.init()
+}
\ No newline at end of file
@@ -1,12 +1,14 @@
 use crate::dto::Failure;
 use crate::modules::ConsentManager;
-use mysql_connection::tools::Execute;
+use mysql_connection::tools::{Exists, StartTransaction};
 use crate::modules::consent_manager::tools::broadcast::BroadcastConsent;
+use crate::modules::consent_manager::tools::QueueDeletion;
 
 pub trait CharacterConsent {
   fn has_given_consent(&self, character_id: u32) -> bool;
-  fn give_consent(&self, character_id: u32) -> Result<(), Failure>;
-  fn withdraw_consent(&self, character_id: u32) -> Result<(), Failure>;
+  fn give_consent(&self, account_id: u32, character_id: u32) -> Result<(), Failure>;
+  fn withdraw_consent(&self, account_id: u32, character_id: u32) -> Result<(), Failure>;
+  fn owns_character(&self, account_id: u32, character_id: u32) -> bool;
 }
 
 impl CharacterConsent for ConsentManager {
@@ -15,38 +17,64 @@ impl CharacterConsent for ConsentManager {
     character_consent.contains(&character_id)
   }
 
-  fn give_consent(&self, character_id: u32) -> Result<(), Failure> {
-    if self.has_given_consent(character_id) {
-      return Err(Failure::ConsentAlreadyGiven);
+  fn give_consent(&self, account_id: u32, character_id: u32) -> Result<(), Failure> {
+    if !self.owns_character(account_id, character_id) {
+      return Err(Failure::NotTheCharacterOwner);
     }
 
+    // Checked under the lock, so that concurrent requests can not both pass
     let mut character_consent = self.character_consent.write().unwrap();
-    if self.db_lp_consent.execute_wparams("INSERT INTO character_consent (`character_id`) VALUES (:character_id)",
-                                          params!(
-                                            "character_id" => character_id
-                                          )) {
-      character_consent.insert(character_id);
-      self.broadcast_character(false, character_id);
-      return Ok(())
+    if character_consent.contains(&character_id) {
+      return Err(Failure::ConsentAlreadyGiven);
     }
-    Err(Failure::Database)
+
+    let mut transaction = self.db_lp_consent.start_transaction().map_err(|_| Failure::Database)?;
+    if self.revokes_export(false) {
+      self.queue_character_deletion(&mut transaction, character_id).map_err(|_| Failure::Database)?;
+    }
+    transaction.execute_wparams("INSERT INTO character_consent (`character_id`) VALUES (:character_id)",
+                                params!(
+                                  "character_id" => character_id
+                                ))
+      .and_then(|()| transaction.commit())
+      .map_err(|_| Failure::Database)?;
+    character_consent.insert(character_id);
+    self.broadcast_character(false, character_id);
+    Ok(())
   }
 
-  fn withdraw_consent(&self, character_id: u32) -> Result<(), Failure> {
-    if !self.has_given_consent(character_id) {
-      return Err(Failure::NoConsentGivenYet);
+  fn withdraw_consent(&self, account_id: u32, character_id: u32) -> Result<(), Failure> {
+    if !self.owns_character(account_id, character_id) {
+      return Err(Failure::NotTheCharacterOwner);
     }
 
+    // Checked under the lock, so that concurrent requests can not both pass
     let mut character_consent = self.character_consent.write().unwrap();
-    if self.db_lp_consent.execute_wparams("UPDATE character_consent SET consent_withdrawn_when = UNIX_TIMESTAMP() \
-                                                   WHERE id = (SELECT MIN(id) FROM character_consent WHERE ISNULL(consent_withdrawn_when) AND character_id=:character_id)",
-                                          params!(
-                                            "character_id" => character_id
-                                          )) {
-      character_consent.remove(&character_id);
-      self.broadcast_character(true, character_id);
-      return Ok(())
+    if !character_consent.contains(&character_id) {
+      return Err(Failure::NoConsentGivenYet);
+    }
+
+    let mut transaction = self.db_lp_consent.start_transaction().map_err(|_| Failure::Database)?;
+    if self.revokes_export(true) {
+      self.queue_character_deletion(&mut transaction, character_id).map_err(|_| Failure::Database)?;
     }
-    Err(Failure::Database)
+    transaction.execute_wparams("UPDATE character_consent SET consent_withdrawn_when = UNIX_TIMESTAMP() \
+                                         WHERE id = (SELECT MIN(id) FROM character_consent WHERE ISNULL(consent_withdrawn_when) AND character_id=:character_id)",
+                                params!(
+                                  "character_id" => character_id
+                                ))
+      .and_then(|()| transaction.commit())
+      .map_err(|_| Failure::Database)?;
+    character_consent.remove(&character_id);
+    self.broadcast_character(true, character_id);
+    Ok(())
+  }
+
+  fn owns_character(&self, account_id: u32, character_id: u32) -> bool {
+    self.db_characters.exists_wparams("SELECT guid FROM characters WHERE guid=:character_id AND account=:account_id",
+                                      params!(
+                                        "character_id" => character_id,
+                                        "account_id" => account_id
+                                      ))
   }
 }
\ No newline at end of file
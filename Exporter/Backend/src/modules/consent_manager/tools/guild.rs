@@ -1,12 +1,13 @@
 use crate::dto::Failure;
 use crate::modules::ConsentManager;
-use mysql_connection::tools::Execute;
+use mysql_connection::tools::{Exists, StartTransaction};
 use crate::modules::consent_manager::tools::broadcast::BroadcastConsent;
+use crate::modules::consent_manager::tools::{CharacterConsent, QueueDeletion};
 
 pub trait GuildConsent {
   fn has_given_consent(&self, guild_id: u32) -> bool;
-  fn give_consent(&self, guild_id: u32, character_id: u32) -> Result<(), Failure>;
-  fn withdraw_consent(&self, guild_id: u32, character_id: u32) -> Result<(), Failure>;
+  fn give_consent(&self, account_id: u32, guild_id: u32, character_id: u32) -> Result<(), Failure>;
+  fn withdraw_consent(&self, account_id: u32, guild_id: u32, character_id: u32) -> Result<(), Failure>;
   fn is_guild_master(&self, character_id: u32, guild_id: u32) -> bool;
 }
 
@@ -16,8 +17,14 @@ impl GuildConsent for ConsentManager {
     guild_consent.contains(&guild_id)
   }
 
-  fn give_consent(&self, guild_id: u32, character_id: u32) -> Result<(), Failure> {
-    if self.has_given_consent(guild_id) {
+  fn give_consent(&self, account_id: u32, guild_id: u32, character_id: u32) -> Result<(), Failure> {
+    if !self.owns_character(account_id, character_id) {
+      return Err(Failure::NotTheCharacterOwner);
+    }
+
+    // Checked under the lock, so that concurrent requests can not both pass
+    let mut guild_consent = self.guild_consent.write().unwrap();
+    if guild_consent.contains(&guild_id) {
       return Err(Failure::ConsentAlreadyGiven);
     }
 
@@ -25,21 +32,30 @@ impl GuildConsent for ConsentManager {
       return Err(Failure::NotTheGuildMaster);
     }
 
-    let mut guild_consent = self.guild_consent.write().unwrap();
-    if self.db_lp_consent.execute_wparams("INSERT INTO guild_consent (`guild_id`, `responsible_character_id`) VALUES (:guild_id, :character_id)",
-                                          params!(
-                                            "guild_id" => guild_id,
-                                            "character_id" => character_id
-                                          )) {
-      guild_consent.insert(guild_id);
-      self.broadcast_guild(false, guild_id);
-      return Ok(())
+    let mut transaction = self.db_lp_consent.start_transaction().map_err(|_| Failure::Database)?;
+    if self.revokes_export(false) {
+      self.queue_guild_deletion(&mut transaction, guild_id).map_err(|_| Failure::Database)?;
     }
-    Err(Failure::Database)
+    transaction.execute_wparams("INSERT INTO guild_consent (`guild_id`, `responsible_character_id`) VALUES (:guild_id, :character_id)",
+                                params!(
+                                  "guild_id" => guild_id,
+                                  "character_id" => character_id
+                                ))
+      .and_then(|()| transaction.commit())
+      .map_err(|_| Failure::Database)?;
+    guild_consent.insert(guild_id);
+    self.broadcast_guild(false, guild_id);
+    Ok(())
   }
 
-  fn withdraw_consent(&self, guild_id: u32, character_id: u32) -> Result<(), Failure> {
-    if !self.has_given_consent(guild_id) {
+  fn withdraw_consent(&self, account_id: u32, guild_id: u32, character_id: u32) -> Result<(), Failure> {
+    if !self.owns_character(account_id, character_id) {
+      return Err(Failure::NotTheCharacterOwner);
+    }
+
+    // Checked under the lock, so that concurrent requests can not both pass
+    let mut guild_consent = self.guild_consent.write().unwrap();
+    if !guild_consent.contains(&guild_id) {
       return Err(Failure::NoConsentGivenYet);
     }
 
@@ -47,21 +63,28 @@ impl GuildConsent for ConsentManager {
       return Err(Failure::NotTheGuildMaster);
     }
 
-    let mut guild_consent = self.guild_consent.write().unwrap();
-    if self.db_lp_consent.execute_wparams("UPDATE guild_consent SET consent_withdrawn_when = UNIX_TIMESTAMP() \
-                                                   WHERE id = (SELECT MIN(id) FROM guild_consent WHERE ISNULL(consent_withdrawn_when) AND guild_id=:guild_id)",
-                                          params!(
-                                            "guild_id" => guild_id
-                                          )) {
-      guild_consent.remove(&guild_id);
-      self.broadcast_guild(true, guild_id);
-      return Ok(())
+    let mut transaction = self.db_lp_consent.start_transaction().map_err(|_| Failure::Database)?;
+    if self.revokes_export(true) {
+      self.queue_guild_deletion(&mut transaction, guild_id).map_err(|_| Failure::Database)?;
     }
-    Err(Failure::Database)
+    transaction.execute_wparams("UPDATE guild_consent SET consent_withdrawn_when = UNIX_TIMESTAMP() \
+                                         WHERE id = (SELECT MIN(id) FROM guild_consent WHERE ISNULL(consent_withdrawn_when) AND guild_id=:guild_id)",
+                                params!(
+                                  "guild_id" => guild_id
+                                ))
+      .and_then(|()| transaction.commit())
+      .map_err(|_| Failure::Database)?;
+    guild_consent.remove(&guild_id);
+    self.broadcast_guild(true, guild_id);
+    Ok(())
   }
 
-  // TODO: Implement!
+  // The guild master is the guild's leader, whatever rank the leader has been given
   fn is_guild_master(&self, character_id: u32, guild_id: u32) -> bool {
-    true
+    self.db_characters.exists_wparams("SELECT guildid FROM guild WHERE guildid=:guild_id AND leaderguid=:character_id",
+                                      params!(
+                                        "character_id" => character_id,
+                                        "guild_id" => guild_id
+                                      ))
   }
 }
\ No newline at end of file
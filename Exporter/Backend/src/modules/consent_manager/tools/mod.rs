@@ -2,8 +2,10 @@ pub use self::character::CharacterConsent;
 pub use self::guild::GuildConsent;
 pub use self::broadcast::BroadcastConsent;
 pub use self::manager::ManagerFrontend;
+pub use self::queue_deletion::QueueDeletion;
 
 mod character;
 mod guild;
 mod broadcast;
-mod manager;
\ No newline at end of file
+mod manager;
+mod queue_deletion;
\ No newline at end of file
@@ -0,0 +1,31 @@
+use crate::modules::ConsentManager;
+use mysql_connection::material::{MySQLError, MySQLTransaction};
+
+pub trait QueueDeletion {
+  fn revokes_export(&self, withdrawn: bool) -> bool;
+  fn queue_character_deletion(&self, transaction: &mut MySQLTransaction, character_id: u32) -> Result<(), MySQLError>;
+  fn queue_guild_deletion(&self, transaction: &mut MySQLTransaction, guild_id: u32) -> Result<(), MySQLError>;
+}
+
+impl QueueDeletion for ConsentManager {
+  // In opt out mode, giving consent is how players opt out
+  fn revokes_export(&self, withdrawn: bool) -> bool {
+    withdrawn == self.opt_in_mode
+  }
+
+  // The transport layer removes the uploaded data, retrying until LegacyPlayers confirms it.
+  // Queued in the transaction that records the consent change, so that neither is stored without the other.
+  fn queue_character_deletion(&self, transaction: &mut MySQLTransaction, character_id: u32) -> Result<(), MySQLError> {
+    transaction.execute_wparams("INSERT INTO deletion_queue (`is_guild`, `entity_id`) VALUES (0, :character_id)",
+                                params!(
+                                  "character_id" => character_id
+                                ))
+  }
+
+  fn queue_guild_deletion(&self, transaction: &mut MySQLTransaction, guild_id: u32) -> Result<(), MySQLError> {
+    transaction.execute_wparams("INSERT INTO deletion_queue (`is_guild`, `entity_id`) VALUES (1, :guild_id)",
+                                params!(
+                                  "guild_id" => guild_id
+                                ))
+  }
+}
\ No newline at end of file
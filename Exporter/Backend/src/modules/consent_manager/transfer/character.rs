@@ -13,13 +13,13 @@ pub fn get_characters(me: State<ConsentManager>, auth: Authenticate) -> Json<Vec
 }
 
 #[post("/character/<character_id>")]
-pub fn give_consent(me: State<ConsentManager>, _auth: Authenticate, character_id: u32) -> Result<(), Failure>
+pub fn give_consent(me: State<ConsentManager>, auth: Authenticate, character_id: u32) -> Result<(), Failure>
 {
-  me.give_consent(character_id)
+  me.give_consent(auth.0, character_id)
 }
 
 #[delete("/character/<character_id>")]
-pub fn withdraw_consent(me: State<ConsentManager>, _auth: Authenticate, character_id: u32) -> Result<(), Failure>
+pub fn withdraw_consent(me: State<ConsentManager>, auth: Authenticate, character_id: u32) -> Result<(), Failure>
 {
-  me.withdraw_consent(character_id)
+  me.withdraw_consent(auth.0, character_id)
 }
\ No newline at end of file
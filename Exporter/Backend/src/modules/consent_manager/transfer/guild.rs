@@ -6,13 +6,13 @@ use crate::modules::ConsentManager;
 use crate::modules::consent_manager::guard::Authenticate;
 
 #[post("/guild/<guild_id>/<character_id>")]
-pub fn give_consent(me: State<ConsentManager>, _auth: Authenticate, guild_id: u32, character_id: u32) -> Result<(), Failure>
+pub fn give_consent(me: State<ConsentManager>, auth: Authenticate, guild_id: u32, character_id: u32) -> Result<(), Failure>
 {
-  me.give_consent(guild_id, character_id)
+  me.give_consent(auth.0, guild_id, character_id)
 }
 
 #[delete("/guild/<guild_id>/<character_id>")]
-pub fn withdraw_consent(me: State<ConsentManager>, _auth: Authenticate, guild_id: u32, character_id: u32) -> Result<(), Failure>
+pub fn withdraw_consent(me: State<ConsentManager>, auth: Authenticate, guild_id: u32, character_id: u32) -> Result<(), Failure>
 {
-  me.withdraw_consent(guild_id, character_id)
+  me.withdraw_consent(auth.0, guild_id, character_id)
 }
\ No newline at end of file
@@ -1,6 +1,6 @@
 use crate::modules::transport_layer::CharacterHistoryDto;
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CharacterDto {
   pub server_uid: u64,
   pub character_history: Option<CharacterHistoryDto>,
@@ -1,6 +1,6 @@
 use crate::modules::transport_layer::CharacterItemDto;
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CharacterGearDto {
   pub head: Option<CharacterItemDto>,
   pub neck: Option<CharacterItemDto>,
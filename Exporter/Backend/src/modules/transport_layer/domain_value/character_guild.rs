@@ -1,6 +1,6 @@
 use crate::modules::transport_layer::GuildDto;
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CharacterGuildDto {
   pub guild: GuildDto,
   pub rank: String
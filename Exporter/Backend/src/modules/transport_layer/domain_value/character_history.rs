@@ -1,6 +1,6 @@
 use crate::modules::transport_layer::{CharacterInfoDto, CharacterGuildDto, CharacterFacialDto};
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CharacterHistoryDto {
   pub character_info: CharacterInfoDto,
   pub character_name: String,
@@ -1,6 +1,6 @@
 use crate::modules::transport_layer::CharacterGearDto;
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CharacterInfoDto {
   pub gear: CharacterGearDto,
   pub hero_class_id: u8,
@@ -1,4 +1,4 @@
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CharacterItemDto {
   pub item_id: u32,
   pub random_property_id: Option<i16>,
@@ -0,0 +1,6 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ConsentDecision {
+  Export,
+  ExportWithoutGuild,
+  Skip
+}
\ No newline at end of file
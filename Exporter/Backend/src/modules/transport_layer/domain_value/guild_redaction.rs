@@ -0,0 +1,21 @@
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GuildRedaction {
+  Strip,
+  // Every anonymized character gets a guild of its own on LegacyPlayers,
+  // which is the price of its guild members not being linkable with each other
+  Anonymize
+}
+
+impl FromStr for GuildRedaction {
+  type Err = ();
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    match value.to_lowercase().as_str() {
+      "strip" => Ok(GuildRedaction::Strip),
+      "anonymize" => Ok(GuildRedaction::Anonymize),
+      _ => Err(())
+    }
+  }
+}
\ No newline at end of file
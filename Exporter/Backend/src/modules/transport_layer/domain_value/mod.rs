@@ -5,7 +5,14 @@ pub use self::character_guild::CharacterGuildDto;
 pub use self::character_history::CharacterHistoryDto;
 pub use self::character_info::CharacterInfoDto;
 pub use self::character_item::CharacterItemDto;
+pub use self::consent_decision::ConsentDecision;
 pub use self::guild::GuildDto;
+pub use self::guild_redaction::GuildRedaction;
+pub use self::outbox_entry::OutboxEntry;
+pub use self::queued_deletion::QueuedDeletion;
+pub use self::set_character_result::SetCharacterResult;
+pub use self::set_character_status::SetCharacterStatus;
+pub use self::upload_outcome::UploadOutcome;
 
 mod character_item;
 mod character;
@@ -14,4 +21,11 @@ mod character_gear;
 mod character_facial;
 mod character_guild;
 mod character_history;
-mod guild;
\ No newline at end of file
+mod guild;
+mod consent_decision;
+mod guild_redaction;
+mod queued_deletion;
+mod outbox_entry;
+mod upload_outcome;
+mod set_character_result;
+mod set_character_status;
\ No newline at end of file
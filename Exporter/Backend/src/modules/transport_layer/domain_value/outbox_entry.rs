@@ -0,0 +1,8 @@
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutboxEntry {
+  pub id: u32,
+  pub character_id: u32,
+  pub guild_id: Option<u32>,
+  pub payload: String,
+  pub attempts: u32,
+}
\ No newline at end of file
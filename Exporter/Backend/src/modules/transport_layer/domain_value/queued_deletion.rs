@@ -0,0 +1,7 @@
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueuedDeletion {
+  pub id: u32,
+  pub is_guild: bool,
+  pub entity_id: u32,
+  pub attempts: u32,
+}
\ No newline at end of file
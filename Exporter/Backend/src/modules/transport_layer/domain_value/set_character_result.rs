@@ -0,0 +1,9 @@
+use crate::modules::transport_layer::SetCharacterStatus;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SetCharacterResult {
+  pub server_uid: u64,
+  pub status: SetCharacterStatus,
+  pub code: Option<u16>,
+  pub reason: Option<String>,
+}
\ No newline at end of file
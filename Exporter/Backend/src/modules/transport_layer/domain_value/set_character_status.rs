@@ -0,0 +1,6 @@
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum SetCharacterStatus {
+  Created,
+  Unchanged,
+  Rejected
+}
\ No newline at end of file
@@ -0,0 +1,27 @@
+use crate::modules::transport_layer::{SetCharacterResult, SetCharacterStatus};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UploadOutcome {
+  Delivered,
+  Rejected,
+  Retry
+}
+
+impl UploadOutcome {
+  // 534 and 536 mean LegacyPlayers refused the character, sending it again yields the same
+  pub fn from_status(status: Option<u16>) -> Self {
+    match status {
+      Some(status) if status >= 200 && status < 300 => UploadOutcome::Delivered,
+      Some(534) | Some(536) => UploadOutcome::Rejected,
+      _ => UploadOutcome::Retry
+    }
+  }
+
+  // A rejection within a batch carries the status the single upload would have responded with
+  pub fn from_result(result: &SetCharacterResult) -> Self {
+    match result.status {
+      SetCharacterStatus::Created | SetCharacterStatus::Unchanged => UploadOutcome::Delivered,
+      SetCharacterStatus::Rejected => UploadOutcome::from_status(result.code)
+    }
+  }
+}
\ No newline at end of file
@@ -0,0 +1,60 @@
+use std::collections::BTreeSet;
+
+use crate::modules::transport_layer::{CharacterDto, ConsentDecision, GuildRedaction, get_anonymous_guild_uid};
+
+const ANONYMOUS_NAME: &str = "Anonymous";
+
+// In opt in mode the consent sets contain everyone who opted in,
+// otherwise they contain everyone who opted out.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConsentPolicy {
+  pub opt_in_mode: bool,
+  pub guild_redaction: GuildRedaction,
+  pub uid_salt: String,
+}
+
+impl ConsentPolicy {
+  pub fn new(opt_in_mode: bool, guild_redaction: GuildRedaction, uid_salt: String) -> Self {
+    ConsentPolicy {
+      opt_in_mode,
+      guild_redaction,
+      uid_salt,
+    }
+  }
+
+  pub fn has_consented(&self, consent: &BTreeSet<u32>, id: u32) -> bool {
+    consent.contains(&id) == self.opt_in_mode
+  }
+
+  pub fn evaluate(&self, character_consent: &BTreeSet<u32>, guild_consent: &BTreeSet<u32>, character_id: u32, guild_id: Option<u32>) -> ConsentDecision {
+    if !self.has_consented(character_consent, character_id) {
+      return ConsentDecision::Skip;
+    }
+
+    match guild_id {
+      Some(guild_id) if !self.has_consented(guild_consent, guild_id) => ConsentDecision::ExportWithoutGuild,
+      _ => ConsentDecision::Export
+    }
+  }
+
+  pub fn apply(&self, decision: ConsentDecision, mut character: CharacterDto) -> Option<CharacterDto> {
+    match decision {
+      ConsentDecision::Skip => None,
+      ConsentDecision::Export => Some(character),
+      ConsentDecision::ExportWithoutGuild => {
+        if let Some(character_history) = character.character_history.as_mut() {
+          match self.guild_redaction {
+            GuildRedaction::Strip => character_history.character_guild = None,
+            // The salted guild uid would still link the guild's members, hence it is replaced as well
+            GuildRedaction::Anonymize => if let Some(character_guild) = character_history.character_guild.as_mut() {
+              character_guild.guild.server_uid = get_anonymous_guild_uid(character.server_uid, &self.uid_salt);
+              character_guild.guild.name = ANONYMOUS_NAME.to_owned();
+              character_guild.rank = ANONYMOUS_NAME.to_owned();
+            }
+          }
+        }
+        Some(character)
+      }
+    }
+  }
+}
\ No newline at end of file
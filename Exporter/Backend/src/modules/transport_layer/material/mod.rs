@@ -1,3 +1,5 @@
+pub use self::consent_policy::ConsentPolicy;
 pub use self::transport_layer::TransportLayer;
 
+mod consent_policy;
 mod transport_layer;
\ No newline at end of file
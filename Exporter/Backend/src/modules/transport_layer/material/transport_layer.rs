@@ -1,16 +1,16 @@
 use std::collections::BTreeSet;
+use std::env;
 use std::sync::mpsc::Receiver;
+use mysql_connection::material::MySQLConnection;
 use reqwest::blocking::Client;
 
-use crate::modules::CharacterDto;
-
 #[derive(Debug)]
 pub struct TransportLayer {
   pub client: Client,
+  pub db_lp_consent: MySQLConnection,
   pub character_consent: BTreeSet<u32>,
   pub guild_consent: BTreeSet<u32>,
 
-  pub receiver_character: Option<Receiver<(u32, CharacterDto)>>,
   pub receiver_character_consent: Option<Receiver<(bool, u32)>>,
   pub receiver_guild_consent: Option<Receiver<(bool, u32)>>,
 }
@@ -19,10 +19,10 @@ impl Default for TransportLayer {
   fn default() -> Self {
     TransportLayer {
       client: Client::new(),
+      db_lp_consent: MySQLConnection::new_with_dns(env::var("LP_CONSENT_MYSQL_DNS").unwrap().as_str()),
       character_consent: BTreeSet::new(),
       guild_consent: BTreeSet::new(),
 
-      receiver_character: None,
       receiver_character_consent: None,
       receiver_guild_consent: None,
     }
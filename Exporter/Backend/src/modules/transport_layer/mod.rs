@@ -1,5 +1,6 @@
-pub use self::material::TransportLayer;
+pub use self::material::{TransportLayer, ConsentPolicy};
 pub use self::domain_value::*;
+pub use self::tools::{get_anonymous_guild_uid, get_server_uid};
 
 #[cfg(test)]
 mod tests;
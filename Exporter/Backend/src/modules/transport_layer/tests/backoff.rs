@@ -0,0 +1,12 @@
+use crate::modules::transport_layer::tools::backoff;
+
+#[test]
+fn backoff_doubles_until_capped() {
+  assert_eq!(backoff(0), 10);
+  assert_eq!(backoff(1), 10);
+  assert_eq!(backoff(2), 20);
+  assert_eq!(backoff(3), 40);
+  assert_eq!(backoff(9), 2560);
+  assert_eq!(backoff(10), 3600);
+  assert_eq!(backoff(u32::max_value()), 3600);
+}
\ No newline at end of file
@@ -0,0 +1,162 @@
+use std::collections::BTreeSet;
+
+use crate::modules::transport_layer::{CharacterDto, CharacterFacialDto, CharacterGearDto, CharacterGuildDto, CharacterHistoryDto, CharacterInfoDto, ConsentDecision, ConsentPolicy, GuildDto, GuildRedaction};
+
+const CHARACTER_ID: u32 = 1;
+const GUILD_ID: u32 = 2;
+const UID_SALT: &str = "SomeSalt";
+
+fn consent_set(listed: bool, id: u32) -> BTreeSet<u32> {
+  let mut consent = BTreeSet::new();
+  if listed {
+    consent.insert(id);
+  }
+  consent
+}
+
+fn character(with_guild: bool) -> CharacterDto {
+  let gear = CharacterGearDto {
+    head: None, neck: None, shoulder: None, back: None, chest: None, shirt: None, tabard: None, wrist: None, main_hand: None, off_hand: None,
+    ternary_hand: None, glove: None, belt: None, leg: None, boot: None, ring1: None, ring2: None, trinket1: None, trinket2: None,
+  };
+  CharacterDto {
+    server_uid: 42,
+    character_history: Some(CharacterHistoryDto {
+      character_info: CharacterInfoDto {
+        gear,
+        hero_class_id: 1,
+        level: 70,
+        gender: false,
+        profession1: None,
+        profession2: None,
+        talent_specialization: None,
+        race_id: 1,
+      },
+      character_name: "Testchar".to_owned(),
+      character_guild: if with_guild {
+        Some(CharacterGuildDto {
+          guild: GuildDto {
+            server_uid: 1337,
+            name: "Testguild".to_owned(),
+          },
+          rank: "Officer".to_owned(),
+        })
+      } else { None },
+      character_title: None,
+      profession_skill_points1: None,
+      profession_skill_points2: None,
+      facial: Some(CharacterFacialDto {
+        skin_color: 0,
+        face_style: 0,
+        hair_style: 0,
+        hair_color: 0,
+        facial_hair: 0,
+      }),
+    }),
+  }
+}
+
+#[test]
+fn decision_matrix() {
+  // (opt_in_mode, character listed, guild listed (None => guildless), expected decision)
+  let matrix = vec![
+    (true, true, None, ConsentDecision::Export),
+    (true, true, Some(true), ConsentDecision::Export),
+    (true, true, Some(false), ConsentDecision::ExportWithoutGuild),
+    (true, false, None, ConsentDecision::Skip),
+    (true, false, Some(true), ConsentDecision::Skip),
+    (true, false, Some(false), ConsentDecision::Skip),
+    (false, false, None, ConsentDecision::Export),
+    (false, false, Some(false), ConsentDecision::Export),
+    (false, false, Some(true), ConsentDecision::ExportWithoutGuild),
+    (false, true, None, ConsentDecision::Skip),
+    (false, true, Some(false), ConsentDecision::Skip),
+    (false, true, Some(true), ConsentDecision::Skip),
+  ];
+
+  for (opt_in_mode, character_listed, guild_listed, expected) in matrix {
+    let consent_policy = ConsentPolicy::new(opt_in_mode, GuildRedaction::Strip, UID_SALT.to_owned());
+    let character_consent = consent_set(character_listed, CHARACTER_ID);
+    let guild_consent = consent_set(guild_listed.unwrap_or(false), GUILD_ID);
+    let guild_id = guild_listed.map(|_| GUILD_ID);
+
+    let decision = consent_policy.evaluate(&character_consent, &guild_consent, CHARACTER_ID, guild_id);
+    assert_eq!(decision, expected, "opt_in_mode: {}, character listed: {}, guild listed: {:?}", opt_in_mode, character_listed, guild_listed);
+  }
+}
+
+#[test]
+fn consent_of_other_ids_is_ignored() {
+  let consent_policy = ConsentPolicy::new(true, GuildRedaction::Strip, UID_SALT.to_owned());
+  let character_consent = consent_set(true, CHARACTER_ID + 1);
+  let guild_consent = consent_set(true, CHARACTER_ID);
+
+  assert_eq!(consent_policy.evaluate(&character_consent, &guild_consent, CHARACTER_ID, None), ConsentDecision::Skip);
+
+  let character_consent = consent_set(true, CHARACTER_ID);
+  assert_eq!(consent_policy.evaluate(&character_consent, &guild_consent, CHARACTER_ID, Some(GUILD_ID)), ConsentDecision::ExportWithoutGuild);
+}
+
+#[test]
+fn apply_skip() {
+  let consent_policy = ConsentPolicy::new(true, GuildRedaction::Strip, UID_SALT.to_owned());
+  assert!(consent_policy.apply(ConsentDecision::Skip, character(true)).is_none());
+}
+
+#[test]
+fn apply_export() {
+  let consent_policy = ConsentPolicy::new(true, GuildRedaction::Strip, UID_SALT.to_owned());
+  let character = consent_policy.apply(ConsentDecision::Export, character(true)).unwrap();
+  let character_guild = character.character_history.unwrap().character_guild.unwrap();
+  assert_eq!(character_guild.guild.server_uid, 1337);
+  assert_eq!(character_guild.guild.name, "Testguild");
+  assert_eq!(character_guild.rank, "Officer");
+}
+
+#[test]
+fn apply_strip_guild() {
+  let consent_policy = ConsentPolicy::new(true, GuildRedaction::Strip, UID_SALT.to_owned());
+  let character = consent_policy.apply(ConsentDecision::ExportWithoutGuild, character(true)).unwrap();
+  assert_eq!(character.server_uid, 42);
+  let character_history = character.character_history.unwrap();
+  assert_eq!(character_history.character_name, "Testchar");
+  assert!(character_history.character_guild.is_none());
+}
+
+#[test]
+fn apply_anonymize_guild() {
+  let consent_policy = ConsentPolicy::new(false, GuildRedaction::Anonymize, UID_SALT.to_owned());
+  let character = consent_policy.apply(ConsentDecision::ExportWithoutGuild, character(true)).unwrap();
+  let character_guild = character.character_history.unwrap().character_guild.unwrap();
+  assert_ne!(character_guild.guild.server_uid, 1337);
+  assert_ne!(character_guild.guild.name, "Testguild");
+  assert!(!character_guild.guild.name.is_empty());
+  assert_ne!(character_guild.rank, "Officer");
+  assert!(!character_guild.rank.is_empty());
+}
+
+#[test]
+fn anonymized_guild_members_are_not_linked() {
+  let consent_policy = ConsentPolicy::new(false, GuildRedaction::Anonymize, UID_SALT.to_owned());
+  let anonymized_guild_uid = |character_uid: u64| {
+    let mut member = character(true);
+    member.server_uid = character_uid;
+    consent_policy.apply(ConsentDecision::ExportWithoutGuild, member).unwrap().character_history.unwrap().character_guild.unwrap().guild.server_uid
+  };
+  assert_eq!(anonymized_guild_uid(42), anonymized_guild_uid(42));
+  assert_ne!(anonymized_guild_uid(42), anonymized_guild_uid(43));
+}
+
+#[test]
+fn apply_anonymize_guildless() {
+  let consent_policy = ConsentPolicy::new(true, GuildRedaction::Anonymize, UID_SALT.to_owned());
+  let character = consent_policy.apply(ConsentDecision::ExportWithoutGuild, character(false)).unwrap();
+  assert!(character.character_history.unwrap().character_guild.is_none());
+}
+
+#[test]
+fn parse_guild_redaction() {
+  assert_eq!("strip".parse::<GuildRedaction>(), Ok(GuildRedaction::Strip));
+  assert_eq!("Anonymize".parse::<GuildRedaction>(), Ok(GuildRedaction::Anonymize));
+  assert!("remove".parse::<GuildRedaction>().is_err());
+}
\ No newline at end of file
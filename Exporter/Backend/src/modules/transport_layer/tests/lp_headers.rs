@@ -0,0 +1,12 @@
+use crate::modules::transport_layer::tools::lp_headers;
+
+#[test]
+fn server_is_only_selected_if_configured() {
+  let headers = lp_headers("token", None);
+  assert_eq!(headers.get("X-Authorization").unwrap(), "token");
+  assert!(headers.get("X-Server").is_none());
+
+  let headers = lp_headers("token", Some(3));
+  assert_eq!(headers.get("X-Authorization").unwrap(), "token");
+  assert_eq!(headers.get("X-Server").unwrap(), "3");
+}
\ No newline at end of file
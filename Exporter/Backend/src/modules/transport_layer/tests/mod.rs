@@ -0,0 +1,4 @@
+mod backoff;
+mod consent_policy;
+mod lp_headers;
+mod upload_outcome;
\ No newline at end of file
@@ -0,0 +1,36 @@
+use crate::modules::transport_layer::{SetCharacterResult, SetCharacterStatus, UploadOutcome};
+
+#[test]
+fn upload_outcome_from_status() {
+  assert_eq!(UploadOutcome::from_status(Some(200)), UploadOutcome::Delivered);
+  assert_eq!(UploadOutcome::from_status(Some(204)), UploadOutcome::Delivered);
+  assert_eq!(UploadOutcome::from_status(Some(534)), UploadOutcome::Rejected);
+  assert_eq!(UploadOutcome::from_status(Some(536)), UploadOutcome::Rejected);
+  assert_eq!(UploadOutcome::from_status(Some(535)), UploadOutcome::Retry);
+  assert_eq!(UploadOutcome::from_status(Some(401)), UploadOutcome::Retry);
+  assert_eq!(UploadOutcome::from_status(Some(502)), UploadOutcome::Retry);
+  assert_eq!(UploadOutcome::from_status(None), UploadOutcome::Retry);
+}
+
+#[test]
+fn upload_outcome_from_result() {
+  let result = |status: SetCharacterStatus, code: Option<u16>| SetCharacterResult {
+    server_uid: 42,
+    status,
+    code,
+    reason: code.map(|_| "Implausible input!".to_owned()),
+  };
+  assert_eq!(UploadOutcome::from_result(&result(SetCharacterStatus::Created, None)), UploadOutcome::Delivered);
+  assert_eq!(UploadOutcome::from_result(&result(SetCharacterStatus::Unchanged, None)), UploadOutcome::Delivered);
+  assert_eq!(UploadOutcome::from_result(&result(SetCharacterStatus::Rejected, Some(534))), UploadOutcome::Rejected);
+  assert_eq!(UploadOutcome::from_result(&result(SetCharacterStatus::Rejected, Some(536))), UploadOutcome::Rejected);
+  assert_eq!(UploadOutcome::from_result(&result(SetCharacterStatus::Rejected, Some(535))), UploadOutcome::Retry);
+  assert_eq!(UploadOutcome::from_result(&result(SetCharacterStatus::Rejected, None)), UploadOutcome::Retry);
+}
+
+#[test]
+fn set_character_results_deserialize() {
+  let results: Vec<SetCharacterResult> = serde_json::from_str(r#"[{"server_uid":1,"status":"Created","code":null,"reason":null},{"server_uid":2,"status":"Rejected","code":536,"reason":"Implausible input!"}]"#).unwrap();
+  assert_eq!(results[0].status, SetCharacterStatus::Created);
+  assert_eq!(UploadOutcome::from_result(&results[1]), UploadOutcome::Rejected);
+}
\ No newline at end of file
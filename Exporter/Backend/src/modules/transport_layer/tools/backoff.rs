@@ -0,0 +1,10 @@
+use std::cmp;
+
+const INITIAL_BACKOFF_IN_SEC: u64 = 10;
+const MAX_BACKOFF_IN_SEC: u64 = 3600;
+
+// Seconds to wait before the next attempt, doubling with every failed attempt
+pub fn backoff(attempts: u32) -> u64 {
+  let exponent = cmp::min(attempts.saturating_sub(1), 16);
+  cmp::min(INITIAL_BACKOFF_IN_SEC << exponent, MAX_BACKOFF_IN_SEC)
+}
\ No newline at end of file
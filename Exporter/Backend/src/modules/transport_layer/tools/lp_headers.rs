@@ -0,0 +1,11 @@
+use reqwest::header::{HeaderMap, HeaderValue};
+
+// Members owning several servers have to tell LegacyPlayers which one is meant
+pub fn lp_headers(api_token: &str, server_id: Option<u32>) -> HeaderMap {
+  let mut headers = HeaderMap::new();
+  headers.insert("X-Authorization", HeaderValue::from_str(api_token).unwrap());
+  if let Some(server_id) = server_id {
+    headers.insert("X-Server", HeaderValue::from(server_id));
+  }
+  headers
+}
\ No newline at end of file
@@ -1,4 +1,14 @@
+pub use self::backoff::backoff;
+pub use self::lp_headers::lp_headers;
+pub use self::process_deletions::ProcessDeletions;
+pub use self::process_outbox::ProcessOutbox;
 pub use self::receive_consent::ReceiveConsent;
+pub use self::server_uid::{get_anonymous_guild_uid, get_server_uid};
 
+mod backoff;
+mod lp_headers;
+mod process_deletions;
+mod process_outbox;
 mod receive_consent;
+mod server_uid;
 pub mod run;
\ No newline at end of file
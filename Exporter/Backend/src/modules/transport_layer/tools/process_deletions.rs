@@ -0,0 +1,58 @@
+use mysql_connection::tools::{Execute, Select, TakeColumn};
+use reqwest::header::HeaderMap;
+
+use crate::modules::TransportLayer;
+use crate::modules::transport_layer::QueuedDeletion;
+use crate::modules::transport_layer::tools::{backoff, get_server_uid};
+
+const DELETIONS_PER_RUN: u32 = 10;
+
+pub trait ProcessDeletions {
+  fn process_deletions(&self, lp_headers: &HeaderMap, url_delete_character: &str, url_delete_guild: &str);
+}
+
+impl ProcessDeletions for TransportLayer {
+  fn process_deletions(&self, lp_headers: &HeaderMap, url_delete_character: &str, url_delete_guild: &str) {
+    let now = time_util::now();
+    let deletions = match self.db_lp_consent.try_select_wparams("SELECT id, is_guild, entity_id, attempts FROM deletion_queue WHERE next_attempt_when <= :now ORDER BY id LIMIT :limit", &|mut row| {
+      Ok(QueuedDeletion {
+        id: row.take_column(0)?,
+        is_guild: row.take_column::<u8>(1)? == 1,
+        entity_id: row.take_column(2)?,
+        attempts: row.take_column(3)?,
+      })
+    }, params!(
+      "now" => now,
+      "limit" => DELETIONS_PER_RUN
+    )) {
+      Ok(deletions) => deletions,
+      // The queue is read again on the next run
+      Err(err) => {
+        println!("Failed to read the deletion queue: {}", err);
+        return;
+      }
+    };
+
+    for deletion in deletions {
+      let url = format!("{}/{}", if deletion.is_guild { url_delete_guild } else { url_delete_character }, get_server_uid(deletion.entity_id));
+      let response = self.client
+        .delete(&url)
+        .headers(lp_headers.clone())
+        .send();
+      // 534: LegacyPlayers does not know this uid, hence there is nothing left to delete
+      let deleted = response.as_ref().map(|response| response.status().is_success() || response.status().as_u16() == 534).unwrap_or(false);
+      println!("Deletion of {} {} => {:?}", if deletion.is_guild { "guild" } else { "character" }, deletion.entity_id, deleted);
+
+      // Deletions are never given up, the delay between the attempts is only capped
+      if deleted {
+        self.db_lp_consent.execute_wparams("DELETE FROM deletion_queue WHERE id=:id", params!("id" => deletion.id));
+      } else {
+        self.db_lp_consent.execute_wparams("UPDATE deletion_queue SET attempts=:attempts, next_attempt_when=:next_attempt_when WHERE id=:id", params!(
+          "id" => deletion.id,
+          "attempts" => deletion.attempts + 1,
+          "next_attempt_when" => now + backoff(deletion.attempts + 1)
+        ));
+      }
+    }
+  }
+}
\ No newline at end of file
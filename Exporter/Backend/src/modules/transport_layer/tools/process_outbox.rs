@@ -0,0 +1,133 @@
+use std::thread;
+use std::time::Duration;
+
+use mysql_connection::tools::{Execute, Select};
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
+
+use crate::modules::{CharacterDto, TransportLayer};
+use crate::modules::transport_layer::{ConsentPolicy, OutboxEntry, SetCharacterResult, UploadOutcome};
+use crate::modules::transport_layer::tools::backoff;
+
+// LegacyPlayers accepts up to 100 characters per batch
+const OUTBOX_ENTRIES_PER_RUN: u32 = 50;
+const MAX_UPLOAD_ATTEMPTS: u32 = 10;
+const MAX_ERROR_LENGTH: usize = 255;
+
+pub trait ProcessOutbox {
+  fn process_outbox(&self, consent_policy: &ConsentPolicy, lp_headers: &HeaderMap, url_set_characters: &str, sleep_duration_rate: Duration) -> usize;
+  fn settle(&self, entry: &OutboxEntry, outcome: UploadOutcome, status: Option<u16>, error: &str, now: u64);
+  fn remove_from_outbox(&self, entry: &OutboxEntry);
+  fn reschedule(&self, entry: &OutboxEntry, status: Option<u16>, error: &str, next_attempt_when: u64);
+  fn dead_letter(&self, entry: &OutboxEntry, status: Option<u16>, error: &str);
+}
+
+impl ProcessOutbox for TransportLayer {
+  // Returns the number of entries that were due
+  fn process_outbox(&self, consent_policy: &ConsentPolicy, lp_headers: &HeaderMap, url_set_characters: &str, sleep_duration_rate: Duration) -> usize {
+    let now = time_util::now();
+    let entries = self.db_lp_consent.select_wparams("SELECT id, character_id, guild_id, payload, attempts FROM character_outbox \
+      WHERE ISNULL(dead_lettered_when) AND next_attempt_when <= :now ORDER BY id LIMIT :limit", &|mut row| {
+      OutboxEntry {
+        id: row.take(0).unwrap(),
+        character_id: row.take(1).unwrap(),
+        guild_id: row.take(2).unwrap(),
+        payload: row.take(3).unwrap(),
+        attempts: row.take(4).unwrap(),
+      }
+    }, params!(
+      "now" => now,
+      "limit" => OUTBOX_ENTRIES_PER_RUN
+    ));
+
+    let mut batch = Vec::with_capacity(entries.len());
+    for entry in entries.iter() {
+      let character = match serde_json::from_str::<CharacterDto>(&entry.payload) {
+        Ok(character) => character,
+        Err(err) => {
+          self.dead_letter(entry, None, &err.to_string());
+          continue;
+        }
+      };
+
+      // Consent is evaluated when sending, as it may have changed while the entry was waiting
+      let decision = consent_policy.evaluate(&self.character_consent, &self.guild_consent, entry.character_id, entry.guild_id);
+      let server_uid = character.server_uid;
+      match consent_policy.apply(decision, character) {
+        Some(character) => batch.push((entry, character)),
+        None => {
+          println!("Character {} ({}) has not given consent, skipping!", entry.character_id, server_uid);
+          self.remove_from_outbox(entry);
+        }
+      }
+    }
+    if batch.is_empty() {
+      return entries.len();
+    }
+
+    thread::sleep(sleep_duration_rate);
+    let characters: Vec<&CharacterDto> = batch.iter().map(|(_, character)| character).collect();
+    let response = self.client
+      .post(url_set_characters)
+      .headers(lp_headers.clone())
+      .header(CONTENT_TYPE, HeaderValue::from_static("application/json"))
+      .body(serde_json::to_string(&characters).unwrap())
+      .send();
+    let (status, body) = match response {
+      Ok(response) => (Some(response.status().as_u16()), response.text().unwrap_or_default()),
+      Err(err) => (None, err.to_string())
+    };
+    println!("Upload of {} characters => {:?}", batch.len(), status);
+
+    // The results are in the order of the characters sent, anything else fails the batch as a whole
+    let results = match UploadOutcome::from_status(status) {
+      UploadOutcome::Delivered => serde_json::from_str::<Vec<SetCharacterResult>>(&body).ok()
+        .filter(|results| results.len() == batch.len()
+          && results.iter().zip(batch.iter()).all(|(result, (_, character))| result.server_uid == character.server_uid)),
+      _ => None
+    };
+    match results {
+      Some(results) => for ((entry, _), result) in batch.iter().zip(results.iter()) {
+        let outcome = UploadOutcome::from_result(result);
+        self.settle(entry, outcome, result.code.or(status), result.reason.as_ref().map(String::as_str).unwrap_or_default(), now);
+      },
+      None => for (entry, _) in batch.iter() {
+        self.settle(entry, UploadOutcome::Retry, status, &body, now);
+      }
+    }
+    entries.len()
+  }
+
+  fn settle(&self, entry: &OutboxEntry, outcome: UploadOutcome, status: Option<u16>, error: &str, now: u64) {
+    match outcome {
+      UploadOutcome::Delivered => self.remove_from_outbox(entry),
+      UploadOutcome::Rejected => self.dead_letter(entry, status, error),
+      UploadOutcome::Retry if entry.attempts + 1 >= MAX_UPLOAD_ATTEMPTS => self.dead_letter(entry, status, error),
+      UploadOutcome::Retry => self.reschedule(entry, status, error, now + backoff(entry.attempts + 1))
+    }
+  }
+
+  fn remove_from_outbox(&self, entry: &OutboxEntry) {
+    self.db_lp_consent.execute_wparams("DELETE FROM character_outbox WHERE id=:id", params!("id" => entry.id));
+  }
+
+  fn reschedule(&self, entry: &OutboxEntry, status: Option<u16>, error: &str, next_attempt_when: u64) {
+    self.db_lp_consent.execute_wparams("UPDATE character_outbox SET attempts=:attempts, next_attempt_when=:next_attempt_when, last_status=:last_status, last_error=:last_error WHERE id=:id", params!(
+      "id" => entry.id,
+      "attempts" => entry.attempts + 1,
+      "next_attempt_when" => next_attempt_when,
+      "last_status" => status,
+      "last_error" => error.chars().take(MAX_ERROR_LENGTH).collect::<String>()
+    ));
+  }
+
+  // Dead letters are kept for inspection, they are retried once dead_lettered_when is reset
+  fn dead_letter(&self, entry: &OutboxEntry, status: Option<u16>, error: &str) {
+    println!("Giving up on outbox entry {} of character {}: {:?} {}", entry.id, entry.character_id, status, error);
+    self.db_lp_consent.execute_wparams("UPDATE character_outbox SET attempts=:attempts, dead_lettered_when=UNIX_TIMESTAMP(), last_status=:last_status, last_error=:last_error WHERE id=:id", params!(
+      "id" => entry.id,
+      "attempts" => entry.attempts + 1,
+      "last_status" => status,
+      "last_error" => error.chars().take(MAX_ERROR_LENGTH).collect::<String>()
+    ));
+  }
+}
\ No newline at end of file
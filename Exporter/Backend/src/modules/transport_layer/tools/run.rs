@@ -1,43 +1,43 @@
 use std::{thread, env};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
-use crate::modules::transport_layer::tools::ReceiveConsent;
+use crate::modules::transport_layer::tools::{lp_headers, ProcessDeletions, ProcessOutbox, ReceiveConsent};
 use crate::modules::TransportLayer;
+use crate::modules::transport_layer::{ConsentPolicy, GuildRedaction};
 use crate::Run;
-use reqwest::header::{HeaderValue, CONTENT_TYPE};
+
+const DELETION_INTERVAL_IN_SEC: u64 = 10;
 
 impl Run for TransportLayer {
   fn run(&mut self) {
     let rate = env::var("REQUESTS_TO_LP_PER_SECOND").unwrap().parse::<f64>().unwrap();
-    let api_token = env::var("LP_API_TOKEN").unwrap();
-    let url_set_character = env::var("URL_SET_CHARACTER").unwrap();
+    let server_id = env::var("LP_SERVER_ID").ok()
+      .filter(|value| !value.is_empty())
+      .map(|value| value.parse::<u32>().unwrap());
+    let lp_headers = lp_headers(&env::var("LP_API_TOKEN").unwrap(), server_id);
+    let url_set_characters = env::var("URL_SET_CHARACTERS").unwrap();
+    let url_delete_character = env::var("URL_DELETE_CHARACTER").unwrap();
+    let url_delete_guild = env::var("URL_DELETE_GUILD").unwrap();
     let opt_in_mode = env::var("OPT_IN_MODE").unwrap().parse::<bool>().unwrap();
+    let guild_redaction = env::var("GUILD_WITHOUT_CONSENT").ok()
+      .and_then(|value| value.parse::<GuildRedaction>().ok())
+      .unwrap_or(GuildRedaction::Strip);
+    let consent_policy = ConsentPolicy::new(opt_in_mode, guild_redaction, env::var("UID_SALT").unwrap());
 
     let sleep_duration_rate = Duration::new(0, (1000000000.0 as f64 * (1.0 / rate)).ceil() as u32);
     let sleep_duration_wait = Duration::new(1, 0);
+    let deletion_interval = Duration::new(DELETION_INTERVAL_IN_SEC, 0);
+    let mut last_deletion_run: Option<Instant> = None;
     loop {
-      thread::sleep(sleep_duration_rate);
-
       self.receive_character_consent();
       self.receive_guild_consent();
 
-      let receiver = self.receiver_character.as_ref().unwrap();
-      let received_res = receiver.try_recv();
-      if received_res.is_ok() {
-        let received = received_res.unwrap();
-        if (opt_in_mode && !self.character_consent.contains(&received.0)) || (!opt_in_mode && self.character_consent.contains(&received.0)) {
-          println!("{} ({}) has not given consent, skipping!", received.1.character_history.unwrap().character_name, received.1.server_uid);
-          continue;
-        }
+      if last_deletion_run.map(|last_run| last_run.elapsed() >= deletion_interval).unwrap_or(true) {
+        self.process_deletions(&lp_headers, &url_delete_character, &url_delete_guild);
+        last_deletion_run = Some(Instant::now());
+      }
 
-        let response = self.client
-          .post(&url_set_character)
-          .header("X-Authorization", HeaderValue::from_str(api_token.as_str()).unwrap())
-          .header(CONTENT_TYPE, HeaderValue::from_static("application/json"))
-          .body(serde_json::to_string(&received.1).unwrap())
-          .send();
-        println!("Response okay => {:?} for {} ({})", response.is_ok(), received.1.character_history.unwrap().character_name, received.1.server_uid);
-      } else {
+      if self.process_outbox(&consent_policy, &lp_headers, &url_set_characters, sleep_duration_rate) == 0 {
         thread::sleep(sleep_duration_wait);
       }
     }
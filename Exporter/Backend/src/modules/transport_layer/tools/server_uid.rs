@@ -0,0 +1,20 @@
+use std::collections::hash_map::DefaultHasher;
+use std::env;
+use std::hash::{Hash, Hasher};
+
+pub fn get_server_uid(id: u32) -> u64 {
+  salted_hash(&id.to_string(), &env::var("UID_SALT").unwrap())
+}
+
+// Stands in for the uid of a guild without consent. It is derived from the character instead of the guild,
+// so that the anonymized members of a guild cannot be linked with each other. The prefix keeps the hashed
+// value apart from the decimal ids hashed by get_server_uid.
+pub fn get_anonymous_guild_uid(character_uid: u64, salt: &str) -> u64 {
+  salted_hash(&format!("anonymous_guild:{}", character_uid), salt)
+}
+
+fn salted_hash(value: &str, salt: &str) -> u64 {
+  let mut hasher = DefaultHasher::new();
+  (value.to_owned() + salt).hash(&mut hasher);
+  hasher.finish()
+}
\ No newline at end of file
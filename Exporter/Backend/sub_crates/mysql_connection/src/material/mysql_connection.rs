@@ -4,6 +4,8 @@ use std::env;
 
 use dotenv::dotenv;
 
+use crate::material::MySQLError;
+
 #[derive(Debug)]
 pub struct MySQLConnection {
   pub con: mysql::Pool
@@ -23,4 +25,8 @@ impl MySQLConnection {
       con: mysql::Pool::new(dns).unwrap()
     }
   }
+
+  pub fn get_connection(&self) -> Result<mysql::PooledConn, MySQLError> {
+    self.con.get_conn().map_err(|err| MySQLError::Connection(err.to_string()))
+  }
 }
\ No newline at end of file
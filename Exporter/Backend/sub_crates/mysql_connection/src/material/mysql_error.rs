@@ -0,0 +1,37 @@
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MySQLError {
+  Connection(String),
+  Query { query: String, reason: String },
+  Column { query: String, column: String, reason: String },
+  Transaction(String),
+}
+
+impl MySQLError {
+  pub fn column(column: &str, reason: &str) -> Self {
+    MySQLError::Column { query: String::new(), column: column.to_owned(), reason: reason.to_owned() }
+  }
+
+  // Row processors do not know the query they are working on, hence it is attached afterwards
+  pub fn with_query(self, query_str: &str) -> Self {
+    match self {
+      MySQLError::Query { reason, .. } => MySQLError::Query { query: query_str.to_owned(), reason },
+      MySQLError::Column { column, reason, .. } => MySQLError::Column { query: query_str.to_owned(), column, reason },
+      other => other
+    }
+  }
+}
+
+impl fmt::Display for MySQLError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      MySQLError::Connection(reason) => write!(f, "Connection failed: {}", reason),
+      MySQLError::Query { query, reason } => write!(f, "Query failed: {} ({})", reason, query),
+      MySQLError::Column { query, column, reason } => write!(f, "Column '{}' could not be read: {} ({})", column, reason, query),
+      MySQLError::Transaction(reason) => write!(f, "Transaction failed: {}", reason),
+    }
+  }
+}
+
+impl std::error::Error for MySQLError {}
\ No newline at end of file
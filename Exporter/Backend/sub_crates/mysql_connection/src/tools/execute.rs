@@ -6,16 +6,29 @@ use mysql::prelude::Queryable;
 pub trait Execute {
   fn execute(&self, query_str: &str) -> bool;
   fn execute_wparams(&self, query_str: &str, params: std::vec::Vec<(std::string::String, mysql::Value)>) -> bool;
+  // Runs several ';' separated statements, e.g. the content of a fixture
+  fn execute_script(&self, script: &str) -> bool;
 }
 
 impl Execute for MySQLConnection {
   fn execute(&self, query_str: &str) -> bool
   {
-    self.con.get_conn().unwrap().exec_drop(query_str, ()).is_ok()
+    self.get_connection()
+      .map(|mut conn| conn.exec_drop(query_str, ()).is_ok())
+      .unwrap_or(false)
   }
 
   fn execute_wparams(&self, query_str: &str, params: std::vec::Vec<(std::string::String, mysql::Value)>) -> bool
   {
-    self.con.get_conn().unwrap().exec_drop(query_str, params).is_ok()
+    self.get_connection()
+      .map(|mut conn| conn.exec_drop(query_str, params).is_ok())
+      .unwrap_or(false)
+  }
+
+  fn execute_script(&self, script: &str) -> bool
+  {
+    self.get_connection()
+      .map(|mut conn| conn.query_drop(script).is_ok())
+      .unwrap_or(false)
   }
 }
\ No newline at end of file
@@ -11,11 +11,11 @@ pub trait Exists {
 impl Exists for MySQLConnection {
   fn exists(&self, query_str: &str) -> bool
   {
-    self.select_value(&["SELECT EXISTS(", query_str, ")"].concat(), &|row| { mysql::from_row(row) }).unwrap()
+    self.select_value(&["SELECT EXISTS(", query_str, ")"].concat(), &|row| { mysql::from_row(row) }).unwrap_or(false)
   }
 
   fn exists_wparams(&self, query_str: &str, params: std::vec::Vec<(std::string::String, mysql::Value)>) -> bool
   {
-    self.select_wparams_value(&["SELECT EXISTS(", query_str, ")"].concat(), &|row| { mysql::from_row(row) }, params).unwrap()
+    self.select_wparams_value(&["SELECT EXISTS(", query_str, ")"].concat(), &|row| { mysql::from_row(row) }, params).unwrap_or(false)
   }
 }
\ No newline at end of file
@@ -1,7 +1,11 @@
 pub use self::execute::Execute;
 pub use self::exists::Exists;
 pub use self::select::Select;
+pub use self::take_column::TakeColumn;
+pub use self::transaction::StartTransaction;
 
 mod execute;
 mod exists;
-mod select;
\ No newline at end of file
+mod select;
+mod take_column;
+mod transaction;
\ No newline at end of file
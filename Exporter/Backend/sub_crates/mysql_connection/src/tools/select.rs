@@ -1,6 +1,6 @@
 use mysql;
 
-use crate::material::MySQLConnection;
+use crate::material::{MySQLConnection, MySQLError};
 use mysql::prelude::Queryable;
 use mysql::prelude::WithParams;
 use mysql::prelude::BinQuery;
@@ -10,17 +10,24 @@ pub trait Select {
   fn select_wparams<T>(&self, query_str: &str, process_row: &dyn Fn(mysql::Row) -> T, params: std::vec::Vec<(std::string::String, mysql::Value)>) -> Vec<T>;
   fn select_value<T>(&self, query_str: &str, process_row: &dyn Fn(mysql::Row) -> T) -> Option<T>;
   fn select_wparams_value<T>(&self, query_str: &str, process_row: &dyn Fn(mysql::Row) -> T, params: std::vec::Vec<(std::string::String, mysql::Value)>) -> Option<T>;
+
+  // Same as above, but failures are returned instead of panicking
+  fn try_select<T>(&self, query_str: &str, process_row: &dyn Fn(mysql::Row) -> Result<T, MySQLError>) -> Result<Vec<T>, MySQLError>;
+  fn try_select_wparams<T>(&self, query_str: &str, process_row: &dyn Fn(mysql::Row) -> Result<T, MySQLError>, params: std::vec::Vec<(std::string::String, mysql::Value)>) -> Result<Vec<T>, MySQLError>;
+  fn try_select_value<T>(&self, query_str: &str, process_row: &dyn Fn(mysql::Row) -> Result<T, MySQLError>) -> Result<Option<T>, MySQLError>;
+  fn try_select_wparams_value<T>(&self, query_str: &str, process_row: &dyn Fn(mysql::Row) -> Result<T, MySQLError>, params: std::vec::Vec<(std::string::String, mysql::Value)>) -> Result<Option<T>, MySQLError>;
 }
 
 impl Select for MySQLConnection {
+  // A failure is fatal here, callers that can handle it use try_select
   fn select<T>(&self, query_str: &str, process_row: &dyn Fn(mysql::Row) -> T) -> Vec<T>
   {
-    self.con.get_conn().unwrap().query_map(query_str, process_row).unwrap()
+    self.try_select(query_str, &|row| Ok(process_row(row))).unwrap_or_else(|err| panic!("{}", err))
   }
 
   fn select_wparams<T>(&self, query_str: &str, process_row: &dyn Fn(mysql::Row) -> T, params: std::vec::Vec<(std::string::String, mysql::Value)>) -> Vec<T>
   {
-    query_str.with(params).map(self.con.get_conn().unwrap(), process_row).unwrap()
+    self.try_select_wparams(query_str, &|row| Ok(process_row(row)), params).unwrap_or_else(|err| panic!("{}", err))
   }
 
   fn select_value<T>(&self, query_str: &str, process_row: &dyn Fn(mysql::Row) -> T) -> Option<T>
@@ -32,4 +39,32 @@ impl Select for MySQLConnection {
   {
     self.select_wparams(query_str, process_row, params).pop()
   }
+
+  fn try_select<T>(&self, query_str: &str, process_row: &dyn Fn(mysql::Row) -> Result<T, MySQLError>) -> Result<Vec<T>, MySQLError>
+  {
+    self.get_connection()?
+      .query_map(query_str, process_row)
+      .map_err(|err| MySQLError::Query { query: query_str.to_owned(), reason: err.to_string() })?
+      .into_iter().collect::<Result<Vec<T>, MySQLError>>()
+      .map_err(|err| err.with_query(query_str))
+  }
+
+  fn try_select_wparams<T>(&self, query_str: &str, process_row: &dyn Fn(mysql::Row) -> Result<T, MySQLError>, params: std::vec::Vec<(std::string::String, mysql::Value)>) -> Result<Vec<T>, MySQLError>
+  {
+    query_str.with(params)
+      .map(self.get_connection()?, process_row)
+      .map_err(|err| MySQLError::Query { query: query_str.to_owned(), reason: err.to_string() })?
+      .into_iter().collect::<Result<Vec<T>, MySQLError>>()
+      .map_err(|err| err.with_query(query_str))
+  }
+
+  fn try_select_value<T>(&self, query_str: &str, process_row: &dyn Fn(mysql::Row) -> Result<T, MySQLError>) -> Result<Option<T>, MySQLError>
+  {
+    self.try_select(query_str, process_row).map(|mut values| values.pop())
+  }
+
+  fn try_select_wparams_value<T>(&self, query_str: &str, process_row: &dyn Fn(mysql::Row) -> Result<T, MySQLError>, params: std::vec::Vec<(std::string::String, mysql::Value)>) -> Result<Option<T>, MySQLError>
+  {
+    self.try_select_wparams(query_str, process_row, params).map(|mut values| values.pop())
+  }
 }
\ No newline at end of file
@@ -0,0 +1,16 @@
+use mysql;
+
+use crate::material::{MySQLConnection, MySQLError, MySQLTransaction};
+
+pub trait StartTransaction {
+  fn start_transaction(&self) -> Result<MySQLTransaction, MySQLError>;
+}
+
+impl StartTransaction for MySQLConnection {
+  fn start_transaction(&self) -> Result<MySQLTransaction, MySQLError>
+  {
+    self.con.start_transaction(mysql::TxOpts::default())
+      .map(MySQLTransaction::new)
+      .map_err(|err| MySQLError::Connection(err.to_string()))
+  }
+}
\ No newline at end of file
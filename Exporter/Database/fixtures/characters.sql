@@ -0,0 +1,49 @@
+-- Minimal subset of a server's character database, used by the backend tests.
+-- The test setup loads it into the database of CHARACTER_MYSQL_DNS.
+CREATE TABLE IF NOT EXISTS `characters` (
+  `guid` INT UNSIGNED NOT NULL,
+  `account` INT UNSIGNED NOT NULL,
+  `name` VARCHAR(12) NOT NULL,
+  `class` TINYINT UNSIGNED NOT NULL,
+  PRIMARY KEY (`guid`)
+);
+
+CREATE TABLE IF NOT EXISTS `guild` (
+  `guildid` INT UNSIGNED NOT NULL,
+  `name` VARCHAR(255) NOT NULL,
+  `leaderguid` INT UNSIGNED NOT NULL,
+  PRIMARY KEY (`guildid`)
+);
+
+CREATE TABLE IF NOT EXISTS `guild_rank` (
+  `guildid` INT UNSIGNED NOT NULL,
+  `rid` TINYINT UNSIGNED NOT NULL,
+  `rname` VARCHAR(255) NOT NULL,
+  PRIMARY KEY (`guildid`, `rid`)
+);
+
+CREATE TABLE IF NOT EXISTS `guild_member` (
+  `guildid` INT UNSIGNED NOT NULL,
+  `guid` INT UNSIGNED NOT NULL,
+  `rank` TINYINT UNSIGNED NOT NULL,
+  PRIMARY KEY (`guid`)
+);
+
+-- Account 900001 owns the guild master and an officer, account 900002 a member of the same guild
+REPLACE INTO `characters` (`guid`, `account`, `name`, `class`) VALUES
+  (1000001, 900001, 'Fixturemaster', 1),
+  (1000002, 900001, 'Fixtureofficer', 8),
+  (1000003, 900002, 'Fixturemember', 4);
+
+REPLACE INTO `guild` (`guildid`, `name`, `leaderguid`) VALUES
+  (800001, 'Fixture Guild', 1000001);
+
+REPLACE INTO `guild_rank` (`guildid`, `rid`, `rname`) VALUES
+  (800001, 0, 'Guild Master'),
+  (800001, 1, 'Officer'),
+  (800001, 2, 'Member');
+
+REPLACE INTO `guild_member` (`guildid`, `guid`, `rank`) VALUES
+  (800001, 1000001, 0),
+  (800001, 1000002, 1),
+  (800001, 1000003, 2);
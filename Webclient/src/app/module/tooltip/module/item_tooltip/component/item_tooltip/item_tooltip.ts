@@ -18,10 +18,6 @@ export class ItemTooltipComponent {
         return socket.slots.every((slot) => slot.item && this.gemMatches(slot.flag, slot.item.flag));
     }
 
-    isEffectActive(items: any, threshold: number): boolean {
-        return items.filter(item => item.active).length >= threshold;
-    }
-
     getFilteredSetItems(): any {
         return this.payload.item_set.set_items
             .sort((left, right) => {
@@ -16,7 +16,7 @@ export interface ItemTooltip {
     item_set: {
         name: string,
         set_items: Array<{ item_id: number, active: boolean, name: string, item_level: number, inventory_type: number }>,
-        set_effects: Array<{ threshold: number, description: string }>
+        set_effects: Array<{ threshold: number, active: boolean, description: string }>
     } | undefined;
     socket: { socket_bonus: string, slots: Array<{ flag: number, item: { icon: string, effect: string, flag: number } | undefined }> } | undefined;
     enchant: string | undefined;